@@ -17,6 +17,8 @@
 The minimum supported Rust version for embedded-graphics is `1.40.0` or greater.
 Ensure you have the correct version of Rust installed, preferably through <https://rustup.rs>.
 
+The optional `async` feature requires Rust `1.75.0` or greater.
+
 ## Development setup
 
 Please see the [development setup guide](https://github.com/embedded-graphics/embedded-graphics/blob/master/doc/development-setup.md).
@@ -17,6 +17,8 @@
 The minimum supported Rust version for embedded-graphics-core is `1.40.0` or greater.
 Ensure you have the correct version of Rust installed, preferably through <https://rustup.rs>.
 
+The optional `async` feature requires Rust `1.75.0` or greater.
+
 ## Development setup
 
 Please see the [development setup guide](../doc/development-setup.md).
@@ -0,0 +1,85 @@
+use crate::{
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// An asynchronous target for embedded-graphics drawing operations.
+///
+/// `AsyncDrawTarget` is the asynchronous counterpart of [`DrawTarget`]. Display drivers which
+/// transfer data using DMA can implement this trait to yield to the executor while a transfer is
+/// in progress, instead of blocking until it is finished.
+///
+/// Like for [`DrawTarget`] only [`draw_iter`] and the [`Dimensions`] trait need to be
+/// implemented. All other methods provide default implementations, which should be overridden to
+/// improve performance if the hardware supports it.
+///
+/// This trait is only available if the `async` feature is enabled, which requires Rust 1.75 or
+/// later.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`draw_iter`]: #tymethod.draw_iter
+/// [`Dimensions`]: ../geometry/trait.Dimensions.html
+#[allow(async_fn_in_trait)]
+pub trait AsyncDrawTarget: Dimensions {
+    /// The pixel color type the targetted display supports.
+    type Color: PixelColor;
+
+    /// Error type to return when a drawing operation fails.
+    type Error;
+
+    /// Draw individual pixels to the display without a defined order.
+    ///
+    /// See [`DrawTarget::draw_iter`] for more information.
+    ///
+    /// [`DrawTarget::draw_iter`]: trait.DrawTarget.html#tymethod.draw_iter
+    async fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>;
+
+    /// Fill a given area with an iterator providing a contiguous stream of pixel colors.
+    ///
+    /// See [`DrawTarget::fill_contiguous`] for more information.
+    ///
+    /// The default implementation of this method delegates to [`draw_iter`](#tymethod.draw_iter).
+    ///
+    /// [`DrawTarget::fill_contiguous`]: trait.DrawTarget.html#method.fill_contiguous
+    async fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(pos, color)| Pixel(pos, color)),
+        )
+        .await
+    }
+
+    /// Fill a given area with a solid color.
+    ///
+    /// The default implementation of this method calls [`fill_contiguous`](#method.fill_contiguous)
+    /// with an iterator that repeats the given `color` for every point in `area`.
+    async fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        self.fill_contiguous(area, core::iter::repeat(color)).await
+    }
+
+    /// Fill the entire display with a solid color.
+    ///
+    /// The default implementation of this method delegates to [`fill_solid`] to fill the
+    /// [`bounding_box`] returned by the [`Dimensions`] implementation.
+    ///
+    /// [`Dimensions`]: ../geometry/trait.Dimensions.html
+    /// [`bounding_box`]: ../geometry/trait.Dimensions.html#tymethod.bounding_box
+    /// [`fill_solid`]: #method.fill_solid
+    async fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.bounding_box();
+
+        self.fill_solid(&area, color).await
+    }
+}
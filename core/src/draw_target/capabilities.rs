@@ -0,0 +1,177 @@
+/// Capabilities of a draw target.
+///
+/// The capabilities are provided by the [`DrawTarget::CAPABILITIES`] associated constant. Because
+/// the value is known at compile time, generic drawing code and draw target adapters can use it
+/// to select the optimal code path for a draw target without any runtime overhead.
+///
+/// The default capabilities, returned by [`Capabilities::new`], don't claim support for any
+/// optional feature. Draw targets should only enable the capabilities they actually support,
+/// because drawing code might use less efficient code paths otherwise.
+///
+/// Because `Capabilities` has the [`non_exhaustive`] attribute, it cannot be created using a
+/// struct literal. Use [`Capabilities::new`] and the `with_*` methods instead. Adapters can
+/// derive their capabilities from the capabilities of the wrapped draw target by calling the
+/// `with_*` methods on the parent's value.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::{Capabilities, IterationOrder},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+/// };
+///
+/// struct ExampleDisplay;
+///
+/// impl DrawTarget for ExampleDisplay {
+///     type Color = Rgb565;
+///     type Error = core::convert::Infallible;
+///
+///     const CAPABILITIES: Capabilities = Capabilities::new()
+///         .with_hardware_fill(true)
+///         .with_iteration_order(IterationOrder::ColumnMajor);
+///
+///     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+///     where
+///         I: IntoIterator<Item = Pixel<Self::Color>>,
+///     {
+///         // Draw pixels to the display
+///
+///         Ok(())
+///     }
+/// }
+///
+/// impl OriginDimensions for ExampleDisplay {
+///     fn size(&self) -> Size {
+///         Size::new(240, 320)
+///     }
+/// }
+///
+/// assert!(ExampleDisplay::CAPABILITIES.hardware_fill);
+/// assert!(!ExampleDisplay::CAPABILITIES.read_back);
+/// ```
+///
+/// [`DrawTarget::CAPABILITIES`]: trait.DrawTarget.html#associatedconstant.CAPABILITIES
+/// [`Capabilities::new`]: #method.new
+/// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// The draw target can read back the color of drawn pixels.
+    ///
+    /// Draw targets which set this flag should implement the [`GetPixel`] trait.
+    ///
+    /// [`GetPixel`]: trait.GetPixel.html
+    pub read_back: bool,
+
+    /// The draw target implements [`fill_solid`] using an accelerated operation.
+    ///
+    /// Adapters use this flag to decide if drawing operations should be converted into
+    /// `fill_solid` calls. For example, a [`Scaled`] draw target fills the block of each scaled
+    /// pixel using `fill_solid` if this flag is set.
+    ///
+    /// [`fill_solid`]: trait.DrawTarget.html#method.fill_solid
+    /// [`Scaled`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/draw_target/struct.Scaled.html
+    pub hardware_fill: bool,
+
+    /// The draw target implements [`fill_contiguous`] using an accelerated operation.
+    ///
+    /// [`fill_contiguous`]: trait.DrawTarget.html#method.fill_contiguous
+    pub hardware_blit: bool,
+
+    /// The order in which the draw target prefers to receive individual pixels.
+    ///
+    /// Drawing code which can draw pixels in any order, like patterned and gradient fills of
+    /// rectangles, uses this order for pixels which are drawn using [`draw_iter`].
+    ///
+    /// [`draw_iter`]: trait.DrawTarget.html#tymethod.draw_iter
+    pub iteration_order: IterationOrder,
+}
+
+impl Capabilities {
+    /// Creates capabilities without support for any optional feature.
+    pub const fn new() -> Self {
+        Self {
+            read_back: false,
+            hardware_fill: false,
+            hardware_blit: false,
+            iteration_order: IterationOrder::RowMajor,
+        }
+    }
+
+    /// Sets the `read_back` flag.
+    pub const fn with_read_back(self, read_back: bool) -> Self {
+        Self { read_back, ..self }
+    }
+
+    /// Sets the `hardware_fill` flag.
+    pub const fn with_hardware_fill(self, hardware_fill: bool) -> Self {
+        Self {
+            hardware_fill,
+            ..self
+        }
+    }
+
+    /// Sets the `hardware_blit` flag.
+    pub const fn with_hardware_blit(self, hardware_blit: bool) -> Self {
+        Self {
+            hardware_blit,
+            ..self
+        }
+    }
+
+    /// Sets the preferred iteration order.
+    pub const fn with_iteration_order(self, iteration_order: IterationOrder) -> Self {
+        Self {
+            iteration_order,
+            ..self
+        }
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pixel iteration order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IterationOrder {
+    /// Row by row, from left to right.
+    RowMajor,
+
+    /// Column by column, from top to bottom.
+    ColumnMajor,
+}
+
+impl Default for IterationOrder {
+    fn default() -> Self {
+        IterationOrder::RowMajor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        const CAPABILITIES: Capabilities = Capabilities::new()
+            .with_read_back(true)
+            .with_hardware_blit(true)
+            .with_iteration_order(IterationOrder::ColumnMajor);
+
+        assert_eq!(
+            CAPABILITIES,
+            Capabilities {
+                read_back: true,
+                hardware_fill: false,
+                hardware_blit: true,
+                iteration_order: IterationOrder::ColumnMajor,
+            }
+        );
+        assert_eq!(Capabilities::default(), Capabilities::new());
+    }
+}
@@ -1,7 +1,15 @@
 //! A target for embedded-graphics drawing operations.
 
+#[cfg(feature = "async")]
+mod async_draw_target;
+mod capabilities;
+
+#[cfg(feature = "async")]
+pub use async_draw_target::AsyncDrawTarget;
+pub use capabilities::{Capabilities, IterationOrder};
+
 use crate::{
-    geometry::Dimensions,
+    geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{PointsIter, Rectangle},
     Pixel,
@@ -293,6 +301,17 @@ pub trait DrawTarget: Dimensions {
     /// [`core::convert::Infallible`]: https://doc.rust-lang.org/stable/core/convert/enum.Infallible.html
     type Error;
 
+    /// Capabilities of this draw target.
+    ///
+    /// Generic drawing code and draw target adapters can use the capabilities to choose the
+    /// optimal code path for this draw target at compile time. See [`Capabilities`] for more
+    /// information.
+    ///
+    /// The default value doesn't claim support for any optional feature.
+    ///
+    /// [`Capabilities`]: struct.Capabilities.html
+    const CAPABILITIES: Capabilities = Capabilities::new();
+
     /// Draw individual pixels to the display without a defined order.
     ///
     /// Due to the unordered nature of the pixel iterator, this method is likely to be the slowest
@@ -408,6 +427,33 @@ pub trait DrawTarget: Dimensions {
         self.fill_contiguous(area, core::iter::repeat(color))
     }
 
+    /// Draw horizontal runs of pixels with a solid color.
+    ///
+    /// Each item of the `runs` iterator is a tuple of the leftmost point of the run, the length of
+    /// the run in pixels and the color of the run. Runs are used by drawing operations which
+    /// produce many short horizontal lines, like filled triangles, patterned fills and the
+    /// background of text. Display drivers which communicate with the display over a serial
+    /// interface can override this method to send each run as a single command, instead of
+    /// transferring the color of each pixel separately.
+    ///
+    /// Like for [`draw_iter`] the runs can be provided in any order and pixels outside the
+    /// drawable area of the display must be ignored.
+    ///
+    /// The default implementation of this method calls [`fill_solid`](#method.fill_solid) with
+    /// a rectangle that is one pixel high for each run.
+    ///
+    /// [`draw_iter`]: #tymethod.draw_iter
+    fn draw_runs<I>(&mut self, runs: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Point, u32, Self::Color)>,
+    {
+        for (point, length, color) in runs {
+            self.fill_solid(&Rectangle::new(point, Size::new(length, 1)), color)?;
+        }
+
+        Ok(())
+    }
+
     /// Fill the entire display with a solid color.
     ///
     /// If the target hardware supports a more optimized way of filling the entire display with a
@@ -423,3 +469,16 @@ pub trait DrawTarget: Dimensions {
         self.fill_solid(&self.bounding_box(), color)
     }
 }
+
+/// Read access to the pixels of a draw target.
+///
+/// Draw targets that keep a copy of the displayed image in memory, like framebuffers, can
+/// implement this trait to support drawing operations that depend on the current content of the
+/// target, for example color blending.
+pub trait GetPixel: DrawTarget {
+    /// Returns the color of the pixel at the given point.
+    ///
+    /// `None` is returned if the point is outside the draw target or if the color of the pixel
+    /// isn't known.
+    fn pixel(&self, point: Point) -> Option<Self::Color>;
+}
@@ -1,4 +1,6 @@
 //! `Drawable` trait and helpers
+#[cfg(feature = "async")]
+use crate::draw_target::AsyncDrawTarget;
 use crate::{draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor};
 
 /// Marks an object as "drawable". Must be implemented for all graphics objects
@@ -106,6 +108,26 @@ pub trait Drawable {
         D: DrawTarget<Color = Self::Color>;
 }
 
+/// Asynchronously drawable object.
+///
+/// `AsyncDrawable` adds the [`draw_async`] method to drawables, which draws the object to an
+/// [`AsyncDrawTarget`]. This allows drivers for displays which use DMA transfers to yield to the
+/// executor while the drawing operation is in progress.
+///
+/// This trait is only available if the `async` feature is enabled, which requires Rust 1.75 or
+/// later.
+///
+/// [`draw_async`]: #tymethod.draw_async
+/// [`AsyncDrawTarget`]: draw_target/trait.AsyncDrawTarget.html
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncDrawable: Drawable {
+    /// Draw the graphics object using the supplied `AsyncDrawTarget`.
+    async fn draw_async<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: AsyncDrawTarget<Color = Self::Color>;
+}
+
 /// A single pixel.
 ///
 /// `Pixel` objects are used to specify the position and color of drawn pixels.
@@ -158,6 +180,19 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<C> AsyncDrawable for Pixel<C>
+where
+    C: PixelColor,
+{
+    async fn draw_async<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: AsyncDrawTarget<Color = C>,
+    {
+        target.draw_iter(core::iter::once(*self)).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // NOTE: `crate` cannot be used here due to circular dependency resolution behavior.
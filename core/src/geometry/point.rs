@@ -1,4 +1,4 @@
-use crate::geometry::Size;
+use crate::{geometry::Size, primitives::Rectangle};
 use core::{
     convert::{TryFrom, TryInto},
     ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -214,6 +214,71 @@ impl Point {
     pub fn component_div(self, other: Self) -> Self {
         Self::new(self.x / other.x, self.y / other.y)
     }
+
+    /// Returns the dot product of two `Point`s.
+    ///
+    /// ```rust
+    /// use embedded_graphics::geometry::Point;
+    ///
+    /// let result = Point::new(2, 3).dot(Point::new(4, -5));
+    ///
+    /// assert_eq!(result, -7);
+    /// ```
+    pub const fn dot(self, other: Self) -> i32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the Z component of the cross product of two `Point`s.
+    ///
+    /// The result is positive if `other` points in a clockwise direction relative to `self`, in
+    /// the display coordinate system with the Y axis pointing down, and negative if it points in a
+    /// counterclockwise direction. The result is zero if both points are parallel.
+    ///
+    /// ```rust
+    /// use embedded_graphics::geometry::Point;
+    ///
+    /// let right = Point::new(1, 0);
+    ///
+    /// assert_eq!(right.cross(Point::new(0, 1)), 1);
+    /// assert_eq!(right.cross(Point::new(0, -1)), -1);
+    /// assert_eq!(right.cross(Point::new(-3, 0)), 0);
+    /// ```
+    pub const fn cross(self, other: Self) -> i32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared length of the vector from the origin to this point.
+    ///
+    /// ```rust
+    /// use embedded_graphics::geometry::Point;
+    ///
+    /// assert_eq!(Point::new(3, -4).length_squared(), 25);
+    /// ```
+    pub const fn length_squared(self) -> i32 {
+        self.dot(self)
+    }
+
+    /// Returns the point inside the rectangle that is closest to this point.
+    ///
+    /// If the rectangle is empty, the top left corner of the rectangle is returned.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{geometry::{Point, Size}, primitives::Rectangle};
+    ///
+    /// let rectangle = Rectangle::new(Point::new(10, 20), Size::new(5, 10));
+    ///
+    /// assert_eq!(Point::new(0, 25).clamp(&rectangle), Point::new(10, 25));
+    /// assert_eq!(Point::new(12, 40).clamp(&rectangle), Point::new(12, 29));
+    /// assert_eq!(Point::new(11, 21).clamp(&rectangle), Point::new(11, 21));
+    /// ```
+    pub fn clamp(self, rectangle: &Rectangle) -> Self {
+        match rectangle.bottom_right() {
+            Some(bottom_right) => self
+                .component_max(rectangle.top_left)
+                .component_min(bottom_right),
+            None => rectangle.top_left,
+        }
+    }
 }
 
 impl Add for Point {
@@ -486,6 +551,13 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn check_length_squared() {
+        let p = Point::new(3, 4);
+
+        assert_eq!(p.length_squared(), 25);
+    }
+
     #[test]
     fn convert_positive_to_u32_tuple() {
         let p = Point::new(10, 20);
@@ -18,6 +18,84 @@ use crate::{
 /// All image drawables are positioned at the origin and need to implement [`OriginDimensions`], in
 /// addition to this trait, to define their dimensions.
 ///
+/// Image decoders should draw the image data using the [`fill_contiguous`] method of the draw
+/// target, which allows display drivers to efficiently transfer the image to the display.
+///
+/// # Examples
+///
+/// This example implements `ImageDrawable` for a simple image format which stores one bit per
+/// pixel and uses a color palette with two entries. Because [`Image`] and [`SubImage`] only depend
+/// on `ImageDrawable`, the new format can be used in the same way as the built-in image types.
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{Image, ImageDrawable, ImageDrawableExt},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Rectangle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+///
+/// /// 8x8 pixel image with a two color palette.
+/// struct PaletteImage {
+///     rows: [u8; 8],
+///     palette: [Rgb565; 2],
+/// }
+///
+/// impl PaletteImage {
+///     fn pixel(&self, point: Point) -> Rgb565 {
+///         let bit = self.rows[point.y as usize] >> (7 - point.x) & 1;
+///
+///         self.palette[usize::from(bit)]
+///     }
+/// }
+///
+/// impl OriginDimensions for PaletteImage {
+///     fn size(&self) -> Size {
+///         Size::new(8, 8)
+///     }
+/// }
+///
+/// impl ImageDrawable for PaletteImage {
+///     type Color = Rgb565;
+///
+///     fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+///     where
+///         D: DrawTarget<Color = Self::Color>,
+///     {
+///         self.draw_sub_image(target, &self.bounding_box())
+///     }
+///
+///     fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+///     where
+///         D: DrawTarget<Color = Self::Color>,
+///     {
+///         // Only draw the part of `area` which is inside the image.
+///         let area = area.intersection(&self.bounding_box());
+///
+///         target.fill_contiguous(
+///             &Rectangle::new(Point::zero(), area.size),
+///             area.points().map(|point| self.pixel(point)),
+///         )
+///     }
+/// }
+///
+/// let image = PaletteImage {
+///     rows: [0x18, 0x3C, 0x7E, 0xFF, 0xFF, 0x7E, 0x3C, 0x18],
+///     palette: [Rgb565::BLACK, Rgb565::RED],
+/// };
+///
+/// # let mut display = MockDisplay::<Rgb565>::new();
+/// Image::new(&image, Point::new(10, 20)).draw(&mut display)?;
+///
+/// // Draw the right half of the image.
+/// let right_half = image.sub_image(&Rectangle::new(Point::new(4, 0), Size::new(4, 8)));
+/// Image::new(&right_half, Point::new(30, 20)).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`fill_contiguous`]: ../draw_target/trait.DrawTarget.html#method.fill_contiguous
+/// [`SubImage`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/image/struct.SubImage.html
 /// [`Image`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/image/struct.Image.html
 /// [`OriginDimensions`]: ../geometry/trait.OriginDimensions.html
 pub trait ImageDrawable: OriginDimensions {
@@ -74,6 +74,9 @@ pub mod primitives;
 
 pub use drawable::{Drawable, Pixel};
 
+#[cfg(feature = "async")]
+pub use drawable::AsyncDrawable;
+
 /// Trait to convert unsigned into signed integer.
 trait SaturatingCast<T> {
     /// Casts a unsigned integer into a positive value.
@@ -84,6 +87,7 @@ trait SaturatingCast<T> {
     /// Casts a unsigned integer into a negative value.
     ///
     /// If the value is too large the minimum negative value is returned instead.
+    #[allow(dead_code)]
     fn saturating_cast_neg(self) -> T;
 }
 
@@ -131,6 +131,7 @@ macro_rules! impl_rgb_to_binary {
 impl_rgb_to_binary!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
 
 #[cfg(test)]
+#[allow(clippy::extra_unused_type_parameters)]
 mod tests {
     use core::fmt::Debug;
 
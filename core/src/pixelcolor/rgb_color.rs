@@ -224,6 +224,7 @@ rgb_color!(Rgb888, RawU24, u32, Rgb = (8, 8, 8));
 rgb_color!(Bgr888, RawU24, u32, Bgr = (8, 8, 8));
 
 #[cfg(test)]
+#[allow(clippy::precedence, clippy::identity_op)]
 mod tests {
     use super::*;
     use crate::pixelcolor::IntoStorage;
@@ -231,7 +232,7 @@ mod tests {
     /// Convert color to integer and back again to test bit positions
     fn test_bpp16<C>(color: C, value: u16)
     where
-        C: RgbColor + From<RawU16> + Into<RawU16> + core::fmt::Debug,
+        C: RgbColor + From<RawU16> + Into<RawU16> + fmt::Debug,
     {
         let value = RawU16::new(value);
 
@@ -242,7 +243,7 @@ mod tests {
     /// Convert color to integer and back again to test bit positions
     fn test_bpp24<C>(color: C, value: u32)
     where
-        C: RgbColor + From<RawU24> + Into<RawU24> + core::fmt::Debug,
+        C: RgbColor + From<RawU24> + Into<RawU24> + fmt::Debug,
     {
         let value = RawU24::new(value);
 
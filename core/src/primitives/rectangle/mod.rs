@@ -11,7 +11,7 @@ use core::{
     cmp::min,
     ops::{Range, RangeInclusive},
 };
-pub use points::Points;
+pub use points::{ColumnMajorPoints, Points};
 
 /// Rectangle primitive
 ///
@@ -64,7 +64,7 @@ impl PointsIter for Rectangle {
     type Iter = Points;
 
     fn points(&self) -> Self::Iter {
-        self::Points::new(self)
+        Points::new(self)
     }
 }
 
@@ -105,6 +105,39 @@ impl Rectangle {
         }
     }
 
+    /// Creates the smallest rectangle which contains all given points.
+    ///
+    /// A zero sized rectangle at the origin is returned if `points` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let points = [Point::new(5, 10), Point::new(-2, 3), Point::new(7, 4)];
+    ///
+    /// assert_eq!(
+    ///     Rectangle::from_points(points.iter().copied()),
+    ///     Rectangle::with_corners(Point::new(-2, 3), Point::new(7, 10))
+    /// );
+    /// ```
+    pub fn from_points<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut points = points.into_iter();
+
+        if let Some(first) = points.next() {
+            let (min, max) = points.fold((first, first), |(min, max), point| {
+                (min.component_min(point), max.component_max(point))
+            });
+
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::zero()
+        }
+    }
+
     /// Returns a zero sized rectangle.
     pub const fn zero() -> Rectangle {
         Rectangle::new(Point::zero(), Size::zero())
@@ -245,6 +278,85 @@ impl Rectangle {
         Rectangle::zero()
     }
 
+    /// Returns the smallest rectangle that contains both `self` and `other`.
+    ///
+    /// Zero sized rectangles don't contain any points and are ignored. If both rectangles are zero
+    /// sized a zero sized rectangle is returned, which makes it possible to accumulate a dirty
+    /// region starting from `Rectangle::zero()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect1 = Rectangle::new(Point::new(0, 5), Size::new(10, 5));
+    /// let rect2 = Rectangle::new(Point::new(20, 0), Size::new(5, 20));
+    ///
+    /// assert_eq!(
+    ///     rect1.envelope(&rect2),
+    ///     Rectangle::new(Point::new(0, 0), Size::new(25, 20))
+    /// );
+    ///
+    /// // Track the area that needs to be redrawn.
+    /// let dirty = [rect1, rect2]
+    ///     .iter()
+    ///     .fold(Rectangle::zero(), |dirty, rect| dirty.envelope(rect));
+    ///
+    /// assert_eq!(dirty, rect1.envelope(&rect2));
+    /// ```
+    pub fn envelope(&self, other: &Rectangle) -> Rectangle {
+        match (self.bottom_right(), other.bottom_right()) {
+            (Some(self_bottom_right), Some(other_bottom_right)) => Rectangle::with_corners(
+                self.top_left.component_min(other.top_left),
+                self_bottom_right.component_max(other_bottom_right),
+            ),
+            (Some(_), None) => *self,
+            (None, Some(_)) => *other,
+            (None, None) => Rectangle::zero(),
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have at least one point in common.
+    ///
+    /// Zero sized rectangles don't contain any points and never overlap another rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect1 = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+    /// let rect2 = Rectangle::new(Point::new(9, 9), Size::new(10, 10));
+    /// let rect3 = Rectangle::new(Point::new(10, 0), Size::new(10, 10));
+    ///
+    /// assert!(rect1.overlaps(&rect2));
+    /// assert!(!rect1.overlaps(&rect3));
+    /// ```
+    pub fn overlaps(&self, other: &Rectangle) -> bool {
+        !self.intersection(other).is_zero_sized()
+    }
+
+    /// Returns `true` if all points in `other` are also contained in `self`.
+    ///
+    /// Zero sized rectangles don't contain any points and are therefore contained in every
+    /// rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+    ///
+    /// assert!(rect.contains_rect(&Rectangle::new(Point::new(2, 3), Size::new(8, 7))));
+    /// assert!(!rect.contains_rect(&Rectangle::new(Point::new(2, 3), Size::new(9, 7))));
+    /// ```
+    pub fn contains_rect(&self, other: &Rectangle) -> bool {
+        other.bottom_right().map_or(true, |bottom_right| {
+            self.contains(other.top_left) && self.contains(bottom_right)
+        })
+    }
+
     /// Returns a resized copy of this rectangle.
     ///
     /// The rectangle is resized relative to the given anchor point.
@@ -289,7 +401,28 @@ impl Rectangle {
 
     /// Offset the rectangle by a given value.
     ///
-    /// Negative values will shrink the rectangle.
+    /// Negative values will shrink the rectangle. The center of the rectangle is preserved, which
+    /// makes this method useful to add padding or margins to a layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     geometry::AnchorPoint,
+    ///     prelude::*,
+    ///     primitives::rectangle::Rectangle,
+    /// };
+    ///
+    /// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+    ///
+    /// // Content area with a 4px margin on each side
+    /// let content = display_area.offset(-4);
+    /// assert_eq!(content, Rectangle::new(Point::new(4, 4), Size::new(120, 56)));
+    ///
+    /// // 10px high header at the top of the content area
+    /// let header = content.resized(Size::new(content.size.width, 10), AnchorPoint::TopLeft);
+    /// assert_eq!(header, Rectangle::new(Point::new(4, 4), Size::new(120, 10)));
+    /// ```
     pub fn offset(&self, offset: i32) -> Self {
         let size = if offset >= 0 {
             self.size.saturating_add(Size::new_equal(offset as u32 * 2))
@@ -338,6 +471,33 @@ impl Rectangle {
             }
     }
 
+    /// Returns an iterator over all points inside the rectangle in column major order.
+    ///
+    /// The [`points`] iterator returns the points in row major order, which means that all points
+    /// in a row are returned before moving on to the next row. This method returns all points in a
+    /// column before moving on to the next column instead, which can be useful for displays
+    /// whose memory is organized in columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect = Rectangle::new(Point::new(10, 20), Size::new(2, 2));
+    ///
+    /// assert!(rect.points_column_major().eq([
+    ///     Point::new(10, 20),
+    ///     Point::new(10, 21),
+    ///     Point::new(11, 20),
+    ///     Point::new(11, 21),
+    /// ].iter().copied()));
+    /// ```
+    ///
+    /// [`points`]: ../trait.PointsIter.html#tymethod.points
+    pub fn points_column_major(&self) -> ColumnMajorPoints {
+        ColumnMajorPoints::new(self)
+    }
+
     /// Returns the range of Y coordinates in this rectangle.
     ///
     /// # Examples
@@ -555,6 +715,68 @@ mod tests {
         assert_eq!(rect2.intersection(&rect1), expected);
     }
 
+    #[test]
+    fn envelope() {
+        let rect1 = Rectangle::new(Point::new(5, 10), Size::new(10, 5));
+        let rect2 = Rectangle::new(Point::new(-5, 12), Size::new(3, 20));
+
+        let expected = Rectangle::with_corners(Point::new(-5, 10), Point::new(14, 31));
+
+        assert_eq!(rect1.envelope(&rect2), expected);
+        assert_eq!(rect2.envelope(&rect1), expected);
+        assert_eq!(rect1.envelope(&rect1), rect1);
+    }
+
+    #[test]
+    fn from_points() {
+        let points = [Point::new(3, 7), Point::new(-1, 2), Point::new(4, 4)];
+
+        assert_eq!(
+            Rectangle::from_points(points.iter().copied()),
+            Rectangle::new(Point::new(-1, 2), Size::new(6, 6))
+        );
+        assert_eq!(
+            Rectangle::from_points(Some(Point::new(3, 7))),
+            Rectangle::new(Point::new(3, 7), Size::new(1, 1))
+        );
+        assert_eq!(Rectangle::from_points(None), Rectangle::zero());
+    }
+
+    #[test]
+    fn envelope_zero_sized() {
+        let rect = Rectangle::new(Point::new(5, 10), Size::new(10, 5));
+        let zero_sized = Rectangle::new(Point::new(50, 50), Size::new(0, 10));
+
+        assert_eq!(rect.envelope(&zero_sized), rect);
+        assert_eq!(zero_sized.envelope(&rect), rect);
+        assert_eq!(zero_sized.envelope(&zero_sized), Rectangle::zero());
+    }
+
+    #[test]
+    fn overlaps() {
+        let rect = Rectangle::new(Point::new(5, 10), Size::new(10, 5));
+
+        assert!(rect.overlaps(&rect));
+        assert!(rect.overlaps(&Rectangle::new(Point::new(14, 14), Size::new(1, 1))));
+        assert!(rect.overlaps(&Rectangle::new(Point::new(0, 0), Size::new(30, 30))));
+        assert!(!rect.overlaps(&Rectangle::new(Point::new(15, 10), Size::new(1, 1))));
+        assert!(!rect.overlaps(&Rectangle::new(Point::new(5, 15), Size::new(1, 1))));
+        assert!(!rect.overlaps(&Rectangle::new(Point::new(6, 11), Size::zero())));
+    }
+
+    #[test]
+    fn contains_rect() {
+        let rect = Rectangle::new(Point::new(5, 10), Size::new(10, 5));
+
+        assert!(rect.contains_rect(&rect));
+        assert!(rect.contains_rect(&Rectangle::new(Point::new(6, 11), Size::new(2, 2))));
+        assert!(rect.contains_rect(&Rectangle::new(Point::new(100, 100), Size::zero())));
+        assert!(!rect.contains_rect(&Rectangle::new(Point::new(4, 11), Size::new(2, 2))));
+        assert!(!rect.contains_rect(&Rectangle::new(Point::new(14, 14), Size::new(2, 1))));
+        assert!(!rect.contains_rect(&Rectangle::new(Point::new(0, 0), Size::new(30, 30))));
+        assert!(!Rectangle::zero().contains_rect(&rect));
+    }
+
     #[test]
     fn offset() {
         let center = Point::new(10, 20);
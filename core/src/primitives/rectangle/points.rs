@@ -55,6 +55,60 @@ impl Iterator for Points {
     }
 }
 
+/// Iterator over all points inside the rectangle in column major order.
+///
+/// See [`Rectangle::points_column_major`] for more information.
+///
+/// [`Rectangle::points_column_major`]: struct.Rectangle.html#method.points_column_major
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ColumnMajorPoints {
+    x: Range<i32>,
+    y: Range<i32>,
+    y_start: i32,
+}
+
+impl ColumnMajorPoints {
+    pub(in crate::primitives::rectangle) fn new(rectangle: &Rectangle) -> Self {
+        if rectangle.is_zero_sized() {
+            return Self::empty();
+        }
+
+        let x = rectangle.columns();
+        let y = rectangle.rows();
+        let y_start = y.start;
+
+        Self { x, y, y_start }
+    }
+
+    /// Create a points iterator that returns no items.
+    pub const fn empty() -> Self {
+        Self {
+            x: 0..0,
+            y: 0..0,
+            y_start: 0,
+        }
+    }
+}
+
+impl Iterator for ColumnMajorPoints {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // MSRV 1.47.0: use Range::is_empty
+        while self.x.end > self.x.start {
+            if let Some(y) = self.y.next() {
+                return Some(Point::new(self.x.start, y));
+            }
+
+            self.x.next();
+            self.y.start = self.y_start;
+        }
+
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +160,34 @@ mod tests {
         let mut points = Points::empty();
         assert_eq!(points.next(), None);
     }
+
+    #[test]
+    fn column_major_points_iter() {
+        let rectangle = Rectangle::new(Point::new(10, 20), Size::new(2, 3));
+
+        let mut points = rectangle.points_column_major();
+        assert_eq!(points.next(), Some(Point::new(10, 20)));
+        assert_eq!(points.next(), Some(Point::new(10, 21)));
+        assert_eq!(points.next(), Some(Point::new(10, 22)));
+        assert_eq!(points.next(), Some(Point::new(11, 20)));
+        assert_eq!(points.next(), Some(Point::new(11, 21)));
+        assert_eq!(points.next(), Some(Point::new(11, 22)));
+        assert_eq!(points.next(), None);
+    }
+
+    #[test]
+    fn column_major_points_iter_zero_size() {
+        for &size in &[Size::zero(), Size::new(0, 1), Size::new(1, 0)] {
+            let rectangle = Rectangle::new(Point::new(1, 2), size);
+
+            let mut points = rectangle.points_column_major();
+            assert_eq!(points.next(), None, "{:?}", size);
+        }
+    }
+
+    #[test]
+    fn column_major_points_iter_empty() {
+        let mut points = ColumnMajorPoints::empty();
+        assert_eq!(points.next(), None);
+    }
 }
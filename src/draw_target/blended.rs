@@ -0,0 +1,491 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{Dimensions, Point},
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Blend mode.
+///
+/// The blend mode determines how the color of a drawn pixel is combined with the color that is
+/// already stored in the draw target. The result of the blend mode is mixed with the existing
+/// color according to the opacity.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum BlendMode {
+    /// The drawn color is placed over the existing color.
+    SourceOver,
+
+    /// The drawn color is combined with the existing color by a bitwise XOR.
+    ///
+    /// Drawing the same pixels twice restores the original content of the draw target, which is
+    /// useful for cursors and selection highlights.
+    Xor,
+
+    /// The existing color is inverted.
+    ///
+    /// The drawn color is ignored and only the shape of the drawn pixels is used. Like [`Xor`],
+    /// drawing the same pixels twice restores the original content of the draw target, which
+    /// makes it possible to draw and erase cursors or rubber band selections without saving the
+    /// background.
+    ///
+    /// [`Xor`]: #variant.Xor
+    Invert,
+}
+
+impl BlendMode {
+    /// Blends a source color with a destination color.
+    ///
+    /// The colors are blended in the RGB888 color space. An `opacity` of `255` returns the result
+    /// of the blend mode and an `opacity` of `0` returns the destination color.
+    pub fn blend<C>(self, source: C, destination: C, opacity: u8) -> C
+    where
+        C: PixelColor + Into<Rgb888> + From<Rgb888>,
+    {
+        let (source, destination) = (source.into(), destination.into());
+
+        let channel = |source: u8, destination: u8| {
+            let blended = match self {
+                BlendMode::SourceOver => source,
+                BlendMode::Xor => source ^ destination,
+                BlendMode::Invert => !destination,
+            };
+
+            let opacity = u32::from(opacity);
+            let value = u32::from(blended) * opacity + u32::from(destination) * (255 - opacity);
+
+            ((value + 127) / 255) as u8
+        };
+
+        C::from(Rgb888::new(
+            channel(source.r(), destination.r()),
+            channel(source.g(), destination.g()),
+            channel(source.b(), destination.b()),
+        ))
+    }
+}
+
+impl Default for BlendMode {
+    fn default() -> Self {
+        Self::SourceOver
+    }
+}
+
+/// Blended draw target.
+///
+/// Created by calling [`blended`] on any [`DrawTarget`].
+/// See the [`blended`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`blended`]: trait.DrawTargetExt.html#tymethod.blended
+#[derive(Debug)]
+pub struct Blended<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    opacity: u8,
+    blend_mode: BlendMode,
+}
+
+impl<'a, T> Blended<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, opacity: u8, blend_mode: BlendMode) -> Self {
+        Self {
+            parent,
+            opacity,
+            blend_mode,
+        }
+    }
+
+    /// Returns `true` if drawn pixels replace the existing content of the parent draw target.
+    fn is_opaque(&self) -> bool {
+        self.opacity == 255 && self.blend_mode == BlendMode::SourceOver
+    }
+}
+
+impl<T> DrawTarget for Blended<'_, T>
+where
+    T: GetPixel,
+    T::Color: Into<Rgb888> + From<Rgb888>,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    // Fills are only forwarded to the parent if the drawn pixels are opaque, which isn't known at
+    // compile time.
+    const CAPABILITIES: Capabilities = T::CAPABILITIES
+        .with_hardware_fill(false)
+        .with_hardware_blit(false);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.opacity == 0 {
+            return Ok(());
+        }
+
+        if self.is_opaque() {
+            return self.parent.draw_iter(pixels);
+        }
+
+        // The pixels are drawn one by one, because the parent can't be read while it is drawing.
+        for Pixel(point, color) in pixels {
+            let color = self.parent.pixel(point).map_or(color, |destination| {
+                self.blend_mode.blend(color, destination, self.opacity)
+            });
+
+            self.parent
+                .draw_iter(core::iter::once(Pixel(point, color)))?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.is_opaque() {
+            self.parent.fill_contiguous(area, colors)
+        } else {
+            self.draw_iter(
+                area.points()
+                    .zip(colors)
+                    .map(|(point, color)| Pixel(point, color)),
+            )
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        if self.is_opaque() {
+            self.parent.fill_solid(area, color)
+        } else {
+            self.fill_contiguous(area, core::iter::repeat(color))
+        }
+    }
+}
+
+impl<T> GetPixel for Blended<'_, T>
+where
+    T: GetPixel,
+    T::Color: Into<Rgb888> + From<Rgb888>,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent.pixel(point)
+    }
+}
+
+impl<T> Dimensions for Blended<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle},
+        transform::Transform,
+        Drawable,
+    };
+
+    #[test]
+    fn blend_source_over() {
+        let source = Rgb888::new(255, 100, 0);
+        let destination = Rgb888::new(55, 0, 200);
+
+        let blend = |opacity| BlendMode::SourceOver.blend(source, destination, opacity);
+        assert_eq!(blend(0), destination);
+        assert_eq!(blend(128), Rgb888::new(155, 50, 100));
+        assert_eq!(blend(255), source);
+    }
+
+    #[test]
+    fn blend_xor() {
+        assert_eq!(
+            BlendMode::Xor.blend(
+                Rgb888::new(0xFF, 0x0F, 0x00),
+                Rgb888::new(0x0F, 0x0F, 0xF0),
+                255
+            ),
+            Rgb888::new(0xF0, 0x00, 0xF0)
+        );
+
+        assert_eq!(
+            BlendMode::Xor.blend(BinaryColor::On, BinaryColor::On, 255),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BlendMode::Xor.blend(BinaryColor::On, BinaryColor::Off, 255),
+            BinaryColor::On
+        );
+    }
+
+    #[test]
+    fn blend_invert() {
+        assert_eq!(
+            BlendMode::Invert.blend(Rgb888::RED, Rgb888::new(0xFF, 0x0F, 0x00), 255),
+            Rgb888::new(0x00, 0xF0, 0xFF)
+        );
+
+        for &source in &[BinaryColor::Off, BinaryColor::On] {
+            assert_eq!(
+                BlendMode::Invert.blend(source, BinaryColor::On, 255),
+                BinaryColor::Off
+            );
+            assert_eq!(
+                BlendMode::Invert.blend(source, BinaryColor::Off, 255),
+                BinaryColor::On
+            );
+        }
+    }
+
+    #[test]
+    fn draw_invert_twice() {
+        let mut display = MockDisplay::from_pattern(&[
+            "..##..", //
+            "..##..", //
+            "..##..", //
+            "..##..", //
+        ]);
+        display.set_allow_overdraw(true);
+
+        let original = display.clone();
+
+        // Rubber band selection.
+        let selection = Rectangle::new(Point::new(1, 0), Size::new(4, 4))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::Off, 1));
+
+        selection
+            .draw(&mut display.blended(255, BlendMode::Invert))
+            .unwrap();
+
+        display.assert_pattern(&[
+            ".#..#.", //
+            ".####.", //
+            ".####.", //
+            ".#..#.", //
+        ]);
+
+        selection
+            .draw(&mut display.blended(255, BlendMode::Invert))
+            .unwrap();
+
+        display.assert_eq(&original);
+    }
+
+    #[test]
+    fn draw_source_over() {
+        let area = Rectangle::new(Point::new(1, 1), Size::new(3, 2));
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.fill_solid(&area, Rgb888::BLUE).unwrap();
+
+        area.translate(Point::new(1, 0))
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+            .draw(&mut display.blended(51, BlendMode::SourceOver))
+            .unwrap();
+
+        let mixed = Rgb888::new(51, 0, 204);
+        let mut expected = MockDisplay::new();
+        expected
+            .draw_iter(area.points().map(|p| Pixel(p, Rgb888::BLUE)))
+            .unwrap();
+        expected.set_allow_overdraw(true);
+        expected
+            .draw_iter(
+                area.translate(Point::new(1, 0))
+                    .points()
+                    .map(|p| Pixel(p, if p.x < 4 { mixed } else { Rgb888::RED })),
+            )
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_source_over_cropped() {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(4, 4)),
+                Rgb888::BLUE,
+            )
+            .unwrap();
+
+        let area = Rectangle::new(Point::new(1, 1), Size::new(2, 2));
+        display
+            .cropped(&area)
+            .blended(51, BlendMode::SourceOver)
+            .fill_solid(&Rectangle::new(Point::zero(), area.size), Rgb888::RED)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(4, 4)),
+                Rgb888::BLUE,
+            )
+            .unwrap();
+        expected.set_allow_overdraw(true);
+        expected.fill_solid(&area, Rgb888::new(51, 0, 204)).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_xor_twice() {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(4, 3)),
+                BinaryColor::Off,
+            )
+            .unwrap();
+        display
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(2, 3)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        let original = display.clone();
+
+        let cursor = Rectangle::new(Point::new(1, 0), Size::new(2, 3));
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        cursor
+            .into_styled(style)
+            .draw(&mut display.blended(255, BlendMode::Xor))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#.#.", //
+            "#.#.", //
+            "#.#.", //
+        ]);
+
+        cursor
+            .into_styled(style)
+            .draw(&mut display.blended(255, BlendMode::Xor))
+            .unwrap();
+
+        display.assert_eq(&original);
+    }
+
+    #[test]
+    fn transparent() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        Rectangle::new(Point::zero(), Size::new(4, 3))
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+            .draw(&mut display.blended(0, BlendMode::SourceOver))
+            .unwrap();
+
+        display.assert_pattern(&[]);
+    }
+
+    #[test]
+    fn unknown_destination() {
+        let mut display = MockDisplay::new();
+
+        Rectangle::new(Point::zero(), Size::new(2, 1))
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+            .draw(&mut display.blended(100, BlendMode::SourceOver))
+            .unwrap();
+
+        display.assert_pattern(&["RR"]);
+    }
+
+    /// Draw target which only supports filling rectangular areas.
+    struct FillOnly(MockDisplay<Rgb888>);
+
+    impl DrawTarget for FillOnly {
+        type Color = Rgb888;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            panic!("draw_iter shouldn't be called");
+        }
+
+        fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Self::Color>,
+        {
+            self.0.fill_contiguous(area, colors)
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            self.0.fill_solid(area, color)
+        }
+    }
+
+    impl GetPixel for FillOnly {
+        fn pixel(&self, point: Point) -> Option<Self::Color> {
+            self.0.pixel(point)
+        }
+    }
+
+    impl Dimensions for FillOnly {
+        fn bounding_box(&self) -> Rectangle {
+            self.0.bounding_box()
+        }
+    }
+
+    #[test]
+    fn opaque_fills_are_forwarded() {
+        let mut display = FillOnly(MockDisplay::new());
+
+        let mut blended = display.blended(255, BlendMode::SourceOver);
+        blended
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(2, 1)), Rgb888::RED)
+            .unwrap();
+        blended
+            .fill_contiguous(
+                &Rectangle::new(Point::new(0, 1), Size::new(2, 1)),
+                [Rgb888::GREEN, Rgb888::BLUE].iter().copied(),
+            )
+            .unwrap();
+
+        display.0.assert_pattern(&[
+            "RR", //
+            "GB", //
+        ]);
+    }
+
+    #[test]
+    fn transparent_fills_are_skipped() {
+        let mut display = FillOnly(MockDisplay::new());
+
+        let mut blended = display.blended(0, BlendMode::Xor);
+        blended
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(2, 1)), Rgb888::RED)
+            .unwrap();
+        blended
+            .fill_contiguous(
+                &Rectangle::new(Point::new(0, 1), Size::new(2, 1)),
+                [Rgb888::GREEN, Rgb888::BLUE].iter().copied(),
+            )
+            .unwrap();
+
+        display.0.assert_pattern(&[]);
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, iterator::contiguous::Cropped,
-    primitives::Rectangle, transform::Transform, Pixel,
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{Dimensions, Point, Size},
+    iterator::contiguous::Cropped,
+    primitives::Rectangle,
+    transform::Transform,
+    Pixel,
 };
 
 /// Clipped draw target.
@@ -37,6 +41,8 @@ where
     type Color = T::Color;
     type Error = T::Error;
 
+    const CAPABILITIES: Capabilities = T::CAPABILITIES;
+
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
@@ -70,6 +76,38 @@ where
 
         self.parent.fill_solid(&area, color)
     }
+
+    fn draw_runs<I>(&mut self, runs: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Point, u32, Self::Color)>,
+    {
+        let clip_area = self.clip_area;
+
+        let runs = runs.into_iter().filter_map(|(point, length, color)| {
+            let run = Rectangle::new(point, Size::new(length, 1)).intersection(&clip_area);
+
+            if run.is_zero_sized() {
+                None
+            } else {
+                Some((run.top_left, run.size.width, color))
+            }
+        });
+
+        self.parent.draw_runs(runs)
+    }
+}
+
+impl<T> GetPixel for Clipped<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if self.clip_area.contains(point) {
+            self.parent.pixel(point)
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> Dimensions for Clipped<'_, T>
@@ -84,7 +122,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        draw_target::{DrawTarget, DrawTargetExt},
+        draw_target::{DrawTarget, DrawTargetExt, GetPixel},
         geometry::Dimensions,
         geometry::{Point, Size},
         mock_display::MockDisplay,
@@ -168,6 +206,28 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn draw_runs() {
+        let mut display = MockDisplay::new();
+
+        let area = Rectangle::new(Point::new(2, 1), Size::new(3, 2));
+        let mut clipped = display.clipped(&area);
+
+        let runs = [
+            (Point::new(0, 1), 7, BinaryColor::On),
+            (Point::new(3, 2), 1, BinaryColor::Off),
+            (Point::new(0, 2), 2, BinaryColor::On),
+            (Point::new(1, 3), 3, BinaryColor::On),
+        ];
+        clipped.draw_runs(runs.iter().copied()).unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "  ###", //
+            "   . ", //
+        ]);
+    }
+
     #[test]
     fn clear() {
         let mut display = MockDisplay::new();
@@ -211,4 +271,24 @@ mod tests {
             Rectangle::new(top_left, expected_size),
         );
     }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(
+                [Point::new(3, 5), Point::new(5, 3)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        let area = Rectangle::new(Point::new(2, 3), Size::new(3, 3));
+        let clipped = display.clipped(&area);
+
+        assert_eq!(clipped.pixel(Point::new(3, 5)), Some(BinaryColor::On));
+        assert_eq!(clipped.pixel(Point::new(2, 3)), None);
+        // Pixels outside the clip area aren't returned.
+        assert_eq!(clipped.pixel(Point::new(5, 3)), None);
+    }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, pixelcolor::PixelColor, primitives::Rectangle,
+    draw_target::{Capabilities, DrawTarget},
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
     Pixel,
 };
 use core::marker::PhantomData;
@@ -41,6 +44,9 @@ where
     type Color = C;
     type Error = T::Error;
 
+    // Pixels can't be read back, because colors can't be converted back to the input color type.
+    const CAPABILITIES: Capabilities = T::CAPABILITIES.with_read_back(false);
+
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
@@ -1,6 +1,6 @@
 use crate::{
-    draw_target::{DrawTarget, DrawTargetExt, Translated},
-    geometry::{OriginDimensions, Size},
+    draw_target::{Capabilities, DrawTarget, DrawTargetExt, GetPixel, Translated},
+    geometry::{Dimensions, OriginDimensions, Point, Size},
     primitives::Rectangle,
     Pixel,
 };
@@ -42,6 +42,8 @@ where
     type Color = T::Color;
     type Error = T::Error;
 
+    const CAPABILITIES: Capabilities = T::CAPABILITIES;
+
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
@@ -59,6 +61,26 @@ where
     fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
         self.parent.fill_solid(area, color)
     }
+
+    fn draw_runs<I>(&mut self, runs: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Point, u32, Self::Color)>,
+    {
+        self.parent.draw_runs(runs)
+    }
+}
+
+impl<T> GetPixel for Cropped<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if self.bounding_box().contains(point) {
+            self.parent.pixel(point)
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> OriginDimensions for Cropped<'_, T>
@@ -73,7 +95,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        draw_target::{DrawTarget, DrawTargetExt},
+        draw_target::{DrawTarget, DrawTargetExt, GetPixel},
         geometry::Dimensions,
         geometry::{Point, Size},
         mock_display::MockDisplay,
@@ -201,4 +223,24 @@ mod tests {
             Rectangle::new(Point::zero(), expected_size),
         );
     }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(
+                [Point::new(3, 5), Point::new(5, 3)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        let area = Rectangle::new(Point::new(2, 3), Size::new(3, 3));
+        let cropped = display.cropped(&area);
+
+        assert_eq!(cropped.pixel(Point::new(1, 2)), Some(BinaryColor::On));
+        assert_eq!(cropped.pixel(Point::new(0, 0)), None);
+        // Pixels outside the cropped area aren't returned.
+        assert_eq!(cropped.pixel(Point::new(3, 0)), None);
+    }
 }
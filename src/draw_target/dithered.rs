@@ -0,0 +1,424 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget},
+    geometry::{Dimensions, Point},
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, PixelColor, Rgb555, Rgb565,
+        Rgb888, RgbColor,
+    },
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// 4x4 Bayer matrix which is used for ordered dithering.
+const BAYER_MATRIX: [[u8; 4]; 4] = [
+    [0, 8, 2, 10], //
+    [12, 4, 14, 6],
+    [3, 11, 1, 9],
+    [15, 7, 13, 5],
+];
+
+/// Color types which can be used as the output of a dithered draw target.
+///
+/// This trait is implemented for all built in color types.
+pub trait DitherColor: PixelColor + From<Rgb888> + Into<Rgb888> {
+    /// Maximum values of the red, green and blue channels.
+    ///
+    /// The maximum values are used to scale the dither pattern to the distance between two
+    /// adjacent colors. Grayscale and binary colors use the maximum luma value for all
+    /// channels.
+    const CHANNEL_MAX: [u8; 3];
+}
+
+impl DitherColor for BinaryColor {
+    const CHANNEL_MAX: [u8; 3] = [1, 1, 1];
+}
+
+macro_rules! impl_dither_color_gray {
+    ($($type:ident => $max:expr),*) => {
+        $(impl DitherColor for $type {
+            const CHANNEL_MAX: [u8; 3] = [$max; 3];
+        })*
+    };
+}
+
+impl_dither_color_gray!(Gray2 => 3, Gray4 => 15, Gray8 => 255);
+
+macro_rules! impl_dither_color_rgb {
+    ($($type:ident),*) => {
+        $(impl DitherColor for $type {
+            const CHANNEL_MAX: [u8; 3] = [Self::MAX_R, Self::MAX_G, Self::MAX_B];
+        })*
+    };
+}
+
+impl_dither_color_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+/// Quantization error of a single pixel.
+///
+/// A buffer of quantization errors is used to store the error of the previous row for
+/// [`Dithering::ErrorDiffusion`].
+///
+/// [`Dithering::ErrorDiffusion`]: enum.Dithering.html#variant.ErrorDiffusion
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct QuantizationError([i16; 3]);
+
+/// Dithering algorithm.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum Dithering<'a> {
+    /// Ordered dithering using a 4x4 Bayer matrix.
+    ///
+    /// Ordered dithering doesn't require any state and can be used for all drawing operations.
+    Ordered,
+
+    /// Floyd–Steinberg error diffusion.
+    ///
+    /// The quantization error of each pixel is distributed to the neighboring pixels, which
+    /// results in less visible patterns than ordered dithering. The buffer is used to store the
+    /// errors for the next row and must have at least one entry per pixel in a row.
+    ///
+    /// Error diffusion requires the pixels to be drawn in row-major order and is therefore only
+    /// used for [`fill_contiguous`] and [`fill_solid`]. Other drawing operations and areas which
+    /// are wider than the buffer use ordered dithering instead.
+    ///
+    /// [`fill_contiguous`]: trait.DrawTarget.html#method.fill_contiguous
+    /// [`fill_solid`]: trait.DrawTarget.html#method.fill_solid
+    ErrorDiffusion(&'a mut [QuantizationError]),
+}
+
+/// Dithered draw target.
+///
+/// Created by calling [`dithered`] on any [`DrawTarget`].
+/// See the [`dithered`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`dithered`]: trait.DrawTargetExt.html#tymethod.dithered
+#[derive(Debug)]
+pub struct Dithered<'a, 'b, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    dithering: Dithering<'b>,
+}
+
+impl<'a, 'b, T> Dithered<'a, 'b, T>
+where
+    T: DrawTarget,
+    T::Color: DitherColor,
+{
+    pub(super) fn new(parent: &'a mut T, dithering: Dithering<'b>) -> Self {
+        Self { parent, dithering }
+    }
+}
+
+/// Quantizes a color using ordered dithering.
+fn ordered<C>(point: Point, color: Rgb888) -> C
+where
+    C: DitherColor,
+{
+    let threshold = i32::from(BAYER_MATRIX[(point.y & 3) as usize][(point.x & 3) as usize]);
+
+    // The offset is scaled to the range between -0.5 and +0.5 times the distance between two
+    // adjacent levels of the output channel.
+    let channel = |value: u8, max: u8| {
+        let offset = (2 * threshold - 15) * 255 / (32 * i32::from(max));
+
+        (i32::from(value) + offset).max(0).min(255) as u8
+    };
+
+    C::from(Rgb888::new(
+        channel(color.r(), C::CHANNEL_MAX[0]),
+        channel(color.g(), C::CHANNEL_MAX[1]),
+        channel(color.b(), C::CHANNEL_MAX[2]),
+    ))
+}
+
+/// Floyd–Steinberg error diffusion state for a single row.
+struct ErrorDiffusion<'a> {
+    /// Errors from the previous row, which are replaced by the errors for the next row.
+    errors: &'a mut [QuantizationError],
+    /// Error for the next pixel in the current row.
+    right: [i16; 3],
+    /// Error for the pixel below the next pixel.
+    below_right: [i16; 3],
+}
+
+impl<'a> ErrorDiffusion<'a> {
+    fn new(errors: &'a mut [QuantizationError]) -> Self {
+        for error in errors.iter_mut() {
+            *error = QuantizationError::default();
+        }
+
+        Self {
+            errors,
+            right: [0; 3],
+            below_right: [0; 3],
+        }
+    }
+
+    /// Quantizes the pixel at index `x` in the current row.
+    fn quantize<C>(&mut self, x: usize, color: Rgb888) -> C
+    where
+        C: DitherColor,
+    {
+        if x == 0 {
+            self.right = [0; 3];
+            self.below_right = [0; 3];
+        }
+
+        let input = [color.r(), color.g(), color.b()];
+        let above = self.errors[x].0;
+
+        let mut wanted = [0u8; 3];
+        for i in 0..3 {
+            wanted[i] = (i16::from(input[i]) + above[i] + self.right[i])
+                .max(0)
+                .min(255) as u8;
+        }
+
+        let output = C::from(Rgb888::new(wanted[0], wanted[1], wanted[2]));
+        let actual: Rgb888 = output.into();
+        let actual = [actual.r(), actual.g(), actual.b()];
+
+        let mut below = [0; 3];
+        for i in 0..3 {
+            let error = i16::from(wanted[i]) - i16::from(actual[i]);
+
+            // The error is distributed using the weights 7/16, 3/16, 5/16 and 1/16. The last
+            // part receives the remainder to prevent rounding errors from accumulating.
+            let right = error * 7 / 16;
+            let below_left = error * 3 / 16;
+            let below_center = error * 5 / 16;
+
+            if x > 0 {
+                self.errors[x - 1].0[i] += below_left;
+            }
+            below[i] = below_center + self.below_right[i];
+
+            self.right[i] = right;
+            self.below_right[i] = error - right - below_left - below_center;
+        }
+        self.errors[x] = QuantizationError(below);
+
+        output
+    }
+}
+
+impl<T> DrawTarget for Dithered<'_, '_, T>
+where
+    T: DrawTarget,
+    T::Color: DitherColor,
+{
+    type Color = Rgb888;
+    type Error = T::Error;
+
+    // Solid fills are drawn using `fill_contiguous`, because the dithering pattern changes the
+    // color of individual pixels. Pixels can't be read back, because the quantized colors can't
+    // be converted back to `Rgb888`.
+    const CAPABILITIES: Capabilities = T::CAPABILITIES
+        .with_read_back(false)
+        .with_hardware_fill(T::CAPABILITIES.hardware_blit);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point, ordered(point, color))),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let width = area.size.width as usize;
+
+        match &mut self.dithering {
+            Dithering::ErrorDiffusion(buffer) if buffer.len() >= width => {
+                let mut diffusion = ErrorDiffusion::new(&mut buffer[0..width]);
+                let left = area.top_left.x;
+
+                self.parent.fill_contiguous(
+                    area,
+                    area.points()
+                        .zip(colors)
+                        .map(|(point, color)| diffusion.quantize((point.x - left) as usize, color)),
+                )
+            }
+            _ => self.parent.fill_contiguous(
+                area,
+                area.points()
+                    .zip(colors)
+                    .map(|(point, color)| ordered(point, color)),
+            ),
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_contiguous(area, core::iter::repeat(color))
+    }
+}
+
+impl<T> Dimensions for Dithered<'_, '_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{draw_target::DrawTargetExt, geometry::Size, mock_display::MockDisplay};
+
+    const GRAY_50: Rgb888 = Rgb888::new(128, 128, 128);
+
+    #[test]
+    fn ordered_binary_color() {
+        let area = Rectangle::new(Point::new(0, 0), Size::new(8, 4));
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display
+            .dithered(Dithering::Ordered)
+            .fill_solid(&area, GRAY_50)
+            .unwrap();
+
+        display.assert_pattern(&[
+            ".#.#.#.#", //
+            "#.#.#.#.", //
+            ".#.#.#.#", //
+            "#.#.#.#.", //
+        ]);
+    }
+
+    #[test]
+    fn ordered_draw_iter() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display
+            .dithered(Dithering::Ordered)
+            .draw_iter(
+                Rectangle::new(Point::zero(), Size::new(4, 4))
+                    .points()
+                    .map(|p| Pixel(p, Rgb888::new(64, 64, 64))),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "....", //
+            "#.#.", //
+            "....", //
+            "#.#.", //
+        ]);
+    }
+
+    #[test]
+    fn ordered_preserves_exact_colors() {
+        let area = Rectangle::new(Point::new(0, 0), Size::new(4, 4));
+
+        for &color in &[Rgb888::BLACK, Rgb888::WHITE, Rgb888::BLUE, Rgb888::YELLOW] {
+            let mut display = MockDisplay::<Rgb565>::new();
+            display
+                .dithered(Dithering::Ordered)
+                .fill_solid(&area, color)
+                .unwrap();
+
+            let mut expected = MockDisplay::new();
+            expected.fill_solid(&area, Rgb565::from(color)).unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", color));
+        }
+    }
+
+    #[test]
+    fn ordered_gray2() {
+        let mut display = MockDisplay::<Gray2>::new();
+        display
+            .dithered(Dithering::Ordered)
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(4, 2)),
+                Rgb888::new(128, 128, 128),
+            )
+            .unwrap();
+
+        // 128 is halfway between the gray levels 85 and 170.
+        display.assert_pattern(&[
+            "1212", //
+            "2121", //
+        ]);
+    }
+
+    #[test]
+    fn error_diffusion() {
+        let mut buffer = [QuantizationError::default(); 8];
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display
+            .dithered(Dithering::ErrorDiffusion(&mut buffer))
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(8, 4)), GRAY_50)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#.#.#.#.", //
+            ".#.#.#.#", //
+            "#.#.#.#.", //
+            ".#.#.#.#", //
+        ]);
+    }
+
+    #[test]
+    fn error_diffusion_preserves_average() {
+        let mut buffer = [QuantizationError::default(); 16];
+        let area = Rectangle::new(Point::new(3, 2), Size::new(16, 16));
+
+        for &(luma, expected_on) in &[(0, 0), (64, 64), (191, 192), (255, 256)] {
+            let mut display = MockDisplay::<BinaryColor>::new();
+            display
+                .dithered(Dithering::ErrorDiffusion(&mut buffer))
+                .fill_solid(&area, Rgb888::new(luma, luma, luma))
+                .unwrap();
+
+            let on = area
+                .points()
+                .filter(|p| display.get_pixel(*p) == Some(BinaryColor::On))
+                .count() as i32;
+
+            assert!((on - expected_on).abs() <= 2, "luma: {}, on: {}", luma, on);
+        }
+    }
+
+    #[test]
+    fn error_diffusion_buffer_too_small() {
+        let area = Rectangle::new(Point::new(0, 0), Size::new(8, 4));
+
+        let mut buffer = [QuantizationError::default(); 7];
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display
+            .dithered(Dithering::ErrorDiffusion(&mut buffer))
+            .fill_solid(&area, GRAY_50)
+            .unwrap();
+
+        let mut expected = MockDisplay::<BinaryColor>::new();
+        expected
+            .dithered(Dithering::Ordered)
+            .fill_solid(&area, GRAY_50)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let bounding_box = display.bounding_box();
+
+        assert_eq!(
+            display.dithered(Dithering::Ordered).bounding_box(),
+            bounding_box
+        );
+    }
+}
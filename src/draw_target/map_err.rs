@@ -0,0 +1,198 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{Dimensions, Point},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Error mapping draw target.
+///
+/// Created by calling [`map_err`] on any [`DrawTarget`].
+/// See the [`map_err`] method documentation for more information.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`map_err`]: trait.DrawTargetExt.html#tymethod.map_err
+#[derive(Debug)]
+pub struct MapErr<'a, T, F> {
+    /// The parent draw target.
+    parent: &'a mut T,
+
+    /// The error mapping function.
+    f: F,
+}
+
+impl<'a, T, F, E> MapErr<'a, T, F>
+where
+    T: DrawTarget,
+    F: FnMut(T::Error) -> E,
+{
+    pub(super) fn new(parent: &'a mut T, f: F) -> Self {
+        Self { parent, f }
+    }
+}
+
+impl<T, F, E> DrawTarget for MapErr<'_, T, F>
+where
+    T: DrawTarget,
+    F: FnMut(T::Error) -> E,
+{
+    type Color = T::Color;
+    type Error = E;
+
+    const CAPABILITIES: Capabilities = T::CAPABILITIES;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.parent.draw_iter(pixels).map_err(&mut self.f)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.parent
+            .fill_contiguous(area, colors)
+            .map_err(&mut self.f)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.fill_solid(area, color).map_err(&mut self.f)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color).map_err(&mut self.f)
+    }
+}
+
+impl<T, F, E> GetPixel for MapErr<'_, T, F>
+where
+    T: GetPixel,
+    F: FnMut(T::Error) -> E,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent.pixel(point)
+    }
+}
+
+impl<T, F> Dimensions for MapErr<'_, T, F>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::{OriginDimensions, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    /// Draw target which returns an error for every drawing operation.
+    struct Failing;
+
+    #[derive(Debug, PartialEq)]
+    struct FailingError(&'static str);
+
+    impl DrawTarget for Failing {
+        type Color = BinaryColor;
+        type Error = FailingError;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            Err(FailingError("draw_iter"))
+        }
+
+        fn fill_contiguous<I>(&mut self, _area: &Rectangle, _colors: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Self::Color>,
+        {
+            Err(FailingError("fill_contiguous"))
+        }
+
+        fn fill_solid(
+            &mut self,
+            _area: &Rectangle,
+            _color: Self::Color,
+        ) -> Result<(), Self::Error> {
+            Err(FailingError("fill_solid"))
+        }
+
+        fn clear(&mut self, _color: Self::Color) -> Result<(), Self::Error> {
+            Err(FailingError("clear"))
+        }
+    }
+
+    impl OriginDimensions for Failing {
+        fn size(&self) -> Size {
+            Size::new(12, 34)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum MappedError {
+        Failing(&'static str),
+    }
+
+    #[test]
+    fn errors_are_mapped() {
+        let area = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut failing = Failing;
+        let mut mapped = failing.map_err(|FailingError(name)| MappedError::Failing(name));
+
+        assert_eq!(
+            mapped.draw_iter(core::iter::once(Pixel(Point::zero(), BinaryColor::On))),
+            Err(MappedError::Failing("draw_iter"))
+        );
+        assert_eq!(
+            mapped.fill_contiguous(&area, core::iter::repeat(BinaryColor::On)),
+            Err(MappedError::Failing("fill_contiguous"))
+        );
+        assert_eq!(
+            mapped.fill_solid(&area, BinaryColor::On),
+            Err(MappedError::Failing("fill_solid"))
+        );
+        assert_eq!(
+            mapped.clear(BinaryColor::On),
+            Err(MappedError::Failing("clear"))
+        );
+    }
+
+    #[test]
+    fn drawing_is_forwarded() {
+        let area = Rectangle::new(Point::new(1, 2), Size::new(3, 2));
+
+        let mut display = MockDisplay::new();
+        display
+            .map_err(|_| MappedError::Failing("unreachable"))
+            .fill_solid(&area, BinaryColor::On)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            "    ", //
+            " ###", //
+            " ###", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut failing = Failing;
+
+        assert_eq!(
+            failing.map_err(|_| ()).bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(12, 34))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{Dimensions, Point},
+    primitives::{PointsIter, Rectangle},
+    Pixel, SaturatingCast,
+};
+
+/// Mirrored draw target.
+///
+/// Created by calling [`mirrored_x`] or [`mirrored_y`] on any [`DrawTarget`].
+/// See the [`mirrored_x`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`mirrored_x`]: trait.DrawTargetExt.html#tymethod.mirrored_x
+/// [`mirrored_y`]: trait.DrawTargetExt.html#tymethod.mirrored_y
+#[derive(Debug)]
+pub struct Mirrored<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    mapping: Mapping,
+}
+
+impl<'a, T> Mirrored<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, axis: Axis) -> Self {
+        let area = parent.bounding_box();
+
+        Self {
+            parent,
+            mapping: Mapping { area, axis },
+        }
+    }
+}
+
+/// Mirrored coordinate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum Axis {
+    X,
+    Y,
+}
+
+/// Mapping from the mirrored coordinate system to the parent coordinate system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Mapping {
+    /// Bounding box of the parent draw target.
+    area: Rectangle,
+    axis: Axis,
+}
+
+impl Mapping {
+    /// Maps a point.
+    fn point(&self, point: Point) -> Point {
+        let Rectangle { top_left, size } = self.area;
+
+        match self.axis {
+            Axis::X => Point::new(
+                2 * top_left.x + size.width.saturating_cast() - 1 - point.x,
+                point.y,
+            ),
+            Axis::Y => Point::new(
+                point.x,
+                2 * top_left.y + size.height.saturating_cast() - 1 - point.y,
+            ),
+        }
+    }
+
+    /// Maps the area that is covered by a rectangle.
+    fn rectangle(&self, rectangle: &Rectangle) -> Rectangle {
+        match rectangle.bottom_right() {
+            Some(bottom_right) => {
+                Rectangle::with_corners(self.point(rectangle.top_left), self.point(bottom_right))
+            }
+            None => *rectangle,
+        }
+    }
+}
+
+impl<T> DrawTarget for Mirrored<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    const CAPABILITIES: Capabilities = T::CAPABILITIES.with_hardware_blit(false);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mapping = self.mapping;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(mapping.point(point), color)),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        // The parent can't be used to fill the area, because the colors aren't in row-major
+        // order after mirroring.
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.mapping.rectangle(area);
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> GetPixel for Mirrored<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent.pixel(self.mapping.point(point))
+    }
+}
+
+impl<T> Dimensions for Mirrored<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.mapping.area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt, geometry::Size, mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    const AREA: Rectangle = Rectangle::new(Point::new(1, 2), Size::new(4, 3));
+
+    /// Draws an L shape into a mirrored 4x3 pixel area.
+    fn draw_l(axis: Axis) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        let mut cropped = display.cropped(&AREA);
+        let mut mirrored = match axis {
+            Axis::X => cropped.mirrored_x(),
+            Axis::Y => cropped.mirrored_y(),
+        };
+
+        mirrored
+            .draw_iter(
+                [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn draw_iter() {
+        draw_l(Axis::X).assert_pattern(&[
+            "     ", //
+            "     ", //
+            "    #", //
+            "   ##", //
+        ]);
+        draw_l(Axis::Y).assert_pattern(&[
+            "     ", //
+            "     ", //
+            "     ", //
+            " ##  ", //
+            " #   ", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous_and_fill_solid() {
+        for &axis in &[Axis::X, Axis::Y] {
+            let area = Rectangle::new(Point::new(0, 1), Size::new(3, 2));
+
+            let mut expected = MockDisplay::new();
+            let mut cropped = expected.cropped(&AREA);
+            Mirrored::new(&mut cropped, axis)
+                .draw_iter(area.points().map(|p| Pixel(p, BinaryColor::On)))
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            let mut cropped = display.cropped(&AREA);
+            Mirrored::new(&mut cropped, axis)
+                .fill_contiguous(&area, core::iter::repeat(BinaryColor::On))
+                .unwrap();
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", axis));
+
+            let mut display = MockDisplay::new();
+            let mut cropped = display.cropped(&AREA);
+            Mirrored::new(&mut cropped, axis)
+                .fill_solid(&area, BinaryColor::On)
+                .unwrap();
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", axis));
+        }
+    }
+
+    #[test]
+    fn fill_contiguous_order() {
+        let mut display = MockDisplay::new();
+        display
+            .cropped(&AREA)
+            .mirrored_x()
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(4, 1)),
+                [true, true, false, false]
+                    .iter()
+                    .map(|on| BinaryColor::from(*on)),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "     ", //
+            " ..##", //
+        ]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(core::iter::once(Pixel(Point::new(63, 10), BinaryColor::On)))
+            .unwrap();
+
+        assert_eq!(
+            display.mirrored_x().pixel(Point::new(0, 10)),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(
+            display.mirrored_y().pixel(Point::new(63, 53)),
+            Some(BinaryColor::On)
+        );
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut translated = display.translated(Point::new(-3, 2));
+        let expected = translated.bounding_box();
+
+        assert_eq!(translated.mirrored_x().bounding_box(), expected);
+        assert_eq!(translated.mirrored_y().bounding_box(), expected);
+    }
+}
@@ -1,18 +1,40 @@
 //! A target for embedded-graphics drawing operations.
 
+mod blended;
 mod clipped;
 mod color_converted;
 mod cropped;
+mod dithered;
+mod map_err;
+mod mirrored;
+mod rotated;
+mod scaled;
+mod transformed;
 mod translated;
 
-use crate::{geometry::Point, pixelcolor::PixelColor, primitives::Rectangle};
+use crate::{
+    geometry::{AffineTransform, Point, Rotation},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
 
+pub use blended::{BlendMode, Blended};
 pub use clipped::Clipped;
 pub use color_converted::ColorConverted;
 pub use cropped::Cropped;
+pub use dithered::{DitherColor, Dithered, Dithering, QuantizationError};
+pub use map_err::MapErr;
+pub use mirrored::Mirrored;
+pub use rotated::Rotated;
+pub use scaled::Scaled;
+pub(crate) use scaled::Scaling;
+pub use transformed::{HoleFilling, Transformed};
 pub use translated::Translated;
 
-pub use embedded_graphics_core::draw_target::DrawTarget;
+pub use embedded_graphics_core::draw_target::{Capabilities, DrawTarget, GetPixel, IterationOrder};
+
+#[cfg(feature = "async")]
+pub use embedded_graphics_core::draw_target::AsyncDrawTarget;
 
 /// Extension trait for `DrawTarget`s.
 pub trait DrawTargetExt: DrawTarget + Sized {
@@ -210,6 +232,308 @@ pub trait DrawTargetExt: DrawTarget + Sized {
     fn color_converted<C>(&mut self) -> ColorConverted<'_, Self, C>
     where
         C: PixelColor + Into<Self::Color>;
+
+    /// Creates a blended draw target based on this draw target.
+    ///
+    /// All pixels drawn to the blended draw target are combined with the current content of the
+    /// parent draw target using the given blend mode and opacity. The `opacity` ranges from `0`,
+    /// which leaves the parent unchanged, to `255` for the full effect of the blend mode.
+    ///
+    /// Blending requires the parent draw target to implement [`GetPixel`]. Pixels whose current
+    /// color can't be read back are drawn without blending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::BlendMode,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb888,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    ///
+    /// let area = Rectangle::new(Point::zero(), Size::new(4, 4));
+    /// display.fill_solid(&area, Rgb888::BLUE)?;
+    ///
+    /// // Draw a semi transparent red overlay.
+    /// area.into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+    ///     .draw(&mut display.blended(128, BlendMode::SourceOver))?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::zero()), Some(Rgb888::new(128, 0, 127)));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`GetPixel`]: trait.GetPixel.html
+    fn blended(&mut self, opacity: u8, blend_mode: BlendMode) -> Blended<'_, Self>;
+
+    /// Creates a transformed draw target based on this draw target.
+    ///
+    /// All drawing operations are mapped through an [`AffineTransform`] before they are passed to
+    /// the parent draw target, which can be used to draw any drawable rotated by an arbitrary
+    /// angle, scaled or sheared. The `hole_filling` parameter defines how gaps between the
+    /// transformed pixels are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::HoleFilling,
+    ///     geometry::AffineTransform,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// // Rotate a gauge needle by 30° around the center of the gauge.
+    /// let center = Point::new(32, 32);
+    /// let transform = AffineTransform::rotation(30.0.deg()).around(center);
+    ///
+    /// Rectangle::new(center - Point::new(0, 1), Size::new(25, 3))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+    ///     .draw(&mut display.transformed(transform, HoleFilling::Cover))?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`AffineTransform`]: ../geometry/struct.AffineTransform.html
+    fn transformed(
+        &mut self,
+        transform: AffineTransform,
+        hole_filling: HoleFilling,
+    ) -> Transformed<'_, Self>;
+
+    /// Creates a rotated draw target based on this draw target.
+    ///
+    /// The coordinate system of the returned draw target is rotated clockwise by the given
+    /// rotation, relative to the coordinate system of the parent draw target. This can be used to
+    /// draw in the logical orientation of a display, regardless of how the display panel is
+    /// mounted, without requiring support from the display driver. The bounding box of the rotated
+    /// draw target has the same top left corner as the parent and its width and height are
+    /// swapped for rotations by 90° and 270°.
+    ///
+    /// Rectangular areas which are filled with a solid color are still passed to
+    /// [`fill_solid`] of the parent draw target. Other drawing operations are converted into
+    /// individual pixels for all rotations except [`Rotation::Deg0`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     geometry::Rotation,
+    ///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Baseline, Text},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// // A 64x32 pixel panel which is mounted in portrait orientation.
+    /// let mut panel = display.cropped(&Rectangle::new(Point::zero(), Size::new(64, 32)));
+    /// let mut portrait = panel.rotated(Rotation::Deg90);
+    ///
+    /// assert_eq!(portrait.bounding_box().size, Size::new(32, 64));
+    ///
+    /// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+    /// Text::with_baseline("Up", Point::zero(), style, Baseline::Top).draw(&mut portrait)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`fill_solid`]: trait.DrawTarget.html#method.fill_solid
+    /// [`Rotation::Deg0`]: ../geometry/enum.Rotation.html#variant.Deg0
+    fn rotated(&mut self, rotation: Rotation) -> Rotated<'_, Self>;
+
+    /// Creates a draw target which maps errors using the given function.
+    ///
+    /// All drawing operations are passed to the parent draw target unchanged and any returned
+    /// error is converted by calling `f`. This makes it possible to use draw targets with
+    /// different error types in generic code, which requires a common error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::convert::Infallible;
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    ///
+    /// /// Errors which can occur while rendering the UI.
+    /// #[derive(Debug)]
+    /// enum RenderError {
+    ///     /// The communication with the display failed.
+    ///     Interface,
+    /// }
+    ///
+    /// fn render<D>(target: &mut D) -> Result<(), RenderError>
+    /// where
+    ///     D: DrawTarget<Color = BinaryColor, Error = RenderError>,
+    /// {
+    ///     Circle::new(Point::new(2, 2), 10)
+    ///         .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///         .draw(target)
+    /// }
+    ///
+    /// // Drawing to this display can't fail, but the `render` function can still be used by
+    /// // mapping the `Infallible` error to `RenderError`.
+    /// let mut display = MockDisplay::<BinaryColor>::new();
+    /// render(&mut display.map_err(|error: Infallible| match error {}))?;
+    /// # Ok::<(), RenderError>(())
+    /// ```
+    fn map_err<F, E>(&mut self, f: F) -> MapErr<'_, Self, F>
+    where
+        F: FnMut(Self::Error) -> E;
+
+    /// Creates a scaled draw target based on this draw target.
+    ///
+    /// Each pixel that is drawn to the scaled draw target is drawn as a block of `factor` x
+    /// `factor` pixels to the parent draw target, which can be used to show user interfaces that
+    /// were designed for a low resolution on a high resolution display. The pixel at point `p`
+    /// is drawn to the block with the top left corner at `p * factor`. The bounding box of the
+    /// scaled draw target contains all points whose blocks are completely inside the bounding box
+    /// of the parent draw target.
+    ///
+    /// Areas which are filled with a solid color are passed to [`fill_solid`] of the parent draw
+    /// target. Other drawing operations are converted into individual pixels if `factor` is
+    /// larger than one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// // Draw the text with 2x2 pixel blocks.
+    /// let mut scaled = display.scaled(2);
+    /// assert_eq!(scaled.bounding_box().size, Size::new(32, 32));
+    ///
+    /// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+    /// Text::with_baseline("Big", Point::zero(), style, Baseline::Top).draw(&mut scaled)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`fill_solid`]: trait.DrawTarget.html#method.fill_solid
+    fn scaled(&mut self, factor: u32) -> Scaled<'_, Self>;
+
+    /// Creates a horizontally mirrored draw target based on this draw target.
+    ///
+    /// All drawing operations are mirrored at the vertical center line of the bounding box of the
+    /// parent draw target, which can be used to correct the output of displays which are viewed
+    /// through a mirror, like head-up displays that are reflected in a windshield. The bounding
+    /// box of the mirrored draw target is the same as the bounding box of the parent.
+    ///
+    /// Areas which are filled with a solid color are passed to [`fill_solid`] of the parent draw
+    /// target. Other drawing operations are converted into individual pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// // The text will be readable in a mirror.
+    /// let mut mirrored = display.mirrored_x();
+    ///
+    /// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+    /// Text::with_baseline("HUD", Point::zero(), style, Baseline::Top).draw(&mut mirrored)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`fill_solid`]: trait.DrawTarget.html#method.fill_solid
+    fn mirrored_x(&mut self) -> Mirrored<'_, Self>;
+
+    /// Creates a vertically mirrored draw target based on this draw target.
+    ///
+    /// All drawing operations are mirrored at the horizontal center line of the bounding box of
+    /// the parent draw target. See [`mirrored_x`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Line, PrimitiveStyle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// // Draw a line along the bottom edge of the display.
+    /// Line::new(Point::new(0, 0), Point::new(63, 0))
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut display.mirrored_y())?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(0, 63)), Some(BinaryColor::On));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`mirrored_x`]: #tymethod.mirrored_x
+    fn mirrored_y(&mut self) -> Mirrored<'_, Self>;
+
+    /// Creates a dithered draw target based on this draw target.
+    ///
+    /// The returned draw target accepts [`Rgb888`] colors and converts them to the color type of
+    /// this draw target by using the given [`Dithering`] algorithm. Dithering reduces the visible
+    /// banding when drawing photos or gradients on draw targets with a low color depth, like
+    /// monochrome or grayscale displays.
+    ///
+    /// # Examples
+    ///
+    /// Draw an `Rgb888` image to a monochrome display using error diffusion:
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::{Dithering, QuantizationError},
+    ///     image::{Image, ImageRaw},
+    ///     pixelcolor::{BinaryColor, Rgb888},
+    ///     prelude::*,
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::<BinaryColor>::new();
+    ///
+    /// // A 4x1 pixel gradient from dark to light gray.
+    /// const DATA: &[u8] = &[
+    ///     32, 32, 32, 96, 96, 96, 160, 160, 160, 224, 224, 224, //
+    /// ];
+    /// let image = ImageRaw::<Rgb888>::new(DATA, 4);
+    ///
+    /// // The buffer needs at least one entry per pixel in the widest drawn row.
+    /// let mut buffer = [QuantizationError::default(); 64];
+    ///
+    /// Image::new(&image, Point::zero())
+    ///     .draw(&mut display.dithered(Dithering::ErrorDiffusion(&mut buffer)))?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Rgb888`]: ../pixelcolor/struct.Rgb888.html
+    /// [`Dithering`]: enum.Dithering.html
+    fn dithered<'b>(&mut self, dithering: Dithering<'b>) -> Dithered<'_, 'b, Self>
+    where
+        Self::Color: DitherColor;
 }
 
 impl<T> DrawTargetExt for T
@@ -234,15 +558,60 @@ where
     {
         ColorConverted::new(self)
     }
+
+    fn blended(&mut self, opacity: u8, blend_mode: BlendMode) -> Blended<'_, Self> {
+        Blended::new(self, opacity, blend_mode)
+    }
+
+    fn transformed(
+        &mut self,
+        transform: AffineTransform,
+        hole_filling: HoleFilling,
+    ) -> Transformed<'_, Self> {
+        Transformed::new(self, transform, hole_filling)
+    }
+
+    fn rotated(&mut self, rotation: Rotation) -> Rotated<'_, Self> {
+        Rotated::new(self, rotation)
+    }
+
+    fn map_err<F, E>(&mut self, f: F) -> MapErr<'_, Self, F>
+    where
+        F: FnMut(Self::Error) -> E,
+    {
+        MapErr::new(self, f)
+    }
+
+    fn scaled(&mut self, factor: u32) -> Scaled<'_, Self> {
+        Scaled::new(self, factor)
+    }
+
+    fn mirrored_x(&mut self) -> Mirrored<'_, Self> {
+        Mirrored::new(self, mirrored::Axis::X)
+    }
+
+    fn mirrored_y(&mut self) -> Mirrored<'_, Self> {
+        Mirrored::new(self, mirrored::Axis::Y)
+    }
+
+    fn dithered<'b>(&mut self, dithering: Dithering<'b>) -> Dithered<'_, 'b, Self>
+    where
+        Self::Color: DitherColor,
+    {
+        Dithered::new(self, dithering)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        draw_target::{DrawTarget, DrawTargetExt},
-        geometry::{Dimensions, Point, Size},
+        draw_target::{
+            BlendMode, Capabilities, Dithering, DrawTarget, DrawTargetExt, HoleFilling,
+            IterationOrder, Rotation,
+        },
+        geometry::{AffineTransform, Dimensions, Point, Size},
         mock_display::MockDisplay,
-        pixelcolor::BinaryColor,
+        pixelcolor::{BinaryColor, Rgb888},
         primitives::{Primitive, PrimitiveStyle, Rectangle},
         Drawable, Pixel,
     };
@@ -365,4 +734,89 @@ mod tests {
             Rectangle::new(top_left, expected_size),
         );
     }
+
+    /// Draw target with accelerated fills.
+    struct Accelerated(MockDisplay<BinaryColor>);
+
+    impl DrawTarget for Accelerated {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        const CAPABILITIES: Capabilities = Capabilities::new()
+            .with_hardware_fill(true)
+            .with_hardware_blit(true)
+            .with_iteration_order(IterationOrder::ColumnMajor);
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            self.0.draw_iter(pixels)
+        }
+    }
+
+    impl Dimensions for Accelerated {
+        fn bounding_box(&self) -> Rectangle {
+            self.0.bounding_box()
+        }
+    }
+
+    fn capabilities<T: DrawTarget>(_target: &T) -> Capabilities {
+        T::CAPABILITIES
+    }
+
+    #[test]
+    fn capabilities_are_forwarded() {
+        let mut display = Accelerated(MockDisplay::new());
+        let expected = Accelerated::CAPABILITIES;
+
+        assert_eq!(capabilities(&display.translated(Point::zero())), expected);
+        assert_eq!(
+            capabilities(
+                &display
+                    .cropped(&Rectangle::zero())
+                    .clipped(&Rectangle::zero())
+            ),
+            expected
+        );
+
+        let mirrored = capabilities(&display.mirrored_x());
+        assert!(mirrored.hardware_fill);
+        assert!(!mirrored.hardware_blit);
+
+        let rotated = capabilities(&display.rotated(Rotation::Deg90));
+        assert!(rotated.hardware_fill);
+        assert!(!rotated.hardware_blit);
+        assert_eq!(rotated.iteration_order, IterationOrder::RowMajor);
+
+        let color_converted = capabilities(&display.color_converted::<BinaryColor>());
+        assert_eq!(color_converted, expected);
+
+        // Solid fills are drawn using `fill_contiguous` to apply the dithering pattern.
+        let dithered = capabilities(&display.dithered(Dithering::Ordered));
+        assert!(dithered.hardware_fill);
+        assert!(dithered.hardware_blit);
+        assert!(!dithered.read_back);
+
+        let transformed = capabilities(
+            &display.transformed(AffineTransform::scaling(2.0, 2.0), HoleFilling::None),
+        );
+        assert_eq!(transformed, Capabilities::new());
+
+        assert!(
+            capabilities(&MockDisplay::<BinaryColor>::new().translated(Point::zero())).read_back
+        );
+    }
+
+    #[test]
+    fn blended_capabilities() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let expected = MockDisplay::<Rgb888>::CAPABILITIES;
+
+        let blended = capabilities(&display.blended(128, BlendMode::SourceOver));
+        assert!(blended.read_back);
+        assert!(!blended.hardware_fill);
+        assert!(!blended.hardware_blit);
+        assert_eq!(blended.iteration_order, expected.iteration_order);
+    }
 }
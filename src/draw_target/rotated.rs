@@ -0,0 +1,358 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel, IterationOrder},
+    geometry::{Dimensions, Point, Rotation, Size},
+    primitives::{PointsIter, Rectangle},
+    Pixel, SaturatingCast,
+};
+
+/// Rotated draw target.
+///
+/// Created by calling [`rotated`] on any [`DrawTarget`].
+/// See the [`rotated`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`rotated`]: trait.DrawTargetExt.html#tymethod.rotated
+#[derive(Debug)]
+pub struct Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    mapping: Mapping,
+}
+
+impl<'a, T> Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, rotation: Rotation) -> Self {
+        let area = parent.bounding_box();
+
+        let right = area.size.width.saturating_cast() - 1;
+        let bottom = area.size.height.saturating_cast() - 1;
+
+        // The rotated area is moved back into the parent area, which means that the top left
+        // corner of the rotated draw target is mapped to a different corner of the parent.
+        let corner = match rotation {
+            Rotation::Deg0 => Point::zero(),
+            Rotation::Deg90 => Point::new(right, 0),
+            Rotation::Deg180 => Point::new(right, bottom),
+            Rotation::Deg270 => Point::new(0, bottom),
+        };
+
+        let offset = area.top_left + corner - rotation.rotate(area.top_left);
+
+        Self::with_offset(parent, rotation, offset)
+    }
+
+    /// Creates a rotated draw target which rotates all points around the origin and translates
+    /// them by `offset` afterwards.
+    pub(crate) fn with_offset(parent: &'a mut T, rotation: Rotation, offset: Point) -> Self {
+        Self {
+            parent,
+            mapping: Mapping { offset, rotation },
+        }
+    }
+}
+
+/// Mapping from the rotated coordinate system to the parent coordinate system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Mapping {
+    /// Offset which is added after the rotation.
+    offset: Point,
+    rotation: Rotation,
+}
+
+impl Mapping {
+    /// Returns `true` if the mapping doesn't change the position of any point.
+    fn is_identity(&self) -> bool {
+        self.rotation == Rotation::Deg0 && self.offset == Point::zero()
+    }
+
+    /// Maps a point.
+    fn point(&self, point: Point) -> Point {
+        self.offset + self.rotation.rotate(point)
+    }
+
+    /// Maps a point from the parent coordinate system back to the rotated coordinate system.
+    fn inverse_point(&self, point: Point) -> Point {
+        self.rotation.inverse().rotate(point - self.offset)
+    }
+
+    /// Maps the area that is covered by a rectangle.
+    fn rectangle(&self, rectangle: &Rectangle) -> Rectangle {
+        match rectangle.bottom_right() {
+            Some(bottom_right) => {
+                Rectangle::with_corners(self.point(rectangle.top_left), self.point(bottom_right))
+            }
+            None => Rectangle::new(self.point(rectangle.top_left), Size::zero()),
+        }
+    }
+}
+
+impl<T> DrawTarget for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    // Accelerated `fill_contiguous` calls can only be forwarded and the preferred iteration order
+    // only applies if the draw target isn't rotated, which isn't known at compile time.
+    const CAPABILITIES: Capabilities = T::CAPABILITIES
+        .with_hardware_blit(false)
+        .with_iteration_order(IterationOrder::RowMajor);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.mapping.is_identity() {
+            return self.parent.draw_iter(pixels);
+        }
+
+        let mapping = self.mapping;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(mapping.point(point), color)),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.mapping.is_identity() {
+            return self.parent.fill_contiguous(area, colors);
+        }
+
+        // The parent can't be used to fill the area, because the colors aren't in row-major
+        // order after the rotation.
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.mapping.rectangle(area);
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> GetPixel for Rotated<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if self.bounding_box().contains(point) {
+            self.parent.pixel(self.mapping.point(point))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Dimensions for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        let area = self.parent.bounding_box();
+
+        match area.bottom_right() {
+            Some(bottom_right) => Rectangle::with_corners(
+                self.mapping.inverse_point(area.top_left),
+                self.mapping.inverse_point(bottom_right),
+            ),
+            None => Rectangle::new(self.mapping.inverse_point(area.top_left), Size::zero()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{draw_target::DrawTargetExt, mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    const AREA: Rectangle = Rectangle::new(Point::new(1, 2), Size::new(4, 3));
+
+    const ROTATIONS: [Rotation; 4] = [
+        Rotation::Deg0,
+        Rotation::Deg90,
+        Rotation::Deg180,
+        Rotation::Deg270,
+    ];
+
+    /// Draws an L shape into a rotated 4x3 pixel area.
+    fn draw_l(rotation: Rotation) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        let mut cropped = display.cropped(&AREA);
+        let mut rotated = cropped.rotated(rotation);
+
+        rotated
+            .draw_iter(
+                [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn draw_iter() {
+        draw_l(Rotation::Deg0).assert_pattern(&[
+            "     ", //
+            "     ", //
+            " #   ", //
+            " ##  ", //
+        ]);
+        draw_l(Rotation::Deg90).assert_pattern(&[
+            "     ", //
+            "     ", //
+            "   ##", //
+            "   # ", //
+        ]);
+        draw_l(Rotation::Deg180).assert_pattern(&[
+            "     ", //
+            "     ", //
+            "     ", //
+            "   ##", //
+            "    #", //
+        ]);
+        draw_l(Rotation::Deg270).assert_pattern(&[
+            "     ", //
+            "     ", //
+            "     ", //
+            "  #  ", //
+            " ##  ", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous_and_fill_solid() {
+        for &rotation in ROTATIONS.iter() {
+            let area = Rectangle::new(Point::new(0, 1), Size::new(2, 2));
+
+            let mut expected = MockDisplay::new();
+            expected
+                .cropped(&AREA)
+                .rotated(rotation)
+                .draw_iter(area.points().map(|p| Pixel(p, BinaryColor::On)))
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            display
+                .cropped(&AREA)
+                .rotated(rotation)
+                .fill_contiguous(&area, core::iter::repeat(BinaryColor::On))
+                .unwrap();
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", rotation));
+
+            let mut display = MockDisplay::new();
+            display
+                .cropped(&AREA)
+                .rotated(rotation)
+                .fill_solid(&area, BinaryColor::On)
+                .unwrap();
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", rotation));
+        }
+    }
+
+    #[test]
+    fn clear() {
+        for &rotation in ROTATIONS.iter() {
+            let mut display = MockDisplay::new();
+            display
+                .cropped(&AREA)
+                .rotated(rotation)
+                .clear(BinaryColor::On)
+                .unwrap();
+
+            let mut expected = MockDisplay::new();
+            expected.fill_solid(&AREA, BinaryColor::On).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut translated = display.translated(Point::new(-3, -2));
+
+        for &rotation in ROTATIONS.iter() {
+            assert_eq!(
+                translated.rotated(rotation).bounding_box(),
+                Rectangle::new(Point::new(3, 2), Size::new(64, 64)),
+                "{:?}",
+                rotation
+            );
+        }
+
+        let mut cropped = display.cropped(&AREA);
+        for &(rotation, expected_size) in &[
+            (Rotation::Deg0, Size::new(4, 3)),
+            (Rotation::Deg90, Size::new(3, 4)),
+            (Rotation::Deg180, Size::new(4, 3)),
+            (Rotation::Deg270, Size::new(3, 4)),
+        ] {
+            assert_eq!(
+                cropped.rotated(rotation).bounding_box(),
+                Rectangle::new(Point::zero(), expected_size),
+                "{:?}",
+                rotation
+            );
+        }
+    }
+
+    #[test]
+    fn translated_parent() {
+        let mut display = MockDisplay::new();
+        display
+            .translated(Point::new(-3, -2))
+            .rotated(Rotation::Deg90)
+            .draw_iter(
+                [Point::new(3, 2), Point::new(66, 65)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected
+            .draw_iter(
+                [Point::new(63, 0), Point::new(0, 63)]
+                    .iter()
+                    .map(|p| Pixel(*p, BinaryColor::On)),
+            )
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(core::iter::once(Pixel(Point::new(4, 2), BinaryColor::On)))
+            .unwrap();
+
+        let mut cropped = display.cropped(&AREA);
+        let rotated = cropped.rotated(Rotation::Deg90);
+
+        assert_eq!(rotated.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(rotated.pixel(Point::new(1, 0)), None);
+        assert_eq!(rotated.pixel(Point::new(3, 0)), None);
+    }
+}
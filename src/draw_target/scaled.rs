@@ -0,0 +1,475 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{Dimensions, Point, Size},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Nearest neighbor scaling factor along one axis.
+///
+/// Point `x` covers all points `x'` in the parent draw target for which
+/// `floor(x' * denom / num) == x`. This results in blocks with the same size if `num` is a
+/// multiple of `denom` and skips points if the scaling factor is less than one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Scaling {
+    num: u32,
+    denom: u32,
+}
+
+impl Scaling {
+    /// No scaling.
+    pub const IDENTITY: Self = Self { num: 1, denom: 1 };
+
+    pub fn new(num: u32, denom: u32) -> Self {
+        assert!(
+            num > 0 && denom > 0,
+            "scaling factor numerator and denominator must be greater than zero"
+        );
+
+        Self { num, denom }
+    }
+
+    pub fn is_identity(self) -> bool {
+        self.num == self.denom
+    }
+
+    /// Returns the first coordinate in the parent which is covered by `value` or a larger
+    /// coordinate.
+    fn scale(self, value: i32) -> i32 {
+        let value = i64::from(value) * i64::from(self.num);
+        let denom = i64::from(self.denom);
+
+        -(-value).div_euclid(denom) as i32
+    }
+
+    /// Returns the coordinate which covers a coordinate in the parent.
+    fn unscale(self, value: i32) -> i32 {
+        let value = i64::from(value) * i64::from(self.denom);
+        let num = i64::from(self.num);
+
+        value.div_euclid(num) as i32
+    }
+
+    /// Returns the scaled length of a line which starts at coordinate `0`.
+    pub fn length(self, length: u32) -> u32 {
+        let length = u64::from(length) * u64::from(self.num);
+        let denom = u64::from(self.denom);
+
+        ((length + denom - 1) / denom) as u32
+    }
+}
+
+/// Scaled draw target.
+///
+/// Created by calling [`scaled`] on any [`DrawTarget`].
+/// See the [`scaled`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`scaled`]: trait.DrawTargetExt.html#tymethod.scaled
+#[derive(Debug)]
+pub struct Scaled<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    x: Scaling,
+    y: Scaling,
+}
+
+impl<'a, T> Scaled<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, factor: u32) -> Self {
+        assert!(factor > 0, "scaling factor must be greater than zero");
+
+        Self::with_scaling(parent, Scaling::new(factor, 1), Scaling::new(factor, 1))
+    }
+
+    /// Creates a scaled draw target with independent horizontal and vertical scaling factors.
+    pub(crate) fn with_scaling(parent: &'a mut T, x: Scaling, y: Scaling) -> Self {
+        Self { parent, x, y }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.x.is_identity() && self.y.is_identity()
+    }
+}
+
+/// Returns the area in the parent draw target which is covered by a rectangle.
+fn scale_rectangle(rectangle: &Rectangle, x: Scaling, y: Scaling) -> Rectangle {
+    let end = rectangle.top_left + rectangle.size;
+
+    let top_left = Point::new(x.scale(rectangle.top_left.x), y.scale(rectangle.top_left.y));
+    let bottom_right = Point::new(x.scale(end.x), y.scale(end.y));
+    let size = bottom_right - top_left;
+
+    Rectangle::new(top_left, Size::new(size.x as u32, size.y as u32))
+}
+
+impl<T> DrawTarget for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    const CAPABILITIES: Capabilities = T::CAPABILITIES.with_hardware_blit(false);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.is_identity() {
+            return self.parent.draw_iter(pixels);
+        }
+
+        let (x, y) = (self.x, self.y);
+
+        // Filling each block is faster than drawing the individual pixels of the block if the
+        // parent supports accelerated fills.
+        if T::CAPABILITIES.hardware_fill {
+            for Pixel(point, color) in pixels {
+                let area = scale_rectangle(&Rectangle::new(point, Size::new(1, 1)), x, y);
+
+                self.parent.fill_solid(&area, color)?;
+            }
+
+            return Ok(());
+        }
+
+        self.parent
+            .draw_iter(pixels.into_iter().flat_map(move |Pixel(point, color)| {
+                scale_rectangle(&Rectangle::new(point, Size::new(1, 1)), x, y)
+                    .points()
+                    .map(move |point| Pixel(point, color))
+            }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.is_identity() {
+            return self.parent.fill_contiguous(area, colors);
+        }
+
+        // The parent can't be used to fill the area, because rows would need to be repeated or
+        // skipped.
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(point, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = scale_rectangle(area, self.x, self.y);
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> GetPixel for Scaled<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        let area = scale_rectangle(&Rectangle::new(point, Size::new(1, 1)), self.x, self.y);
+
+        // Points which are skipped by scaling down aren't visible in the parent.
+        if area.is_zero_sized() {
+            None
+        } else {
+            self.parent.pixel(area.top_left)
+        }
+    }
+}
+
+impl<T> Dimensions for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        let parent = self.parent.bounding_box();
+        let end = parent.top_left + parent.size;
+
+        // Only points whose blocks are completely inside the parent are included. Points at the
+        // edges which are skipped by scaling down are excluded.
+        let top_left = Point::new(
+            (self.x.unscale(parent.top_left.x - 1) + 1).max(self.x.unscale(parent.top_left.x)),
+            (self.y.unscale(parent.top_left.y - 1) + 1).max(self.y.unscale(parent.top_left.y)),
+        );
+        let bottom_right = Point::new(
+            self.x.unscale(end.x).min(self.x.unscale(end.x - 1) + 1),
+            self.y.unscale(end.y).min(self.y.unscale(end.y - 1) + 1),
+        );
+
+        let size = bottom_right - top_left;
+
+        Rectangle::new(
+            top_left,
+            Size::new(size.x.max(0) as u32, size.y.max(0) as u32),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+        display
+            .scaled(2)
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), BinaryColor::On),
+                    Pixel(Point::new(2, 1), BinaryColor::Off),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "##    ", //
+            "##    ", //
+            "    ..", //
+            "    ..", //
+        ]);
+    }
+
+    /// Draw target with accelerated fills, which counts the number of `fill_solid` calls.
+    struct Accelerated {
+        display: MockDisplay<BinaryColor>,
+        fills: usize,
+    }
+
+    impl DrawTarget for Accelerated {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        const CAPABILITIES: Capabilities = Capabilities::new().with_hardware_fill(true);
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            self.display.draw_iter(pixels)
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            self.fills += 1;
+
+            self.display.fill_solid(area, color)
+        }
+    }
+
+    impl Dimensions for Accelerated {
+        fn bounding_box(&self) -> Rectangle {
+            self.display.bounding_box()
+        }
+    }
+
+    #[test]
+    fn draw_iter_hardware_fill() {
+        let mut accelerated = Accelerated {
+            display: MockDisplay::new(),
+            fills: 0,
+        };
+        accelerated
+            .scaled(2)
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), BinaryColor::On),
+                    Pixel(Point::new(2, 1), BinaryColor::Off),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        assert_eq!(accelerated.fills, 2);
+        accelerated.display.assert_pattern(&[
+            "##    ", //
+            "##    ", //
+            "    ..", //
+            "    ..", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let mut display = MockDisplay::new();
+        display
+            .scaled(3)
+            .fill_contiguous(
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 2)),
+                [true, false, false, true]
+                    .iter()
+                    .map(|on| BinaryColor::from(*on)),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   ###...", //
+            "   ###...", //
+            "   ###...", //
+            "   ...###", //
+            "   ...###", //
+            "   ...###", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid() {
+        let mut display = MockDisplay::new();
+        display
+            .scaled(2)
+            .fill_solid(
+                &Rectangle::new(Point::new(0, 1), Size::new(3, 1)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            "      ", //
+            "######", //
+            "######", //
+        ]);
+    }
+
+    #[test]
+    fn factor_one() {
+        let circle = crate::primitives::Circle::new(Point::new(1, 2), 7)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        let mut display = MockDisplay::new();
+        circle.draw(&mut display.scaled(1)).unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(core::iter::once(Pixel(Point::new(6, 3), BinaryColor::On)))
+            .unwrap();
+
+        let scaled = display.scaled(3);
+        assert_eq!(scaled.pixel(Point::new(2, 1)), Some(BinaryColor::On));
+        assert_eq!(scaled.pixel(Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        assert_eq!(
+            display.scaled(5).bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(12, 12))
+        );
+
+        let mut translated = display.translated(Point::new(3, -5));
+        assert_eq!(
+            translated.scaled(4).bounding_box(),
+            Rectangle::new(Point::new(0, 2), Size::new(15, 15))
+        );
+    }
+
+    #[test]
+    fn bounding_box_fractional() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        assert_eq!(
+            Scaled::with_scaling(&mut display, Scaling::new(3, 2), Scaling::new(1, 2))
+                .bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(42, 127))
+        );
+
+        let mut translated = display.translated(Point::new(-1, 1));
+        assert_eq!(
+            Scaled::with_scaling(&mut translated, Scaling::new(3, 2), Scaling::new(3, 2))
+                .bounding_box(),
+            Rectangle::new(Point::new(1, -1), Size::new(42, 43))
+        );
+    }
+
+    #[test]
+    fn fill_solid_fractional() {
+        let mut display = MockDisplay::new();
+        Scaled::with_scaling(&mut display, Scaling::new(3, 2), Scaling::new(2, 1))
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 1)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  ###", //
+            "  ###", //
+        ]);
+    }
+
+    #[test]
+    fn get_pixel_scaled_down() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(core::iter::once(Pixel(Point::new(1, 0), BinaryColor::On)))
+            .unwrap();
+
+        let scaled = Scaled::with_scaling(&mut display, Scaling::new(2, 3), Scaling::IDENTITY);
+        assert_eq!(scaled.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(scaled.pixel(Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn scaling_length() {
+        assert_eq!(Scaling::new(3, 1).length(5), 15);
+        assert_eq!(Scaling::new(1, 2).length(4), 2);
+        assert_eq!(Scaling::new(1, 2).length(5), 3);
+        assert_eq!(Scaling::new(3, 2).length(3), 5);
+    }
+
+    #[test]
+    fn scaling_scale() {
+        let scaling = Scaling::new(3, 2);
+        let starts = [0, 1, 2, 3].iter().map(|x| scaling.scale(*x));
+        assert!(starts.eq([0, 2, 3, 5].iter().copied()));
+
+        let scaling = Scaling::new(1, 3);
+        let starts = [0, 1, 2, 3, 4].iter().map(|x| scaling.scale(*x));
+        assert!(starts.eq([0, 1, 1, 1, 2].iter().copied()));
+    }
+
+    #[test]
+    #[should_panic(expected = "scaling factor numerator and denominator must be greater than zero")]
+    fn zero_denom() {
+        Scaling::new(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "scaling factor must be greater than zero")]
+    fn zero_factor() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.scaled(0);
+    }
+}
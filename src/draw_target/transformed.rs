@@ -0,0 +1,329 @@
+use crate::{
+    draw_target::{Capabilities, DrawTarget},
+    geometry::{AffineTransform, Dimensions, Point, Size},
+    primitives::{rectangle, PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Hole filling strategy for transformed draw targets.
+///
+/// Scaling up or rotating a drawable by mapping each pixel to a single pixel in the parent draw
+/// target leaves gaps between the mapped pixels. The hole filling strategy defines how these gaps
+/// are handled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HoleFilling {
+    /// Map each pixel to the single pixel which is closest to its transformed position.
+    ///
+    /// This is the fastest strategy, but it can leave holes if the transformation scales up or
+    /// rotates the drawable.
+    None,
+
+    /// Map each pixel to all pixels which are covered by its transformed area.
+    ///
+    /// This strategy doesn't leave any holes, but requires more calculations per pixel.
+    Cover,
+}
+
+/// Transformed draw target.
+///
+/// Created by calling [`transformed`] on any [`DrawTarget`].
+/// See the [`transformed`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`transformed`]: trait.DrawTargetExt.html#tymethod.transformed
+#[derive(Debug)]
+pub struct Transformed<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    transform: AffineTransform,
+    hole_filling: HoleFilling,
+}
+
+impl<'a, T> Transformed<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(
+        parent: &'a mut T,
+        transform: AffineTransform,
+        hole_filling: HoleFilling,
+    ) -> Self {
+        Self {
+            parent,
+            transform,
+            hole_filling,
+        }
+    }
+}
+
+/// Iterator over the pixels in the parent draw target that are covered by a transformed pixel.
+struct CoveredPoints {
+    /// Center of the transformed pixel as fixed point coordinates.
+    center: (i64, i64),
+    /// Linear part of the transformation.
+    linear: (i64, i64, i64, i64),
+    /// Determinant of the linear part.
+    det: i64,
+    points: rectangle::Points,
+}
+
+impl CoveredPoints {
+    fn new(transform: &AffineTransform, point: Point) -> Self {
+        let center = transform.transform_point_f(point).to_bits();
+        let linear = transform.linear();
+        let (a, b, d, e) = linear;
+
+        // The transformed pixel is a parallelogram, whose corners are offset from the center by
+        // half of the transformed unit vectors.
+        let half_width = ((a.abs() + b.abs()) / 2, (d.abs() + e.abs()) / 2);
+        let min = Point::new(
+            floor(center.0 - half_width.0),
+            floor(center.1 - half_width.1),
+        );
+        let max = Point::new(ceil(center.0 + half_width.0), ceil(center.1 + half_width.1));
+
+        let det = (a * e - b * d) >> 16;
+        let points = if det != 0 {
+            Rectangle::with_corners(min, max).points()
+        } else {
+            Rectangle::zero().points()
+        };
+
+        Self {
+            center,
+            linear,
+            det,
+            points,
+        }
+    }
+
+    /// Returns `true` if the point is covered by the transformed pixel.
+    fn contains(&self, point: Point) -> bool {
+        let (a, b, d, e) = self.linear;
+        let dx = (i64::from(point.x) << 16) - self.center.0;
+        let dy = (i64::from(point.y) << 16) - self.center.1;
+
+        // Coordinates of the point relative to the transformed unit vectors, multiplied by the
+        // determinant.
+        let sign = self.det.signum();
+        let s = ((e * dx - b * dy) >> 16) * sign;
+        let t = ((a * dy - d * dx) >> 16) * sign;
+
+        // The range is half open to make sure that points on the edge between two adjacent pixels
+        // are only drawn once.
+        let det = self.det.abs();
+        -det < 2 * s && 2 * s <= det && -det < 2 * t && 2 * t <= det
+    }
+}
+
+impl Iterator for CoveredPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let point = self.points.next()?;
+            if self.contains(point) {
+                return Some(point);
+            }
+        }
+    }
+}
+
+/// Rounds a fixed point value down.
+fn floor(value: i64) -> i32 {
+    (value >> 16) as i32
+}
+
+/// Rounds a fixed point value up.
+fn ceil(value: i64) -> i32 {
+    ((value + (1 << 16) - 1) >> 16) as i32
+}
+
+impl<T> DrawTarget for Transformed<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    // All drawing operations are converted into individual pixels and the transformation can
+    // change the iteration order, which means that none of the parent capabilities can be used.
+    const CAPABILITIES: Capabilities = Capabilities::new();
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let transform = self.transform;
+
+        match self.hole_filling {
+            HoleFilling::None => self.parent.draw_iter(
+                pixels
+                    .into_iter()
+                    .map(|Pixel(point, color)| Pixel(transform.transform_point(point), color)),
+            ),
+            HoleFilling::Cover => {
+                self.parent
+                    .draw_iter(pixels.into_iter().flat_map(|Pixel(point, color)| {
+                        CoveredPoints::new(&transform, point).map(move |point| Pixel(point, color))
+                    }))
+            }
+        }
+    }
+}
+
+impl<T> Dimensions for Transformed<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        let inverse = match self.transform.inverse() {
+            Some(inverse) => inverse,
+            None => return Rectangle::zero(),
+        };
+
+        let parent = self.parent.bounding_box();
+        let bottom_right = match parent.bottom_right() {
+            Some(bottom_right) => bottom_right,
+            None => return Rectangle::new(inverse.transform_point(parent.top_left), Size::zero()),
+        };
+
+        let corners = [
+            parent.top_left,
+            Point::new(bottom_right.x, parent.top_left.y),
+            Point::new(parent.top_left.x, bottom_right.y),
+            bottom_right,
+        ];
+
+        Rectangle::from_points(
+            corners
+                .iter()
+                .map(|corner| inverse.transform_point(*corner)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw_target::{DrawTarget, DrawTargetExt, HoleFilling},
+        geometry::{AffineTransform, AngleUnit, Dimensions, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle, Rectangle},
+        Drawable, Pixel,
+    };
+
+    #[test]
+    fn rotate_90() {
+        let mut display = MockDisplay::new();
+
+        let transform = AffineTransform::rotation(90.0.deg()).around(Point::new(2, 2));
+        let mut transformed = display.transformed(transform, HoleFilling::None);
+
+        Rectangle::new(Point::new(1, 1), Size::new(3, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut transformed)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "  ## ", //
+            "  ## ", //
+            "  ## ", //
+        ]);
+    }
+
+    #[test]
+    fn scale_without_hole_filling() {
+        let mut display = MockDisplay::new();
+
+        let mut transformed =
+            display.transformed(AffineTransform::scaling(2.0, 2.0), HoleFilling::None);
+        transformed
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), BinaryColor::On),
+                    Pixel(Point::new(1, 0), BinaryColor::On),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "# #", //
+        ]);
+    }
+
+    #[test]
+    fn scale_with_hole_filling() {
+        let mut display = MockDisplay::new();
+
+        let mut transformed =
+            display.transformed(AffineTransform::scaling(2.0, 2.0), HoleFilling::Cover);
+        transformed
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), BinaryColor::On),
+                    Pixel(Point::new(1, 0), BinaryColor::Off),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "##..", //
+            "##..", //
+        ]);
+    }
+
+    #[test]
+    fn rotate_45_with_hole_filling() {
+        let mut display = MockDisplay::new();
+
+        let transform = AffineTransform::rotation(45.0.deg())
+            .around(Point::new(5, 5))
+            .then(&AffineTransform::translation(Point::new(5, 0)));
+        let mut transformed = display.transformed(transform, HoleFilling::Cover);
+
+        Rectangle::new(Point::new(3, 3), Size::new(5, 5))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut transformed)
+            .unwrap();
+
+        // All pixels inside the rotated square are drawn, without any holes.
+        for y in 3..8 {
+            let row = (0..20)
+                .filter(|x| display.get_pixel(Point::new(*x, y)).is_some())
+                .collect::<arrayvec::ArrayVec<[i32; 20]>>();
+
+            assert!(
+                row.windows(2).all(|pair| pair[1] == pair[0] + 1),
+                "hole in row {}: {:?}",
+                y,
+                row
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let transform = AffineTransform::scaling(2.0, 4.0);
+        let transformed = display.transformed(transform, HoleFilling::None);
+
+        assert_eq!(
+            transformed.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(33, 17))
+        );
+
+        let transformed =
+            display.transformed(AffineTransform::scaling(0.0, 1.0), HoleFilling::None);
+        assert_eq!(transformed.bounding_box(), Rectangle::zero());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{Capabilities, DrawTarget, GetPixel},
     geometry::{Dimensions, Point},
     iterator::PixelIteratorExt,
     primitives::Rectangle,
@@ -39,6 +39,8 @@ where
     type Color = T::Color;
     type Error = T::Error;
 
+    const CAPABILITIES: Capabilities = T::CAPABILITIES;
+
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
@@ -60,11 +62,32 @@ where
         self.parent.fill_solid(&area, color)
     }
 
+    fn draw_runs<I>(&mut self, runs: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Point, u32, Self::Color)>,
+    {
+        let offset = self.offset;
+
+        self.parent.draw_runs(
+            runs.into_iter()
+                .map(|(point, length, color)| (point + offset, length, color)),
+        )
+    }
+
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
         self.parent.clear(color)
     }
 }
 
+impl<T> GetPixel for Translated<'_, T>
+where
+    T: GetPixel,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        self.parent.pixel(point + self.offset)
+    }
+}
+
 impl<T> Dimensions for Translated<'_, T>
 where
     T: DrawTarget,
@@ -77,7 +100,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        draw_target::{DrawTarget, DrawTargetExt},
+        draw_target::{DrawTarget, DrawTargetExt, GetPixel},
         geometry::Dimensions,
         geometry::{Point, Size},
         mock_display::MockDisplay,
@@ -159,6 +182,27 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn draw_runs() {
+        let mut display = MockDisplay::new();
+
+        let runs = [
+            (Point::new(0, 0), 3, BinaryColor::On),
+            (Point::new(1, 1), 1, BinaryColor::Off),
+        ];
+        display
+            .translated(Point::new(1, 2))
+            .draw_runs(runs.iter().copied())
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            "    ", //
+            " ###", //
+            "  . ", //
+        ]);
+    }
+
     #[test]
     fn clear() {
         let mut display = MockDisplay::new();
@@ -183,4 +227,18 @@ mod tests {
             translated.bounding_box()
         );
     }
+
+    #[test]
+    fn get_pixel() {
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(core::iter::once(Pixel(Point::new(3, 5), BinaryColor::On)))
+            .unwrap();
+
+        let translated = display.translated(Point::new(1, 3));
+
+        assert_eq!(translated.pixel(Point::new(2, 2)), Some(BinaryColor::On));
+        assert_eq!(translated.pixel(Point::new(3, 5)), None);
+        assert_eq!(translated.pixel(Point::new(-2, 0)), None);
+    }
 }
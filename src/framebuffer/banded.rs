@@ -0,0 +1,322 @@
+use core::convert::Infallible;
+
+use crate::{
+    draw_target::DrawTarget,
+    framebuffer::{bytes_per_row, private::StoreRaw, Framebuffer},
+    geometry::{Dimensions, Point, Size},
+    image::Image,
+    iterator::raw::RawDataSlice,
+    pixelcolor::{
+        raw::{BigEndian, RawData},
+        PixelColor,
+    },
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+/// Horizontal band of a display.
+///
+/// A band is the draw target which is passed to the scene closure by [`render_banded`]. It uses
+/// the coordinate system of the display and its bounding box is the bounding box of the entire
+/// display, which makes it possible to use the same drawing code as for a draw target which
+/// covers the whole display. Only the pixels inside the current band are stored.
+///
+/// [`render_banded`]: fn.render_banded.html
+#[derive(Debug)]
+pub struct Band<'a, C>
+where
+    C: PixelColor,
+{
+    framebuffer: Framebuffer<'a, C, BigEndian>,
+    area: Rectangle,
+    display_area: Rectangle,
+}
+
+impl<C> Band<'_, C>
+where
+    C: PixelColor,
+{
+    /// Returns the area of the display which is covered by this band.
+    ///
+    /// The area can be used to skip drawables which don't intersect the current band.
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<C> DrawTarget for Band<'_, C>
+where
+    C: PixelColor,
+    C::Raw: From<C> + StoreRaw<BigEndian>,
+{
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let offset = self.area.top_left;
+
+        // Pixels outside the band are ignored by the framebuffer.
+        self.framebuffer.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point - offset, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.area);
+
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+
+        self.framebuffer.fill_solid(
+            &Rectangle::new(area.top_left - self.area.top_left, area.size),
+            color,
+        )
+    }
+}
+
+impl<C> Dimensions for Band<'_, C>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.display_area
+    }
+}
+
+/// Renders a scene to a display in horizontal bands.
+///
+/// The display is divided into horizontal bands, which are as high as the number of rows that fit
+/// into `buffer`. For each band the buffer is filled with the `background` color, the `scene`
+/// closure is called to draw the scene into the band and the content of the band is drawn to
+/// `display` using [`fill_contiguous`].
+///
+/// This makes it possible to use a framebuffer, which avoids flickering and enables efficient
+/// transfers to the display, on devices which don't have enough RAM to store the entire
+/// display content. Because the scene is drawn once for each band, the `scene` closure must draw
+/// the same content each time it is called. The drawing code inside the closure can use
+/// [`Band::area`] to skip drawables which don't intersect the current band.
+///
+/// The buffer uses the same format as a [`Framebuffer`] with the [`BigEndian`] byte order.
+///
+/// # Panics
+///
+/// Panics if `buffer` is too small to store a single row of the display.
+///
+/// # Examples
+///
+/// Draw to an `Rgb565` display with a 4 KiB buffer. For a display which is 320 pixels wide each
+/// band contains 6 rows:
+///
+/// ```
+/// use embedded_graphics::{
+///     framebuffer::render_banded,
+///     mono_font::{ascii::FONT_10X20, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+///     text::{Alignment, Text},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<Rgb565>::new();
+///
+/// let mut buffer = [0u8; 4096];
+///
+/// render_banded(&mut display, &mut buffer, Rgb565::BLACK, |band| {
+///     let center = band.bounding_box().center();
+///
+///     Circle::with_center(center, 40)
+///         .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///         .draw(band)?;
+///
+///     let style = MonoTextStyle::new(&FONT_10X20, Rgb565::WHITE);
+///     Text::with_alignment("Hi", center, style, Alignment::Center).draw(band)?;
+///
+///     Ok(())
+/// })?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`fill_contiguous`]: ../draw_target/trait.DrawTarget.html#method.fill_contiguous
+/// [`Band::area`]: struct.Band.html#method.area
+/// [`Framebuffer`]: struct.Framebuffer.html
+/// [`BigEndian`]: ../pixelcolor/raw/enum.BigEndian.html
+pub fn render_banded<D, F>(
+    display: &mut D,
+    buffer: &mut [u8],
+    background: D::Color,
+    mut scene: F,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+    D::Color: From<<D::Color as PixelColor>::Raw>,
+    <D::Color as PixelColor>::Raw: From<D::Color> + StoreRaw<BigEndian>,
+    for<'a> RawDataSlice<'a, <D::Color as PixelColor>::Raw, BigEndian>:
+        IntoIterator<Item = <D::Color as PixelColor>::Raw>,
+    F: FnMut(&mut Band<'_, D::Color>) -> Result<(), Infallible>,
+{
+    let display_area = display.bounding_box();
+    if display_area.is_zero_sized() {
+        return Ok(());
+    }
+
+    let width = display_area.size.width;
+    let bytes_per_row = bytes_per_row(width, <D::Color as PixelColor>::Raw::BITS_PER_PIXEL);
+    let band_height = (buffer.len() / bytes_per_row) as u32;
+
+    assert!(
+        band_height > 0,
+        "buffer is too small to store a single row of the display"
+    );
+
+    let mut y = 0;
+    while y < display_area.size.height {
+        let height = band_height.min(display_area.size.height - y);
+        let area = Rectangle::new(
+            display_area.top_left + Point::new(0, y as i32),
+            Size::new(width, height),
+        );
+
+        let data = &mut buffer[0..height as usize * bytes_per_row];
+        let mut band = Band {
+            framebuffer: Framebuffer::new(data, width),
+            area,
+            display_area,
+        };
+
+        // The band and the closure use `Infallible` as the error type.
+        let _ = band.framebuffer.clear(background);
+        let _ = scene(&mut band);
+
+        Image::new(&band.framebuffer.as_image(), area.top_left).draw(display)?;
+
+        y += height;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb565, RgbColor},
+        primitives::{Circle, Primitive, PrimitiveStyle, Triangle},
+    };
+
+    fn scene<D>(target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Rgb565>,
+    {
+        Circle::new(Point::new(10, 5), 30)
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3))
+            .draw(target)?;
+
+        Triangle::new(Point::new(3, 40), Point::new(60, 20), Point::new(40, 62))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+            .draw(target)?;
+
+        Rectangle::new(Point::new(20, 30), Size::new(10, 9))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+            .draw(target)
+    }
+
+    fn expected(area: &Rectangle) -> MockDisplay<Rgb565> {
+        let mut expected = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        expected.fill_solid(area, Rgb565::BLACK).unwrap();
+        scene(&mut expected).unwrap();
+
+        expected
+    }
+
+    #[test]
+    fn render_bands() {
+        // 64 pixels * 2 bytes * 5 rows, plus some bytes which aren't used.
+        let mut buffer = [0u8; 64 * 2 * 5 + 7];
+
+        let mut display = MockDisplay::new();
+        let mut calls = 0;
+        render_banded(&mut display, &mut buffer, Rgb565::BLACK, |band| {
+            calls += 1;
+            scene(band)
+        })
+        .unwrap();
+
+        display.assert_eq(&expected(&display.bounding_box()));
+        assert_eq!(calls, 13);
+    }
+
+    #[test]
+    fn band_areas() {
+        let mut buffer = [0u8; 8 * 8];
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut cropped = display.cropped(&Rectangle::new(Point::new(3, 2), Size::new(80, 20)));
+        let bounding_box = cropped.bounding_box();
+
+        let mut areas = [Rectangle::zero(); 3];
+        let mut index = 0;
+        render_banded(&mut cropped, &mut buffer, BinaryColor::Off, |band| {
+            assert_eq!(band.bounding_box(), bounding_box);
+
+            areas[index] = band.area();
+            index += 1;
+
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(
+            areas,
+            [
+                Rectangle::new(Point::new(0, 0), Size::new(61, 8)),
+                Rectangle::new(Point::new(0, 8), Size::new(61, 8)),
+                Rectangle::new(Point::new(0, 16), Size::new(61, 4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn translated_display() {
+        let mut buffer = [0u8; 64 * 2 * 7];
+
+        let mut display = MockDisplay::new();
+        let mut translated = display.translated(Point::new(-4, -3));
+        render_banded(&mut translated, &mut buffer, Rgb565::BLACK, |band| {
+            assert_eq!(band.bounding_box().top_left, Point::new(4, 3));
+
+            scene(band)
+        })
+        .unwrap();
+
+        let area = Rectangle::new(Point::new(4, 3), Size::new(64, 64));
+        let mut expected = MockDisplay::new();
+        expected
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(64, 64)),
+                Rgb565::BLACK,
+            )
+            .unwrap();
+        expected.set_allow_overdraw(true);
+        scene(&mut expected.translated(Point::new(-4, -3)).clipped(&area)).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer is too small")]
+    fn buffer_too_small() {
+        let mut buffer = [0u8; 7];
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        render_banded(&mut display, &mut buffer, BinaryColor::Off, |_| Ok(())).unwrap();
+    }
+}
@@ -0,0 +1,465 @@
+//! Framebuffer.
+//!
+//! A [`Framebuffer`] is a draw target which stores the drawn pixels in a byte slice in RAM. The
+//! pixel data is packed in the same format that is used by [`ImageRaw`], which means that the
+//! content of the framebuffer can be sent to a display in one burst by using [`as_bytes`] or drawn
+//! to another draw target by using [`as_image`].
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_graphics::{
+//!     framebuffer::Framebuffer,
+//!     image::Image,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle},
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::<BinaryColor>::new();
+//!
+//! // Buffer for a 32x16 pixel display with 1 bit per pixel.
+//! let mut buffer = [0u8; 32 * 16 / 8];
+//! let mut framebuffer = Framebuffer::<BinaryColor>::new(&mut buffer, 32);
+//!
+//! Circle::new(Point::new(2, 2), 12)
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut framebuffer)?;
+//!
+//! // The raw data can be sent to the display controller in one burst...
+//! let data: &[u8] = framebuffer.as_bytes();
+//! assert_eq!(data.len(), 64);
+//!
+//! // ...or the framebuffer can be drawn to another draw target.
+//! Image::new(&framebuffer.as_image(), Point::zero()).draw(&mut display)?;
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
+//! [`Framebuffer`]: struct.Framebuffer.html
+//! [`ImageRaw`]: ../image/struct.ImageRaw.html
+//! [`as_bytes`]: struct.Framebuffer.html#method.as_bytes
+//! [`as_image`]: struct.Framebuffer.html#method.as_image
+
+use core::marker::PhantomData;
+
+use crate::{
+    draw_target::{Capabilities, DrawTarget, GetPixel},
+    geometry::{OriginDimensions, Point, Size},
+    image::ImageRaw,
+    iterator::raw::RawDataSlice,
+    pixelcolor::{
+        raw::{
+            BigEndian, ByteOrder, LittleEndian, RawData, RawU1, RawU16, RawU2, RawU24, RawU32,
+            RawU4, RawU8, ToBytes,
+        },
+        PixelColor,
+    },
+    Pixel,
+};
+use private::StoreRaw;
+
+mod banded;
+
+pub use banded::{render_banded, Band};
+
+/// Framebuffer.
+///
+/// The framebuffer stores the pixel data in a mutable byte slice. The storage format is
+/// determined by the [`PixelColor`] type `C` and the [`ByteOrder`] `BO`, in the same way as for
+/// [`ImageRaw`]. For color types with less than 8 bits per pixel multiple pixels are packed into a
+/// single byte and the start of each row is aligned to the next whole byte.
+///
+/// The size of the slice which is required for a framebuffer with `width` x `height` pixels is
+/// `(width * bits_per_pixel + 7) / 8 * height` bytes. For example, a 128x64 pixel framebuffer
+/// requires 1024 bytes for [`BinaryColor`] and 16384 bytes for [`Rgb565`].
+///
+/// See the [module-level documentation] for an example.
+///
+/// [`PixelColor`]: ../pixelcolor/trait.PixelColor.html
+/// [`ByteOrder`]: ../pixelcolor/raw/trait.ByteOrder.html
+/// [`ImageRaw`]: ../image/struct.ImageRaw.html
+/// [`BinaryColor`]: ../pixelcolor/enum.BinaryColor.html
+/// [`Rgb565`]: ../pixelcolor/struct.Rgb565.html
+/// [module-level documentation]: index.html
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Framebuffer<'a, C, BO = BigEndian>
+where
+    C: PixelColor,
+    BO: ByteOrder,
+{
+    data: &'a mut [u8],
+    size: Size,
+    color_type: PhantomData<C>,
+    byte_order: PhantomData<BO>,
+}
+
+impl<'a, C, BO> Framebuffer<'a, C, BO>
+where
+    C: PixelColor,
+    BO: ByteOrder,
+{
+    /// Creates a new framebuffer.
+    ///
+    /// Only the width of the framebuffer needs to be specified. The height of the framebuffer
+    /// will be calculated based on the length of the given slice. If the length of the slice
+    /// isn't an integer multiple of the data length for a single row the remaining bytes will be
+    /// ignored.
+    ///
+    /// The existing content of `data` isn't modified, which can be used to continue drawing on
+    /// previously rendered data.
+    pub fn new(data: &'a mut [u8], width: u32) -> Self {
+        let bytes_per_row = bytes_per_row(width, C::Raw::BITS_PER_PIXEL);
+
+        // Prevent panic for `width == 0` by returning a zero sized framebuffer.
+        let height = data.len().checked_div(bytes_per_row).unwrap_or(0);
+
+        let (data, _) = data.split_at_mut(height * bytes_per_row);
+        let width = if height > 0 { width } else { 0 };
+
+        Self {
+            data,
+            size: Size::new(width, height as u32),
+            color_type: PhantomData,
+            byte_order: PhantomData,
+        }
+    }
+
+    /// Returns the raw pixel data.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.data
+    }
+
+    /// Returns the raw pixel data as a mutable slice.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        self.data
+    }
+
+    /// Returns the index of the byte which contains the given point and the bit offset inside
+    /// this byte.
+    ///
+    /// `None` is returned if the point is outside the framebuffer.
+    fn byte_index(&self, point: Point) -> Option<(usize, usize)> {
+        if point.x < 0
+            || point.y < 0
+            || point.x as u32 >= self.size.width
+            || point.y as u32 >= self.size.height
+        {
+            return None;
+        }
+
+        let bits_per_pixel = C::Raw::BITS_PER_PIXEL;
+        let bit_index = point.x as usize * bits_per_pixel;
+
+        let index =
+            point.y as usize * bytes_per_row(self.size.width, bits_per_pixel) + bit_index / 8;
+
+        // Pixels with less than 8 bits per pixel are stored starting with the most significant
+        // bits. The shift is always zero for all other bit depths.
+        let shift = (8 - bits_per_pixel % 8 - bit_index % 8) % 8;
+
+        Some((index, shift))
+    }
+}
+
+impl<'a, C, BO> Framebuffer<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    /// Returns an image which contains the content of the framebuffer.
+    pub fn as_image(&self) -> ImageRaw<'_, C, BO> {
+        ImageRaw::new(self.data, self.size.width)
+    }
+}
+
+impl<C, BO> DrawTarget for Framebuffer<'_, C, BO>
+where
+    C: PixelColor,
+    BO: ByteOrder,
+    C::Raw: From<C> + StoreRaw<BO>,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    const CAPABILITIES: Capabilities = Capabilities::new().with_read_back(true);
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some((index, shift)) = self.byte_index(point) {
+                C::Raw::from(color).store(self.data, index, shift);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, BO> GetPixel for Framebuffer<'_, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    C::Raw: From<C> + StoreRaw<BO>,
+    for<'a> RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    fn pixel(&self, point: Point) -> Option<C> {
+        self.as_image().pixel(point)
+    }
+}
+
+impl<C, BO> OriginDimensions for Framebuffer<'_, C, BO>
+where
+    C: PixelColor,
+    BO: ByteOrder,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Returns the length of each row in bytes.
+fn bytes_per_row(width: u32, bits_per_pixel: usize) -> usize {
+    (width as usize * bits_per_pixel + 7) / 8
+}
+
+mod private {
+    /// Raw data types which can be stored in a framebuffer.
+    pub trait StoreRaw<BO> {
+        /// Stores the raw value at the given byte index and bit offset.
+        fn store(self, data: &mut [u8], index: usize, shift: usize);
+    }
+}
+
+macro_rules! impl_store_bits {
+    ($type:ident) => {
+        impl<BO> StoreRaw<BO> for $type {
+            fn store(self, data: &mut [u8], index: usize, shift: usize) {
+                let mask = ((1 << $type::BITS_PER_PIXEL) - 1) << shift;
+
+                data[index] = (data[index] & !mask) | (self.into_inner() << shift);
+            }
+        }
+    };
+}
+
+impl_store_bits!(RawU1);
+impl_store_bits!(RawU2);
+impl_store_bits!(RawU4);
+
+impl<BO> StoreRaw<BO> for RawU8 {
+    fn store(self, data: &mut [u8], index: usize, _shift: usize) {
+        data[index] = self.into_inner();
+    }
+}
+
+macro_rules! impl_store_bytes {
+    ($type:ident, $byte_order:ident, $to_bytes:ident) => {
+        impl StoreRaw<$byte_order> for $type {
+            fn store(self, data: &mut [u8], index: usize, _shift: usize) {
+                let bytes = self.$to_bytes();
+
+                data[index..index + bytes.len()].copy_from_slice(&bytes);
+            }
+        }
+    };
+}
+
+impl_store_bytes!(RawU16, BigEndian, to_be_bytes);
+impl_store_bytes!(RawU16, LittleEndian, to_le_bytes);
+impl_store_bytes!(RawU24, BigEndian, to_be_bytes);
+impl_store_bytes!(RawU24, LittleEndian, to_le_bytes);
+impl_store_bytes!(RawU32, BigEndian, to_be_bytes);
+impl_store_bytes!(RawU32, LittleEndian, to_le_bytes);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::Dimensions,
+        image::Image,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb888, RgbColor},
+        primitives::{Primitive, PrimitiveStyle, Rectangle, Triangle},
+        Drawable,
+    };
+
+    #[test]
+    fn size() {
+        let mut data = [0; 10];
+
+        assert_eq!(
+            Framebuffer::<BinaryColor>::new(&mut data, 12).size(),
+            Size::new(12, 5)
+        );
+        assert_eq!(
+            Framebuffer::<Rgb565>::new(&mut data, 2).size(),
+            Size::new(2, 2)
+        );
+        assert_eq!(
+            Framebuffer::<Rgb888>::new(&mut data, 4).size(),
+            Size::zero()
+        );
+        assert_eq!(Framebuffer::<Gray8>::new(&mut data, 0).size(), Size::zero());
+    }
+
+    #[test]
+    fn partial_rows_are_ignored() {
+        let mut data = [0; 7];
+        let framebuffer = Framebuffer::<Gray8>::new(&mut data, 3);
+
+        assert_eq!(framebuffer.size(), Size::new(3, 2));
+        assert_eq!(framebuffer.as_bytes().len(), 6);
+    }
+
+    #[test]
+    fn binary_color() {
+        let mut data = [0; 4];
+        let mut framebuffer = Framebuffer::<BinaryColor>::new(&mut data, 10);
+
+        framebuffer
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), BinaryColor::On),
+                    Pixel(Point::new(7, 0), BinaryColor::On),
+                    Pixel(Point::new(8, 0), BinaryColor::On),
+                    Pixel(Point::new(9, 1), BinaryColor::On),
+                    Pixel(Point::new(7, 0), BinaryColor::Off),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            framebuffer.as_bytes(),
+            &[0b1000_0000, 0b1000_0000, 0b0000_0000, 0b0100_0000]
+        );
+    }
+
+    #[test]
+    fn gray2_and_gray4() {
+        let mut data = [0; 2];
+        let mut framebuffer = Framebuffer::<Gray2>::new(&mut data, 5);
+
+        framebuffer
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), Gray2::new(3)),
+                    Pixel(Point::new(2, 0), Gray2::new(1)),
+                    Pixel(Point::new(4, 0), Gray2::new(2)),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+        assert_eq!(framebuffer.as_bytes(), &[0b11_00_01_00, 0b10_00_00_00]);
+
+        let mut data = [0xFF; 2];
+        let mut framebuffer = Framebuffer::<Gray4>::new(&mut data, 3);
+
+        framebuffer
+            .draw_iter(core::iter::once(Pixel(Point::new(1, 0), Gray4::new(0x5))))
+            .unwrap();
+        assert_eq!(framebuffer.as_bytes(), &[0xF5, 0xFF]);
+    }
+
+    #[test]
+    fn rgb565_byte_order() {
+        let color = Rgb565::new(0x1F, 0x00, 0x01);
+        let pixel = Pixel(Point::new(1, 0), color);
+
+        let mut data = [0; 4];
+        let mut framebuffer = Framebuffer::<Rgb565, BigEndian>::new(&mut data, 2);
+        framebuffer.draw_iter(core::iter::once(pixel)).unwrap();
+        assert_eq!(framebuffer.as_bytes(), &[0x00, 0x00, 0xF8, 0x01]);
+
+        let mut data = [0; 4];
+        let mut framebuffer = Framebuffer::<Rgb565, LittleEndian>::new(&mut data, 2);
+        framebuffer.draw_iter(core::iter::once(pixel)).unwrap();
+        assert_eq!(framebuffer.as_bytes(), &[0x00, 0x00, 0x01, 0xF8]);
+    }
+
+    #[test]
+    fn rgb888() {
+        let mut data = [0; 6];
+        let mut framebuffer = Framebuffer::<Rgb888>::new(&mut data, 1);
+
+        framebuffer
+            .draw_iter(core::iter::once(Pixel(
+                Point::new(0, 1),
+                Rgb888::new(0x11, 0x22, 0x33),
+            )))
+            .unwrap();
+
+        assert_eq!(framebuffer.as_bytes(), &[0, 0, 0, 0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn out_of_bounds_pixels_are_ignored() {
+        let mut data = [0; 2];
+        let mut framebuffer = Framebuffer::<BinaryColor>::new(&mut data, 4);
+
+        framebuffer
+            .draw_iter(
+                [
+                    Pixel(Point::new(-1, 0), BinaryColor::On),
+                    Pixel(Point::new(4, 0), BinaryColor::On),
+                    Pixel(Point::new(0, 2), BinaryColor::On),
+                    Pixel(Point::new(0, -1), BinaryColor::On),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        assert_eq!(framebuffer.as_bytes(), &[0, 0]);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut data = [0; 8];
+        let mut framebuffer = Framebuffer::<Rgb565, LittleEndian>::new(&mut data, 2);
+
+        framebuffer
+            .draw_iter(core::iter::once(Pixel(Point::new(1, 1), Rgb565::RED)))
+            .unwrap();
+
+        assert_eq!(framebuffer.pixel(Point::new(1, 1)), Some(Rgb565::RED));
+        assert_eq!(framebuffer.pixel(Point::new(0, 1)), Some(Rgb565::BLACK));
+        assert_eq!(framebuffer.pixel(Point::new(2, 1)), None);
+    }
+
+    #[test]
+    fn as_image() {
+        let mut data = [0; 2 * 20];
+        let mut framebuffer = Framebuffer::<BinaryColor>::new(&mut data, 12);
+        let bounding_box = framebuffer.bounding_box();
+
+        let triangle = Triangle::new(Point::new(1, 1), Point::new(11, 5), Point::new(3, 18))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        framebuffer.clear(BinaryColor::Off).unwrap();
+        triangle.draw(&mut framebuffer).unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&framebuffer.as_image(), Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected
+            .fill_solid(&bounding_box, BinaryColor::Off)
+            .unwrap();
+        expected.set_allow_overdraw(true);
+        triangle.draw(&mut expected.clipped(&bounding_box)).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(
+            bounding_box,
+            Rectangle::new(Point::zero(), Size::new(12, 20))
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::geometry::{Angle, Point, PointF, Trigonometry};
+
+/// Number of fractional bits in the fixed point matrix coefficients.
+const FRAC_BITS: u32 = PointF::FRAC_BITS;
+
+/// Fixed point representation of `1.0`.
+const ONE: i64 = 1 << FRAC_BITS;
+
+/// Converts a floating point value into a fixed point value.
+fn to_fixed(value: f32) -> i64 {
+    let value = value * ONE as f32;
+
+    // Round to the nearest value, because `f32::round` isn't available in `core`.
+    if value < 0.0 {
+        (value - 0.5) as i64
+    } else {
+        (value + 0.5) as i64
+    }
+}
+
+/// Affine transformation.
+///
+/// An affine transformation maps points by multiplying them with a 2x3 matrix, which can
+/// represent any combination of translations, rotations, scalings and shears. The coefficients of
+/// the matrix are stored as fixed point numbers with 16 fractional bits, which means that
+/// transforming a point doesn't require floating point operations.
+///
+/// Transformations are created by using one of the constructors and can be combined by using the
+/// [`then`] method.
+///
+/// The [`transformed`] draw target adapter can be used to apply an affine transformation to any
+/// drawable.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{geometry::AffineTransform, prelude::*};
+///
+/// // Rotate by 90° around (10, 10) and move the result 5 pixels to the right.
+/// let transform = AffineTransform::rotation(90.0.deg())
+///     .around(Point::new(10, 10))
+///     .then(&AffineTransform::translation(Point::new(5, 0)));
+///
+/// assert_eq!(transform.transform_point(Point::new(20, 10)), Point::new(15, 20));
+/// ```
+///
+/// [`then`]: #method.then
+/// [`transformed`]: ../draw_target/trait.DrawTargetExt.html#tymethod.transformed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffineTransform {
+    // The matrix is stored as `[[a, b, c], [d, e, f]]`, which maps `(x, y)` to
+    // `(a * x + b * y + c, d * x + e * y + f)`.
+    a: i64,
+    b: i64,
+    c: i64,
+    d: i64,
+    e: i64,
+    f: i64,
+}
+
+impl AffineTransform {
+    /// Returns the identity transformation, which doesn't change any points.
+    pub const fn identity() -> Self {
+        Self {
+            a: ONE,
+            b: 0,
+            c: 0,
+            d: 0,
+            e: ONE,
+            f: 0,
+        }
+    }
+
+    /// Returns a translation by the given offset.
+    pub const fn translation(offset: Point) -> Self {
+        Self {
+            c: offset.x as i64 * ONE,
+            f: offset.y as i64 * ONE,
+            ..Self::identity()
+        }
+    }
+
+    /// Returns a rotation around the origin.
+    ///
+    /// Positive angles rotate clockwise, because the Y axis points down in the display
+    /// coordinate system. Use [`around`] to rotate around a different point.
+    ///
+    /// [`around`]: #method.around
+    pub fn rotation(angle: Angle) -> Self {
+        let sin = to_fixed(angle.sin().into());
+        let cos = to_fixed(angle.cos().into());
+
+        Self {
+            a: cos,
+            b: -sin,
+            d: sin,
+            e: cos,
+            ..Self::identity()
+        }
+    }
+
+    /// Returns a scaling relative to the origin.
+    ///
+    /// Use [`around`] to scale relative to a different point.
+    ///
+    /// [`around`]: #method.around
+    pub fn scaling(x: f32, y: f32) -> Self {
+        Self {
+            a: to_fixed(x),
+            e: to_fixed(y),
+            ..Self::identity()
+        }
+    }
+
+    /// Returns a shear.
+    ///
+    /// The X coordinate of each point is shifted by `x` times the Y coordinate and the Y coordinate
+    /// is shifted by `y` times the X coordinate.
+    pub fn shear(x: f32, y: f32) -> Self {
+        Self {
+            b: to_fixed(x),
+            d: to_fixed(y),
+            ..Self::identity()
+        }
+    }
+
+    /// Returns a transformation which applies this transformation relative to the given point.
+    ///
+    /// This can be used to rotate or scale an object around its center, instead of the origin.
+    pub fn around(&self, center: Point) -> Self {
+        Self::translation(-center)
+            .then(self)
+            .then(&Self::translation(center))
+    }
+
+    /// Returns a transformation which first applies this transformation and then `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        let mul = |x: i64, y: i64| (x * y) >> FRAC_BITS;
+
+        Self {
+            a: mul(other.a, self.a) + mul(other.b, self.d),
+            b: mul(other.a, self.b) + mul(other.b, self.e),
+            c: mul(other.a, self.c) + mul(other.b, self.f) + other.c,
+            d: mul(other.d, self.a) + mul(other.e, self.d),
+            e: mul(other.d, self.b) + mul(other.e, self.e),
+            f: mul(other.d, self.c) + mul(other.e, self.f) + other.f,
+        }
+    }
+
+    /// Returns the inverse transformation.
+    ///
+    /// Returns `None` if the transformation can't be inverted, for example if it scales by zero.
+    pub fn inverse(&self) -> Option<Self> {
+        // The determinant has 32 fractional bits.
+        let det = self.a * self.e - self.b * self.d;
+        if det == 0 {
+            return None;
+        }
+
+        // Divides a value with 32 fractional bits by the determinant.
+        let div = |value: i64| ((i128::from(value) << FRAC_BITS) / i128::from(det)) as i64;
+
+        Some(Self {
+            a: div(self.e << FRAC_BITS),
+            b: div(-self.b << FRAC_BITS),
+            c: div(self.b * self.f - self.e * self.c),
+            d: div(-self.d << FRAC_BITS),
+            e: div(self.a << FRAC_BITS),
+            f: div(self.d * self.c - self.a * self.f),
+        })
+    }
+
+    /// Transforms a point.
+    ///
+    /// The result is rounded to the nearest integer coordinates.
+    pub fn transform_point(&self, point: Point) -> Point {
+        self.transform_point_f(point).round()
+    }
+
+    /// Transforms a point with sub-pixel precision.
+    pub fn transform_point_f(&self, point: Point) -> PointF {
+        let x = i64::from(point.x);
+        let y = i64::from(point.y);
+
+        PointF::from_bits(
+            self.a * x + self.b * y + self.c,
+            self.d * x + self.e * y + self.f,
+        )
+    }
+
+    /// Returns the linear part of the matrix as fixed point values `(a, b, d, e)`.
+    pub(crate) fn linear(&self) -> (i64, i64, i64, i64) {
+        (self.a, self.b, self.d, self.e)
+    }
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn identity() {
+        let point = Point::new(-3, 7);
+
+        assert_eq!(AffineTransform::identity().transform_point(point), point);
+        assert_eq!(AffineTransform::default(), AffineTransform::identity());
+    }
+
+    #[test]
+    fn translation() {
+        assert_eq!(
+            AffineTransform::translation(Point::new(2, -3)).transform_point(Point::new(1, 1)),
+            Point::new(3, -2)
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let transform = AffineTransform::rotation(90.0.deg());
+
+        assert_eq!(
+            transform.transform_point(Point::new(10, 0)),
+            Point::new(0, 10)
+        );
+        assert_eq!(
+            transform.transform_point(Point::new(0, 10)),
+            Point::new(-10, 0)
+        );
+
+        let transform = AffineTransform::rotation(45.0.deg());
+        assert_eq!(
+            transform.transform_point(Point::new(10, 0)),
+            Point::new(7, 7)
+        );
+    }
+
+    #[test]
+    fn scaling() {
+        let transform = AffineTransform::scaling(2.0, 0.5);
+
+        assert_eq!(
+            transform.transform_point(Point::new(3, 4)),
+            Point::new(6, 2)
+        );
+    }
+
+    #[test]
+    fn shear() {
+        let transform = AffineTransform::shear(1.0, 0.0);
+
+        assert_eq!(
+            transform.transform_point(Point::new(3, 4)),
+            Point::new(7, 4)
+        );
+    }
+
+    #[test]
+    fn around() {
+        let transform = AffineTransform::rotation(180.0.deg()).around(Point::new(10, 20));
+
+        assert_eq!(
+            transform.transform_point(Point::new(10, 20)),
+            Point::new(10, 20)
+        );
+        assert_eq!(
+            transform.transform_point(Point::new(12, 21)),
+            Point::new(8, 19)
+        );
+    }
+
+    #[test]
+    fn then() {
+        let transform = AffineTransform::scaling(2.0, 2.0)
+            .then(&AffineTransform::translation(Point::new(1, 0)));
+
+        assert_eq!(
+            transform.transform_point(Point::new(3, 4)),
+            Point::new(7, 8)
+        );
+
+        let transform = AffineTransform::translation(Point::new(1, 0))
+            .then(&AffineTransform::scaling(2.0, 2.0));
+
+        assert_eq!(
+            transform.transform_point(Point::new(3, 4)),
+            Point::new(8, 8)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = AffineTransform::rotation(30.0.deg())
+            .then(&AffineTransform::scaling(2.0, 3.0))
+            .then(&AffineTransform::translation(Point::new(5, -7)));
+        let inverse = transform.inverse().unwrap();
+
+        for &point in &[Point::new(0, 0), Point::new(10, -20), Point::new(-30, 5)] {
+            assert_eq!(
+                inverse.transform_point(transform.transform_point(point)),
+                point
+            );
+        }
+
+        assert_eq!(AffineTransform::scaling(0.0, 1.0).inverse(), None);
+    }
+}
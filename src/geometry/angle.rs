@@ -118,6 +118,7 @@ pub(crate) trait Trigonometry {
     fn cos(self) -> Real;
 
     /// Get the tangent of the angle.
+    #[allow(dead_code)]
     fn tan(self) -> Option<Real>;
 }
 
@@ -312,6 +313,7 @@ impl Neg for Angle {
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision, clippy::approx_constant)]
 mod tests {
     use super::*;
     use float_cmp::{approx_eq, ApproxEq, F32Margin};
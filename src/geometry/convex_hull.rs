@@ -0,0 +1,324 @@
+use crate::geometry::Point;
+
+/// Calculates the convex hull of a set of points.
+///
+/// The vertices of the convex hull are written into `hull` and the returned slice contains the
+/// used part of the buffer. The vertices are sorted in clockwise order, as seen on the display,
+/// starting with the leftmost point. If multiple points share the smallest X coordinate the
+/// topmost of these points is used as the first vertex. Points which lie on an edge of the hull
+/// aren't included in the result.
+///
+/// The hull is calculated using Andrew's monotone chain algorithm, which doesn't require any
+/// memory allocations. To sort the input, `points` is reordered in place.
+///
+/// # Panics
+///
+/// Panics if `hull` is shorter than `points`.
+///
+/// # Examples
+///
+/// Outline a set of touch points using a polygon:
+///
+/// ```
+/// use embedded_graphics::{
+///     geometry::convex_hull,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Polygon, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<BinaryColor>::new();
+///
+/// let mut touch_points = [
+///     Point::new(10, 10),
+///     Point::new(20, 12),
+///     Point::new(15, 15),
+///     Point::new(12, 25),
+///     Point::new(25, 20),
+/// ];
+/// let mut buffer = [Point::zero(); 5];
+///
+/// let hull = convex_hull(&mut touch_points, &mut buffer);
+/// assert_eq!(
+///     hull,
+///     &[
+///         Point::new(10, 10),
+///         Point::new(20, 12),
+///         Point::new(25, 20),
+///         Point::new(12, 25),
+///     ]
+/// );
+///
+/// Polygon::new(hull)
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+pub fn convex_hull<'a>(points: &mut [Point], hull: &'a mut [Point]) -> &'a [Point] {
+    assert!(
+        hull.len() >= points.len(),
+        "hull buffer is too small for the given points"
+    );
+
+    points.sort_unstable_by_key(|point| (point.x, point.y));
+
+    // Remove duplicate points, which are adjacent after sorting.
+    let mut unique_len = 0;
+    for index in 0..points.len() {
+        if unique_len == 0 || points[unique_len - 1] != points[index] {
+            points[unique_len] = points[index];
+            unique_len += 1;
+        }
+    }
+    let points = &points[0..unique_len];
+
+    let mut len = 0;
+
+    // Lower half of the hull, which is the upper half on the display because the Y axis points
+    // down.
+    for &point in points.iter() {
+        while len >= 2 && !is_clockwise(hull[len - 2], hull[len - 1], point) {
+            len -= 1;
+        }
+
+        hull[len] = point;
+        len += 1;
+    }
+
+    // Upper half of the hull. The first point is already part of the hull and is only used to
+    // remove the vertices that would make the hull concave.
+    //
+    // While the upper half is calculated the hull can temporarily contain one more vertex than
+    // there are points, which would be removed again before the end. To fit the result into a
+    // buffer with `points.len()` elements, the last vertex isn't stored in the buffer until another
+    // vertex is added.
+    let lower_len = len + 1;
+    let mut last = None;
+    for (index, &point) in points.iter().enumerate().rev().skip(1) {
+        while len + last.is_some() as usize >= lower_len {
+            let (a, b) = match last {
+                Some(last) => (hull[len - 1], last),
+                None => (hull[len - 2], hull[len - 1]),
+            };
+
+            if is_clockwise(a, b, point) {
+                break;
+            }
+
+            if last.take().is_none() {
+                len -= 1;
+            }
+        }
+
+        if index > 0 {
+            if let Some(last) = last {
+                hull[len] = last;
+                len += 1;
+            }
+            last = Some(point);
+        }
+    }
+
+    if let Some(last) = last {
+        hull[len] = last;
+        len += 1;
+    }
+
+    &hull[0..len]
+}
+
+/// Returns `true` if the path from `a` over `b` to `c` turns clockwise on the display.
+fn is_clockwise(a: Point, b: Point, c: Point) -> bool {
+    // The products can exceed the range of `i64` for large coordinates.
+    let (ab_x, ab_y) = (
+        i128::from(b.x) - i128::from(a.x),
+        i128::from(b.y) - i128::from(a.y),
+    );
+    let (ac_x, ac_y) = (
+        i128::from(c.x) - i128::from(a.x),
+        i128::from(c.y) - i128::from(a.y),
+    );
+
+    ab_x * ac_y - ab_y * ac_x > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calculates the convex hull using a buffer which has exactly the same length as `points`.
+    fn hull(points: &[Point]) -> ([Point; 16], usize) {
+        let mut points_buffer = [Point::zero(); 16];
+        let mut hull_buffer = [Point::zero(); 16];
+
+        let points_buffer = &mut points_buffer[0..points.len()];
+        points_buffer.copy_from_slice(points);
+
+        let len = convex_hull(points_buffer, &mut hull_buffer[0..points.len()]).len();
+
+        (hull_buffer, len)
+    }
+
+    fn assert_hull(points: &[Point], expected: &[Point]) {
+        let (hull, len) = hull(points);
+
+        assert_eq!(&hull[0..len], expected, "{:?}", points);
+    }
+
+    #[test]
+    fn empty() {
+        assert_hull(&[], &[]);
+    }
+
+    #[test]
+    fn single_point() {
+        assert_hull(&[Point::new(3, 4)], &[Point::new(3, 4)]);
+    }
+
+    #[test]
+    fn two_points() {
+        assert_hull(
+            &[Point::new(5, 1), Point::new(3, 4)],
+            &[Point::new(3, 4), Point::new(5, 1)],
+        );
+        assert_hull(&[Point::new(3, 4), Point::new(3, 4)], &[Point::new(3, 4)]);
+    }
+
+    #[test]
+    fn duplicate_points() {
+        assert_hull(&[Point::new(1, 1); 5], &[Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn colinear_points() {
+        assert_hull(
+            &[
+                Point::new(2, 2),
+                Point::new(0, 0),
+                Point::new(3, 3),
+                Point::new(1, 1),
+            ],
+            &[Point::new(0, 0), Point::new(3, 3)],
+        );
+    }
+
+    #[test]
+    fn square_with_inner_and_edge_points() {
+        assert_hull(
+            &[
+                Point::new(5, 5),
+                Point::new(0, 10),
+                Point::new(10, 0),
+                Point::new(5, 0),
+                Point::new(0, 0),
+                Point::new(3, 7),
+                Point::new(10, 10),
+                Point::new(10, 4),
+            ],
+            &[
+                Point::new(0, 0),
+                Point::new(10, 0),
+                Point::new(10, 10),
+                Point::new(0, 10),
+            ],
+        );
+    }
+
+    #[test]
+    fn hull_uses_all_points() {
+        let points = [
+            Point::new(0, 5),
+            Point::new(5, 0),
+            Point::new(10, 5),
+            Point::new(5, 10),
+        ];
+
+        assert_hull(
+            &points,
+            &[
+                Point::new(0, 5),
+                Point::new(5, 0),
+                Point::new(10, 5),
+                Point::new(5, 10),
+            ],
+        );
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let max = i32::max_value();
+        let min = i32::min_value();
+
+        assert_hull(
+            &[
+                Point::new(min, min),
+                Point::new(max, min),
+                Point::new(0, 0),
+                Point::new(max, max),
+                Point::new(min, max),
+            ],
+            &[
+                Point::new(min, min),
+                Point::new(max, min),
+                Point::new(max, max),
+                Point::new(min, max),
+            ],
+        );
+    }
+
+    #[test]
+    fn all_points_on_lower_half() {
+        assert_hull(
+            &[
+                Point::new(5, 14),
+                Point::new(-17, 20),
+                Point::new(-6, -19),
+                Point::new(8, -17),
+                Point::new(-2, 12),
+            ],
+            &[
+                Point::new(-17, 20),
+                Point::new(-6, -19),
+                Point::new(8, -17),
+                Point::new(5, 14),
+            ],
+        );
+    }
+
+    #[test]
+    fn random_points() {
+        // Simple linear congruential generator to get reproducible pseudo random points.
+        let mut state = 12345u32;
+        let mut random = || {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            ((state >> 16) % 41) as i32 - 20
+        };
+
+        for _ in 0..1000 {
+            let mut points = [Point::zero(); 5];
+            for point in points.iter_mut() {
+                *point = Point::new(random(), random());
+            }
+
+            let (hull, len) = hull(&points);
+            let hull = &hull[0..len];
+
+            // Every point must be inside or on the edge of the hull.
+            for &point in points.iter() {
+                for (index, &a) in hull.iter().enumerate() {
+                    let b = hull[(index + 1) % hull.len()];
+                    assert!(hull.len() < 3 || !is_clockwise(b, a, point), "{:?}", points);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "hull buffer is too small")]
+    fn buffer_too_small() {
+        let mut points = [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)];
+        let mut hull = [Point::zero(); 2];
+
+        convex_hull(&mut points, &mut hull);
+    }
+}
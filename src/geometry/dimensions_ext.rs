@@ -0,0 +1,115 @@
+use crate::{
+    geometry::{AnchorPoint, Dimensions, Point},
+    primitives::Rectangle,
+    transform::Transform,
+};
+
+/// Extension trait for objects with dimensions.
+///
+/// This trait is implemented for all types that implement [`Dimensions`] and adds methods to align
+/// objects relative to each other, for example to center a drawable on the display.
+///
+/// # Examples
+///
+/// Draw a text in the bottom right corner of the display:
+///
+/// ```
+/// use embedded_graphics::{
+///     geometry::AnchorPoint,
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     text::Text,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<BinaryColor>::new();
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+///
+/// Text::new("Bottom", Point::zero(), style)
+///     .aligned_to(&display.bounding_box(), AnchorPoint::BottomRight)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Dimensions`]: trait.Dimensions.html
+pub trait DimensionsExt: Dimensions {
+    /// Returns the translation which aligns this object to `reference`.
+    ///
+    /// After the returned offset is applied, the given anchor point of this object's bounding box
+    /// is located at the same position as the anchor point of `reference`. Centered anchor
+    /// points use the same rounding as [`Rectangle::resized`].
+    ///
+    /// [`Rectangle::resized`]: ../primitives/rectangle/struct.Rectangle.html#method.resized
+    fn align_to(&self, reference: &Rectangle, anchor_point: AnchorPoint) -> Point {
+        let bounding_box = self.bounding_box();
+
+        reference.resized(bounding_box.size, anchor_point).top_left - bounding_box.top_left
+    }
+
+    /// Returns a copy of this object which is aligned to `reference`.
+    ///
+    /// See [`align_to`] for more details.
+    ///
+    /// [`align_to`]: #method.align_to
+    fn aligned_to(&self, reference: &Rectangle, anchor_point: AnchorPoint) -> Self
+    where
+        Self: Transform + Sized,
+    {
+        self.translate(self.align_to(reference, anchor_point))
+    }
+}
+
+impl<T> DimensionsExt for T where T: Dimensions + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, Primitive, PrimitiveStyle},
+    };
+
+    #[test]
+    fn align_to() {
+        let display = Rectangle::new(Point::zero(), Size::new(64, 32));
+        let rectangle = Rectangle::new(Point::new(3, 4), Size::new(10, 5));
+
+        let expected = [
+            (AnchorPoint::TopLeft, Point::new(-3, -4)),
+            (AnchorPoint::TopCenter, Point::new(24, -4)),
+            (AnchorPoint::TopRight, Point::new(51, -4)),
+            (AnchorPoint::CenterLeft, Point::new(-3, 9)),
+            (AnchorPoint::Center, Point::new(24, 9)),
+            (AnchorPoint::CenterRight, Point::new(51, 9)),
+            (AnchorPoint::BottomLeft, Point::new(-3, 23)),
+            (AnchorPoint::BottomCenter, Point::new(24, 23)),
+            (AnchorPoint::BottomRight, Point::new(51, 23)),
+        ];
+
+        for &(anchor_point, offset) in expected.iter() {
+            assert_eq!(
+                rectangle.align_to(&display, anchor_point),
+                offset,
+                "{:?}",
+                anchor_point
+            );
+        }
+    }
+
+    #[test]
+    fn aligned_to() {
+        let display = Rectangle::new(Point::new(10, 10), Size::new(21, 21));
+
+        let circle = Circle::new(Point::new(50, 3), 5)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+            .aligned_to(&display, AnchorPoint::Center);
+
+        // The styled bounding box, which includes the stroke, is centered.
+        assert_eq!(
+            circle.bounding_box(),
+            Rectangle::new(Point::new(17, 17), Size::new(7, 7))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Integer math helpers.
+
+/// Divides two numbers, rounding to the nearest integer.
+///
+/// Halfway values are rounded away from zero.
+pub(crate) fn div_round(numerator: i64, denominator: i64) -> i64 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+
+    if numerator >= 0 {
+        (numerator + denominator / 2) / denominator
+    } else {
+        (numerator - denominator / 2) / denominator
+    }
+}
+
+/// Integer square root, rounded down.
+pub(crate) fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method, starting with an estimate that is guaranteed to be too large.
+    let mut x = 1 << ((64 - value.leading_zeros() + 1) / 2);
+    loop {
+        let next = (x + value / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Fixed point representation of a 45° angle, used by [`quadrant_atan2`].
+///
+/// [`quadrant_atan2`]: fn.quadrant_atan2.html
+pub(crate) const OCTANT: i64 = 1 << 16;
+
+/// Approximates the angle between the X axis and the vector from the origin to `(x, y)`.
+///
+/// Both coordinates must be non-negative and at least one of them must be non-zero. The angle is
+/// returned in fixed point units, with [`OCTANT`] corresponding to 45°, and is accurate to about
+/// 0.3°.
+///
+/// [`OCTANT`]: constant.OCTANT.html
+pub(crate) fn quadrant_atan2(y: i64, x: i64) -> i64 {
+    // Approximation of `atan(t) / (PI / 4)` for `0 <= t <= 1`.
+    let atan = |t: i64| t + t * (OCTANT - t) / OCTANT * 3477 / 10000;
+
+    if y <= x {
+        atan(y * OCTANT / x)
+    } else {
+        2 * OCTANT - atan(x * OCTANT / y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_round_ties() {
+        assert_eq!(div_round(5, 2), 3);
+        assert_eq!(div_round(-5, 2), -3);
+        assert_eq!(div_round(5, -2), -3);
+        assert_eq!(div_round(-5, -2), 3);
+        assert_eq!(div_round(65535, 131072), 0);
+    }
+
+    #[test]
+    fn div_round_nearest() {
+        assert_eq!(div_round(4, 3), 1);
+        assert_eq!(div_round(5, 3), 2);
+        assert_eq!(div_round(-4, 3), -1);
+        assert_eq!(div_round(-5, 3), -2);
+        assert_eq!(div_round(0, -7), 0);
+    }
+
+    #[test]
+    fn isqrt() {
+        for value in 0..10_000u64 {
+            let root = super::isqrt(value);
+            assert!(
+                root * root <= value && (root + 1) * (root + 1) > value,
+                "{}",
+                value
+            );
+        }
+
+        assert_eq!(super::isqrt(core::u64::MAX), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn quadrant_atan2() {
+        assert_eq!(super::quadrant_atan2(0, 10), 0);
+        assert_eq!(super::quadrant_atan2(10, 10), OCTANT);
+        assert_eq!(super::quadrant_atan2(10, 0), 2 * OCTANT);
+
+        for &(y, x, expected) in &[
+            (1, 2, 26.565),
+            (2, 1, 63.435),
+            (1, 3, 18.435),
+            (3, 4, 36.870),
+            (1000, 1, 89.943),
+        ] {
+            let angle = super::quadrant_atan2(y, x) as f32 * 45.0 / OCTANT as f32;
+
+            assert!(
+                (angle - expected).abs() < 0.3,
+                "({}, {}): expected {}°, got {}°",
+                x,
+                y,
+                expected,
+                angle
+            );
+        }
+    }
+}
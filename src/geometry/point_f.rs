@@ -0,0 +1,201 @@
+use crate::geometry::{div_round, Point};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Point with sub-pixel precision.
+///
+/// The coordinates of a `PointF` are stored as fixed point numbers with 16 fractional bits, which
+/// makes it possible to represent intermediate results of geometric calculations without rounding
+/// them to whole pixels and without requiring floating point operations. The integer part uses
+/// the full range of `i32`, which means that every [`Point`] can be converted into a `PointF`
+/// without any loss of precision.
+///
+/// `PointF` is used to represent the result of affine transformations and can be converted back
+/// into a [`Point`] by using one of the rounding methods.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::geometry::{Point, PointF};
+///
+/// let a = PointF::from(Point::new(1, 2));
+/// let b = PointF::from_ratio(Point::new(7, -3), 2);
+///
+/// assert_eq!(b.floor(), Point::new(3, -2));
+/// assert_eq!(b.ceil(), Point::new(4, -1));
+/// assert_eq!((a + b).round(), Point::new(5, 1));
+/// ```
+///
+/// [`Point`]: struct.Point.html
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PointF {
+    x: i64,
+    y: i64,
+}
+
+impl PointF {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+
+    /// Fixed point representation of one pixel.
+    const ONE: i64 = 1 << Self::FRAC_BITS;
+
+    /// Creates a point from raw fixed point coordinates.
+    pub const fn from_bits(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the raw fixed point coordinates.
+    pub const fn to_bits(self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+
+    /// Creates a point with the coordinates `numerator / denominator`.
+    ///
+    /// The result is rounded to the nearest representable value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn from_ratio(numerator: Point, denominator: i64) -> Self {
+        let div = |value: i32| div_round(i64::from(value) << Self::FRAC_BITS, denominator);
+
+        Self::from_bits(div(numerator.x), div(numerator.y))
+    }
+
+    /// Returns the nearest point with integer coordinates.
+    ///
+    /// Halfway values are rounded away from zero.
+    pub fn round(self) -> Point {
+        let round = |value: i64| div_round(value, Self::ONE) as i32;
+
+        Point::new(round(self.x), round(self.y))
+    }
+
+    /// Returns the largest point with integer coordinates which is less than or equal to this
+    /// point.
+    pub fn floor(self) -> Point {
+        Point::new(
+            (self.x >> Self::FRAC_BITS) as i32,
+            (self.y >> Self::FRAC_BITS) as i32,
+        )
+    }
+
+    /// Returns the smallest point with integer coordinates which is greater than or equal to this
+    /// point.
+    pub fn ceil(self) -> Point {
+        let ceil = |value: i64| ((value + Self::ONE - 1) >> Self::FRAC_BITS) as i32;
+
+        Point::new(ceil(self.x), ceil(self.y))
+    }
+}
+
+impl From<Point> for PointF {
+    fn from(point: Point) -> Self {
+        Self::from_bits(
+            i64::from(point.x) << Self::FRAC_BITS,
+            i64::from(point.y) << Self::FRAC_BITS,
+        )
+    }
+}
+
+impl Add for PointF {
+    type Output = PointF;
+
+    fn add(self, other: PointF) -> PointF {
+        PointF::from_bits(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for PointF {
+    fn add_assign(&mut self, other: PointF) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for PointF {
+    type Output = PointF;
+
+    fn sub(self, other: PointF) -> PointF {
+        PointF::from_bits(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for PointF {
+    fn sub_assign(&mut self, other: PointF) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Neg for PointF {
+    type Output = PointF;
+
+    fn neg(self) -> PointF {
+        PointF::from_bits(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_point_is_lossless() {
+        for &point in &[
+            Point::zero(),
+            Point::new(-3, 7),
+            Point::new(i32::max_value(), i32::min_value()),
+        ] {
+            let fixed = PointF::from(point);
+
+            assert_eq!(fixed.round(), point);
+            assert_eq!(fixed.floor(), point);
+            assert_eq!(fixed.ceil(), point);
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        let point = PointF::from_ratio(Point::new(5, -5), 2);
+        assert_eq!(point.round(), Point::new(3, -3));
+        assert_eq!(point.floor(), Point::new(2, -3));
+        assert_eq!(point.ceil(), Point::new(3, -2));
+
+        let point = PointF::from_ratio(Point::new(4, -4), 3);
+        assert_eq!(point.round(), Point::new(1, -1));
+        assert_eq!(point.floor(), Point::new(1, -2));
+        assert_eq!(point.ceil(), Point::new(2, -1));
+    }
+
+    #[test]
+    fn from_ratio() {
+        assert_eq!(
+            PointF::from_ratio(Point::new(1, 3), 4).to_bits(),
+            (16384, 49152)
+        );
+        assert_eq!(
+            PointF::from_ratio(Point::new(1, -1), -3),
+            PointF::from_ratio(Point::new(-1, 1), 3)
+        );
+        assert_eq!(
+            PointF::from_ratio(Point::new(-1, 2), 3).to_bits(),
+            (-21845, 43691)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = PointF::from_bits(1, 2);
+        let b = PointF::from_bits(10, 20);
+
+        assert_eq!(a + b, PointF::from_bits(11, 22));
+        assert_eq!(b - a, PointF::from_bits(9, 18));
+        assert_eq!(-a, PointF::from_bits(-1, -2));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::geometry::{quadrant_atan2, Angle, Point, Real, Trigonometry, OCTANT};
+
+/// Polar coordinates.
+///
+/// This trait adds methods to convert between [`Point`]s and polar coordinates, which can be
+/// used to position gauge needles, clock hands or other elements around a center point.
+///
+/// Angles use the same convention as [`Arc`] and [`Sector`]: an angle of zero degrees points to
+/// the right and positive angles rotate counterclockwise, which means that an angle of 90° points
+/// up.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// let center = Point::new(32, 32);
+///
+/// // A clock hand pointing to three o'clock.
+/// let hand = Line::new(center, center + Point::from_polar(0.0.deg(), 20));
+/// assert_eq!(hand.end, Point::new(52, 32));
+///
+/// // The angle of the hand can be calculated from its end points.
+/// assert_eq!(hand.start.angle_to(hand.end), 0.0.deg());
+/// ```
+///
+/// [`Point`]: struct.Point.html
+/// [`Arc`]: ../primitives/arc/struct.Arc.html
+/// [`Sector`]: ../primitives/sector/struct.Sector.html
+pub trait PolarCoordinates {
+    /// Creates a point from polar coordinates.
+    ///
+    /// The returned point is located `radius` pixels away from the origin in the direction given by
+    /// `angle`. The coordinates are rounded to the nearest integer.
+    fn from_polar(angle: Angle, radius: u32) -> Self;
+
+    /// Returns the angle of the direction from this point to `other`.
+    ///
+    /// The angle is approximated using integer arithmetic and is accurate to about 0.3°.
+    /// The returned angle is normalized to the range from 0° to 360° and is zero if both points
+    /// are equal.
+    fn angle_to(self, other: Self) -> Angle;
+}
+
+impl PolarCoordinates for Point {
+    fn from_polar(angle: Angle, radius: u32) -> Self {
+        let radius = Real::from(radius);
+
+        Point::new(
+            i32::from((radius * angle.cos()).round()),
+            -i32::from((radius * angle.sin()).round()),
+        )
+    }
+
+    fn angle_to(self, other: Self) -> Angle {
+        // The Y axis is inverted to make positive angles rotate counterclockwise.
+        let dx = i64::from(other.x) - i64::from(self.x);
+        let dy = i64::from(self.y) - i64::from(other.y);
+
+        if dx == 0 && dy == 0 {
+            return Angle::zero();
+        }
+
+        let mut angle = quadrant_atan2(dy.abs(), dx.abs());
+
+        if dx < 0 {
+            angle = 4 * OCTANT - angle;
+        }
+        if dy < 0 {
+            angle = (8 * OCTANT - angle) % (8 * OCTANT);
+        }
+
+        Angle::from_degrees(angle as f32 * 45.0 / OCTANT as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    /// Asserts that the angle from the origin to `point` is close to `expected` degrees.
+    fn assert_angle(point: Point, expected: f32) {
+        let angle = Point::zero().angle_to(point).to_degrees();
+
+        assert!(
+            (angle - expected).abs() < 0.3,
+            "{:?}: expected {}°, got {}°",
+            point,
+            expected,
+            angle
+        );
+    }
+
+    #[test]
+    fn from_polar() {
+        assert_eq!(Point::from_polar(0.0.deg(), 10), Point::new(10, 0));
+        assert_eq!(Point::from_polar(90.0.deg(), 10), Point::new(0, -10));
+        assert_eq!(Point::from_polar(180.0.deg(), 10), Point::new(-10, 0));
+        assert_eq!(Point::from_polar(270.0.deg(), 10), Point::new(0, 10));
+        assert_eq!(Point::from_polar(45.0.deg(), 10), Point::new(7, -7));
+        assert_eq!(Point::from_polar(123.0.deg(), 0), Point::zero());
+    }
+
+    #[test]
+    fn angle_to() {
+        assert_angle(Point::new(10, 0), 0.0);
+        assert_angle(Point::new(10, -10), 45.0);
+        assert_angle(Point::new(0, -10), 90.0);
+        assert_angle(Point::new(-10, 0), 180.0);
+        assert_angle(Point::new(0, 10), 270.0);
+        assert_angle(Point::new(10, 1), 360.0 - 5.710_593);
+        assert_angle(Point::new(-3, -7), 180.0 - 66.801_41);
+
+        assert_eq!(Point::new(3, 4).angle_to(Point::new(3, 4)), Angle::zero());
+    }
+
+    #[test]
+    fn round_trip() {
+        for degrees in (0..360).step_by(15) {
+            let point = Point::from_polar((degrees as f32).deg(), 1000);
+
+            assert_angle(point, degrees as f32);
+        }
+    }
+
+    #[test]
+    fn relative_angle() {
+        let center = Point::new(20, 20);
+
+        assert_eq!(center.angle_to(Point::new(20, 5)), 90.0.deg());
+        assert_eq!(center.angle_to(Point::new(5, 20)), 180.0.deg());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::geometry::Point;
+
+/// Rotation by a multiple of 90°.
+///
+/// Rotations are applied clockwise, as seen on the display. The same rotation type is used to
+/// rotate text and the coordinate system of draw targets.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Rotation {
+    /// No rotation.
+    Deg0,
+    /// Rotate by 90°.
+    Deg90,
+    /// Rotate by 180°.
+    Deg180,
+    /// Rotate by 270°.
+    Deg270,
+}
+
+impl Rotation {
+    /// Rotates a vector around the origin.
+    pub(crate) fn rotate(self, vector: Point) -> Point {
+        match self {
+            Rotation::Deg0 => vector,
+            Rotation::Deg90 => Point::new(-vector.y, vector.x),
+            Rotation::Deg180 => -vector,
+            Rotation::Deg270 => Point::new(vector.y, -vector.x),
+        }
+    }
+
+    /// Returns the rotation that reverses this rotation.
+    pub(crate) fn inverse(self) -> Self {
+        match self {
+            Rotation::Deg90 => Rotation::Deg270,
+            Rotation::Deg270 => Rotation::Deg90,
+            rotation => rotation,
+        }
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::Deg0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATIONS: [Rotation; 4] = [
+        Rotation::Deg0,
+        Rotation::Deg90,
+        Rotation::Deg180,
+        Rotation::Deg270,
+    ];
+
+    #[test]
+    fn rotate() {
+        let vector = Point::new(3, 1);
+
+        // Clockwise rotation in screen coordinates, with the Y axis pointing down.
+        assert!(ROTATIONS.iter().map(|r| r.rotate(vector)).eq([
+            Point::new(3, 1),
+            Point::new(-1, 3),
+            Point::new(-3, -1),
+            Point::new(1, -3),
+        ]
+        .iter()
+        .copied()));
+    }
+
+    #[test]
+    fn inverse() {
+        let vector = Point::new(3, 1);
+
+        for rotation in ROTATIONS.iter() {
+            assert_eq!(rotation.inverse().rotate(rotation.rotate(vector)), vector);
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    image::{Image, ImageDrawable, ImageDrawableExt},
+    primitives::Rectangle,
+    transform::Transform,
+    Drawable,
+};
+
+/// Animated sprite.
+///
+/// An `AnimatedSprite` draws a single frame of an animation that is stored in a sprite sheet. All
+/// frames in the sprite sheet must have the same size and are arranged in a grid, starting in the
+/// top left corner of the sheet. The frames are ordered from left to right and continue on the
+/// next row of the grid if the end of a row is reached.
+///
+/// The current frame is advanced to the next frame by calling [`advance`], which restarts the
+/// animation with the first frame after the last frame has been reached. This makes
+/// `AnimatedSprite` useful for loading spinners and other small looping animations.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{AnimatedSprite, ImageRaw},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+///
+/// /// Sprite sheet which contains four 4 x 4 pixel frames of a spinner in a 2 x 2 grid.
+/// #[rustfmt::skip]
+/// const SPINNER: &[u8] = &[
+///     0b0110_0000,
+///     0b0000_0110,
+///     0b0000_0110,
+///     0b0000_0000,
+///     0b0000_0000,
+///     0b0110_0000,
+///     0b0110_0000,
+///     0b0000_0110,
+/// ];
+/// let sheet = ImageRaw::<BinaryColor>::new(SPINNER, 8);
+///
+/// let mut spinner = AnimatedSprite::new(&sheet, Size::new(4, 4), 4, Point::new(10, 10));
+///
+/// # for _ in 0..4 {
+/// # let mut display = MockDisplay::new();
+/// // Call this code periodically to animate the spinner.
+/// spinner.draw(&mut display)?;
+/// spinner.advance();
+/// # }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`advance`]: #method.advance
+#[derive(Debug, Clone, Copy)]
+pub struct AnimatedSprite<'a, T> {
+    sheet: &'a T,
+    frame_size: Size,
+    frame_count: u32,
+    frame: u32,
+    position: Point,
+}
+
+impl<'a, T> AnimatedSprite<'a, T>
+where
+    T: ImageDrawable,
+{
+    /// Creates a new animated sprite.
+    ///
+    /// The sprite is drawn with its top left corner at `position`, starting with the first frame.
+    /// Frames which are partially or completely outside the sprite sheet are cropped to the
+    /// bounding box of the sheet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_count` is zero.
+    pub fn new(sheet: &'a T, frame_size: Size, frame_count: u32, position: Point) -> Self {
+        assert!(frame_count > 0, "frame count must be greater than zero");
+
+        Self {
+            sheet,
+            frame_size,
+            frame_count,
+            frame: 0,
+            position,
+        }
+    }
+
+    /// Returns the index of the current frame.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Sets the current frame.
+    ///
+    /// Indices larger than the number of frames wrap around to the start of the animation.
+    pub fn set_frame(&mut self, frame: u32) {
+        self.frame = frame % self.frame_count;
+    }
+
+    /// Returns the number of frames.
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Advances the animation to the next frame.
+    ///
+    /// The animation restarts with the first frame after the last frame has been reached.
+    pub fn advance(&mut self) {
+        self.set_frame(self.frame + 1);
+    }
+
+    /// Returns the area of the current frame in the sprite sheet.
+    ///
+    /// `None` is returned if the sheet is narrower than a single frame.
+    fn frame_area(&self) -> Option<Rectangle> {
+        let columns = self
+            .sheet
+            .size()
+            .width
+            .checked_div(self.frame_size.width)
+            .filter(|columns| *columns > 0)?;
+
+        let column = self.frame % columns;
+        let row = self.frame / columns;
+
+        Some(Rectangle::new(
+            Point::zero() + self.frame_size.component_mul(Size::new(column, row)),
+            self.frame_size,
+        ))
+    }
+}
+
+impl<T> Drawable for AnimatedSprite<'_, T>
+where
+    T: ImageDrawable,
+{
+    type Color = T::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if let Some(area) = self.frame_area() {
+            Image::new(&self.sheet.sub_image(&area), self.position).draw(target)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Dimensions for AnimatedSprite<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.position, self.frame_size)
+    }
+}
+
+impl<T> Transform for AnimatedSprite<'_, T> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            sheet: self.sheet,
+            frame_size: self.frame_size,
+            frame_count: self.frame_count,
+            frame: self.frame,
+            position: self.position + by,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{image::ImageRaw, mock_display::MockDisplay, pixelcolor::Gray4};
+
+    /// Sprite sheet with 2 x 1 pixel frames in a 2 x 2 grid.
+    #[rustfmt::skip]
+    const SHEET: &[u8] = &[
+        0x12, 0x34,
+        0x56, 0x78,
+    ];
+
+    fn draw_frame(sprite: &AnimatedSprite<ImageRaw<Gray4>>) -> MockDisplay<Gray4> {
+        let mut display = MockDisplay::new();
+        sprite.draw(&mut display).unwrap();
+
+        display
+    }
+
+    #[test]
+    fn advance() {
+        let sheet = ImageRaw::<Gray4>::new(SHEET, 4);
+        let mut sprite = AnimatedSprite::new(&sheet, Size::new(2, 1), 3, Point::new(1, 1));
+
+        let expected = [
+            ["   ", " 12"],
+            ["   ", " 34"],
+            ["   ", " 56"],
+            ["   ", " 12"],
+        ];
+
+        for (index, pattern) in expected.iter().enumerate() {
+            assert_eq!(sprite.frame(), index as u32 % 3);
+            draw_frame(&sprite).assert_pattern(pattern);
+
+            sprite.advance();
+        }
+    }
+
+    #[test]
+    fn set_frame() {
+        let sheet = ImageRaw::<Gray4>::new(SHEET, 4);
+        let mut sprite = AnimatedSprite::new(&sheet, Size::new(2, 1), 4, Point::zero());
+        assert_eq!(sprite.frame_count(), 4);
+
+        sprite.set_frame(3);
+        draw_frame(&sprite).assert_pattern(&["78"]);
+
+        sprite.set_frame(6);
+        assert_eq!(sprite.frame(), 2);
+        draw_frame(&sprite).assert_pattern(&["56"]);
+    }
+
+    #[test]
+    fn frame_wider_than_sheet() {
+        let sheet = ImageRaw::<Gray4>::new(SHEET, 4);
+        let sprite = AnimatedSprite::new(&sheet, Size::new(5, 1), 1, Point::zero());
+
+        draw_frame(&sprite).assert_pattern(&[]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let sheet = ImageRaw::<Gray4>::new(SHEET, 4);
+        let sprite = AnimatedSprite::new(&sheet, Size::new(2, 1), 4, Point::new(3, 4))
+            .translate(Point::new(1, 2));
+
+        assert_eq!(
+            sprite.bounding_box(),
+            Rectangle::new(Point::new(4, 6), Size::new(2, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "frame count must be greater than zero")]
+    fn zero_frame_count() {
+        let sheet = ImageRaw::<Gray4>::new(SHEET, 4);
+        AnimatedSprite::new(&sheet, Size::new(2, 1), 0, Point::zero());
+    }
+}
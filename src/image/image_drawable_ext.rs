@@ -1,8 +1,8 @@
-use crate::image::SubImage;
+use crate::image::{ImageWithTransparency, SubImage};
 use embedded_graphics_core::{image::ImageDrawable, primitives::Rectangle};
 
 /// Extension trait for image drawables.
-pub trait ImageDrawableExt: Sized {
+pub trait ImageDrawableExt: ImageDrawable + Sized {
     /// Returns a sub image of this image drawable.
     ///
     /// If any of the given `area` lies outside the bounding box of the parent image, the
@@ -37,14 +37,46 @@ pub trait ImageDrawableExt: Sized {
     ///
     /// # Ok::<(), core::convert::Infallible>(())
     /// ```
-    fn sub_image(&self, area: &Rectangle) -> SubImage<Self>;
+    fn sub_image(&self, area: &Rectangle) -> SubImage<'_, Self>;
+
+    /// Returns an image with a transparent color.
+    ///
+    /// All pixels which have the given color aren't drawn, which is commonly used to draw sprites
+    /// with irregular outlines on top of a background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     image::{Image, ImageRawBE},
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay as Display;
+    /// # let mut display: Display<Rgb565> = Display::default();
+    ///
+    /// # let data = [0u8; 16 * 16 * 2];
+    /// // or: let data = include_bytes!("sprite.raw");
+    /// let sprite = ImageRawBE::<Rgb565>::new(&data, 16);
+    ///
+    /// // Magenta pixels in the sprite are treated as transparent.
+    /// let sprite = sprite.with_transparent_color(Rgb565::MAGENTA);
+    ///
+    /// Image::new(&sprite, Point::new(10, 20)).draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn with_transparent_color(&self, color: Self::Color) -> ImageWithTransparency<'_, Self>;
 }
 
 impl<T> ImageDrawableExt for T
 where
     T: ImageDrawable,
 {
-    fn sub_image(&self, area: &Rectangle) -> SubImage<T> {
+    fn sub_image(&self, area: &Rectangle) -> SubImage<'_, T> {
         SubImage::new(self, area)
     }
+
+    fn with_transparent_color(&self, color: Self::Color) -> ImageWithTransparency<'_, T> {
+        ImageWithTransparency::new(self, color)
+    }
 }
@@ -27,7 +27,10 @@ pub type ImageRawBE<'a, C> = ImageRaw<'a, C, BigEndian>;
 /// specified for colors which aren't stored in multiple bytes.
 ///
 /// For color types with less than 8 bits per pixels the start of each row is
-/// aligned to the next whole byte.
+/// aligned to the next whole byte and the leftmost pixel is stored in the most
+/// significant bits of a byte. Images with a different layout can be drawn by
+/// changing the row stride with [`with_stride`] and the order of the pixels
+/// inside each byte with [`with_bit_order`].
 ///
 /// Details about the conversion of raw data to color types are explained in the
 /// [`raw` module documentation].
@@ -105,7 +108,41 @@ pub type ImageRawBE<'a, C> = ImageRaw<'a, C, BigEndian>;
 /// # assert_eq!(image1, image2);
 /// ```
 ///
+/// ## Draw an image with LSB first bit order and padded rows
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{BitOrder, Image, ImageRaw},
+///     pixelcolor::Gray4,
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// /// 3 x 2 pixel image with 4 bits per pixel. The leftmost pixel is stored in
+/// /// the low nibble and each row is padded to 4 bytes.
+/// #[rustfmt::skip]
+/// const DATA: &[u8] = &[
+///     0x21, 0x03, 0x00, 0x00,
+///     0x54, 0x06, 0x00, 0x00,
+/// ];
+///
+/// let raw_image = ImageRaw::<Gray4>::new(DATA, 3)
+///     .with_stride(4)
+///     .with_bit_order(BitOrder::LsbFirst);
+///
+/// let mut display = Display::default();
+/// Image::new(&raw_image, Point::zero()).draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "123", //
+///     "456", //
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
 /// [`raw` module documentation]: ../pixelcolor/raw/index.html
+/// [`with_stride`]: #method.with_stride
+/// [`with_bit_order`]: #method.with_bit_order
 /// [`Drawable`]: ../drawable/trait.Drawable.html
 /// [`ImageRawBE`]: type.ImageRawBE.html
 /// [`ImageRawLE`]: type.ImageRawLE.html
@@ -124,6 +161,12 @@ where
     /// Image size in pixels
     size: Size,
 
+    /// Distance between the start of two consecutive rows in bytes
+    stride: usize,
+
+    /// Order of the pixels inside a byte for color types with less than 8 bits per pixel
+    bit_order: BitOrder,
+
     pixel_type: PhantomData<C>,
     byte_order: PhantomData<BO>,
 }
@@ -145,36 +188,147 @@ where
             return Self {
                 data: &[],
                 size: Size::zero(),
+                stride: 0,
+                bit_order: BitOrder::MsbFirst,
                 pixel_type: PhantomData,
                 byte_order: PhantomData,
             };
         }
 
-        let height = data.len() / bytes_per_row(width, C::Raw::BITS_PER_PIXEL);
+        let stride = bytes_per_row(width, C::Raw::BITS_PER_PIXEL);
+        let height = data.len() / stride;
 
         Self {
             data,
             size: Size::new(width, height as u32),
+            stride,
+            bit_order: BitOrder::MsbFirst,
             pixel_type: PhantomData,
             byte_order: PhantomData,
         }
     }
 
-    /// Returns the actual row width in pixels.
+    /// Sets the row stride in bytes.
+    ///
+    /// By default each row starts at the first byte after the previous row. The stride can be
+    /// used to draw images which contain additional padding bytes at the end of each row, for
+    /// example images whose rows are aligned to 32 bit words. The last row doesn't need to
+    /// include the padding bytes. The height of the image is recalculated based on the length of
+    /// the image data.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `stride` is smaller than the number of bytes that are required to
+    /// store a single row.
+    pub fn with_stride(self, stride: usize) -> Self {
+        let row_length = bytes_per_row(self.size.width, C::Raw::BITS_PER_PIXEL);
+
+        assert!(
+            stride >= row_length,
+            "stride must be at least as long as a single row"
+        );
+
+        let height = if self.size.width > 0 && self.data.len() >= row_length {
+            (self.data.len() - row_length) / stride + 1
+        } else {
+            0
+        };
+
+        Self {
+            size: Size::new(self.size.width, height as u32),
+            stride,
+            ..self
+        }
+    }
+
+    /// Sets the bit order.
+    ///
+    /// The bit order determines the order of the pixels inside each byte for color types with
+    /// less than 8 bits per pixel. The default bit order is [`BitOrder::MsbFirst`]. For color
+    /// types with 8 or more bits per pixel the bit order is ignored.
     ///
-    /// For images with less than 8 bits per pixel each row is padded to contain an integer number
-    /// of bytes. This method returns the width of each row including the padding pixels.
-    fn data_width(&self) -> u32 {
-        if C::Raw::BITS_PER_PIXEL < 8 {
-            let pixels_per_byte = 8 / C::Raw::BITS_PER_PIXEL as u32;
+    /// [`BitOrder::MsbFirst`]: enum.BitOrder.html#variant.MsbFirst
+    pub fn with_bit_order(self, bit_order: BitOrder) -> Self {
+        Self { bit_order, ..self }
+    }
+
+    /// Creates an image with the given memory layout.
+    pub(crate) fn from_layout(
+        data: &'a [u8],
+        size: Size,
+        stride: usize,
+        bit_order: BitOrder,
+    ) -> Self {
+        Self {
+            data,
+            size,
+            stride,
+            bit_order,
+            pixel_type: PhantomData,
+            byte_order: PhantomData,
+        }
+    }
+
+    /// Returns the image data.
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the row stride in bytes.
+    pub(crate) fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the bit order.
+    pub(crate) fn bit_order(&self) -> BitOrder {
+        self.bit_order
+    }
 
-            bytes_per_row(self.size.width, C::Raw::BITS_PER_PIXEL) as u32 * pixels_per_byte
+    /// Returns the data of a row, starting at the first byte of the row.
+    pub(super) fn row_data(&self, y: u32) -> &'a [u8] {
+        self.data.get(y as usize * self.stride..).unwrap_or(&[])
+    }
+
+    /// Returns the mask which converts an index inside a row into an index inside the raw data.
+    ///
+    /// For images with LSB first bit order the order of the pixel inside each byte is reversed,
+    /// which is equivalent to inverting the lower bits of the index.
+    pub(super) fn index_mask(&self) -> usize {
+        if C::Raw::BITS_PER_PIXEL < 8 && self.bit_order == BitOrder::LsbFirst {
+            8 / C::Raw::BITS_PER_PIXEL - 1
         } else {
-            self.size.width
+            0
         }
     }
 }
 
+impl<'a, C, BO> ImageRaw<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    /// Returns the color of the pixel at the given point.
+    ///
+    /// `None` is returned if the point is outside the image.
+    pub(crate) fn pixel(&self, point: Point) -> Option<C> {
+        if point.x < 0
+            || point.y < 0
+            || point.x as u32 >= self.size.width
+            || point.y as u32 >= self.size.height
+        {
+            return None;
+        }
+
+        let index = point.x as usize ^ self.index_mask();
+
+        RawDataSlice::<C::Raw, BO>::new(self.row_data(point.y as u32))
+            .into_iter()
+            .nth(index)
+            .map(Into::into)
+    }
+}
+
 impl<'a> ImageRaw<'a, BinaryColor> {
     /// Creates a new binary image.
     ///
@@ -191,19 +345,41 @@ impl<'a> ImageRaw<'a, BinaryColor> {
     //       (requires if in const function)
     // MSRV: remove this function when const functions with trait bounds are supported
     pub const fn new_binary(data: &'a [u8], width: u32) -> Self {
-        let height = data.len() / bytes_per_row(width, 1);
+        let stride = bytes_per_row(width, 1);
+        let height = data.len() / stride;
 
         Self {
             data,
             size: Size::new(width, height as u32),
+            stride,
+            bit_order: BitOrder::MsbFirst,
             pixel_type: PhantomData,
             byte_order: PhantomData,
         }
     }
 }
 
+/// Bit order.
+///
+/// The bit order determines the order of the pixels inside a byte for color types with less than 8
+/// bits per pixel.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum BitOrder {
+    /// The leftmost pixel is stored in the most significant bits.
+    MsbFirst,
+
+    /// The leftmost pixel is stored in the least significant bits.
+    LsbFirst,
+}
+
+impl Default for BitOrder {
+    fn default() -> Self {
+        BitOrder::MsbFirst
+    }
+}
+
 /// Returns the length of each row in bytes.
-const fn bytes_per_row(width: u32, bits_per_pixel: usize) -> usize {
+pub(super) const fn bytes_per_row(width: u32, bits_per_pixel: usize) -> usize {
     (width as usize * bits_per_pixel + 7) / 8
 }
 
@@ -219,12 +395,9 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        let row_skip = self.data_width() - self.size.width;
+        let area = self.bounding_box();
 
-        target.fill_contiguous(
-            &self.bounding_box(),
-            ContiguousPixels::new(self, self.size, 0, row_skip as usize),
-        )
+        target.fill_contiguous(&area, ContiguousPixels::new(self, &area))
     }
 
     fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
@@ -241,14 +414,9 @@ where
             return Ok(());
         }
 
-        let data_width = self.data_width() as usize;
-
-        let initial_skip = area.top_left.y as usize * data_width + area.top_left.x as usize;
-        let row_skip = data_width - area.size.width as usize;
-
         target.fill_contiguous(
             &Rectangle::new(Point::zero(), area.size),
-            ContiguousPixels::new(self, area.size, initial_skip, row_skip),
+            ContiguousPixels::new(self, area),
         )
     }
 }
@@ -269,13 +437,19 @@ where
     BO: ByteOrder,
     RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
 {
+    data: &'a [u8],
+    stride: usize,
+
+    /// Iterator over the pixels in the current row, which is only used if the index mask is zero.
     iter: <RawDataSlice<'a, C::Raw, BO> as IntoIterator>::IntoIter,
+    index_mask: usize,
 
+    x: usize,
     remaining_x: u32,
     width: u32,
 
+    y: u32,
     remaining_y: u32,
-    row_skip: usize,
 }
 
 impl<'a, C, BO> ContiguousPixels<'a, C, BO>
@@ -284,22 +458,32 @@ where
     BO: ByteOrder,
     RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
 {
-    fn new(image: &ImageRaw<'a, C, BO>, size: Size, initial_skip: usize, row_skip: usize) -> Self {
-        let mut iter = RawDataSlice::new(image.data).into_iter();
-
-        if initial_skip > 0 {
-            iter.nth(initial_skip - 1);
-        }
+    /// Returns the data of the current row.
+    fn row_data(&self) -> &'a [u8] {
+        self.data
+            .get(self.y as usize * self.stride..)
+            .unwrap_or(&[])
+    }
 
+    /// Creates an iterator over the pixels in `area`, which must be inside the image.
+    fn new(image: &ImageRaw<'a, C, BO>, area: &Rectangle) -> Self {
         // Set `remaining_y` to `0` if `width == 0` to prevent integer underflow in `next`.
-        let remaining_y = if size.width > 0 { size.height } else { 0 };
+        let remaining_y = if area.size.width > 0 {
+            area.size.height
+        } else {
+            0
+        };
 
         Self {
-            iter,
-            remaining_x: size.width,
-            width: size.width,
+            data: image.data,
+            stride: image.stride,
+            iter: RawDataSlice::new(&[]).into_iter(),
+            index_mask: image.index_mask(),
+            x: area.top_left.x as usize,
+            remaining_x: 0,
+            width: area.size.width,
+            y: area.top_left.y as u32,
             remaining_y,
-            row_skip,
         }
     }
 }
@@ -313,21 +497,36 @@ where
     type Item = C;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining_x > 0 {
-            self.remaining_x -= 1;
-
-            self.iter.next()
-        } else {
+        if self.remaining_x == 0 {
             if self.remaining_y == 0 {
                 return None;
             }
 
             self.remaining_y -= 1;
-            self.remaining_x = self.width - 1;
+            self.remaining_x = self.width;
 
-            self.iter.nth(self.row_skip)
+            self.iter = RawDataSlice::new(self.row_data()).into_iter();
+            if self.x > 0 && self.index_mask == 0 {
+                self.iter.nth(self.x - 1);
+            }
         }
-        .map(|c| c.into())
+
+        let column = (self.width - self.remaining_x) as usize;
+        self.remaining_x -= 1;
+
+        let color = if self.index_mask == 0 {
+            self.iter.next()
+        } else {
+            RawDataSlice::<C::Raw, BO>::new(self.row_data())
+                .into_iter()
+                .nth((self.x + column) ^ self.index_mask)
+        };
+
+        if self.remaining_x == 0 {
+            self.y += 1;
+        }
+
+        color.map(|c| c.into())
     }
 }
 
@@ -337,7 +536,7 @@ mod tests {
     use crate::{
         draw_target::DrawTarget,
         geometry::Point,
-        image::Image,
+        image::{Image, ImageDrawableExt},
         iterator::PixelIteratorExt,
         mock_display::{ColorMapping, MockDisplay},
         pixelcolor::{raw::RawU32, *},
@@ -477,6 +676,132 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bpp1_lsb_first() {
+        let data = [
+            0b0100_1101,
+            0b1, //
+            0b1111_0000,
+            0b0, //
+        ];
+        let image_data = ImageRaw::<BinaryColor>::new(&data, 9).with_bit_order(BitOrder::LsbFirst);
+
+        assert_pattern(
+            image_data,
+            &[
+                "#.##..#.#", //
+                "....####.", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bpp2_lsb_first() {
+        let data = [
+            0b00_01_10_11,
+            0b11, //
+            0b11_10_01_00,
+            0b00, //
+        ];
+        let image_data = ImageRaw::<Gray2>::new(&data, 5).with_bit_order(BitOrder::LsbFirst);
+
+        assert_pattern(
+            image_data,
+            &[
+                "32103", //
+                "01230", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bpp4_lsb_first() {
+        let data = [
+            0x21, 0x03, //
+            0x54, 0x06, //
+        ];
+        let image_data = ImageRaw::<Gray4>::new(&data, 3).with_bit_order(BitOrder::LsbFirst);
+
+        assert_pattern(
+            image_data,
+            &[
+                "123", //
+                "456", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bit_order_is_ignored_for_bpp8() {
+        let data = [0x11, 0x33];
+        let image_data = ImageRaw::<Gray8>::new(&data, 2).with_bit_order(BitOrder::LsbFirst);
+
+        assert_pattern(image_data, &["13"]);
+    }
+
+    #[test]
+    fn stride() {
+        let data = [
+            0x11, 0x22, 0xFF, 0xFF, //
+            0x33, 0x44, 0xFF, 0xFF, //
+            0x55, 0x66, //
+        ];
+        let image_data = ImageRaw::<Gray8>::new(&data, 2).with_stride(4);
+
+        assert_eq!(image_data.size(), Size::new(2, 3));
+        assert_pattern(
+            image_data,
+            &[
+                "12", //
+                "34", //
+                "56", //
+            ],
+        );
+    }
+
+    #[test]
+    fn stride_incomplete_last_row() {
+        let data = [
+            0x11, 0x22, 0xFF, //
+            0x33, //
+        ];
+        let image_data = ImageRaw::<Gray8>::new(&data, 2).with_stride(3);
+
+        assert_eq!(image_data.size(), Size::new(2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "stride must be at least as long as a single row")]
+    fn stride_too_small() {
+        ImageRaw::<Rgb565>::new(&[0; 16], 2).with_stride(3);
+    }
+
+    #[test]
+    fn sub_image_with_stride_and_lsb_first() {
+        let data = [
+            0x10, 0x32, 0x54, 0xFF, //
+            0x98, 0xBA, 0xDC, 0xFF, //
+        ];
+        let image_data = ImageRaw::<Gray4>::new(&data, 6)
+            .with_stride(4)
+            .with_bit_order(BitOrder::LsbFirst);
+
+        let sub_image = image_data.sub_image(&Rectangle::new(Point::new(1, 0), Size::new(4, 2)));
+
+        let mut display = MockDisplay::new();
+        Image::new(&sub_image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "1234", //
+            "9ABC", //
+        ]);
+
+        assert_eq!(image_data.pixel(Point::new(4, 1)), Some(Gray4::new(0xC)));
+        assert_eq!(image_data.pixel(Point::new(6, 1)), None);
+    }
+
     #[test]
     fn bpp8_1() {
         let data = [
@@ -673,4 +998,39 @@ mod tests {
         let data = [0u8; 4];
         assert_eq!(ImageRaw::<BinaryColor>::new(&data, 12).size().height, 2);
     }
+
+    #[test]
+    fn pixel() {
+        let data = [
+            0b1010_0000,
+            0b0000_0000, //
+            0b0100_0000,
+            0b0100_0000, //
+        ];
+        let image = ImageRaw::<BinaryColor>::new(&data, 10);
+
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(9, 1)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(8, 1)), Some(BinaryColor::Off));
+
+        assert_eq!(image.pixel(Point::new(10, 0)), None);
+        assert_eq!(image.pixel(Point::new(0, 2)), None);
+        assert_eq!(image.pixel(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn pixel_rgb() {
+        let data = [
+            0x12, 0x34, 0x56, 0x78, //
+            0x9A, 0xBC, 0xDE, 0xF0, //
+        ];
+        let image = ImageRawLE::<Rgb565>::new(&data, 2);
+
+        assert_eq!(
+            image.pixel(Point::new(1, 1)),
+            Some(raw::RawU16::new(0xF0DE).into())
+        );
+    }
 }
@@ -0,0 +1,463 @@
+use core::{iter::repeat, marker::PhantomData};
+
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, OriginDimensions, Size},
+    image::{image_raw::bytes_per_row, ImageDrawable, ImageRaw},
+    iterator::raw::RawDataSlice,
+    pixelcolor::{
+        raw::{BigEndian, ByteOrder, RawData},
+        PixelColor,
+    },
+    primitives::Rectangle,
+};
+
+/// A run-length encoded image.
+///
+/// `ImageRle` stores images as a sequence of runs, which makes it a good fit for large images
+/// with mostly flat areas, like splash screens or backgrounds. The runs are decoded on the fly
+/// while the image is drawn and no additional memory is required.
+///
+/// Each run consists of a count byte, which specifies the number of consecutive pixels with the
+/// same color, followed by the raw color value of these pixels. The raw value is stored in
+/// `(C::Raw::BITS_PER_PIXEL + 7) / 8` bytes, using the byte order `BO` for multibyte values and
+/// the least significant bits of a single byte for color types with less than 8 bits per pixel.
+/// This is the same format that is returned by the [`ToBytes`] methods. Runs never continue across
+/// the end of a row and runs with a count of zero are ignored.
+///
+/// Because the runs don't contain any information about the image height, the height is
+/// calculated by adding up the lengths of all runs when a new image is created. A trailing
+/// partial row will be ignored.
+///
+/// Image data in this format can be generated by using [`RleEncoder`], for example in a build
+/// script or in a small tool that converts images to include them in the firmware.
+///
+/// To draw an `ImageRle` object it needs to be wrapped in an [`Image`] object.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{Image, ImageRle},
+///     pixelcolor::Gray8,
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<Gray8>::new();
+///
+/// /// 8 x 3 pixel image with 8 bits per pixel.
+/// #[rustfmt::skip]
+/// const DATA: &[u8] = &[
+///     8, 0x00,
+///     3, 0x00, 2, 0xFF, 3, 0x00,
+///     8, 0x00,
+/// ];
+///
+/// let image = ImageRle::<Gray8>::new(DATA, 8);
+/// assert_eq!(image.size(), Size::new(8, 3));
+///
+/// Image::new(&image, Point::zero()).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`ToBytes`]: ../pixelcolor/raw/trait.ToBytes.html
+/// [`RleEncoder`]: struct.RleEncoder.html
+/// [`Image`]: struct.Image.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ImageRle<'a, C, BO = BigEndian>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    /// Encoded image data
+    data: &'a [u8],
+
+    /// Image size in pixels
+    size: Size,
+
+    pixel_type: PhantomData<C>,
+    byte_order: PhantomData<BO>,
+}
+
+impl<'a, C, BO> ImageRle<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    /// Creates a new run-length encoded image.
+    ///
+    /// Only the width of the image needs to be specified. The height of the image will be
+    /// calculated based on the total length of all runs in the image data.
+    pub fn new(data: &'a [u8], width: u32) -> Self {
+        let height = if width > 0 {
+            let pixels: usize = runs::<C>(data).map(|run| usize::from(run[0])).sum();
+
+            pixels / width as usize
+        } else {
+            0
+        };
+
+        Self {
+            data,
+            size: Size::new(width, height as u32),
+            pixel_type: PhantomData,
+            byte_order: PhantomData,
+        }
+    }
+}
+
+/// Returns an iterator over the runs in the encoded data.
+///
+/// Each item contains the count byte followed by the raw color value.
+fn runs<C>(data: &[u8]) -> impl Iterator<Item = &[u8]>
+where
+    C: PixelColor,
+{
+    data.chunks_exact(1 + bytes_per_row(1, C::Raw::BITS_PER_PIXEL))
+}
+
+/// Decodes the raw color value of a run.
+fn decode<'a, C, BO>(value: &'a [u8]) -> Option<C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    if C::Raw::BITS_PER_PIXEL < 8 {
+        Some(C::Raw::from_u32(u32::from(value[0])).into())
+    } else {
+        RawDataSlice::<C::Raw, BO>::new(value)
+            .into_iter()
+            .next()
+            .map(Into::into)
+    }
+}
+
+impl<'a, C, BO> ImageDrawable for ImageRle<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let area = self.bounding_box();
+
+        let colors = runs::<C>(self.data).flat_map(|run| {
+            let count = usize::from(run[0]);
+
+            decode::<C, BO>(&run[1..])
+                .into_iter()
+                .flat_map(move |color| repeat(color).take(count))
+        });
+
+        target.fill_contiguous(&area, colors)
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        // Don't draw anything if `area` is zero sized or partially outside the image.
+        if area.is_zero_sized()
+            || area.top_left.x < 0
+            || area.top_left.y < 0
+            || area.top_left.x as u32 + area.size.width > self.size.width
+            || area.top_left.y as u32 + area.size.height > self.size.height
+        {
+            return Ok(());
+        }
+
+        // The runs need to be decoded from the start of the image data, because the image doesn't
+        // contain any information about where each row starts.
+        self.draw(&mut target.translated(-area.top_left).clipped(area))
+    }
+}
+
+impl<C, BO> OriginDimensions for ImageRle<'_, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Run-length encoder.
+///
+/// `RleEncoder` converts the pixel data of an [`ImageRaw`] into the format used by [`ImageRle`].
+/// The encoder is an iterator over the bytes of the encoded data, which can, for example, be
+/// collected into a `Vec` in a build script and written to a file:
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{ImageRaw, ImageRle, RleEncoder},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+/// };
+///
+/// #[rustfmt::skip]
+/// const RAW: &[u8] = &[
+///     0b11110000, 0b00001111,
+///     0b11111111, 0b11111111,
+/// ];
+/// let raw = ImageRaw::<BinaryColor>::new(RAW, 16);
+///
+/// let data: Vec<u8> = RleEncoder::new(&raw).collect();
+/// assert_eq!(data, [4, 1, 8, 0, 4, 1, 16, 1]);
+///
+/// let image = ImageRle::<BinaryColor>::new(&data, raw.size().width);
+/// assert_eq!(image.size(), raw.size());
+/// ```
+///
+/// [`ImageRaw`]: struct.ImageRaw.html
+/// [`ImageRle`]: struct.ImageRle.html
+#[derive(Debug)]
+pub struct RleEncoder<'a, C, BO = BigEndian>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    image: &'a ImageRaw<'a, C, BO>,
+
+    /// Position of the next pixel that hasn't been encoded.
+    x: u32,
+    y: u32,
+
+    /// Encoded bytes of the current run.
+    run: [u8; 5],
+    run_length: usize,
+    run_index: usize,
+}
+
+impl<'a, C, BO> RleEncoder<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    /// Creates a new encoder.
+    pub fn new(image: &'a ImageRaw<'a, C, BO>) -> Self {
+        Self {
+            image,
+            x: 0,
+            y: 0,
+            run: [0; 5],
+            run_length: 0,
+            run_index: 0,
+        }
+    }
+
+    /// Returns the raw value of a pixel in the current row.
+    fn value(&self, x: u32) -> [u8; 4] {
+        let bits_per_pixel = C::Raw::BITS_PER_PIXEL;
+        let row = self.image.row_data(self.y);
+        let mut value = [0; 4];
+
+        if bits_per_pixel < 8 {
+            let pixels_per_byte = 8 / bits_per_pixel;
+            let index = x as usize ^ self.image.index_mask();
+            let shift = 8 - bits_per_pixel * (index % pixels_per_byte + 1);
+            let mask = (1u16 << bits_per_pixel) as u8 - 1;
+
+            value[0] = (row[index / pixels_per_byte] >> shift) & mask;
+        } else {
+            let bytes = bits_per_pixel / 8;
+            let start = x as usize * bytes;
+
+            value[..bytes].copy_from_slice(&row[start..start + bytes]);
+        }
+
+        value
+    }
+}
+
+impl<'a, C, BO> Iterator for RleEncoder<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.run_index == self.run_length {
+            let size = self.image.size();
+            if self.y >= size.height || size.width == 0 {
+                return None;
+            }
+
+            let value = self.value(self.x);
+            let mut count = 1;
+            while count < u32::from(u8::max_value())
+                && self.x + count < size.width
+                && self.value(self.x + count) == value
+            {
+                count += 1;
+            }
+
+            let value_length = bytes_per_row(1, C::Raw::BITS_PER_PIXEL);
+            self.run[0] = count as u8;
+            self.run[1..=value_length].copy_from_slice(&value[..value_length]);
+            self.run_length = 1 + value_length;
+            self.run_index = 0;
+
+            self.x += count;
+            if self.x == size.width {
+                self.x = 0;
+                self.y += 1;
+            }
+        }
+
+        let byte = self.run[self.run_index];
+        self.run_index += 1;
+
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Point,
+        image::{BitOrder, Image, ImageDrawableExt},
+        mock_display::MockDisplay,
+        pixelcolor::{
+            raw::{LittleEndian, ToBytes},
+            BinaryColor, Gray2, Gray4, Rgb565, Rgb888, RgbColor,
+        },
+        Drawable,
+    };
+    use arrayvec::ArrayVec;
+
+    /// Asserts that the encoded data matches `expected` and that the decoded image is identical
+    /// to the original image.
+    fn assert_round_trip<C, BO>(raw: ImageRaw<C, BO>, expected: &[u8])
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw> + crate::mock_display::ColorMapping,
+        BO: ByteOrder,
+        for<'a> RawDataSlice<'a, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+    {
+        let data: ArrayVec<[u8; 64]> = RleEncoder::new(&raw).collect();
+        assert_eq!(data.as_slice(), expected);
+
+        let rle = ImageRle::<C, BO>::new(&data, raw.size().width);
+        assert_eq!(rle.size(), raw.size());
+
+        let mut expected = MockDisplay::new();
+        Image::new(&raw, Point::zero()).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&rle, Point::zero()).draw(&mut display).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bpp1() {
+        assert_round_trip(
+            ImageRaw::<BinaryColor>::new(&[0b1110_0000, 0b0000_0000, 0b0001_0000], 4),
+            &[3, 1, 1, 0, 4, 0, 3, 0, 1, 1],
+        );
+    }
+
+    #[test]
+    fn bpp2_lsb_first() {
+        assert_round_trip(
+            ImageRaw::<Gray2>::new(&[0b11_10_01_01, 0b00_00_00_00], 4)
+                .with_bit_order(BitOrder::LsbFirst),
+            &[2, 1, 1, 2, 1, 3, 4, 0],
+        );
+    }
+
+    #[test]
+    fn bpp4_stride() {
+        assert_round_trip(
+            ImageRaw::<Gray4>::new(&[0x12, 0x20, 0xFF, 0x33, 0x30], 3).with_stride(3),
+            &[1, 0x1, 2, 0x2, 3, 0x3],
+        );
+    }
+
+    #[test]
+    fn bpp16_little_endian() {
+        let red = Rgb565::RED.to_le_bytes();
+        let green = Rgb565::GREEN.to_le_bytes();
+
+        let mut raw = [0u8; 6];
+        raw[0..2].copy_from_slice(&red);
+        raw[2..4].copy_from_slice(&red);
+        raw[4..6].copy_from_slice(&green);
+
+        assert_round_trip(
+            ImageRaw::<Rgb565, LittleEndian>::new(&raw, 3),
+            &[2, red[0], red[1], 1, green[0], green[1]],
+        );
+    }
+
+    #[test]
+    fn bpp24() {
+        assert_round_trip(
+            ImageRaw::<Rgb888>::new(&[0x00, 0x00, 0xFF, 0x00, 0x00, 0xFF], 1),
+            &[1, 0x00, 0x00, 0xFF, 1, 0x00, 0x00, 0xFF],
+        );
+    }
+
+    #[test]
+    fn long_run() {
+        let raw = [0xFF; 40];
+        let image = ImageRaw::<BinaryColor>::new(&raw, 320);
+
+        let data: ArrayVec<[u8; 8]> = RleEncoder::new(&image).collect();
+        assert_eq!(data.as_slice(), &[255, 1, 65, 1][..]);
+    }
+
+    #[test]
+    fn height() {
+        let image = ImageRle::<Gray4>::new(&[3, 0x1, 2, 0x2, 0, 0x5, 4, 0x3, 2], 3);
+        assert_eq!(image.size(), Size::new(3, 3));
+
+        let image = ImageRle::<Gray4>::new(&[3, 0x1], 0);
+        assert_eq!(image.size(), Size::zero());
+    }
+
+    #[test]
+    fn zero_count() {
+        let image = ImageRle::<Gray4>::new(&[1, 0x1, 0, 0x2, 2, 0x3], 3);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&["133"]);
+    }
+
+    #[test]
+    fn sub_image() {
+        #[rustfmt::skip]
+        let data = [
+            4, 0x1,
+            1, 0x2, 2, 0x3, 1, 0x4,
+            4, 0x5,
+        ];
+        let image = ImageRle::<Gray4>::new(&data, 4);
+
+        let mut display = MockDisplay::new();
+        Image::new(
+            &image.sub_image(&Rectangle::new(Point::new(1, 1), Size::new(3, 2))),
+            Point::new(1, 1),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            " 334", //
+            " 555", //
+        ]);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Size},
+    image::ImageDrawable,
+    iterator::ContiguousIteratorExt,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Image with a transparent color.
+///
+/// All pixels in the parent [`ImageDrawable`] which have the transparent color are skipped when
+/// the image is drawn. This makes it possible to draw sprites with irregular outlines on top of a
+/// background.
+///
+/// To create an image with transparency call the [`with_transparent_color`] method on the parent
+/// [`ImageDrawable`].
+///
+/// Because the remaining pixels don't form a contiguous area, they are drawn by using
+/// [`draw_iter`] instead of [`fill_contiguous`], which can be slower on some draw targets.
+///
+/// [`ImageDrawable`]: trait.ImageDrawable.html
+/// [`with_transparent_color`]: trait.ImageDrawableExt.html#tymethod.with_transparent_color
+/// [`draw_iter`]: ../draw_target/trait.DrawTarget.html#tymethod.draw_iter
+/// [`fill_contiguous`]: ../draw_target/trait.DrawTarget.html#method.fill_contiguous
+#[derive(Debug)]
+pub struct ImageWithTransparency<'a, T>
+where
+    T: ImageDrawable,
+{
+    parent: &'a T,
+    transparent_color: T::Color,
+}
+
+impl<'a, T> ImageWithTransparency<'a, T>
+where
+    T: ImageDrawable,
+{
+    pub(super) fn new(parent: &'a T, transparent_color: T::Color) -> Self {
+        Self {
+            parent,
+            transparent_color,
+        }
+    }
+}
+
+impl<T> OriginDimensions for ImageWithTransparency<'_, T>
+where
+    T: ImageDrawable,
+{
+    fn size(&self) -> Size {
+        self.parent.size()
+    }
+}
+
+impl<'a, T> ImageDrawable for ImageWithTransparency<'a, T>
+where
+    T: ImageDrawable,
+{
+    type Color = T::Color;
+
+    fn draw<DT>(&self, target: &mut DT) -> Result<(), DT::Error>
+    where
+        DT: DrawTarget<Color = Self::Color>,
+    {
+        self.parent.draw(&mut TransparentDrawTarget::new(
+            target,
+            self.transparent_color,
+        ))
+    }
+
+    fn draw_sub_image<DT>(&self, target: &mut DT, area: &Rectangle) -> Result<(), DT::Error>
+    where
+        DT: DrawTarget<Color = Self::Color>,
+    {
+        self.parent.draw_sub_image(
+            &mut TransparentDrawTarget::new(target, self.transparent_color),
+            area,
+        )
+    }
+}
+
+/// Draw target which skips all pixels with the transparent color.
+struct TransparentDrawTarget<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    transparent_color: T::Color,
+}
+
+impl<'a, T> TransparentDrawTarget<'a, T>
+where
+    T: DrawTarget,
+{
+    fn new(parent: &'a mut T, transparent_color: T::Color) -> Self {
+        Self {
+            parent,
+            transparent_color,
+        }
+    }
+}
+
+impl<T> DrawTarget for TransparentDrawTarget<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let transparent_color = self.transparent_color;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(_, color)| *color != transparent_color),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(colors.into_iter().into_pixels(area))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        if color == self.transparent_color {
+            Ok(())
+        } else {
+            self.parent.fill_solid(area, color)
+        }
+    }
+}
+
+impl<T> Dimensions for TransparentDrawTarget<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Point,
+        image::{Image, ImageDrawableExt, ImageRaw},
+        mock_display::MockDisplay,
+        pixelcolor::{Gray4, GrayColor},
+        Drawable,
+    };
+
+    #[rustfmt::skip]
+    const DATA: &[u8] = &[
+        0x00, 0x10, //
+        0x32, 0x00, //
+        0x04, 0x55, //
+    ];
+
+    fn background() -> MockDisplay<Gray4> {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(5, 4)),
+                Gray4::new(0xF),
+            )
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn draw() {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+
+        let mut display = background();
+        Image::new(
+            &image.with_transparent_color(Gray4::BLACK),
+            Point::new(1, 1),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "FFFFF", //
+            "FFF1F", //
+            "F32FF", //
+            "FF455", //
+        ]);
+    }
+
+    #[test]
+    fn draw_sub_image() {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+        let transparent = image.with_transparent_color(Gray4::new(0x5));
+
+        let mut display = background();
+        Image::new(
+            &transparent.sub_image(&Rectangle::new(Point::new(1, 1), Size::new(3, 2))),
+            Point::zero(),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "200FF", //
+            "4FFFF", //
+            "FFFFF", //
+            "FFFFF", //
+        ]);
+    }
+
+    #[test]
+    fn size() {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+
+        assert_eq!(
+            image.with_transparent_color(Gray4::BLACK).size(),
+            Size::new(4, 3)
+        );
+    }
+}
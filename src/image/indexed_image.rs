@@ -0,0 +1,328 @@
+use core::marker::PhantomData;
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Point, Size},
+    image::{image_raw::bytes_per_row, ImageDrawable},
+    iterator::raw::RawDataSlice,
+    pixelcolor::{
+        raw::{BigEndian, RawData},
+        PixelColor,
+    },
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// An image which stores palette indices instead of colors.
+///
+/// Each pixel in an `IndexedImage` is an index into a palette of colors. The storage format of the
+/// indices is determined by the raw data type `R`, which can be [`RawU1`], [`RawU2`], [`RawU4`] or
+/// [`RawU8`]. The indices are stored in the same format as the pixels in an [`ImageRaw`]: the
+/// leftmost pixel is stored in the most significant bits and the start of each row is aligned to
+/// the next whole byte.
+///
+/// Compared to an [`ImageRaw`] that uses a color type with more bits per pixel, indexed images can
+/// significantly reduce the size of icons which only use a few different colors. Because the
+/// palette isn't part of the image data it can be changed at runtime by using [`set_palette`],
+/// for example to draw the same icon in different colors.
+///
+/// Pixels with an index that is outside the palette are treated as transparent and aren't drawn.
+///
+/// To draw an `IndexedImage` object it needs to be wrapped in an [`Image`] object.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{Image, IndexedImage},
+///     pixelcolor::{raw::RawU2, Rgb565},
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<Rgb565>::new();
+///
+/// /// 4 x 4 pixel icon with 2 bits per pixel.
+/// #[rustfmt::skip]
+/// const ICON: &[u8] = &[
+///     0b00_01_01_00,
+///     0b01_10_10_01,
+///     0b01_10_10_01,
+///     0b00_01_01_00,
+/// ];
+///
+/// const ACTIVE: &[Rgb565] = &[Rgb565::BLACK, Rgb565::WHITE, Rgb565::GREEN];
+/// const DISABLED: &[Rgb565] = &[Rgb565::BLACK, Rgb565::CSS_GRAY, Rgb565::CSS_DARK_GRAY];
+///
+/// let mut icon = IndexedImage::<RawU2, _>::new(ICON, 4, ACTIVE);
+/// Image::new(&icon, Point::new(10, 10)).draw(&mut display)?;
+///
+/// // Draw the same icon with a different palette.
+/// icon.set_palette(DISABLED);
+/// Image::new(&icon, Point::new(20, 10)).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`RawU1`]: ../pixelcolor/raw/struct.RawU1.html
+/// [`RawU2`]: ../pixelcolor/raw/struct.RawU2.html
+/// [`RawU4`]: ../pixelcolor/raw/struct.RawU4.html
+/// [`RawU8`]: ../pixelcolor/raw/struct.RawU8.html
+/// [`ImageRaw`]: struct.ImageRaw.html
+/// [`Image`]: struct.Image.html
+/// [`set_palette`]: #method.set_palette
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct IndexedImage<'a, R, C> {
+    /// Image data, packed as dictated by raw data type `R`
+    data: &'a [u8],
+
+    /// Image size in pixels
+    size: Size,
+
+    palette: &'a [C],
+
+    raw_type: PhantomData<R>,
+}
+
+impl<'a, R, C> IndexedImage<'a, R, C>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+{
+    /// Creates a new indexed image.
+    ///
+    /// Only the width of the image needs to be specified. The height of the image will be
+    /// calculated based on the length of the given image data. If the length of the image data
+    /// isn't an integer multiple of the data length for a single row the last partial row will
+    /// be ignored.
+    pub fn new(data: &'a [u8], width: u32, palette: &'a [C]) -> Self {
+        let height = if width > 0 {
+            data.len() / bytes_per_row(width, R::BITS_PER_PIXEL)
+        } else {
+            0
+        };
+
+        Self {
+            data,
+            size: Size::new(width, height as u32),
+            palette,
+            raw_type: PhantomData,
+        }
+    }
+
+    /// Returns the palette.
+    pub fn palette(&self) -> &'a [C] {
+        self.palette
+    }
+
+    /// Sets the palette.
+    pub fn set_palette(&mut self, palette: &'a [C]) {
+        self.palette = palette;
+    }
+
+    /// Returns `true` if every possible index refers to a color in the palette.
+    fn is_opaque(&self) -> bool {
+        self.palette.len() >= 1 << R::BITS_PER_PIXEL
+    }
+}
+
+impl<'a, R, C> IndexedImage<'a, R, C>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+    RawDataSlice<'a, R, BigEndian>: IntoIterator<Item = R>,
+{
+    /// Returns the color of the pixel at the given point.
+    ///
+    /// `None` is returned if the point is outside the image or if the index is outside the palette.
+    fn pixel(&self, point: Point) -> Option<C> {
+        if point.x < 0
+            || point.y < 0
+            || point.x as u32 >= self.size.width
+            || point.y as u32 >= self.size.height
+        {
+            return None;
+        }
+
+        let row_start = point.y as usize * bytes_per_row(self.size.width, R::BITS_PER_PIXEL);
+
+        RawDataSlice::<R, BigEndian>::new(&self.data[row_start..])
+            .into_iter()
+            .nth(point.x as usize)
+            .and_then(|index| self.palette.get(usize::from(index.into_inner())))
+            .copied()
+    }
+}
+
+impl<'a, R, C> ImageDrawable for IndexedImage<'a, R, C>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+    RawDataSlice<'a, R, BigEndian>: IntoIterator<Item = R>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_sub_image(target, &self.bounding_box())
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        // Don't draw anything if `area` is zero sized or partially outside the image.
+        if area.is_zero_sized()
+            || area.top_left.x < 0
+            || area.top_left.y < 0
+            || area.top_left.x as u32 + area.size.width > self.size.width
+            || area.top_left.y as u32 + area.size.height > self.size.height
+        {
+            return Ok(());
+        }
+
+        if self.is_opaque() {
+            target.fill_contiguous(
+                &Rectangle::new(Point::zero(), area.size),
+                area.points().filter_map(|point| self.pixel(point)),
+            )
+        } else {
+            target.draw_iter(area.points().filter_map(|point| {
+                self.pixel(point)
+                    .map(|color| Pixel(point - area.top_left, color))
+            }))
+        }
+    }
+}
+
+impl<R, C> OriginDimensions for IndexedImage<'_, R, C> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        image::{Image, ImageDrawableExt},
+        mock_display::MockDisplay,
+        pixelcolor::{
+            raw::{RawU1, RawU2, RawU4, RawU8},
+            BinaryColor, Gray4, Rgb565, RgbColor,
+        },
+        Drawable,
+    };
+
+    const PALETTE: &[Gray4] = &[
+        Gray4::new(0x1),
+        Gray4::new(0x2),
+        Gray4::new(0x3),
+        Gray4::new(0x4),
+    ];
+
+    #[test]
+    fn bpp1() {
+        let data = [0b1010_0000, 0b0111_0000];
+        let image = IndexedImage::<RawU1, _>::new(&data, 4, &[Rgb565::BLUE, Rgb565::RED]);
+        assert_eq!(image.size(), Size::new(4, 2));
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "RBRB", //
+            "BRRR", //
+        ]);
+    }
+
+    #[test]
+    fn bpp2() {
+        let data = [0b00_01_10_11, 0b11_00_00_00, 0b11_10_01_00, 0b00_00_00_00];
+        let image = IndexedImage::<RawU2, _>::new(&data, 5, PALETTE);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "12344", //
+            "43211", //
+        ]);
+    }
+
+    #[test]
+    fn bpp4_transparent() {
+        let data = [0x01, 0x2F, 0xE3, 0x10];
+        let image = IndexedImage::<RawU4, _>::new(&data, 4, PALETTE);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "123 ", //
+            " 421", //
+        ]);
+    }
+
+    #[test]
+    fn bpp8() {
+        let data = [0x00, 0x02, 0x01];
+        let image = IndexedImage::<RawU8, _>::new(&data, 3, &[BinaryColor::On, BinaryColor::Off]);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&["# ."]);
+    }
+
+    #[test]
+    fn set_palette() {
+        let data = [0b1100_0000];
+        let mut image =
+            IndexedImage::<RawU1, _>::new(&data, 4, &[BinaryColor::Off, BinaryColor::On]);
+
+        image.set_palette(&[BinaryColor::On, BinaryColor::Off]);
+        assert_eq!(image.palette(), &[BinaryColor::On, BinaryColor::Off]);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&["..##"]);
+    }
+
+    #[test]
+    fn sub_image() {
+        let data = [0x01, 0x23, 0x32, 0x10, 0x00, 0x00];
+        let image = IndexedImage::<RawU4, _>::new(&data, 4, PALETTE);
+
+        let mut display = MockDisplay::new();
+        Image::new(
+            &image.sub_image(&Rectangle::new(Point::new(1, 1), Size::new(3, 2))),
+            Point::new(1, 0),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            " 321", //
+            " 111", //
+        ]);
+    }
+
+    #[test]
+    fn zero_width() {
+        let image = IndexedImage::<RawU4, _>::new(&[0; 4], 0, PALETTE);
+
+        assert_eq!(image.size(), Size::zero());
+    }
+}
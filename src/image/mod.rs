@@ -99,18 +99,30 @@
 //! [`SubImage`]: struct.SubImage.html
 //! [`prelude`]: ../prelude/index.html
 
+mod animated_sprite;
 mod image_drawable_ext;
 mod image_raw;
+mod image_rle;
+mod image_with_transparency;
+mod indexed_image;
+mod nine_slice;
 mod sub_image;
+mod tiled_image;
 
+pub use animated_sprite::AnimatedSprite;
 pub use embedded_graphics_core::image::ImageDrawable;
 pub use image_drawable_ext::ImageDrawableExt;
-pub use image_raw::{ImageRaw, ImageRawBE, ImageRawLE};
+pub use image_raw::{BitOrder, ImageRaw, ImageRawBE, ImageRawLE};
+pub use image_rle::{ImageRle, RleEncoder};
+pub use image_with_transparency::ImageWithTransparency;
+pub use indexed_image::IndexedImage;
+pub use nine_slice::{NineSlice, NineSliceMode};
 pub use sub_image::SubImage;
+pub use tiled_image::TiledImage;
 
 use crate::{
-    draw_target::{DrawTarget, DrawTargetExt},
-    geometry::{Dimensions, OriginDimensions, Point},
+    draw_target::{DrawTarget, DrawTargetExt, Scaled, Scaling},
+    geometry::{Dimensions, OriginDimensions, Point, Size},
     primitives::Rectangle,
     transform::Transform,
     Drawable,
@@ -135,6 +147,7 @@ use core::fmt::Debug;
 pub struct Image<'a, T> {
     image_drawable: &'a T,
     offset: Point,
+    scaling: Scaling,
 }
 
 impl<'a, T> Image<'a, T>
@@ -146,6 +159,60 @@ where
         Self {
             image_drawable,
             offset: position,
+            scaling: Scaling::IDENTITY,
+        }
+    }
+
+    /// Scales the image by `num / denom`.
+    ///
+    /// The image is scaled by using nearest neighbor sampling, which keeps the hard edges of
+    /// pixel art and icons intact. If `num` is an integer multiple of `denom` every image pixel is
+    /// drawn as a block of `num / denom` by `num / denom` display pixels. Scaling factors less
+    /// than one skip image pixels, which works best if `denom` is an integer multiple of `num`.
+    ///
+    /// The scaling factor replaces any previously set factor and doesn't affect the position of
+    /// the image, which is always the top left corner of the scaled image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num` or `denom` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::{Image, ImageRaw},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::<BinaryColor>::new();
+    ///
+    /// // 8x8 pixel icon
+    /// let icon: ImageRaw<BinaryColor> = ImageRaw::new(&[0x3C; 8], 8);
+    ///
+    /// // Draw the icon at twice its size for high density displays.
+    /// let image = Image::new(&icon, Point::new(10, 10)).scaled(2, 1);
+    /// assert_eq!(
+    ///     image.bounding_box(),
+    ///     Rectangle::new(Point::new(10, 10), Size::new(16, 16))
+    /// );
+    /// image.draw(&mut display)?;
+    ///
+    /// // Draw the icon at half its size.
+    /// let image = Image::new(&icon, Point::new(40, 10)).scaled(1, 2);
+    /// assert_eq!(
+    ///     image.bounding_box(),
+    ///     Rectangle::new(Point::new(40, 10), Size::new(4, 4))
+    /// );
+    /// image.draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn scaled(self, num: u32, denom: u32) -> Self {
+        Self {
+            scaling: Scaling::new(num, denom),
+            ..self
         }
     }
 }
@@ -181,6 +248,7 @@ impl<T> Transform for Image<'_, T> {
         Self {
             image_drawable: self.image_drawable,
             offset: self.offset + by,
+            scaling: self.scaling,
         }
     }
 
@@ -227,8 +295,13 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        self.image_drawable
-            .draw(&mut display.translated(self.offset))
+        let mut translated = display.translated(self.offset);
+
+        self.image_drawable.draw(&mut Scaled::with_scaling(
+            &mut translated,
+            self.scaling,
+            self.scaling,
+        ))
     }
 }
 
@@ -237,7 +310,15 @@ where
     T: OriginDimensions,
 {
     fn bounding_box(&self) -> Rectangle {
-        self.image_drawable.bounding_box().translate(self.offset)
+        let size = self.image_drawable.size();
+
+        Rectangle::new(
+            self.offset,
+            Size::new(
+                self.scaling.length(size.width),
+                self.scaling.length(size.height),
+            ),
+        )
     }
 }
 
@@ -288,4 +369,87 @@ mod tests {
             " .#.#", //
         ]);
     }
+
+    #[test]
+    fn scaled_up() {
+        let image_raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0b1000_0000, 0b0100_0000], 2);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image_raw, Point::new(1, 0))
+            .scaled(3, 1)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " ###...", //
+            " ###...", //
+            " ###...", //
+            " ...###", //
+            " ...###", //
+            " ...###", //
+        ]);
+    }
+
+    #[test]
+    fn scaled_fractional() {
+        let image_raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0b1010_0000, 0b0100_0000], 3);
+
+        let image = Image::new(&image_raw, Point::zero()).scaled(3, 2);
+        assert_eq!(
+            image.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(5, 3))
+        );
+
+        let mut display = MockDisplay::new();
+        image.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "##.##", //
+            "##.##", //
+            "..#..", //
+        ]);
+    }
+
+    #[test]
+    fn scaled_down() {
+        let image_raw: ImageRaw<BinaryColor> =
+            ImageRaw::new(&[0b1100_0000, 0b1100_0000, 0b0011_0000, 0b0011_0000], 4);
+
+        let image = Image::new(&image_raw, Point::new(1, 1)).scaled(1, 2);
+        assert_eq!(
+            image.bounding_box(),
+            Rectangle::new(Point::new(1, 1), Size::new(2, 2))
+        );
+
+        let mut display = MockDisplay::new();
+        image.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "   ", //
+            " #.", //
+            " .#", //
+        ]);
+    }
+
+    #[test]
+    fn scaled_translate() {
+        let image_raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0xAA, 0x55, 0xAA, 0x55], 4);
+
+        let image = Image::new(&image_raw, Point::zero())
+            .scaled(2, 1)
+            .translate(Point::new(1, 2));
+
+        assert_eq!(
+            image.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::new(8, 8))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn scaled_zero() {
+        let image_raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0xAA, 0x55, 0xAA, 0x55], 4);
+
+        Image::new(&image_raw, Point::zero()).scaled(0, 1);
+    }
 }
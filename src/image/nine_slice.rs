@@ -0,0 +1,385 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt, Scaled, Scaling},
+    geometry::{Dimensions, Point, Size},
+    image::{ImageDrawable, ImageDrawableExt, TiledImage},
+    primitives::Rectangle,
+    transform::Transform,
+    Drawable,
+};
+
+/// Nine-slice image.
+///
+/// A nine-slice image, also known as a 9-patch, is used to draw a source image into an area of
+/// arbitrary size without distorting the borders of the image. The source image is divided into
+/// nine slices by the `center` rectangle: the four corners are drawn unscaled, the top and bottom
+/// edges are resized horizontally, the left and right edges are resized vertically and the center
+/// slice is resized in both directions. This is commonly used to draw skinned buttons and panels
+/// with variable sizes from a single small image.
+///
+/// The [`NineSliceMode`] determines if the edges and the center are stretched or tiled to fill
+/// the area. If the area is smaller than the combined size of the borders the borders are cropped
+/// at the center of the area.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{ImageRaw, NineSlice, NineSliceMode},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<BinaryColor>::new();
+///
+/// /// 6 x 6 pixel button with rounded corners and a 2 pixel border.
+/// #[rustfmt::skip]
+/// const BUTTON: &[u8] = &[
+///     0b011110_00,
+///     0b100001_00,
+///     0b101101_00,
+///     0b101101_00,
+///     0b100001_00,
+///     0b011110_00,
+/// ];
+/// let button = ImageRaw::<BinaryColor>::new(BUTTON, 6);
+///
+/// // The 2 x 2 pixel center of the image is stretched to fill the area.
+/// let center = Rectangle::new(Point::new(2, 2), Size::new(2, 2));
+///
+/// NineSlice::new(
+///     &button,
+///     &center,
+///     &Rectangle::new(Point::new(5, 5), Size::new(40, 12)),
+/// )
+/// .draw(&mut display)?;
+///
+/// // Tile the edges and the center instead of stretching them.
+/// NineSlice::new(
+///     &button,
+///     &center,
+///     &Rectangle::new(Point::new(5, 20), Size::new(30, 10)),
+/// )
+/// .with_mode(NineSliceMode::Tile)
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`NineSliceMode`]: enum.NineSliceMode.html
+#[derive(Debug, Clone, Copy)]
+pub struct NineSlice<'a, T> {
+    image: &'a T,
+    center: Rectangle,
+    area: Rectangle,
+    mode: NineSliceMode,
+}
+
+impl<'a, T> NineSlice<'a, T>
+where
+    T: ImageDrawable,
+{
+    /// Creates a new nine-slice image.
+    ///
+    /// The `center` rectangle defines the center slice in the coordinate system of the source
+    /// image and will be limited to the bounding box of the image. The `area` is the area on the
+    /// draw target that is covered by the nine-slice image.
+    pub fn new(image: &'a T, center: &Rectangle, area: &Rectangle) -> Self {
+        Self {
+            image,
+            center: center.intersection(&image.bounding_box()),
+            area: *area,
+            mode: NineSliceMode::Stretch,
+        }
+    }
+
+    /// Sets the mode which is used to fill the edges and the center.
+    pub fn with_mode(self, mode: NineSliceMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Draws a single slice.
+    fn draw_slice<D>(
+        &self,
+        target: &mut D,
+        source: &Rectangle,
+        dest: &Rectangle,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = T::Color>,
+    {
+        let slice = self.image.sub_image(source);
+
+        if source.size == dest.size {
+            return slice.draw(&mut target.translated(dest.top_left));
+        }
+
+        match self.mode {
+            NineSliceMode::Stretch => slice.draw(&mut Scaled::with_scaling(
+                &mut target.translated(dest.top_left),
+                Scaling::new(dest.size.width, source.size.width),
+                Scaling::new(dest.size.height, source.size.height),
+            )),
+            NineSliceMode::Tile => TiledImage::new(&slice, dest).draw(target),
+        }
+    }
+}
+
+/// Nine-slice mode.
+///
+/// The mode determines how the edges and the center of a [`NineSlice`] image are resized.
+///
+/// [`NineSlice`]: struct.NineSlice.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NineSliceMode {
+    /// The slices are stretched by using nearest neighbor scaling.
+    Stretch,
+
+    /// The slices are repeated.
+    Tile,
+}
+
+impl Default for NineSliceMode {
+    fn default() -> Self {
+        NineSliceMode::Stretch
+    }
+}
+
+/// Source and destination range of a column or row of slices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    source_start: i32,
+    source_length: u32,
+    dest_start: i32,
+    dest_length: u32,
+}
+
+impl Span {
+    /// Splits the source and destination ranges into three spans.
+    ///
+    /// The borders are cropped at their inner edge if the destination range isn't large enough to
+    /// contain both borders.
+    fn split(
+        image_length: u32,
+        center_start: i32,
+        center_length: u32,
+        area_start: i32,
+        area_length: u32,
+    ) -> [Self; 3] {
+        let leading = (center_start as u32).min(area_length);
+        let trailing = image_length
+            .saturating_sub(center_start as u32 + center_length)
+            .min(area_length - leading);
+
+        [
+            Self {
+                source_start: 0,
+                source_length: leading,
+                dest_start: area_start,
+                dest_length: leading,
+            },
+            Self {
+                source_start: center_start,
+                source_length: center_length,
+                dest_start: area_start + leading as i32,
+                dest_length: area_length - leading - trailing,
+            },
+            Self {
+                source_start: (image_length - trailing) as i32,
+                source_length: trailing,
+                dest_start: area_start + (area_length - trailing) as i32,
+                dest_length: trailing,
+            },
+        ]
+    }
+}
+
+impl<T> Drawable for NineSlice<'_, T>
+where
+    T: ImageDrawable,
+{
+    type Color = T::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let size = self.image.size();
+
+        let columns = Span::split(
+            size.width,
+            self.center.top_left.x,
+            self.center.size.width,
+            self.area.top_left.x,
+            self.area.size.width,
+        );
+        let rows = Span::split(
+            size.height,
+            self.center.top_left.y,
+            self.center.size.height,
+            self.area.top_left.y,
+            self.area.size.height,
+        );
+
+        for row in rows.iter() {
+            for column in columns.iter() {
+                let source = Rectangle::new(
+                    Point::new(column.source_start, row.source_start),
+                    Size::new(column.source_length, row.source_length),
+                );
+                let dest = Rectangle::new(
+                    Point::new(column.dest_start, row.dest_start),
+                    Size::new(column.dest_length, row.dest_length),
+                );
+
+                // An empty center can't be resized to fill the area.
+                if !source.is_zero_sized() && !dest.is_zero_sized() {
+                    self.draw_slice(target, &source, &dest)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Dimensions for NineSlice<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<T> Transform for NineSlice<'_, T> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            image: self.image,
+            center: self.center,
+            area: self.area.translate(by),
+            mode: self.mode,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.area.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{image::ImageRaw, mock_display::MockDisplay, pixelcolor::Gray4};
+
+    #[rustfmt::skip]
+    const DATA: &[u8] = &[
+        0x12, 0x34,
+        0x56, 0x78,
+        0x9A, 0xBC,
+    ];
+
+    const CENTER: Rectangle = Rectangle::new(Point::new(1, 1), Size::new(2, 1));
+
+    fn draw(area: Rectangle, mode: NineSliceMode) -> MockDisplay<Gray4> {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+
+        let mut display = MockDisplay::new();
+        NineSlice::new(&image, &CENTER, &area)
+            .with_mode(mode)
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn unscaled() {
+        for &mode in &[NineSliceMode::Stretch, NineSliceMode::Tile] {
+            draw(Rectangle::new(Point::new(1, 0), Size::new(4, 3)), mode).assert_pattern(&[
+                " 1234", //
+                " 5678", //
+                " 9ABC", //
+            ]);
+        }
+    }
+
+    #[test]
+    fn stretch() {
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(7, 4)),
+            NineSliceMode::Stretch,
+        )
+        .assert_pattern(&[
+            "        ", //
+            " 1222334", //
+            " 5666778", //
+            " 5666778", //
+            " 9AAABBC", //
+        ]);
+    }
+
+    #[test]
+    fn tile() {
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(7, 4)),
+            NineSliceMode::Tile,
+        )
+        .assert_pattern(&[
+            "        ", //
+            " 1232324", //
+            " 5676768", //
+            " 5676768", //
+            " 9ABABAC", //
+        ]);
+    }
+
+    #[test]
+    fn shrink() {
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(3, 2)),
+            NineSliceMode::Stretch,
+        )
+        .assert_pattern(&[
+            "    ", //
+            " 124", //
+            " 9AC", //
+        ]);
+
+        draw(
+            Rectangle::new(Point::zero(), Size::new(2, 1)),
+            NineSliceMode::Tile,
+        )
+        .assert_pattern(&["14"]);
+    }
+
+    #[test]
+    fn center_outside_image() {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+        let center = Rectangle::new(Point::new(2, -1), Size::new(5, 3));
+
+        let mut display = MockDisplay::new();
+        NineSlice::new(
+            &image,
+            &center,
+            &Rectangle::new(Point::zero(), Size::new(5, 3)),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "12334", //
+            "56778", //
+            "9ABBC", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let image = ImageRaw::<Gray4>::new(DATA, 4);
+        let area = Rectangle::new(Point::new(1, 2), Size::new(10, 20));
+
+        let nine_slice = NineSlice::new(&image, &CENTER, &area).translate(Point::new(3, 4));
+
+        assert_eq!(nine_slice.bounding_box(), area.translate(Point::new(3, 4)));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, Point, Size},
+    image::ImageDrawable,
+    primitives::Rectangle,
+    transform::Transform,
+    Drawable,
+};
+
+/// Tiled image.
+///
+/// A `TiledImage` repeats an image horizontally and vertically to cover a rectangular area. This
+/// can be used to draw textured backgrounds or dithered shading patterns which are stored as
+/// small images.
+///
+/// By default the top left corner of the area is also the top left corner of the first tile. The
+/// tiles can be moved relative to the area by using [`with_offset`], for example to align the
+/// pattern in multiple areas to the same grid.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{ImageRaw, TiledImage},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<BinaryColor>::new();
+///
+/// /// 2 x 2 pixel checkerboard pattern.
+/// #[rustfmt::skip]
+/// const PATTERN: &[u8] = &[
+///     0b10_000000,
+///     0b01_000000,
+/// ];
+/// let pattern = ImageRaw::<BinaryColor>::new(PATTERN, 2);
+///
+/// let area = Rectangle::new(Point::new(5, 5), Size::new(40, 20));
+///
+/// TiledImage::new(&pattern, &area)
+///     .with_offset(Point::new(1, 0))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`with_offset`]: #method.with_offset
+#[derive(Debug, Clone, Copy)]
+pub struct TiledImage<'a, T> {
+    image: &'a T,
+    area: Rectangle,
+    offset: Point,
+}
+
+impl<'a, T> TiledImage<'a, T>
+where
+    T: ImageDrawable,
+{
+    /// Creates a new tiled image which covers the given area.
+    pub fn new(image: &'a T, area: &Rectangle) -> Self {
+        Self {
+            image,
+            area: *area,
+            offset: Point::zero(),
+        }
+    }
+
+    /// Sets the offset of the tiles relative to the top left corner of the area.
+    pub fn with_offset(self, offset: Point) -> Self {
+        Self { offset, ..self }
+    }
+}
+
+impl<T> Drawable for TiledImage<'_, T>
+where
+    T: ImageDrawable,
+{
+    type Color = T::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let tile_size = self.image.size();
+        let bottom_right = match self.area.bottom_right() {
+            Some(bottom_right) if tile_size.width > 0 && tile_size.height > 0 => bottom_right,
+            _ => return Ok(()),
+        };
+
+        // Top left corner of the tile which contains the top left corner of the area.
+        let offset = Point::new(
+            self.offset.x.rem_euclid(tile_size.width as i32),
+            self.offset.y.rem_euclid(tile_size.height as i32),
+        );
+        let top_left = self.area.top_left + offset
+            - Size::new(
+                if offset.x > 0 { tile_size.width } else { 0 },
+                if offset.y > 0 { tile_size.height } else { 0 },
+            );
+
+        let mut clipped = target.clipped(&self.area);
+
+        let mut y = top_left.y;
+        while y <= bottom_right.y {
+            let mut x = top_left.x;
+            while x <= bottom_right.x {
+                self.image.draw(&mut clipped.translated(Point::new(x, y)))?;
+
+                x += tile_size.width as i32;
+            }
+
+            y += tile_size.height as i32;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Dimensions for TiledImage<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<T> Transform for TiledImage<'_, T> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            image: self.image,
+            area: self.area.translate(by),
+            offset: self.offset,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.area.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{image::ImageRaw, mock_display::MockDisplay, pixelcolor::Gray4};
+
+    #[rustfmt::skip]
+    const DATA: &[u8] = &[
+        0x12, 0x30,
+        0x45, 0x60,
+    ];
+
+    fn draw(area: Rectangle, offset: Point) -> MockDisplay<Gray4> {
+        let image = ImageRaw::<Gray4>::new(DATA, 3);
+
+        let mut display = MockDisplay::new();
+        TiledImage::new(&image, &area)
+            .with_offset(offset)
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn tiles() {
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(7, 5)),
+            Point::zero(),
+        )
+        .assert_pattern(&[
+            "        ", //
+            " 1231231", //
+            " 4564564", //
+            " 1231231", //
+            " 4564564", //
+            " 1231231", //
+        ]);
+    }
+
+    #[test]
+    fn offset() {
+        let expected = [
+            "     ", //
+            " 3123", //
+            " 6456", //
+            " 3123", //
+        ];
+
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(4, 3)),
+            Point::new(1, 0),
+        )
+        .assert_pattern(&expected);
+
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::new(4, 3)),
+            Point::new(-5, 4),
+        )
+        .assert_pattern(&expected);
+    }
+
+    #[test]
+    fn empty_area() {
+        draw(
+            Rectangle::new(Point::new(1, 1), Size::zero()),
+            Point::zero(),
+        )
+        .assert_pattern(&[]);
+    }
+
+    #[test]
+    fn empty_image() {
+        let image = ImageRaw::<Gray4>::new(&[], 3);
+
+        let mut display = MockDisplay::new();
+        TiledImage::new(&image, &Rectangle::new(Point::zero(), Size::new(10, 10)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let image = ImageRaw::<Gray4>::new(DATA, 3);
+        let area = Rectangle::new(Point::new(1, 2), Size::new(3, 4));
+
+        assert_eq!(
+            TiledImage::new(&image, &area)
+                .translate(Point::new(5, 6))
+                .bounding_box(),
+            area.translate(Point::new(5, 6))
+        );
+    }
+}
@@ -112,10 +112,71 @@ where
     }
 }
 
+/// Converts a contiguous iterator of optional colors into horizontal runs.
+///
+/// Consecutive pixels in the same row which have the same color are combined into a single run.
+/// Pixels with a `None` color are skipped.
+#[derive(Debug)]
+pub(crate) struct Runs<I, C>
+where
+    I: Iterator<Item = Option<C>>,
+    C: PixelColor,
+{
+    iter: Zip<rectangle::Points, I>,
+    run: Option<(Point, u32, C)>,
+}
+
+impl<I, C> Runs<I, C>
+where
+    I: Iterator<Item = Option<C>>,
+    C: PixelColor,
+{
+    pub(crate) fn new(iter: I, area: &Rectangle) -> Self {
+        Self {
+            iter: area.points().zip(iter),
+            run: None,
+        }
+    }
+}
+
+impl<I, C> Iterator for Runs<I, C>
+where
+    I: Iterator<Item = Option<C>>,
+    C: PixelColor,
+{
+    type Item = (Point, u32, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (point, color) in &mut self.iter {
+            let color = match color {
+                Some(color) => color,
+                None => match self.run.take() {
+                    Some(run) => return Some(run),
+                    None => continue,
+                },
+            };
+
+            if let Some((start, length, run_color)) = &mut self.run {
+                if *run_color == color && start.y == point.y {
+                    *length += 1;
+                    continue;
+                }
+            }
+
+            if let Some(run) = self.run.replace((point, 1, color)) {
+                return Some(run);
+            }
+        }
+
+        self.run.take()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::pixelcolor::Gray8;
+    use arrayvec::ArrayVec;
 
     #[test]
     fn cropped() {
@@ -171,4 +232,38 @@ mod tests {
         }
         assert_eq!(cropped_iter.next(), None);
     }
+
+    #[test]
+    fn runs() {
+        let area = Rectangle::new(Point::new(1, 2), Size::new(4, 3));
+
+        let colors = [
+            Some(1),
+            Some(1),
+            None,
+            Some(1), //
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(3), //
+            Some(3),
+            None,
+            None,
+            None, //
+        ];
+
+        let runs: ArrayVec<[_; 8]> =
+            Runs::new(colors.iter().map(|c| c.map(Gray8::new)), &area).collect();
+
+        assert_eq!(
+            runs.as_slice(),
+            &[
+                (Point::new(1, 2), 2, Gray8::new(1)),
+                (Point::new(4, 2), 1, Gray8::new(1)),
+                (Point::new(1, 3), 2, Gray8::new(2)),
+                (Point::new(3, 3), 2, Gray8::new(3)),
+                (Point::new(1, 4), 1, Gray8::new(3)),
+            ]
+        );
+    }
 }
@@ -41,6 +41,12 @@ where
 
     /// Returns a translated version of the iterator.
     fn translated(self, offset: Point) -> pixel::Translated<Self>;
+
+    /// Returns the smallest rectangle which contains all pixels.
+    ///
+    /// This consumes the iterator. A zero sized rectangle at the origin is returned if the
+    /// iterator is empty.
+    fn bounding_box(self) -> Rectangle;
 }
 
 impl<I, C> PixelIteratorExt<C> for I
@@ -58,13 +64,21 @@ where
     fn translated(self, offset: Point) -> pixel::Translated<Self> {
         pixel::Translated::new(self, offset)
     }
+
+    fn bounding_box(self) -> Rectangle {
+        Rectangle::from_points(self.map(|Pixel(point, _)| point))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        geometry::Point, iterator::PixelIteratorExt, mock_display::MockDisplay,
-        pixelcolor::BinaryColor, Pixel,
+        geometry::{Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+        Pixel,
     };
 
     #[test]
@@ -84,4 +98,22 @@ mod tests {
             "  .", //
         ]);
     }
+
+    #[test]
+    fn bounding_box() {
+        let pixels = [
+            Pixel(Point::new(3, 5), BinaryColor::On),
+            Pixel(Point::new(1, 7), BinaryColor::Off),
+            Pixel(Point::new(4, 6), BinaryColor::On),
+        ];
+
+        assert_eq!(
+            pixels.iter().copied().bounding_box(),
+            Rectangle::new(Point::new(1, 5), Size::new(4, 3))
+        );
+        assert_eq!(
+            core::iter::empty::<Pixel<BinaryColor>>().bounding_box(),
+            Rectangle::zero()
+        );
+    }
 }
@@ -101,10 +101,14 @@
 //! Additional features can be enabled by adding the following features to your `Cargo.toml`.
 //!
 //! * `nalgebra_support` - use the [Nalgebra](https://crates.io/crates/nalgebra) crate with `no_std`
-//! support to enable conversions from `nalgebra::Vector2` to [`Point`] and [`Size`].
+//!   support to enable conversions from `nalgebra::Vector2` to [`Point`] and [`Size`].
 //!
 //! * `fixed_point` - use fixed point arithmetic instead of floating point for all trigonometric
-//! calculation.
+//!   calculation.
+//!
+//! * `async` - add the [`AsyncDrawTarget`] and [`AsyncDrawable`] traits for display drivers which
+//!   can yield to an async executor during long transfers. This feature requires Rust 1.75 or
+//!   later.
 //!
 //! # Migrating from older versions
 //!
@@ -211,6 +215,8 @@
 //! [`MockDisplay`]: ./mock_display/struct.MockDisplay.html
 //! [`Point`]: ./geometry/struct.Point.html
 //! [`Size`]: ./geometry/struct.Size.html
+//! [`AsyncDrawTarget`]: ./draw_target/trait.AsyncDrawTarget.html
+//! [`AsyncDrawable`]: ./trait.AsyncDrawable.html
 //! [`DrawTarget`]: https://docs.rs/embedded-graphics-core/latest/embedded_graphics_core/draw_target/trait.DrawTarget.html
 //! [`embedded-graphics-core`]: https://docs.rs/embedded-graphics-core/
 //! [`Drawable`]: ./drawable/trait.Drawable.html
@@ -233,6 +239,7 @@
 
 pub mod draw_target;
 pub mod examples;
+pub mod framebuffer;
 pub mod geometry;
 pub mod image;
 pub mod iterator;
@@ -240,11 +247,16 @@ pub mod mock_display;
 pub mod mono_font;
 pub mod prelude;
 pub mod primitives;
+pub mod style;
 pub mod text;
 pub mod transform;
+pub mod var_font;
 
 pub use embedded_graphics_core::{pixelcolor, Drawable, Pixel};
 
+#[cfg(feature = "async")]
+pub use embedded_graphics_core::AsyncDrawable;
+
 /// Trait to convert unsigned into signed integer.
 trait SaturatingCast<T> {
     /// Casts a unsigned integer into a positive value.
@@ -255,6 +267,7 @@ trait SaturatingCast<T> {
     /// Casts a unsigned integer into a negative value.
     ///
     /// If the value is too large the minimum negative value is returned instead.
+    #[allow(dead_code)]
     fn saturating_cast_neg(self) -> T;
 }
 
@@ -186,7 +186,7 @@ mod color_mapping;
 mod fancy_panic;
 
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{Capabilities, DrawTarget, GetPixel},
     geometry::{Dimensions, OriginDimensions, Point, Size},
     pixelcolor::{PixelColor, Rgb888, RgbColor},
     primitives::{PointsIter, Rectangle},
@@ -325,26 +325,12 @@ where
 
     /// Returns the area that was affected by drawing operations.
     pub fn affected_area(&self) -> Rectangle {
-        let (tl, br) = self
-            .bounding_box()
-            .points()
-            .zip(self.pixels.iter())
-            .filter_map(|(point, color)| color.map(|_| point))
-            .fold(
-                (None, None),
-                |(tl, br): (Option<Point>, Option<Point>), point| {
-                    (
-                        tl.map(|tl| tl.component_min(point)).or(Some(point)),
-                        br.map(|br| br.component_max(point)).or(Some(point)),
-                    )
-                },
-            );
-
-        if let (Some(tl), Some(br)) = (tl, br) {
-            Rectangle::with_corners(tl, br)
-        } else {
-            Rectangle::zero()
-        }
+        Rectangle::from_points(
+            self.bounding_box()
+                .points()
+                .zip(self.pixels.iter())
+                .filter_map(|(point, color)| color.map(|_| point)),
+        )
     }
 
     /// Returns the `affected_area` with the top left corner extended to `(0, 0)`.
@@ -498,6 +484,7 @@ where
     ///
     /// `MockDisplay` doesn't implement the `PartialEq` to make sure that the `assert_eq` and
     /// `assert_pattern` methods are used instead of the `assert_eq!` macro.
+    #[allow(clippy::should_implement_trait)]
     pub fn eq(&self, other: &MockDisplay<C>) -> bool {
         self.pixels.iter().eq(other.pixels.iter())
     }
@@ -712,6 +699,8 @@ where
     type Color = C;
     type Error = core::convert::Infallible;
 
+    const CAPABILITIES: Capabilities = Capabilities::new().with_read_back(true);
+
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
@@ -726,6 +715,35 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<C> crate::draw_target::AsyncDrawTarget for MockDisplay<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    async fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        DrawTarget::draw_iter(self, pixels)
+    }
+}
+
+impl<C> GetPixel for MockDisplay<C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: Point) -> Option<C> {
+        if DISPLAY_AREA.contains(point) {
+            self.get_pixel(point)
+        } else {
+            None
+        }
+    }
+}
+
 impl<C> OriginDimensions for MockDisplay<C>
 where
     C: PixelColor,
@@ -735,6 +753,35 @@ where
     }
 }
 
+/// Runs a future to completion.
+///
+/// This is a minimal executor for testing, which busy polls the future.
+#[cfg(all(test, feature = "async"))]
+// The `async` feature requires Rust 1.75, which is newer than the minimum supported Rust version of
+// the crate. All APIs used in this function are available in Rust 1.75.
+#[allow(clippy::incompatible_msrv)]
+pub(crate) fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    extern crate std;
+    use std::{sync::Arc, task::Wake};
+
+    /// Waker which doesn't do anything, because the future is polled in a busy loop.
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Arc::new(NoopWaker).into();
+    let mut future = core::pin::pin!(future);
+    let mut context = core::task::Context::from_waker(&waker);
+
+    loop {
+        if let core::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
 /// Wrapper to implement `Display` for formatting function.
 struct MessageWrapper<F>(F);
 
@@ -809,4 +856,31 @@ mod tests {
 
         display1.diff(&display2).assert_eq(&expected);
     }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn async_draw_target() {
+        use crate::{draw_target::AsyncDrawTarget, primitives::Rectangle, AsyncDrawable};
+
+        let mut display = MockDisplay::new();
+        block_on(async {
+            AsyncDrawTarget::fill_solid(
+                &mut display,
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 2)),
+                BinaryColor::On,
+            )
+            .await?;
+
+            Pixel(Point::new(0, 2), BinaryColor::Off)
+                .draw_async(&mut display)
+                .await
+        })
+        .unwrap();
+
+        display.assert_pattern(&[
+            " ##", //
+            " ##", //
+            ".  ", //
+        ]);
+    }
 }
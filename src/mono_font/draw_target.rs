@@ -1,5 +1,5 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, iterator::ContiguousIteratorExt,
+    draw_target::DrawTarget, geometry::Dimensions, iterator::contiguous::Runs,
     pixelcolor::BinaryColor, primitives::Rectangle, Pixel,
 };
 
@@ -24,13 +24,16 @@ impl<T: DrawTarget> DrawTarget for MonoFontDrawTarget<'_, T, Foreground<T::Color
     {
         let foreground_color = self.colors.0;
 
-        self.parent.draw_iter(
-            colors
-                .into_iter()
-                .into_pixels(area)
-                .filter(|Pixel(_, color)| color.is_on())
-                .map(|Pixel(pos, _)| Pixel(pos, foreground_color)),
-        )
+        self.parent.draw_runs(Runs::new(
+            colors.into_iter().map(|color| {
+                if color.is_on() {
+                    Some(foreground_color)
+                } else {
+                    None
+                }
+            }),
+            area,
+        ))
     }
 
     fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
@@ -62,13 +65,16 @@ impl<T: DrawTarget> DrawTarget for MonoFontDrawTarget<'_, T, Background<T::Color
     {
         let foreground_color = self.colors.0;
 
-        self.parent.draw_iter(
-            colors
-                .into_iter()
-                .into_pixels(&area)
-                .filter(|Pixel(_, color)| color.is_off())
-                .map(|Pixel(pos, _)| Pixel(pos, foreground_color)),
-        )
+        self.parent.draw_runs(Runs::new(
+            colors.into_iter().map(|color| {
+                if color.is_off() {
+                    Some(foreground_color)
+                } else {
+                    None
+                }
+            }),
+            area,
+        ))
     }
 
     fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
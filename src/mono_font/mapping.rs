@@ -152,7 +152,7 @@ impl GlyphMapping for StrGlyphMapping<'_> {
 }
 
 macro_rules! impl_mapping {
-    ($( $(#[$meta:meta])* ($enum_variant:ident, $constant:ident, $mapping:expr), )*) => {
+    ($( $(#[$meta:meta])* ($enum_variant:ident, $constant:ident, $mapping:expr $(, $replacement:expr)?), )*) => {
         /// Mapping.
         ///
         /// This enum lists all mappings that are included in embedded-graphics. It is used
@@ -191,9 +191,13 @@ macro_rules! impl_mapping {
 
         $(
             $(#[$meta])*
-            pub const $constant: StrGlyphMapping = StrGlyphMapping::new($mapping, '?' as usize - ' ' as usize);
+            pub const $constant: StrGlyphMapping = StrGlyphMapping::new($mapping, impl_mapping!(@replacement $($replacement)?));
         )*
     };
+
+    // Mappings use `?` as the replacement character by default.
+    (@replacement) => { '?' as usize - ' ' as usize };
+    (@replacement $replacement:expr) => { $replacement };
 }
 
 // TODO: Add Iso8859_6 (Latin/Arabic), Iso8859_8 (Latin/Hebrew) and Iso8859_11 (Latin/Thai) when we support RTL and combining characters.
@@ -239,6 +243,11 @@ impl_mapping!(
 
     /// JIS X 0201: Japanese katakana (halfwidth).
     (JisX0201, JIS_X0201, "\0\u{20}\u{7f}\0\u{ff60}\u{ff9f}"),
+
+    /// Numeric: digits, space and the characters `+,-./:`.
+    ///
+    /// Characters which aren't included in this mapping are replaced by a space.
+    (Numeric, NUMERIC, "\u{20}\0\u{2b}\u{3a}", 0),
 );
 
 #[cfg(test)]
@@ -5,6 +5,8 @@
 //!
 //! Additional custom fonts can be added by the application or other crates. This
 //! is demonstrated in the `text-custom-font` example in the [examples repository].
+//! The [`mono_font!`] macro can be used to define a custom font with default settings for the
+//! baseline and text decorations.
 //!
 //! # Examples
 //!
@@ -18,6 +20,17 @@
 //! [Wikipedia](https://en.wikipedia.org/wiki/ISO/IEC_8859#The_parts_of_ISO/IEC_8859) for a list of
 //! languages.
 //!
+//! Applications which only display numbers, like clocks or sensor readouts, can use the [`numeric`]
+//! subset to save flash memory. It only contains the digits, a space and the characters `+,-./:`,
+//! which reduces the size of the glyph data to about a third of the ASCII subset.
+//!
+//! The largest built-in font is `FONT_10X20`. There are no built-in 12x16 or 16x24 fonts,
+//! because the fonts are generated from the X11 misc-fixed font set, which doesn't include these
+//! sizes. Larger text, for example for a clock which needs to be readable from a distance, can be
+//! drawn by setting [`TextStyle::scale`] to an integer factor. This is a workaround: each pixel of
+//! the font is drawn as a block of pixels, which results in blocky glyphs. Fonts designed for a
+//! larger size can be added as custom fonts.
+//!
 //! The table below shows the ASCII variant of the built-in fonts. See the [subset modules](#modules) for
 //! an overview of the complete character set included in the other variants.
 //!
@@ -43,11 +56,15 @@
 //! [`text` module]: ../text/index.html#examples
 //! [`MonoTextStyle`]: struct.MonoTextStyle.html
 //! [examples repository]:  https://github.com/embedded-graphics/examples
+//! [`mono_font!`]: ../macro.mono_font.html
+//! [`numeric`]: numeric/index.html
+//! [`TextStyle::scale`]: ../text/struct.TextStyle.html#structfield.scale
 
-mod draw_target;
+pub(crate) mod draw_target;
 mod generated;
 pub mod mapping;
 mod mono_text_style;
+pub mod numeric;
 
 use core::fmt;
 
@@ -98,7 +115,7 @@ pub struct MonoFont<'a> {
 
 impl MonoFont<'_> {
     /// Returns a subimage for a glyph.
-    pub(crate) fn glyph(&self, c: char) -> SubImage<'_, ImageRaw<BinaryColor>> {
+    pub(crate) fn glyph(&self, c: char) -> SubImage<'_, ImageRaw<'_, BinaryColor>> {
         let glyphs_per_row = self.image.size().width / self.character_size.width;
 
         // Char _code_ offset from first char, most often a space
@@ -188,7 +205,7 @@ impl DecorationDimensions {
         }
     }
 
-    fn to_rectangle(&self, position: Point, width: u32) -> Rectangle {
+    pub(crate) fn to_rectangle(self, position: Point, width: u32) -> Rectangle {
         let top_left = position + Size::new(0, self.offset);
         let size = Size::new(width, self.height);
 
@@ -196,6 +213,92 @@ impl DecorationDimensions {
     }
 }
 
+/// Defines a custom [`MonoFont`].
+///
+/// The macro creates a `MonoFont` from the font image, the size of a single glyph and the glyph
+/// mapping. All other settings are optional and use these defaults if they are omitted:
+///
+/// * `character_spacing`: `0`
+/// * `baseline`: the bottom row of the glyph
+/// * `strikethrough`: [`DecorationDimensions::default_strikethrough`]
+/// * `underline`: [`DecorationDimensions::default_underline`]
+///
+/// The glyphs are stored in the image in a grid of equally sized cells, in the order that is
+/// defined by the glyph mapping. The macro can be used to initialize constants and statics.
+///
+/// # Examples
+///
+/// This example defines a font with three 4x4 pixel icons, which are mapped to the characters `a`
+/// to `c`.
+///
+/// ```
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     mono_font::{mapping::StrGlyphMapping, MonoFont, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     text::{Baseline, Text},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// const ICONS: MonoFont = embedded_graphics::mono_font! {
+///     image: ImageRaw::new_binary(
+///         &[
+///             0b0110_1111, 0b1001_0000, //
+///             0b1111_1001, 0b0110_0000, //
+///             0b1111_1001, 0b0110_0000, //
+///             0b0110_1111, 0b1001_0000, //
+///         ],
+///         12,
+///     ),
+///     character_size: Size::new(4, 4),
+///     glyph_mapping: &StrGlyphMapping::new("\0ac", 0),
+///     character_spacing: 1,
+/// };
+///
+/// let style = MonoTextStyle::new(&ICONS, BinaryColor::On);
+/// Text::with_baseline("abc", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+/// #
+/// # display.assert_pattern(&[
+/// #     " ##  #### #  #",
+/// #     "#### #  #  ## ",
+/// #     "#### #  #  ## ",
+/// #     " ##  #### #  #",
+/// # ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`MonoFont`]: mono_font/struct.MonoFont.html
+/// [`DecorationDimensions::default_strikethrough`]: mono_font/struct.DecorationDimensions.html#method.default_strikethrough
+/// [`DecorationDimensions::default_underline`]: mono_font/struct.DecorationDimensions.html#method.default_underline
+#[macro_export]
+macro_rules! mono_font {
+    (
+        image: $image:expr,
+        character_size: $character_size:expr,
+        glyph_mapping: $glyph_mapping:expr
+        $(, $field:ident: $value:expr)* $(,)?
+    ) => {
+        $crate::mono_font::MonoFont {
+            $($field: $value,)*
+            ..$crate::mono_font::MonoFont {
+                image: $image,
+                character_size: $character_size,
+                character_spacing: 0,
+                baseline: $character_size.height - 1,
+                strikethrough: $crate::mono_font::DecorationDimensions::default_strikethrough(
+                    $character_size.height,
+                ),
+                underline: $crate::mono_font::DecorationDimensions::default_underline(
+                    $character_size.height,
+                ),
+                glyph_mapping: $glyph_mapping,
+            }
+        }
+    };
+}
+
 const NULL_FONT: MonoFont = MonoFont {
     image: ImageRaw::new_binary(&[], 1),
     character_size: Size::zero(),
@@ -280,4 +383,102 @@ pub(crate) mod tests {
         test_baseline(&ascii::FONT_9X18);
         test_baseline(&ascii::FONT_10X20);
     }
+
+    #[test]
+    fn numeric() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&numeric::FONT_6X10)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("-12.5 +3,0\n8/9 10:47", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        let style = MonoTextStyleBuilder::new()
+            .font(&ascii::FONT_6X10)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("-12.5 +3,0\n8/9 10:47", Point::zero(), style, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn numeric_replacement() {
+        let mut display = MockDisplay::new();
+        Text::with_baseline(
+            "1a2",
+            Point::zero(),
+            MonoTextStyle::new(&numeric::FONT_6X10, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline(
+            "1 2",
+            Point::zero(),
+            MonoTextStyle::new(&ascii::FONT_6X10, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn mono_font_macro() {
+        const FONT: MonoFont = crate::mono_font! {
+            image: ImageRaw::new_binary(&[0b1010_0000, 0b0101_0000], 4),
+            character_size: Size::new(2, 2),
+            glyph_mapping: &mapping::ASCII,
+        };
+
+        assert_eq!(FONT.character_size, Size::new(2, 2));
+        assert_eq!(FONT.character_spacing, 0);
+        assert_eq!(FONT.baseline, 1);
+        assert_eq!(
+            FONT.strikethrough,
+            DecorationDimensions::default_strikethrough(2)
+        );
+        assert_eq!(FONT.underline, DecorationDimensions::default_underline(2));
+        assert_eq!(FONT.glyph_mapping.index('!'), 1);
+    }
+
+    #[test]
+    fn mono_font_macro_overrides() {
+        const FONT: MonoFont = crate::mono_font! {
+            image: ImageRaw::new_binary(&[0b1010_0000, 0b0101_0000], 4),
+            character_size: Size::new(2, 2),
+            glyph_mapping: &mapping::ASCII,
+            character_spacing: 1,
+            baseline: 0,
+            underline: DecorationDimensions::new(2, 2),
+        };
+
+        assert_eq!(FONT.character_spacing, 1);
+        assert_eq!(FONT.baseline, 0);
+        assert_eq!(
+            FONT.strikethrough,
+            DecorationDimensions::default_strikethrough(2)
+        );
+        assert_eq!(FONT.underline, DecorationDimensions::new(2, 2));
+
+        assert_text_from_pattern(
+            " ",
+            &FONT,
+            &[
+                "# ", //
+                " #", //
+            ],
+        );
+    }
 }
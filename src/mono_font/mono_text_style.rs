@@ -398,6 +398,12 @@ impl<C> MonoTextStyleBuilder<'_, C> {
     }
 }
 
+impl<C> Default for MonoTextStyleBuilder<'_, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a, C> MonoTextStyleBuilder<'a, C> {
     /// Sets the font.
     pub fn font<'b>(self, font: &'b MonoFont<'b>) -> MonoTextStyleBuilder<'b, C> {
@@ -582,7 +588,7 @@ mod tests {
             .strikethrough();
 
         assert_eq!(
-            base.clone().reset_text_color().build(),
+            base.reset_text_color().build(),
             MonoTextStyleBuilder::new()
                 .font(&FONT_10X20)
                 .background_color(BinaryColor::On)
@@ -592,7 +598,7 @@ mod tests {
         );
 
         assert_eq!(
-            base.clone().reset_background_color().build(),
+            base.reset_background_color().build(),
             MonoTextStyleBuilder::new()
                 .font(&FONT_10X20)
                 .text_color(BinaryColor::On)
@@ -602,7 +608,7 @@ mod tests {
         );
 
         assert_eq!(
-            base.clone().reset_underline().build(),
+            base.reset_underline().build(),
             MonoTextStyleBuilder::new()
                 .font(&FONT_10X20)
                 .text_color(BinaryColor::On)
@@ -612,7 +618,7 @@ mod tests {
         );
 
         assert_eq!(
-            base.clone().reset_strikethrough().build(),
+            base.reset_strikethrough().build(),
             MonoTextStyleBuilder::new()
                 .font(&FONT_10X20)
                 .text_color(BinaryColor::On)
@@ -0,0 +1,328 @@
+// The numeric subset isn't generated by the font converter. The glyph images in
+// `fonts/raw/numeric` and `fonts/png/numeric` were copied from the ASCII glyph images and use the
+// same layout with 16 glyphs per row: the space glyph is followed by the glyphs for `+` to `:`.
+// The font definitions are copies of the definitions in `generated/ascii.rs` with the image
+// paths and glyph mapping changed. Both must be updated if the ASCII fonts are regenerated.
+//! NUMERIC glyph subset.
+//!
+//! | Type | Screenshot | | Type | Screenshot |
+//! |------|------------|-|------|------------|
+//! | `FONT_4X6` | ![FONT_4X6](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAEAAAAAMCAAAAAD3mOQVAAAAbElEQVR42tVPSRLAIAhL/v9oKpvATHvRU3EQg0ACoSbEtJXQXLgAFD0rLrCj/vFrgDfQXhszSxMLHQIjJqtXuxpTEcSlDnxVkJy7sO1TSvxm6xjrF6szW0uwB7k5O+uJHbb1AcLLAdcKfr/CA6G0Sw3+y638AAAAAElFTkSuQmCC) | | `FONT_7X13_ITALIC` | ![FONT_7X13_ITALIC](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAHAAAAAaCAAAAABK5IerAAAA1ElEQVR42u2U2w6EIAxEmf//6FmVgV5CIu4Da8iSGCtgT2eooiweeC2Q505qvx7O12lpzlnq6bijL9lOPAMel3FrhFSNn2iRq5VA0nDDUwJGoMI+G3lfAS27F5amai2Xe4P16q0BGQ6U6XzHQFVdzG3FLZn0lnaG8AofC2QxHeamcy8arnAKmF+MtjZJpbcwp4BmJkbNklOE/kToR/hbrnEARMZnry4xTROdst4J+vDg20NKMHRv8lvZ69f2B74aSKwFcqnOXyjcv2mWOmo/1Y0VLh4feeuQG+DnX/wAAAAASUVORK5CYII=) |
+//! | `FONT_5X7` | ![FONT_5X7](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAFAAAAAOCAAAAACd/sT2AAAAiElEQVR42u2R2w7AIAhD6f9/NHNSKlt2yTL3NhKJGji2CLsJB5Mb2jJDP8Zu3LmxBLXvAOZw9kFsHkdTFEW6AnZdiGyVFQqZiv4BXGWHWM+NzAqY1CyhJBrhHLYKXaw0VYCjVQSRykCwn72A1Xd7ucxeHpmkpX7K6cc8jRmMHdDnQj8A/pbfxgKZQV4PIWc2zwAAAABJRU5ErkJggg==) | | `FONT_7X14` | ![FONT_7X14](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAHAAAAAcCAAAAACcvWS2AAAA2UlEQVR42u2U0RaDIAxDyf9/dOa0lDZnc3A2cQ/gg6CFCyQtyuSG3kDiBiAtfIdzn822CsOI8R/92/bCCA8VhQpNG4BfQ7gOwiLthc5LcxhsbgC2FeMGdN4YMCM8MlHrlUKABv0FsGqpi25P0zVqsHUcwmQhiqFOgCXRDmDRcJqRek8oLJUqqBrlejnlC2AcWwZkf0jIGw2ZM0VOpCjpClBN/OzivKS4I0rbzKGvZLWXhpDjvtFQDD4BSxTnmtJGXFZGx2rpAv41kJgL5NRz3nHCZZplmmWa3B6hBq4dGMrOHQAAAABJRU5ErkJggg==) |
+//! | `FONT_5X8` | ![FONT_5X8](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAFAAAAAQCAAAAACkIqcdAAAAjElEQVR42u2SUQ6AIAxD2/sfumo6tkGMP2rih0QJjPXRTYmHB68ORb9e7LmyQmNVMSFS2LRnQFGhY/JjWyIneboCGpA2kWg7jGm6ZACV1avaECa6LwcwOOU6nv1gcqjegeGqJMBCEOcI1pKz7uOq2Ngc2HpfhgV2L/2jLH0UX/ltPgLUs9AXgH/Jd8cGlfdhEV8xIzAAAAAASUVORK5CYII=) | | `FONT_7X14_BOLD` | ![FONT_7X14_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAHAAAAAcCAAAAACcvWS2AAAAzElEQVR42u2UyxaDMAhE4f8/ejynScNAYorWahdxoYYg1wwPlZsvTfoh7XkhENUf9f5aoQVB8XhbbeU8DwALU0uw+taMzUDe3qU+NCeawbT97z7Q9tx3J4HkSfFJRKVQZ4GgMwkLJ9IUFi9Cl+0AhCshhIKaATljIdt9eX11QlZ2BvTJTQFDVcDJZSLGgvTAvRzC9eYgtnTH9NsTIIZ9iA5oYlE7h662WuVpEHOo8gl47VgbjjakTL+fpQv410DcSn0CuIpmFc3K4cPADRmPqh1tf4udAAAAAElFTkSuQmCC) |
+//! | `FONT_6X9` | ![FONT_6X9](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAASCAAAAACBGYQuAAAAnElEQVR42u2TWQ6AIAxE6f0P/USELoLhwyXGSAhpYJxpO1XSzUvmEDKGAqSgS0z9Fov9fcXnUzzNoUDdjRJV20glEBh+3XMBREkbRmtCagV6xlcTwLWL0Dpc1ojPpbDbla+s4TPPrgKcKbF8ul5kAU3FBHxCadAihgJqoNqIMbe4G4Ro8tTra8b0cv4kL/jRTgtwq8oTAn+Lvj6mC+hwYBOgTAmdAAAAAElFTkSuQmCC) | | `FONT_8X13` | ![FONT_8X13](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAIAAAAAaCAAAAABaqAsyAAAA3UlEQVR42u2UUQ6EIAxE6f0PPbIL2GmpCewPq2KiKEj7mA5IWnzJzQDw+R91FmpTwoDiwXzDjkO7c/sDQL6Zo7wLAZaR1qUvieaerfjljeSvAdABtB6IZ4rmzwNoLrNsykyrzBjJKOGmljIRAJwl0FnEr1hDN+Vbwhq8KZWMacgDMqUAy2wLn8TVmOuvhkDkmXEAU9O+8JSQpCPTDQCo9nJRehPUPHsmZ7ZAwW8TAkgAxF46t3G4v/W44P3OFePjQS6lntmeLzqKN8BDASBrAbBUh39QYJtwm/DlJjwACE6VG0R7P3YAAAAASUVORK5CYII=) |
+//! | `FONT_6X10` | ![FONT_6X10](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAAUCAAAAABXQGczAAAAqUlEQVR42u2T2w6AIAxD6f9/dL3QsUskvKgPRhOI4LauB0R7+ME6hOhDM48kWi79nb5PbewzYpmpAEFVKHJnUeQCCta8FiCaJVgMMYx1dZdJ8Ye+pQRcTOiIPGTEeu9VunwLxsRs7SA0GxaGYrTiLkozVYDh1D1MQCt/FCC2SF9x0fAg5WTle99K9yQDaYGiXasikDgRL/0Hv8BNrOcCfNbGCw7+M/j8GWzD+3YVC7vvQgAAAABJRU5ErkJggg==) | | `FONT_8X13_BOLD` | ![FONT_8X13_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAIAAAAAaCAAAAABaqAsyAAAA6klEQVR42u2USxKDMAxDo/sfWtDmY1sxMHRDpw2LBmiwXySNUR6+cG879/3sX/G9spahq8fwzPg/3et9vQdQK2N0RqOgImG8spt63/c3SEDKXwKMhiwTgEkCYcoBXustAPbCvRPCl+PXLIAKUI4BeJkJyolFZTt5l7c2CtFwmVELFGACcl0jQFCC4r8LRDxAq59YwFwI73MAcCKPExtaKptDzzJAAZiDLp6nkB8AOPEjV5lkxRmYhC1JrtuHcii2KADhax5OA8aahIETHLb5dGL2d47iBfCjAHwSA4eT748UWCFcIVwhfPbaAEZLohvtr8tWAAAAAElFTkSuQmCC) |
+//! | `FONT_6X12` | ![FONT_6X12](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAAYCAAAAAAggqdIAAAAoklEQVR42u2T2w6AMAhD7f9/dL0wWNk0xgeN0flAlEAP6xDTzQ/+AOBSw62QW1ib6L2s76x5lsQSoTLH+jAlxKeAS2cIEFZj8QogaojIlxNA9acdAMUuJutcTADFKXjapvYmujlGPjtBuJGtqaKuSrFPa5tJ5dLlXhNA/OkNaQBEs0UdIKaNrUh70hiSN82acKQ//uQBeBOA90IeAAyLPr+mM04ibhk2nSBPAAAAAElFTkSuQmCC) | | `FONT_8X13_ITALIC` | ![FONT_8X13_ITALIC](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAIAAAAAaCAAAAABaqAsyAAAA4UlEQVR42u1VSQ7EIAzD/3+0hwESDEFq1QuzgKqGsiSOYyjS5oZvA8C8gbaLxbK6ofqjDaAu0Hn24WwfAKhPMiD0V7dlRsekb+ubxZTdrfjmlDMANxJUvfrwUwDqYNgwfBtLjXqMARRAZkpmJ0kwSCRQ7sQmqEFXyrvvbrpkXBoDA1cESNAx4YSZIXcVyzRL5j4ALakCCDWW06CSuQag5COElqQWWKIWJ62tEihmDQARjwguaS3D8fYgw3FXwejtsAIQSkGcq/gA+HkAxF4A3EnERzBwRLizAu0vgv9mYG97AZ9clRsNwfnQAAAAAElFTkSuQmCC) |
+//! | `FONT_6X13` | ![FONT_6X13](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAAaCAAAAABtSgZDAAAAuklEQVR42u2UgQrDMAhE9f8/+sai0TNp2MrIGCOFBls0L+fZqmy+9AcAULt9xbMIvRYR8/sW2/oeANooojNO6alHFtuqfM71/lYABrgcBoz5NwGZQxCTF5glAOQHije8uW+C3l8HiEHCFEkP5KWC6MbQ+zy+cN/n3PsA6kVqDUDJL4DeHr0YT68fVPAUXQEGkycdNtdsaztvmfTih0jyV99B6RP0X34VB7AfAN0LwF4ZX1BwTD4mf3o9AOhXlBupfVOFAAAAAElFTkSuQmCC) | | `FONT_9X15` | ![FONT_9X15](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAJAAAAAeCAAAAADml8jMAAAA9ElEQVR42u2V2xKEIAxDzf9/dHZWKaS2OC77IOPAgxeQ9NAGxDZZwyuA+J3FOpnHnUWPTpm+h+Eb0ynTMcwDI2ERlKtSQjrlaRMN0QJiqFtALTzBBMh4QETKTOcPoJogE5JZgceYS6XQzfQ4UMxHrEjxRQ1mb5XMWw/NlwrEYHNmxr8A0vVY5hwtN7j0ad121IEMBbVQJwnv1iNGuVjSr0A+uJNLDBIyNAzEcB64ze22SF44BQpbKR0/Xjop6QHphmon3V6XViCeCiU9bUjW3g5G9E9qYrJfx2xAj/G85G+/gBbQGYgTcc0JtDy0TL1MvYAebB+MSqYfd8UQQQAAAABJRU5ErkJggg==) |
+//! | `FONT_6X13_BOLD` | ![FONT_6X13_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAAaCAAAAABtSgZDAAAAvUlEQVR42u1UWw7DIAyL739obytJMFC6h9apk+gHalESP2IVdvKDCwDwXsStcDv56GI0s76z3jMuDK8BZANGOJQvvzRnkCeU53x+aWgAQg4Erqt/E6CWC0jYgl0AmsqybNfl6HAxwwmi+Ofeq7AQ/UxButF5b0kfSQojGX4AwFa1+SB3vKnfmcpOkxAcGjVFEwAOMW0kyepqjkyT3uxDYiOxxmz+Qa7+7lexAL60zDkAz9XxAwVryWvJlwe4AUVsgRUB/3WeAAAAAElFTkSuQmCC) | | `FONT_9X15_BOLD` | ![FONT_9X15_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAJAAAAAeCAAAAADml8jMAAAA+0lEQVR42u2W2w6EIAxE6f9/9OwiYqflEuVFYmqiS2DbHsYBlLTZJV8AwhGFGlsbONOB8+ZBaKnzP9DqNao2ZJ1HE4mWLjdDiqLreOpG5FvaUreAKFEiwZxsZdB3EtAVdTWeA5ECDsK8Pip0/LBunYmtA7UoFAXzFrWYVkVy1mOF8yBPztscPePPgDpmMV3GUTDQZRYLCkEdPFxuWp7nw0aZZHgIZIobOBfNna1zO09MPYRmP3DFadl3uaxqzdq2UeNlz+4R3+GXCZky6e5XWzLctJyeNzdGDBR67ejAa4ecbMbzjdM+gALIA2EnLP6IDIXC1GHqMHUA/a8fPjiqH+heEKoAAAAASUVORK5CYII=) |
+//! | `FONT_6X13_ITALIC` | ![FONT_6X13_ITALIC](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAGAAAAAaCAAAAABtSgZDAAAAwklEQVR42u2U4Q7DIAiEufd/aJYJwlFr5x+XmaxJCbXKBxwRsvnBLwAU9rrV9ynthzV8Xm9+s4sARaMIRhzoq3vm50rkOY9vB5QBXg4DrvuXABzU91B8WHmBmQKU9NCizQhIJRwgBpFslYwarBdAqYYAVR7e+wkQ3ait9/gxh1WeW4BkIsN0+vlLFTxEAaB9ZnGTcCiRyvXGCsqgx4+0ECkrmMV/mNvzroo/YD9AsRegW+v4RgXni7y1Q3RtnVvB5ucFlBKTGCSXPKgAAAAASUVORK5CYII=) | | `FONT_9X18` | ![FONT_9X18](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAJAAAAAkCAAAAABDduwHAAAA8klEQVR42u2V2xLDIAhEw/9/9HZyQRYwprUPdabkIRIDeoQNkW2xSwroL4CwR6EFQ8dzBm7l/SXN4Bz9jFC4TPOIkkAXFGNr1vEyzdJTi7oMyVu9BXSxHJbZlC2FdJh9nuOuxgRQO48uxFGQMDwA+SxNAeV8UBQXijZrehFTnvmS88ZASDJHT/gjIG9RKm3DWFXW4iYTGXI1CUChduE8JJTBCp8C5eMlIfccvwZC6gcki/iR+6FXKcnNggd2vknJHRDrN7RBKxBYd6B2OGqn6iMPpVnl1/EznvrbF1ABFVABdYGwENeaQKWhEnWJuoAKyK4XpcCjJeIJayQAAAAASUVORK5CYII=) |
+//! | `FONT_7X13` | ![FONT_7X13](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAHAAAAAaCAAAAABK5IerAAAA0ElEQVR42u2UjQ7DIAiEvfd/6JutDDht0v0kzmQ2TVCrfsBBUSY/WBbIupO2/RjXyXGacQvTjPkbfa2ad4DtNW4biQN4zt2eQ9tpBhrDDc8sFRg3Zgf6c58D005Zar6UFKgJEcCahfgkirLTV2OSzDni1BDtboauJWmIVyPsWJbJAtEp3HCRBx+/AWphpJJ3ue6AnkxcokLHkuLR2tH60Jqy4QUQA96TVeD6dk3mnZpazp1KvTkA+7wSf/Nr28ClgcRcIKfG+YsId9HsollcwwcRjJUbPfSYCAAAAABJRU5ErkJggg==) | | `FONT_9X18_BOLD` | ![FONT_9X18_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAJAAAAAkCAAAAABDduwHAAAA8ElEQVR42u2VwRKDMAhEw/9/9HZGBZdA0poempniQR0M5AErkbbZJQX0D0A4vKDO0CCXBRz5+EgWnE9vEXKXdR6JYMamlLi278yNYcUFlLjVR0B2gxYDvPXN44BSHo1zvjwHsnw0KfYCwd3ZT4D8mhUghHpEIBOTkIZMXh0cGtEzEKLMkQh/CuRLYUCppaui0j6tEPzf44E6NJ8PCyVNrK20LCY8UAj6mfAdEMI8IFm01pdaAsOoU6mXWyyDQuRAvgY04VyDQLoDj8PZONU18qY1mxwdv+Op076ACqiACigDwk5cWwKVhkrUJeoCKiC6XoWCnyXLNueXAAAAAElFTkSuQmCC) |
+//! | `FONT_7X13_BOLD` | ![FONT_7X13_BOLD](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAHAAAAAaCAAAAABK5IerAAAAwUlEQVR42u1UiwqAMAjU///o67GVnm6DIiJiEQxr8zzvnMrLj34VENtOlO1lwX4almX9fET8r0R1uQKI/d0OVvBYgLoPcFWoW5Q5DPHqQQUDOsJUgTwE6Ha6qGTE0UNtFMqAGCrKnE4epgwnhddVvIY9wABvrWNOqW0xQlY7tRQNni3AqBOb9TYg5a4uTSPSMyQXmkzT8g7YA6ciEkfOsIVUU8vSmcPgVTx+Fem8SyfgDUC8iuoH6a8Mp2mmab6u4QLlypUbA0Kv1gAAAABJRU5ErkJggg==) | | `FONT_10X20` | ![FONT_10X20](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAKAAAAAoCAAAAABcR65EAAABE0lEQVR42u3W2xKDIAwEUPP/H71Oq2KyLHirrZ2JL6VS4AAJ1IaHP5bAXwNRuigllH4RRoBNb5Zx4T75XSnZRVno0ErFUmk8gfUr1a7Vse1FoO9wBrINYQK84BDeULLWhu1dQCtSdIHzBEREVOv7QSARrB2aywS+BvS0LlCt9Epmrv9dDYTIbDSyfQMYA8+N+X5BJWrgqadXsIo32lWRnDqBqa3L7FcnuAcI2gOr9qEFjHl/IQaDxc++EX7cNwNj0x5wy6UMBBP5289TDYRtACFuGQxVOrjLDKLSpLd1lh+6SXYC/Tk8RZm+WasTu7589D1uB3z5dyuBCUxgAhOYwJuAeDD0P4AZgwnMJMksTmACTz8jpjPXKae27UoAAAAASUVORK5CYII=) |
+
+/// 4x6 pixel monospace font.
+pub const FONT_4X6: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_4x6.raw"),
+        64,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(4, 6),
+    character_spacing: 0,
+    baseline: 4,
+    underline: crate::mono_font::DecorationDimensions::new(4 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(6 / 2, 1),
+};
+
+/// 5x7 pixel monospace font.
+pub const FONT_5X7: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_5x7.raw"),
+        80,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(5, 7),
+    character_spacing: 0,
+    baseline: 5,
+    underline: crate::mono_font::DecorationDimensions::new(5 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(7 / 2, 1),
+};
+
+/// 5x8 pixel monospace font.
+pub const FONT_5X8: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_5x8.raw"),
+        80,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(5, 8),
+    character_spacing: 0,
+    baseline: 6,
+    underline: crate::mono_font::DecorationDimensions::new(6 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(8 / 2, 1),
+};
+
+/// 6x9 pixel monospace font.
+pub const FONT_6X9: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x9.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 9),
+    character_spacing: 0,
+    baseline: 6,
+    underline: crate::mono_font::DecorationDimensions::new(6 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(9 / 2, 1),
+};
+
+/// 6x10 pixel monospace font.
+pub const FONT_6X10: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x10.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 10),
+    character_spacing: 0,
+    baseline: 7,
+    underline: crate::mono_font::DecorationDimensions::new(7 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(10 / 2, 1),
+};
+
+/// 6x12 pixel monospace font.
+pub const FONT_6X12: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x12.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 12),
+    character_spacing: 0,
+    baseline: 9,
+    underline: crate::mono_font::DecorationDimensions::new(9 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(12 / 2, 1),
+};
+
+/// 6x13 pixel monospace font.
+pub const FONT_6X13: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x13.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 6x13 pixel monospace font.
+pub const FONT_6X13_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x13_bold.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 6x13 pixel monospace font.
+pub const FONT_6X13_ITALIC: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_6x13_italic.raw"),
+        96,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(6, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 7x13 pixel monospace font.
+pub const FONT_7X13: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_7x13.raw"),
+        112,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(7, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 7x13 pixel monospace font.
+pub const FONT_7X13_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_7x13_bold.raw"),
+        112,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(7, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 7x13 pixel monospace font.
+pub const FONT_7X13_ITALIC: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_7x13_italic.raw"),
+        112,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(7, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 7x14 pixel monospace font.
+pub const FONT_7X14: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_7x14.raw"),
+        112,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(7, 14),
+    character_spacing: 0,
+    baseline: 11,
+    underline: crate::mono_font::DecorationDimensions::new(11 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(14 / 2, 1),
+};
+
+/// 7x14 pixel monospace font.
+pub const FONT_7X14_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_7x14_bold.raw"),
+        112,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(7, 14),
+    character_spacing: 0,
+    baseline: 11,
+    underline: crate::mono_font::DecorationDimensions::new(11 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(14 / 2, 1),
+};
+
+/// 8x13 pixel monospace font.
+pub const FONT_8X13: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_8x13.raw"),
+        128,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(8, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 8x13 pixel monospace font.
+pub const FONT_8X13_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_8x13_bold.raw"),
+        128,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(8, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 8x13 pixel monospace font.
+pub const FONT_8X13_ITALIC: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_8x13_italic.raw"),
+        128,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(8, 13),
+    character_spacing: 0,
+    baseline: 10,
+    underline: crate::mono_font::DecorationDimensions::new(10 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(13 / 2, 1),
+};
+
+/// 9x15 pixel monospace font.
+pub const FONT_9X15: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_9x15.raw"),
+        144,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(9, 15),
+    character_spacing: 0,
+    baseline: 11,
+    underline: crate::mono_font::DecorationDimensions::new(11 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(15 / 2, 1),
+};
+
+/// 9x15 pixel monospace font.
+pub const FONT_9X15_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_9x15_bold.raw"),
+        144,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(9, 15),
+    character_spacing: 0,
+    baseline: 11,
+    underline: crate::mono_font::DecorationDimensions::new(11 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(15 / 2, 1),
+};
+
+/// 9x18 pixel monospace font.
+pub const FONT_9X18: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_9x18.raw"),
+        144,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(9, 18),
+    character_spacing: 0,
+    baseline: 13,
+    underline: crate::mono_font::DecorationDimensions::new(13 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(18 / 2, 1),
+};
+
+/// 9x18 pixel monospace font.
+pub const FONT_9X18_BOLD: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_9x18_bold.raw"),
+        144,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(9, 18),
+    character_spacing: 0,
+    baseline: 13,
+    underline: crate::mono_font::DecorationDimensions::new(13 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(18 / 2, 1),
+};
+
+/// 10x20 pixel monospace font.
+pub const FONT_10X20: crate::mono_font::MonoFont = crate::mono_font::MonoFont {
+    image: crate::image::ImageRaw::new_binary(
+        include_bytes!("../../fonts/raw/numeric/font_10x20.raw"),
+        160,
+    ),
+    glyph_mapping: &crate::mono_font::mapping::NUMERIC,
+    character_size: crate::geometry::Size::new(10, 20),
+    character_spacing: 0,
+    baseline: 15,
+    underline: crate::mono_font::DecorationDimensions::new(15 + 2, 1),
+    strikethrough: crate::mono_font::DecorationDimensions::new(20 / 2, 1),
+};
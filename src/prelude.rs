@@ -3,7 +3,10 @@
 #[doc(no_inline)]
 pub use crate::{
     draw_target::{DrawTarget, DrawTargetExt},
-    geometry::{Angle, AngleUnit, Dimensions, OriginDimensions, Point, Size},
+    geometry::{
+        Angle, AngleUnit, Dimensions, DimensionsExt, OriginDimensions, Point, PolarCoordinates,
+        Size,
+    },
     image::{ImageDrawable, ImageDrawableExt},
     iterator::{ContiguousIteratorExt, PixelIteratorExt},
     pixelcolor::{
@@ -11,6 +14,6 @@ pub use crate::{
         GrayColor, IntoStorage, PixelColor, RgbColor, WebColors,
     },
     primitives::{ContainsPoint, OffsetOutline, PointsIter, Primitive},
-    transform::Transform,
+    transform::{Mirror, Rotate, Scale, Transform},
     Drawable, Pixel,
 };
@@ -0,0 +1,468 @@
+//! Anti-aliased rendering of circles and ellipses.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::{
+        rectangle,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        Circle, Ellipse, PointsIter, Rectangle,
+    },
+    Pixel,
+};
+
+/// Number of samples per pixel along each axis.
+const SAMPLES: i32 = 4;
+
+/// Anti-aliased style for circles and ellipses.
+///
+/// Pixels along the edges of an anti-aliased shape are drawn in a color between the shape color
+/// and the background color, which is weighted by the fraction of the pixel that is covered by
+/// the shape. Because draw targets can't be read back, the background color must be specified in
+/// the style.
+///
+/// The stroke is centered on the edge of the shape. Pixels that aren't covered by the stroke or
+/// the fill at all are not drawn.
+///
+/// The colors are blended in the RGB888 color space, which is supported by all RGB and grayscale
+/// color types in embedded-graphics.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{AntialiasedStyle, Circle, Ellipse},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Filled circle on a black background
+/// Circle::new(Point::new(5, 5), 20)
+///     .into_styled(AntialiasedStyle::with_fill(Rgb565::RED, Rgb565::BLACK))
+///     .draw(&mut display)?;
+///
+/// // Ellipse with a 3px wide stroke and a fill
+/// let mut style = AntialiasedStyle::with_stroke(Rgb565::WHITE, 3, Rgb565::BLACK);
+/// style.fill_color = Some(Rgb565::BLUE);
+///
+/// Ellipse::new(Point::new(30, 5), Size::new(25, 40))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AntialiasedStyle<C> {
+    /// Fill color.
+    pub fill_color: Option<C>,
+
+    /// Stroke color.
+    pub stroke_color: Option<C>,
+
+    /// Stroke width.
+    pub stroke_width: u32,
+
+    /// Background color the edges are blended with.
+    pub background_color: C,
+}
+
+impl<C> AntialiasedStyle<C> {
+    /// Creates an anti-aliased fill style.
+    pub const fn with_fill(fill_color: C, background_color: C) -> Self {
+        Self {
+            fill_color: Some(fill_color),
+            stroke_color: None,
+            stroke_width: 0,
+            background_color,
+        }
+    }
+
+    /// Creates an anti-aliased stroke style.
+    pub const fn with_stroke(stroke_color: C, stroke_width: u32, background_color: C) -> Self {
+        Self {
+            fill_color: None,
+            stroke_color: Some(stroke_color),
+            stroke_width,
+            background_color,
+        }
+    }
+
+    /// Returns the number of pixels the stroke extends beyond the edge of the shape.
+    fn outside_stroke_width(&self) -> u32 {
+        if self.stroke_color.is_some() {
+            (self.stroke_width + 1) / 2
+        } else {
+            0
+        }
+    }
+}
+
+/// Ellipse with coordinates scaled by `2 * SAMPLES` to be able to represent sample positions.
+///
+/// `PointF` isn't used here, because the 4th powers of coordinates with 16 fractional bits, which
+/// are calculated in `contains`, would overflow 64 bit integers. The sample positions only require
+/// 3 fractional bits.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct ScaledEllipse {
+    center: Point,
+    semi_axes: Size,
+}
+
+impl ScaledEllipse {
+    /// Creates a scaled ellipse, which is expanded by `offset` half pixels in each direction.
+    fn new(ellipse: &Ellipse, offset: i32) -> Self {
+        let size = ellipse.size;
+
+        // The semi axes in units of half pixels are equal to the size in whole pixels.
+        let width = (size.width as i32 + offset).max(0);
+        let height = (size.height as i32 + offset).max(0);
+
+        Self {
+            center: ellipse.top_left * 2 * SAMPLES
+                + Point::new(size.width as i32, size.height as i32) * SAMPLES,
+            semi_axes: Size::new(width as u32, height as u32) * SAMPLES as u32,
+        }
+    }
+
+    /// Returns `true` if the scaled point is inside the ellipse.
+    fn contains(&self, point: Point) -> bool {
+        let delta = point - self.center;
+
+        let a = i64::from(self.semi_axes.width);
+        let b = i64::from(self.semi_axes.height);
+        let x = i64::from(delta.x) * b;
+        let y = i64::from(delta.y) * a;
+
+        x * x + y * y < (a * b) * (a * b)
+    }
+
+    /// Returns the number of samples inside the ellipse for a pixel.
+    fn coverage(&self, point: Point) -> u32 {
+        let top_left = point * 2 * SAMPLES;
+
+        let mut count = 0;
+        for y in 0..SAMPLES {
+            for x in 0..SAMPLES {
+                if self.contains(top_left + Point::new(x * 2 + 1, y * 2 + 1)) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+/// Pixel iterator for an anti-aliased circle or ellipse.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AntialiasedPixels<C> {
+    points: rectangle::Points,
+
+    outer_edge: ScaledEllipse,
+    inner_edge: ScaledEllipse,
+
+    fill_color: Option<Rgb888>,
+    stroke_color: Option<Rgb888>,
+    background_color: Rgb888,
+
+    color_type: core::marker::PhantomData<C>,
+}
+
+impl<C> AntialiasedPixels<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    fn new(ellipse: &Ellipse, style: &AntialiasedStyle<C>) -> Self {
+        let stroke_width = if style.stroke_color.is_some() {
+            style.stroke_width as i32
+        } else {
+            0
+        };
+
+        let points = if style.fill_color.is_some() || stroke_width > 0 {
+            // PERF: Only the pixels along the edges need to be supersampled.
+            ellipse
+                .bounding_box()
+                .offset(style.outside_stroke_width() as i32)
+                .points()
+        } else {
+            rectangle::Points::empty()
+        };
+
+        Self {
+            points,
+            outer_edge: ScaledEllipse::new(ellipse, stroke_width),
+            inner_edge: ScaledEllipse::new(ellipse, -stroke_width),
+            fill_color: style.fill_color.map(Into::into),
+            stroke_color: style.stroke_color.map(Into::into),
+            background_color: style.background_color.into(),
+            color_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the blended color for a pixel or `None` if the pixel isn't covered.
+    fn color(&self, point: Point) -> Option<C> {
+        let outer = self.outer_edge.coverage(point);
+        if outer == 0 {
+            return None;
+        }
+        let inner = self.inner_edge.coverage(point);
+
+        let mut weights = [(0, self.background_color); 3];
+        if let Some(color) = self.stroke_color {
+            weights[0] = (outer - inner, color);
+        }
+        if let Some(color) = self.fill_color {
+            weights[1] = (inner, color);
+        }
+
+        let covered: u32 = weights.iter().map(|(weight, _)| weight).sum();
+        if covered == 0 {
+            return None;
+        }
+
+        let total = (SAMPLES * SAMPLES) as u32;
+        weights[2] = (total - covered, self.background_color);
+
+        let channel = |f: fn(&Rgb888) -> u8| {
+            let sum: u32 = weights
+                .iter()
+                .map(|(weight, color)| weight * u32::from(f(color)))
+                .sum();
+
+            ((sum + total / 2) / total) as u8
+        };
+
+        Some(C::from(Rgb888::new(
+            channel(Rgb888::r),
+            channel(Rgb888::g),
+            channel(Rgb888::b),
+        )))
+    }
+}
+
+impl<C> Iterator for AntialiasedPixels<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let point = self.points.next()?;
+
+            if let Some(color) = self.color(point) {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+impl<C> StyledPixels<AntialiasedStyle<C>> for Ellipse
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Iter = AntialiasedPixels<C>;
+
+    fn pixels(&self, style: &AntialiasedStyle<C>) -> Self::Iter {
+        AntialiasedPixels::new(self, style)
+    }
+}
+
+impl<C> StyledDrawable<AntialiasedStyle<C>> for Ellipse
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &AntialiasedStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.pixels(style))
+    }
+}
+
+impl<C> StyledDimensions<AntialiasedStyle<C>> for Ellipse {
+    fn styled_bounding_box(&self, style: &AntialiasedStyle<C>) -> Rectangle {
+        self.bounding_box()
+            .offset(style.outside_stroke_width() as i32)
+    }
+}
+
+impl<C> StyledPixels<AntialiasedStyle<C>> for Circle
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Iter = AntialiasedPixels<C>;
+
+    fn pixels(&self, style: &AntialiasedStyle<C>) -> Self::Iter {
+        AntialiasedPixels::new(&to_ellipse(self), style)
+    }
+}
+
+impl<C> StyledDrawable<AntialiasedStyle<C>> for Circle
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &AntialiasedStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.pixels(style))
+    }
+}
+
+impl<C> StyledDimensions<AntialiasedStyle<C>> for Circle {
+    fn styled_bounding_box(&self, style: &AntialiasedStyle<C>) -> Rectangle {
+        self.bounding_box()
+            .offset(style.outside_stroke_width() as i32)
+    }
+}
+
+/// Returns an ellipse with the same bounding box as a circle.
+fn to_ellipse(circle: &Circle) -> Ellipse {
+    Ellipse::new(circle.top_left, Size::new_equal(circle.diameter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{Gray4, GrayColor},
+        primitives::Primitive,
+        Drawable,
+    };
+
+    #[test]
+    fn filled_circle() {
+        let mut display = MockDisplay::new();
+
+        Circle::new(Point::new(0, 0), 7)
+            .into_styled(AntialiasedStyle::with_fill(Gray4::WHITE, Gray4::BLACK))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " 5DFD5 ", "5FFFFF5", "DFFFFFD", "FFFFFFF", "DFFFFFD", "5FFFFF5", " 5DFD5 ",
+        ]);
+    }
+
+    #[test]
+    fn stroked_circle() {
+        let mut display = MockDisplay::new();
+
+        Circle::new(Point::new(1, 1), 9)
+            .into_styled(AntialiasedStyle::with_stroke(Gray4::WHITE, 1, Gray4::BLACK))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   26862   ",
+            "  8E989E8  ",
+            " 8C1   1C8 ",
+            "2E1     1E2",
+            "69       96",
+            "88       88",
+            "69       96",
+            "2E1     1E2",
+            " 8C1   1C8 ",
+            "  8E989E8  ",
+            "   26862   ",
+        ]);
+    }
+
+    #[test]
+    fn circle_equals_ellipse() {
+        let mut style = AntialiasedStyle::with_stroke(Gray4::WHITE, 3, Gray4::BLACK);
+        style.fill_color = Some(Gray4::new(5));
+
+        for diameter in 0..15 {
+            let circle = Circle::new(Point::new(3, 4), diameter).into_styled(style);
+            let ellipse =
+                Ellipse::new(Point::new(3, 4), Size::new_equal(diameter)).into_styled(style);
+
+            assert!(circle.pixels().eq(ellipse.pixels()), "{}", diameter);
+            assert_eq!(circle.bounding_box(), ellipse.bounding_box());
+        }
+    }
+
+    #[test]
+    fn interior_and_exterior() {
+        let ellipse = Ellipse::new(Point::new(2, 3), Size::new(30, 20));
+
+        let mut display = MockDisplay::new();
+        ellipse
+            .into_styled(AntialiasedStyle::with_fill(Gray4::WHITE, Gray4::BLACK))
+            .draw(&mut display)
+            .unwrap();
+
+        let mut aliased = MockDisplay::new();
+        ellipse
+            .into_styled(crate::primitives::PrimitiveStyle::with_fill(Gray4::WHITE))
+            .draw(&mut aliased)
+            .unwrap();
+
+        // All pixels which are fully inside the ellipse are also drawn by the aliased ellipse.
+        for point in display.affected_area().points() {
+            match display.get_pixel(point) {
+                Some(Gray4::WHITE) => assert_eq!(aliased.get_pixel(point), Some(Gray4::WHITE)),
+                Some(color) => assert_ne!(color, Gray4::BLACK, "{:?}", point),
+                None => assert_eq!(aliased.get_pixel(point), None, "{:?}", point),
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        for stroke_width in 0..6 {
+            let mut style = AntialiasedStyle::with_stroke(Gray4::WHITE, stroke_width, Gray4::BLACK);
+            style.fill_color = Some(Gray4::new(5));
+
+            let styled = Ellipse::new(Point::new(10, 10), Size::new(20, 15)).into_styled(style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                styled.bounding_box(),
+                "{}",
+                stroke_width
+            );
+        }
+    }
+
+    #[test]
+    fn transparent() {
+        let style = AntialiasedStyle {
+            fill_color: None,
+            stroke_color: None,
+            stroke_width: 5,
+            background_color: Gray4::BLACK,
+        };
+
+        assert_eq!(
+            Circle::new(Point::zero(), 10)
+                .into_styled(style)
+                .pixels()
+                .next(),
+            None
+        );
+    }
+}
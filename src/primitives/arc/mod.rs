@@ -1,9 +1,12 @@
 //! The arc primitive
 
 use crate::{
-    geometry::{Angle, Dimensions, Point, Size},
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_90DEG},
+        Angle, Dimensions, Point, Size,
+    },
     primitives::{Circle, PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{Mirror, Rotate, Scale, Transform},
 };
 
 mod points;
@@ -43,6 +46,35 @@ pub use styled::StyledPixelsIterator;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// ## Draw a circular progress indicator
+///
+/// Thick arcs support the line caps set by [`PrimitiveStyle::line_cap`].
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Arc, LineCap, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let progress = 0.7;
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::GREEN)
+///     .stroke_width(5)
+///     .line_cap(LineCap::Round)
+///     .build();
+///
+/// Arc::with_center(Point::new(32, 32), 48, 90.0.deg(), (-360.0 * progress).deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`PrimitiveStyle::line_cap`]: ../struct.PrimitiveStyle.html#structfield.line_cap
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct Arc {
     /// Top-left point of the bounding-box of the circle supporting the arc
@@ -163,6 +195,59 @@ impl Transform for Arc {
     }
 }
 
+impl Rotate for Arc {
+    /// Rotate the arc by 90° clockwise around `center`.
+    ///
+    /// The angles of an arc are measured counterclockwise, which means that the start angle is
+    /// decreased by 90°.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Arc;
+    /// # use embedded_graphics::prelude::*;
+    /// let arc = Arc::new(Point::new(0, 0), 11, 0.0.deg(), 90.0.deg());
+    /// let rotated = arc.rotate_90(Point::new(5, 5));
+    ///
+    /// assert_eq!(rotated.top_left, Point::new(0, 0));
+    /// assert_eq!(rotated.angle_start, 270.0.deg());
+    /// assert_eq!(rotated.angle_sweep, 90.0.deg());
+    /// ```
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::from_circle(
+            self.to_circle().rotate_90(center),
+            (self.angle_start - ANGLE_90DEG).normalize(),
+            self.angle_sweep,
+        )
+    }
+}
+
+impl Mirror for Arc {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_x(axis),
+            (ANGLE_180DEG - self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_y(axis),
+            (-self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+}
+
+impl Scale for Arc {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::from_circle(
+            self.to_circle().scale_around(center, num, denom),
+            self.angle_start,
+            self.angle_sweep,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
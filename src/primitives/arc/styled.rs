@@ -1,22 +1,137 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{
+        angle_consts::ANGLE_360DEG, Angle, Dimensions, Point, PointExt, Real, Size, Trigonometry,
+    },
+    iterator::PixelIteratorExt,
     pixelcolor::PixelColor,
     primitives::{
         arc::Arc,
-        common::{DistanceIterator, PlaneSector},
+        circle::diameter_to_threshold,
+        common::{DistanceIterator, PlaneSector, NORMAL_VECTOR_SCALE},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        OffsetOutline, PrimitiveStyle, Rectangle,
+        LineCap, OffsetOutline, PrimitiveStyle, Rectangle,
     },
     Pixel, SaturatingCast,
 };
 
+/// Caps at both ends of a thick arc.
+///
+/// All points are relative to the center of the arc and scaled up by a factor of 2 to match the
+/// values returned by `DistanceIterator`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Caps {
+    line_cap: LineCap,
+
+    /// Center points of the caps on the center line of the stroke.
+    centers: [Point; 2],
+
+    /// Tangent directions pointing away from the arc, scaled by `NORMAL_VECTOR_SCALE`.
+    directions: [Point; 2],
+
+    stroke_width: u32,
+}
+
+impl Caps {
+    fn new<C: PixelColor>(primitive: &Arc, style: &PrimitiveStyle<C>) -> Self {
+        // Butt caps don't add any pixels and are also used for thin strokes and full circles.
+        let line_cap = if style.stroke_width > 1 && primitive.angle_sweep.abs() < ANGLE_360DEG {
+            style.line_cap
+        } else {
+            LineCap::Butt
+        };
+
+        // Diameter of the center line of the stroke, which is equal to its radius scaled by 2.
+        let radius_2x = primitive
+            .diameter
+            .saturating_add(style.outside_stroke_width()) as i32
+            - style.inside_stroke_width() as i32;
+
+        let sweep_sign = if primitive.angle_sweep < Angle::zero() {
+            -1
+        } else {
+            1
+        };
+
+        let angle_end = primitive.angle_start + primitive.angle_sweep;
+
+        Self {
+            line_cap,
+            centers: [
+                point_at_angle(primitive.angle_start, radius_2x),
+                point_at_angle(angle_end, radius_2x),
+            ],
+            directions: [
+                tangent_at_angle(primitive.angle_start) * -sweep_sign,
+                tangent_at_angle(angle_end) * sweep_sign,
+            ],
+            stroke_width: style.stroke_width,
+        }
+    }
+
+    /// Returns the number of pixels the caps can extend beyond the outside edge of the stroke.
+    fn outset(&self) -> u32 {
+        match self.line_cap {
+            LineCap::Square => (self.stroke_width + 1) / 2,
+            LineCap::Butt | LineCap::Round => 0,
+        }
+    }
+
+    /// Returns `true` if a point is inside one of the caps.
+    fn contains(&self, delta: Point) -> bool {
+        let width = self.stroke_width as i32;
+
+        self.centers
+            .iter()
+            .zip(self.directions.iter())
+            .any(|(center, direction)| {
+                let delta = delta - *center;
+
+                match self.line_cap {
+                    LineCap::Butt => false,
+                    LineCap::Square => {
+                        let along = delta.dot(*direction);
+                        let across = delta.dot(direction.rotate_90());
+
+                        along >= 0
+                            && along < width * NORMAL_VECTOR_SCALE
+                            && across.abs() < width * NORMAL_VECTOR_SCALE
+                    }
+                    LineCap::Round => {
+                        (delta.length_squared() as u32) < diameter_to_threshold(self.stroke_width)
+                    }
+                }
+            })
+    }
+}
+
+/// Returns the point at the given angle and distance from the origin.
+fn point_at_angle(angle: Angle, distance: i32) -> Point {
+    let distance = Real::from(distance);
+
+    Point::new(
+        i32::from((distance * angle.cos()).round()),
+        -i32::from((distance * angle.sin()).round()),
+    )
+}
+
+/// Returns the direction of increasing angles at the given angle.
+fn tangent_at_angle(angle: Angle) -> Point {
+    let scale = Real::from(NORMAL_VECTOR_SCALE);
+
+    -Point::new(
+        i32::from((scale * angle.sin()).round()),
+        i32::from((scale * angle.cos()).round()),
+    )
+}
+
 /// Pixel iterator for each pixel in the arc border
 #[derive(Clone, PartialEq, Debug)]
 pub struct StyledPixelsIterator<C> {
     iter: DistanceIterator,
 
     plane_sector: PlaneSector,
+    caps: Caps,
 
     outer_threshold: u32,
     inner_threshold: u32,
@@ -31,9 +146,16 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let outside_edge = circle.offset(style.outside_stroke_width().saturating_cast());
         let inside_edge = circle.offset(style.inside_stroke_width().saturating_cast_neg());
 
+        let caps = Caps::new(primitive, style);
+
         let iter = if !style.is_transparent() {
             // PERF: The distance iterator should use the smaller arc bounding box
-            outside_edge.distances()
+            DistanceIterator::new(
+                outside_edge.center_2x(),
+                &outside_edge
+                    .bounding_box()
+                    .offset(caps.outset().saturating_cast()),
+            )
         } else {
             DistanceIterator::empty()
         };
@@ -43,6 +165,7 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         Self {
             iter,
             plane_sector,
+            caps,
             outer_threshold: outside_edge.threshold(),
             inner_threshold: inside_edge.threshold(),
             stroke_color: style.stroke_color,
@@ -58,12 +181,14 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
         let outer_threshold = self.outer_threshold;
         let inner_threshold = self.inner_threshold;
         let plane_sector = self.plane_sector;
+        let caps = self.caps;
 
         self.iter
             .find(|(_, delta, distance)| {
-                *distance < outer_threshold
+                (*distance < outer_threshold
                     && *distance >= inner_threshold
-                    && plane_sector.contains(*delta)
+                    && plane_sector.contains(*delta))
+                    || caps.contains(*delta)
             })
             .map(|(point, ..)| Pixel(point, stroke_color))
     }
@@ -94,11 +219,16 @@ impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Arc {
 }
 
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Arc {
-    // FIXME: This doesn't take into account start/end angles. This should be fixed to close #405.
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let offset = style.outside_stroke_width().saturating_cast();
+        // The bounding box is determined by the drawn pixels, because the area covered by the
+        // start and end angles can't be calculated exactly without rasterizing the shape.
+        let bounding_box = StyledPixelsIterator::new(self, &style.coverage_style()).bounding_box();
 
-        self.bounding_box().offset(offset)
+        if bounding_box.is_zero_sized() {
+            Rectangle::new(self.center(), Size::zero())
+        } else {
+            bounding_box
+        }
     }
 }
 
@@ -110,7 +240,9 @@ mod tests {
         geometry::{AnchorPoint, AngleUnit, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, LineCap, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
         Drawable,
     };
 
@@ -234,13 +366,11 @@ mod tests {
                 .build(),
         );
 
-        assert_eq!(center.bounding_box(), inside.bounding_box());
-        assert_eq!(outside.bounding_box(), inside.bounding_box());
-
-        // TODO: Uncomment when arc bounding box is fixed in #405
-        // let mut display = MockDisplay::new();
-        // center.draw(&mut display).unwrap();
-        // assert_eq!(display.affected_area(), center.bounding_box());
+        for styled in [center, inside, outside].iter() {
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
     }
 
     #[test]
@@ -259,4 +389,120 @@ mod tests {
 
         assert_eq!(transparent_arc.bounding_box(), stroked_arc.bounding_box(),);
     }
+
+    #[test]
+    fn line_caps() {
+        let arc = Arc::with_center(Point::new(1, 12), 16, 0.0.deg(), 90.0.deg());
+
+        for &(line_cap, expected) in &[
+            (
+                LineCap::Butt,
+                &[
+                    "            ",
+                    "            ",
+                    "            ",
+                    "  ###       ",
+                    "  #####     ",
+                    "  #######   ",
+                    "  ########  ",
+                    "  ########  ",
+                    "    ####### ",
+                    "      ##### ",
+                    "      ######",
+                    "       #####",
+                    "       #####",
+                ][..],
+            ),
+            (
+                LineCap::Square,
+                &[
+                    "            ",
+                    "            ",
+                    "            ",
+                    "#####       ",
+                    "#######     ",
+                    "#########   ",
+                    "##########  ",
+                    "##########  ",
+                    "    ####### ",
+                    "      ##### ",
+                    "      ######",
+                    "       #####",
+                    "       #####",
+                    "       #####",
+                    "       #####",
+                ][..],
+            ),
+            (
+                LineCap::Round,
+                &[
+                    "            ",
+                    "            ",
+                    "            ",
+                    " ####       ",
+                    "#######     ",
+                    "#########   ",
+                    "##########  ",
+                    " #########  ",
+                    "    ####### ",
+                    "      ##### ",
+                    "      ######",
+                    "       #####",
+                    "       #####",
+                    "       #####",
+                    "        ### ",
+                ][..],
+            ),
+        ] {
+            let mut display = MockDisplay::new();
+
+            arc.into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(5)
+                    .line_cap(line_cap)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+            display.assert_pattern_with_message(expected, |f| write!(f, "{:?}", line_cap));
+        }
+    }
+
+    #[test]
+    fn line_caps_bounding_box() {
+        for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round].iter() {
+            for &(angle_start, angle_sweep) in &[
+                (0.0.deg(), 90.0.deg()),
+                (30.0.deg(), -200.0.deg()),
+                (200.0.deg(), 300.0.deg()),
+                (0.0.deg(), 360.0.deg()),
+            ] {
+                for stroke_width in 1..8 {
+                    let styled = Arc::with_center(Point::new(20, 20), 15, angle_start, angle_sweep)
+                        .into_styled(
+                            PrimitiveStyleBuilder::new()
+                                .stroke_color(BinaryColor::On)
+                                .stroke_width(stroke_width)
+                                .line_cap(*line_cap)
+                                .build(),
+                        );
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    assert_eq!(
+                        styled.bounding_box(),
+                        display.affected_area(),
+                        "{:?}, {:?}, {:?}, {}",
+                        line_cap,
+                        angle_start,
+                        angle_sweep,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
 }
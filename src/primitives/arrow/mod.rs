@@ -0,0 +1,413 @@
+//! The arrow primitive
+
+use crate::{
+    geometry::{div_round, isqrt, Dimensions, Point},
+    primitives::{ContainsPoint, Line, Primitive, Rectangle, Triangle},
+    transform::{scale_length, Mirror, Rotate, Scale, Transform},
+};
+
+mod styled;
+
+pub use styled::StyledPixelsIterator;
+
+/// Arrow primitive
+///
+/// An arrow is a [`Line`] with an arrow head at its end and an optional second head at its start.
+/// The shaft of the arrow is drawn like a line, which means that the stroke width, pattern and
+/// line cap of the style are used. The heads are drawn in the stroke color and the fill color of
+/// the style is ignored.
+///
+/// [`Line`]: ../line/struct.Line.html
+///
+/// # Examples
+///
+/// ## Draw arrows with different heads
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Arrow, ArrowHead, Line, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyle::with_stroke(Rgb565::WHITE, 1);
+///
+/// // Arrow with a filled head
+/// Arrow::new(
+///     Line::new(Point::new(5, 10), Point::new(55, 10)),
+///     ArrowHead::filled(8, 7),
+/// )
+/// .into_styled(style)
+/// .draw(&mut display)?;
+///
+/// // Thick double headed arrow with open heads
+/// Arrow::new(
+///     Line::new(Point::new(5, 30), Point::new(55, 50)),
+///     ArrowHead::open(10, 10),
+/// )
+/// .with_tail(ArrowHead::open(10, 10))
+/// .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw a wind vane
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Arrow, ArrowHead, Line, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let center = Point::new(32, 32);
+/// let wind_direction = Point::new(20, -15);
+///
+/// Arrow::new(
+///     Line::new(center - wind_direction, center + wind_direction),
+///     ArrowHead::filled(10, 9),
+/// )
+/// .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Arrow {
+    /// Line from the tail to the tip of the arrow
+    pub line: Line,
+
+    /// Head at the end of the line
+    pub head: ArrowHead,
+
+    /// Optional head at the start of the line
+    pub tail: Option<ArrowHead>,
+}
+
+impl Arrow {
+    /// Creates a new arrow with a head at the end of `line`.
+    pub const fn new(line: Line, head: ArrowHead) -> Self {
+        Self {
+            line,
+            head,
+            tail: None,
+        }
+    }
+
+    /// Adds a second head at the start of the line.
+    pub const fn with_tail(self, tail: ArrowHead) -> Self {
+        Self {
+            tail: Some(tail),
+            ..self
+        }
+    }
+
+    /// Returns the shape of the head and tail and the line of the shaft.
+    fn geometry(&self, stroke_width: u32) -> (Option<HeadShape>, Option<HeadShape>, Line) {
+        let head = HeadShape::new(&self.head, self.line.end, self.line.start, stroke_width);
+        let tail = self
+            .tail
+            .as_ref()
+            .and_then(|tail| HeadShape::new(tail, self.line.start, self.line.end, stroke_width));
+
+        let shaft = Line::new(
+            tail.map_or(self.line.start, |tail| tail.shaft_end),
+            head.map_or(self.line.end, |head| head.shaft_end),
+        );
+
+        (head, tail, shaft)
+    }
+}
+
+impl Primitive for Arrow {}
+
+impl Dimensions for Arrow {
+    fn bounding_box(&self) -> Rectangle {
+        let (head, tail, _) = self.geometry(1);
+
+        [head, tail]
+            .iter()
+            .flatten()
+            .fold(self.line.bounding_box(), |bounding_box, shape| {
+                bounding_box.envelope(&shape.outer.bounding_box())
+            })
+    }
+}
+
+impl Transform for Arrow {
+    /// Translate the arrow from its current position to a new position by (x, y) pixels,
+    /// returning a new `Arrow`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Arrow, ArrowHead, Line};
+    /// # use embedded_graphics::prelude::*;
+    /// let arrow = Arrow::new(
+    ///     Line::new(Point::new(5, 10), Point::new(15, 20)),
+    ///     ArrowHead::filled(5, 5),
+    /// );
+    /// let moved = arrow.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.line, Line::new(Point::new(15, 20), Point::new(25, 30)));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            line: self.line.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the arrow from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Arrow, ArrowHead, Line};
+    /// # use embedded_graphics::prelude::*;
+    /// let mut arrow = Arrow::new(
+    ///     Line::new(Point::new(5, 10), Point::new(15, 20)),
+    ///     ArrowHead::filled(5, 5),
+    /// );
+    /// arrow.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(arrow.line, Line::new(Point::new(15, 20), Point::new(25, 30)));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.line.translate_mut(by);
+
+        self
+    }
+}
+
+impl Rotate for Arrow {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self {
+            line: self.line.rotate_90(center),
+            ..*self
+        }
+    }
+}
+
+impl Mirror for Arrow {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self {
+            line: self.line.mirror_x(axis),
+            ..*self
+        }
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self {
+            line: self.line.mirror_y(axis),
+            ..*self
+        }
+    }
+}
+
+impl Scale for Arrow {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let scale = |head: ArrowHead| ArrowHead {
+            length: scale_length(head.length, num, denom),
+            width: scale_length(head.width, num, denom),
+            ..head
+        };
+
+        Self {
+            line: self.line.scale_around(center, num, denom),
+            head: scale(self.head),
+            tail: self.tail.map(scale),
+        }
+    }
+}
+
+/// Arrow head.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ArrowHead {
+    /// Shape of the head
+    pub shape: ArrowHeadShape,
+
+    /// Length of the head along the line in pixels
+    pub length: u32,
+
+    /// Width of the head perpendicular to the line in pixels
+    ///
+    /// The head is symmetric to the line, which is why odd widths give the best results.
+    pub width: u32,
+}
+
+impl ArrowHead {
+    /// Creates a filled triangular arrow head.
+    pub const fn filled(length: u32, width: u32) -> Self {
+        Self {
+            shape: ArrowHeadShape::Filled,
+            length,
+            width,
+        }
+    }
+
+    /// Creates an open V shaped arrow head.
+    pub const fn open(length: u32, width: u32) -> Self {
+        Self {
+            shape: ArrowHeadShape::Open,
+            length,
+            width,
+        }
+    }
+}
+
+/// Arrow head shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ArrowHeadShape {
+    /// Filled triangle.
+    Filled,
+
+    /// Open V shape.
+    ///
+    /// The thickness of the V is equal to the stroke width.
+    Open,
+}
+
+/// Scaling factor for the calculation of the head vertices.
+const SCALE: i64 = 256;
+
+/// Shape of an arrow head.
+///
+/// An open head is represented by the difference of two triangles, which makes it possible to
+/// draw it without overlapping pixels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct HeadShape {
+    outer: Triangle,
+    inner: Option<Triangle>,
+    shaft_end: Point,
+}
+
+impl HeadShape {
+    /// Creates a head with the tip at `tip`, pointing away from `from`.
+    ///
+    /// Returns `None` if the direction of the head is undefined.
+    fn new(head: &ArrowHead, tip: Point, from: Point, stroke_width: u32) -> Option<Self> {
+        let delta = tip - from;
+        let (dx, dy) = (i64::from(delta.x), i64::from(delta.y));
+
+        let scaled_length = isqrt(((dx * dx + dy * dy) * SCALE * SCALE) as u64) as i64;
+        if scaled_length == 0 {
+            return None;
+        }
+
+        // Returns a vector in the direction of `delta`, which is optionally rotated by 90°. The
+        // length of the vector is given in half pixels, scaled by `SCALE`.
+        let vector = |scaled_half_pixels: i64, rotate: bool| {
+            let (x, y) = if rotate { (-dy, dx) } else { (dx, dy) };
+            let denominator = 2 * scaled_length;
+
+            Point::new(
+                div_round(x * scaled_half_pixels, denominator) as i32,
+                div_round(y * scaled_half_pixels, denominator) as i32,
+            )
+        };
+
+        // Distances between the vertices, in half pixels.
+        let length = 2 * i64::from(head.length.saturating_sub(1));
+        let width = i64::from(head.width.saturating_sub(1));
+
+        let base = tip - vector(length * SCALE, false);
+        let side = vector(width * SCALE, true);
+        let outer = Triangle::new(tip, base + side, base - side);
+
+        match head.shape {
+            ArrowHeadShape::Filled => Some(Self {
+                outer,
+                inner: None,
+                shaft_end: base,
+            }),
+            ArrowHeadShape::Open => {
+                // The inner triangle is moved back along the line, which results in a
+                // perpendicular distance of `stroke_width` between the edges of both triangles.
+                let scaled_distance = if width > 0 {
+                    let edge_length =
+                        isqrt(((length * length + width * width) * SCALE * SCALE) as u64) as i64;
+
+                    2 * i64::from(stroke_width) * edge_length / width
+                } else {
+                    0
+                };
+
+                let offset = vector(scaled_distance, false);
+                let inner = outer.translate(Point::zero() - offset);
+
+                Some(Self {
+                    outer,
+                    inner: Some(inner),
+                    shaft_end: tip - offset,
+                })
+            }
+        }
+    }
+
+    /// Returns `true` if the head contains the given point.
+    fn contains(&self, point: Point) -> bool {
+        self.outer.contains(point) && !self.inner.map_or(false, |inner| inner.contains(point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Size;
+
+    #[test]
+    fn bounding_box() {
+        let arrow = Arrow::new(
+            Line::new(Point::new(10, 10), Point::new(30, 10)),
+            ArrowHead::filled(5, 9),
+        );
+
+        assert_eq!(
+            arrow.bounding_box(),
+            Rectangle::new(Point::new(10, 6), Size::new(21, 9))
+        );
+
+        assert_eq!(
+            arrow.with_tail(ArrowHead::open(3, 13)).bounding_box(),
+            Rectangle::new(Point::new(10, 4), Size::new(21, 13))
+        );
+    }
+
+    #[test]
+    fn zero_length() {
+        let arrow = Arrow::new(
+            Line::new(Point::new(10, 10), Point::new(10, 10)),
+            ArrowHead::filled(5, 9),
+        )
+        .with_tail(ArrowHead::open(5, 9));
+
+        assert_eq!(
+            arrow.geometry(1),
+            (
+                None,
+                None,
+                Line::new(Point::new(10, 10), Point::new(10, 10))
+            )
+        );
+        assert_eq!(
+            arrow.bounding_box(),
+            Rectangle::new(Point::new(10, 10), Size::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn head_vertices() {
+        let arrow = Arrow::new(
+            Line::new(Point::new(0, 0), Point::new(0, 20)),
+            ArrowHead::filled(6, 9),
+        );
+
+        let (head, tail, shaft) = arrow.geometry(1);
+        assert_eq!(
+            head.unwrap().outer,
+            Triangle::new(Point::new(0, 20), Point::new(-4, 15), Point::new(4, 15))
+        );
+        assert_eq!(tail, None);
+        assert_eq!(shaft, Line::new(Point::new(0, 0), Point::new(0, 15)));
+    }
+}
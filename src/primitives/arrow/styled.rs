@@ -0,0 +1,259 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        arrow::{Arrow, HeadShape},
+        line,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        triangle, PointsIter, PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+/// Pixel iterator for each pixel in a styled arrow.
+#[derive(Clone, Debug)]
+pub struct StyledPixelsIterator<C> {
+    head: Option<HeadShape>,
+    tail: Option<HeadShape>,
+    head_points: Option<triangle::Points>,
+    tail_points: Option<triangle::Points>,
+    shaft: line::StyledPixelsIterator<C>,
+    stroke_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(arrow: &Arrow, style: &PrimitiveStyle<C>) -> Self {
+        let (head, tail, shaft) = arrow.geometry(style.stroke_width);
+
+        // Heads are only drawn if the stroke is visible.
+        let stroke_color = style.effective_stroke_color();
+        let points =
+            |shape: Option<HeadShape>| stroke_color.and(shape).map(|shape| shape.outer.points());
+
+        Self {
+            head,
+            tail,
+            head_points: points(head),
+            tail_points: points(tail),
+            shaft: shaft.pixels(style),
+            stroke_color,
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.head;
+        let tail = self.tail;
+        let head_contains = |point| head.map_or(false, |head| head.contains(point));
+        let tail_contains = |point| tail.map_or(false, |tail| tail.contains(point));
+
+        if let Some(color) = self.stroke_color {
+            if let Some(point) = self
+                .head_points
+                .as_mut()
+                .and_then(|points| points.find(|point| head_contains(*point)))
+            {
+                return Some(Pixel(point, color));
+            }
+
+            // Pixels that are shared by the head and the tail are only drawn once.
+            if let Some(point) = self.tail_points.as_mut().and_then(|points| {
+                points.find(|point| tail_contains(*point) && !head_contains(*point))
+            }) {
+                return Some(Pixel(point, color));
+            }
+        }
+
+        // Skip shaft pixels that have already been drawn as part of a head.
+        self.shaft
+            .find(|Pixel(point, _)| !head_contains(*point) && !tail_contains(*point))
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Arrow {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Arrow {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Arrow {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let (head, tail, shaft) = self.geometry(style.stroke_width);
+
+        [head, tail]
+            .iter()
+            .flatten()
+            .fold(shaft.styled_bounding_box(style), |bounding_box, shape| {
+                bounding_box.envelope(&shape.outer.bounding_box())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Point,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{ArrowHead, Line, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn filled_head() {
+        let mut display = MockDisplay::new();
+
+        Arrow::new(
+            Line::new(Point::new(0, 3), Point::new(12, 3)),
+            ArrowHead::filled(5, 7),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "        #    ",
+            "        ###  ",
+            "        #### ",
+            "#############",
+            "        #### ",
+            "        ##   ",
+            "        #    ",
+        ]);
+    }
+
+    #[test]
+    fn open_head() {
+        let mut display = MockDisplay::new();
+
+        Arrow::new(
+            Line::new(Point::new(0, 5), Point::new(12, 5)),
+            ArrowHead::open(6, 11),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "       #     ",
+            "        #    ",
+            "         #   ",
+            "          #  ",
+            "           # ",
+            "#############",
+            "           # ",
+            "          #  ",
+            "         #   ",
+            "        #    ",
+            "       #     ",
+        ]);
+    }
+
+    #[test]
+    fn thick_open_head_and_tail() {
+        let mut display = MockDisplay::new();
+
+        Arrow::new(
+            Line::new(Point::new(1, 6), Point::new(20, 6)),
+            ArrowHead::open(6, 11),
+        )
+        .with_tail(ArrowHead::filled(6, 11))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "                     ",
+            "      #        #     ",
+            "     ##        ##    ",
+            "    ###        ###   ",
+            "   ####        ####  ",
+            "  ################## ",
+            " ####################",
+            "  ################## ",
+            "   ####        ####  ",
+            "    ###        ###   ",
+            "     ##        ##    ",
+            "      #        #     ",
+        ]);
+    }
+
+    #[test]
+    fn pixels_and_bounding_box() {
+        let heads = [ArrowHead::filled(7, 9), ArrowHead::open(8, 11)];
+
+        for &(start, end) in &[
+            (Point::new(5, 5), Point::new(50, 20)),
+            (Point::new(50, 50), Point::new(10, 30)),
+            (Point::new(30, 5), Point::new(32, 55)),
+            (Point::new(10, 12), Point::new(14, 12)),
+        ] {
+            for &head in heads.iter() {
+                for &tail in heads.iter() {
+                    for stroke_width in 1..5 {
+                        let styled = Arrow::new(Line::new(start, end), head)
+                            .with_tail(tail)
+                            .into_styled(PrimitiveStyle::with_stroke(
+                                BinaryColor::On,
+                                stroke_width,
+                            ));
+
+                        // MockDisplay panics if a pixel is drawn more than once.
+                        let mut display = MockDisplay::new();
+                        styled.pixels().draw(&mut display).unwrap();
+
+                        let affected_area = display.affected_area();
+                        assert_eq!(
+                            styled.bounding_box().intersection(&affected_area),
+                            affected_area,
+                            "{:?} {:?} {}",
+                            start,
+                            end,
+                            stroke_width
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transparent() {
+        let arrow = Arrow::new(
+            Line::new(Point::new(0, 5), Point::new(12, 5)),
+            ArrowHead::filled(5, 10),
+        );
+
+        assert_eq!(
+            arrow
+                .into_styled(PrimitiveStyle::<BinaryColor>::with_fill(BinaryColor::On))
+                .pixels()
+                .next(),
+            None
+        );
+    }
+}
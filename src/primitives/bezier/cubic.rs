@@ -0,0 +1,367 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{div_round, Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        bezier::{
+            second_difference, segment_count, subdivide, Points, StyledPixelsIterator, VertexBuffer,
+        },
+        polyline::PolylineBuf,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PointsIter, Primitive, PrimitiveStyle, Rectangle,
+    },
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
+    },
+};
+
+/// Cubic Bézier curve primitive
+///
+/// A cubic Bézier curve starts at `start` and ends at `end`. The curve leaves `start` in the
+/// direction of `control1` and arrives at `end` from the direction of `control2`, but doesn't
+/// pass through the control points.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, CubicBezier},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// CubicBezier::new(
+///     Point::new(5, 30),
+///     Point::new(20, 0),
+///     Point::new(40, 60),
+///     Point::new(55, 30),
+/// )
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::YELLOW, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct CubicBezier {
+    /// Start point
+    pub start: Point,
+
+    /// First control point
+    pub control1: Point,
+
+    /// Second control point
+    pub control2: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl CubicBezier {
+    /// Creates a new cubic Bézier curve.
+    pub const fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Returns the curve subdivided into line segments.
+    fn polyline(&self) -> PolylineBuf<VertexBuffer> {
+        let second_differences = second_difference(self.start, self.control1, self.control2)
+            .max(second_difference(self.control1, self.control2, self.end));
+        let segments = segment_count(second_differences, 3);
+
+        let n3 = i64::from(segments).pow(3);
+
+        subdivide(segments, |u, t| {
+            let weights = [u * u * u, 3 * u * u * t, 3 * u * t * t, t * t * t];
+
+            let x = weights[0] * i64::from(self.start.x)
+                + weights[1] * i64::from(self.control1.x)
+                + weights[2] * i64::from(self.control2.x)
+                + weights[3] * i64::from(self.end.x);
+            let y = weights[0] * i64::from(self.start.y)
+                + weights[1] * i64::from(self.control1.y)
+                + weights[2] * i64::from(self.control2.y)
+                + weights[3] * i64::from(self.end.y);
+
+            // The vertices are calculated exactly and rounded to the nearest pixel.
+            Point::new(div_round(x, n3) as i32, div_round(y, n3) as i32)
+        })
+    }
+}
+
+impl Primitive for CubicBezier {}
+
+impl PointsIter for CubicBezier {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points {
+            iter: self.polyline().points(),
+        }
+    }
+}
+
+impl Dimensions for CubicBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.polyline().bounding_box()
+    }
+}
+
+impl Transform for CubicBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `CubicBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(10, 0),
+    ///     Point::new(20, 0),
+    ///     Point::new(25, 10),
+    /// );
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Point::new(15, 20));
+    /// assert_eq!(moved.control1, Point::new(20, 10));
+    /// assert_eq!(moved.control2, Point::new(30, 10));
+    /// assert_eq!(moved.end, Point::new(35, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control1: self.control1 + by,
+            control2: self.control2 + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(10, 0),
+    ///     Point::new(20, 0),
+    ///     Point::new(25, 10),
+    /// );
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(curve.start, Point::new(15, 20));
+    /// assert_eq!(curve.control1, Point::new(20, 10));
+    /// assert_eq!(curve.control2, Point::new(30, 10));
+    /// assert_eq!(curve.end, Point::new(35, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control1 += by;
+        self.control2 += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl Rotate for CubicBezier {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::new(
+            rotate_point_90(self.start, center),
+            rotate_point_90(self.control1, center),
+            rotate_point_90(self.control2, center),
+            rotate_point_90(self.end, center),
+        )
+    }
+}
+
+impl Mirror for CubicBezier {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_x(self.start, axis),
+            mirror_point_x(self.control1, axis),
+            mirror_point_x(self.control2, axis),
+            mirror_point_x(self.end, axis),
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_y(self.start, axis),
+            mirror_point_y(self.control1, axis),
+            mirror_point_y(self.control2, axis),
+            mirror_point_y(self.end, axis),
+        )
+    }
+}
+
+impl Scale for CubicBezier {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::new(
+            scale_point(self.start, center, num, denom),
+            scale_point(self.control1, center, num, denom),
+            scale_point(self.control2, center, num, denom),
+            scale_point(self.end, center, num, denom),
+        )
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for CubicBezier {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator {
+            iter: self.polyline().pixels(style),
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for CubicBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.polyline().draw_styled(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for CubicBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        self.polyline().styled_bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn straight_line() {
+        let curve = CubicBezier::new(
+            Point::new(2, 5),
+            Point::new(10, 5),
+            Point::new(15, 5),
+            Point::new(30, 5),
+        );
+
+        assert!(curve
+            .points()
+            .eq(Line::new(Point::new(2, 5), Point::new(30, 5)).points()));
+    }
+
+    #[test]
+    fn dimensions() {
+        let curve = CubicBezier::new(
+            Point::new(0, 20),
+            Point::new(0, 0),
+            Point::new(20, 0),
+            Point::new(20, 20),
+        );
+
+        // The curve only reaches three quarters of the way to the control points.
+        assert_eq!(
+            curve.bounding_box(),
+            Rectangle::new(Point::new(0, 5), Size::new(21, 16))
+        );
+    }
+
+    #[test]
+    fn thin_stroke() {
+        let mut display = MockDisplay::new();
+
+        CubicBezier::new(
+            Point::new(0, 8),
+            Point::new(8, -8),
+            Point::new(8, 24),
+            Point::new(16, 8),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "                 ",
+            "                 ",
+            "                 ",
+            "    ##           ",
+            "  ##  #          ",
+            " #    #          ",
+            " #     #         ",
+            "#      #         ",
+            "#       #       #",
+            "         #     # ",
+            "         #     # ",
+            "          #   #  ",
+            "          ##  #  ",
+            "            ##   ",
+        ]);
+    }
+
+    #[test]
+    fn styled() {
+        let curve = CubicBezier::new(
+            Point::new(5, 30),
+            Point::new(20, -10),
+            Point::new(30, 60),
+            Point::new(55, 20),
+        );
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 1..6 {
+                let styled = curve.into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .stroke_alignment(*alignment)
+                        .build(),
+                );
+
+                let mut drawable = MockDisplay::new();
+                styled.draw(&mut drawable).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&drawable);
+
+                // The bounding box of thick polylines isn't always tight, which is why only
+                // containment is checked here.
+                let affected_area = drawable.affected_area();
+                assert_eq!(
+                    styled.bounding_box().intersection(&affected_area),
+                    affected_area,
+                    "{:?}, {}",
+                    alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Bézier curve primitives.
+//!
+//! Bézier curves are drawn by subdividing the curve into straight line segments. The number of
+//! segments is chosen based on the shape of the curve to keep the distance between the segments
+//! and the exact curve below half a pixel. The number of segments is limited to
+//! [`MAX_SEGMENTS`], which means that very large curves can be less accurate.
+//!
+//! Styled curves support the same style options as a [`Polyline`].
+//!
+//! [`MAX_SEGMENTS`]: constant.MAX_SEGMENTS.html
+//! [`Polyline`]: ../polyline/struct.Polyline.html
+
+use crate::{
+    geometry::Point,
+    pixelcolor::PixelColor,
+    primitives::polyline::{BufPoints, BufStyledPixelsIterator, PolylineBuf},
+    Pixel,
+};
+
+mod cubic;
+mod quadratic;
+
+pub use cubic::CubicBezier;
+pub use quadratic::QuadraticBezier;
+
+/// The maximum number of line segments a curve is subdivided into.
+pub const MAX_SEGMENTS: u32 = 31;
+
+/// Vertex buffer with enough space for a curve with `MAX_SEGMENTS` segments.
+type VertexBuffer = [Point; MAX_SEGMENTS as usize + 1];
+
+/// Iterator over all points on a Bézier curve.
+#[derive(Clone, Debug)]
+pub struct Points {
+    iter: BufPoints<VertexBuffer>,
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// Pixel iterator for each pixel in a styled Bézier curve.
+#[derive(Clone, Debug)]
+pub struct StyledPixelsIterator<C> {
+    iter: BufStyledPixelsIterator<VertexBuffer, C>,
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// Returns the number of segments required to approximate a curve.
+///
+/// The number of segments is calculated using Wang's formula with a tolerance of half a pixel.
+/// `second_differences` is the largest squared length of the second differences of the control
+/// points and `degree` is the degree of the curve.
+pub(in crate::primitives) fn segment_count(second_differences: u64, degree: u64) -> u32 {
+    // The maximum distance between the curve and the segments is `d * (d - 1) / 8 * M / n^2`,
+    // with the degree `d`, the number of segments `n` and the length of the largest second
+    // difference `M`. Both sides are squared to avoid sqrt() costs.
+    let factor = (degree * (degree - 1))
+        .pow(2)
+        .saturating_mul(second_differences);
+
+    (1..MAX_SEGMENTS)
+        .find(|n| 16 * u64::from(*n).pow(4) >= factor)
+        .unwrap_or(MAX_SEGMENTS)
+}
+
+/// Returns the squared length of the second difference of three points.
+fn second_difference(a: Point, b: Point, c: Point) -> u64 {
+    let dx = (i64::from(a.x) - 2 * i64::from(b.x) + i64::from(c.x)).abs() as u64;
+    let dy = (i64::from(a.y) - 2 * i64::from(b.y) + i64::from(c.y)).abs() as u64;
+
+    dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+}
+
+/// Creates a polyline from the vertices returned by `vertex`.
+///
+/// `vertex` is called with the weights `(n - i, i)` for every vertex `i` of a curve with `n`
+/// segments.
+fn subdivide<F>(segments: u32, vertex: F) -> PolylineBuf<VertexBuffer>
+where
+    F: Fn(i64, i64) -> Point,
+{
+    let mut polyline = PolylineBuf::new([Point::zero(); MAX_SEGMENTS as usize + 1]);
+
+    let n = i64::from(segments);
+    for i in 0..=n {
+        // The buffer is large enough for the maximum number of segments.
+        let _ = polyline.push(vertex(n - i, i));
+    }
+
+    polyline
+}
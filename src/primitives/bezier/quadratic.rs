@@ -0,0 +1,345 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{div_round, Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        bezier::{
+            second_difference, segment_count, subdivide, Points, StyledPixelsIterator, VertexBuffer,
+        },
+        polyline::PolylineBuf,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PointsIter, Primitive, PrimitiveStyle, Rectangle,
+    },
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
+    },
+};
+
+/// Quadratic Bézier curve primitive
+///
+/// A quadratic Bézier curve starts at `start` and ends at `end`. The curve is pulled towards the
+/// `control` point, but doesn't pass through it.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, QuadraticBezier},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// QuadraticBezier::new(Point::new(5, 50), Point::new(30, 0), Point::new(55, 50))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::YELLOW, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct QuadraticBezier {
+    /// Start point
+    pub start: Point,
+
+    /// Control point
+    pub control: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl QuadraticBezier {
+    /// Creates a new quadratic Bézier curve.
+    pub const fn new(start: Point, control: Point, end: Point) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    /// Returns the curve subdivided into line segments.
+    fn polyline(&self) -> PolylineBuf<VertexBuffer> {
+        let segments = segment_count(second_difference(self.start, self.control, self.end), 2);
+
+        let n2 = i64::from(segments).pow(2);
+
+        subdivide(segments, |u, t| {
+            let weights = [u * u, 2 * u * t, t * t];
+
+            let x = weights[0] * i64::from(self.start.x)
+                + weights[1] * i64::from(self.control.x)
+                + weights[2] * i64::from(self.end.x);
+            let y = weights[0] * i64::from(self.start.y)
+                + weights[1] * i64::from(self.control.y)
+                + weights[2] * i64::from(self.end.y);
+
+            // The vertices are calculated exactly and rounded to the nearest pixel.
+            Point::new(div_round(x, n2) as i32, div_round(y, n2) as i32)
+        })
+    }
+}
+
+impl Primitive for QuadraticBezier {}
+
+impl PointsIter for QuadraticBezier {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points {
+            iter: self.polyline().points(),
+        }
+    }
+}
+
+impl Dimensions for QuadraticBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.polyline().bounding_box()
+    }
+}
+
+impl Transform for QuadraticBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `QuadraticBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = QuadraticBezier::new(Point::new(5, 10), Point::new(15, 0), Point::new(25, 10));
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Point::new(15, 20));
+    /// assert_eq!(moved.control, Point::new(25, 10));
+    /// assert_eq!(moved.end, Point::new(35, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control: self.control + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve =
+    ///     QuadraticBezier::new(Point::new(5, 10), Point::new(15, 0), Point::new(25, 10));
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(curve.start, Point::new(15, 20));
+    /// assert_eq!(curve.control, Point::new(25, 10));
+    /// assert_eq!(curve.end, Point::new(35, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl Rotate for QuadraticBezier {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::new(
+            rotate_point_90(self.start, center),
+            rotate_point_90(self.control, center),
+            rotate_point_90(self.end, center),
+        )
+    }
+}
+
+impl Mirror for QuadraticBezier {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_x(self.start, axis),
+            mirror_point_x(self.control, axis),
+            mirror_point_x(self.end, axis),
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_y(self.start, axis),
+            mirror_point_y(self.control, axis),
+            mirror_point_y(self.end, axis),
+        )
+    }
+}
+
+impl Scale for QuadraticBezier {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::new(
+            scale_point(self.start, center, num, denom),
+            scale_point(self.control, center, num, denom),
+            scale_point(self.end, center, num, denom),
+        )
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for QuadraticBezier {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator {
+            iter: self.polyline().pixels(style),
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for QuadraticBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.polyline().draw_styled(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for QuadraticBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        self.polyline().styled_bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, LineCap, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn straight_line() {
+        let curve = QuadraticBezier::new(Point::new(2, 5), Point::new(10, 5), Point::new(30, 5));
+
+        assert!(curve
+            .points()
+            .eq(Line::new(Point::new(2, 5), Point::new(30, 5)).points()));
+    }
+
+    #[test]
+    fn dimensions() {
+        let curve = QuadraticBezier::new(Point::new(0, 20), Point::new(10, 0), Point::new(20, 20));
+
+        // The curve only reaches half way to the control point.
+        assert_eq!(
+            curve.bounding_box(),
+            Rectangle::new(Point::new(0, 10), Size::new(21, 11))
+        );
+    }
+
+    #[test]
+    fn thin_stroke() {
+        let mut display = MockDisplay::new();
+
+        QuadraticBezier::new(Point::new(0, 8), Point::new(8, -8), Point::new(16, 8))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "        ##       ",
+            "      ##  ##     ",
+            "    ##      #    ",
+            "   #         #   ",
+            "   #         #   ",
+            "  #           #  ",
+            " #             # ",
+            " #             # ",
+            "#               #",
+        ]);
+    }
+
+    #[test]
+    fn styled() {
+        let curve =
+            QuadraticBezier::new(Point::new(5, 40), Point::new(20, -10), Point::new(50, 30));
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 1..6 {
+                let styled = curve.into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .stroke_alignment(*alignment)
+                        .build(),
+                );
+
+                let mut drawable = MockDisplay::new();
+                styled.draw(&mut drawable).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&drawable);
+
+                // The bounding box of thick polylines isn't always tight, which is why only
+                // containment is checked here.
+                let affected_area = drawable.affected_area();
+                assert_eq!(
+                    styled.bounding_box().intersection(&affected_area),
+                    affected_area,
+                    "{:?}, {}",
+                    alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn line_caps() {
+        let curve = QuadraticBezier::new(Point::new(5, 5), Point::new(10, 5), Point::new(20, 5));
+        let line = Line::new(curve.start, curve.end);
+
+        for &line_cap in &[LineCap::Butt, LineCap::Square, LineCap::Round] {
+            for &stroke_width in &[3, 5] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .line_cap(line_cap)
+                    .build();
+
+                let mut display = MockDisplay::new();
+                curve.into_styled(style).draw(&mut display).unwrap();
+
+                let mut expected = MockDisplay::new();
+                line.into_styled(style).draw(&mut expected).unwrap();
+
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{:?}, {}", line_cap, stroke_width)
+                });
+                assert_eq!(
+                    curve.into_styled(style).bounding_box(),
+                    line.into_styled(style).bounding_box(),
+                    "{:?}, {}",
+                    line_cap,
+                    stroke_width
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,514 @@
+//! The Catmull–Rom spline primitive
+
+use crate::{
+    geometry::{div_round, Dimensions, Point, Size},
+    primitives::{bezier::segment_count, PointsIter, Polyline, Primitive, Rectangle},
+    transform::Transform,
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+
+/// Catmull–Rom spline primitive
+///
+/// A Catmull–Rom spline is a smooth curve that passes through all of its vertices, which makes it
+/// useful to draw smoothed plots of sampled data. The curve between two neighboring vertices is a
+/// cubic curve, whose tangents are determined by the vertices before and after the segment.
+///
+/// The spline is drawn by subdividing each segment into straight lines. The number of lines per
+/// segment is chosen based on the curvature of the segment, which keeps the distance to the exact
+/// curve below half a pixel for all but very large curves.
+///
+/// Styled splines support the same style options as a [`Polyline`]. Thin splines are drawn without
+/// a buffer, by calculating the subdivided vertices while the spline is drawn. Thick splines are
+/// drawn in chunks of consecutive lines, which are stored in a small buffer on the stack. The
+/// lines in each chunk are joined like the segments of a polyline, but the ends of neighboring
+/// chunks are drawn like the ends of separate polylines, which can leave small seams in long
+/// splines. Use [`subdivide`] to store all subdivided vertices in a buffer and draw the returned
+/// [`Polyline`] instead to draw long thick splines without seams. The points on a thin spline can
+/// be iterated without a buffer by using [`points`].
+///
+/// [`subdivide`]: #method.subdivide
+/// [`points`]: ../trait.PointsIter.html#tymethod.points
+/// [`Polyline`]: ../polyline/struct.Polyline.html
+///
+/// # Examples
+///
+/// ## Draw a smoothed plot
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{CatmullRomSpline, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let samples = [
+///     Point::new(5, 40),
+///     Point::new(15, 20),
+///     Point::new(25, 30),
+///     Point::new(35, 10),
+///     Point::new(45, 25),
+///     Point::new(55, 20),
+/// ];
+///
+/// let spline = CatmullRomSpline::new(&samples);
+///
+/// let mut buffer = [Point::zero(); 64];
+/// spline
+///     .subdivide(&mut buffer)
+///     .unwrap()
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw a styled spline
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{CatmullRomSpline, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let vertices = [
+///     Point::new(5, 40),
+///     Point::new(25, 10),
+///     Point::new(45, 30),
+/// ];
+///
+/// CatmullRomSpline::new(&vertices)
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 5))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw a thin spline without a buffer
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::CatmullRomSpline,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let samples = [
+///     Point::new(5, 40),
+///     Point::new(15, 20),
+///     Point::new(25, 30),
+///     Point::new(35, 10),
+/// ];
+///
+/// CatmullRomSpline::new(&samples)
+///     .points()
+///     .map(|point| Pixel(point, Rgb565::GREEN))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct CatmullRomSpline<'a> {
+    /// An offset to apply to the spline as a whole
+    pub translate: Point,
+
+    /// All vertices the spline passes through
+    pub vertices: &'a [Point],
+}
+
+impl<'a> CatmullRomSpline<'a> {
+    /// Creates a new Catmull–Rom spline from a list of vertices.
+    ///
+    /// The end tangents are calculated by repeating the first and last vertex. If fewer than two
+    /// vertices are provided, the spline will not render anything.
+    pub const fn new(vertices: &'a [Point]) -> Self {
+        Self {
+            translate: Point::zero(),
+            vertices,
+        }
+    }
+
+    /// Returns the number of vertices after the spline is subdivided into straight lines.
+    ///
+    /// This is the minimum length of the buffer passed to [`subdivide`].
+    ///
+    /// [`subdivide`]: #method.subdivide
+    pub fn subdivided_len(&self) -> usize {
+        self.subdivided_vertices().count()
+    }
+
+    /// Subdivides the spline into straight lines.
+    ///
+    /// The subdivided vertices are written to `buffer`, which must contain at least
+    /// [`subdivided_len`] elements, otherwise `None` is returned. The returned polyline borrows
+    /// its vertices from `buffer` and uses the same translation as this spline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::CatmullRomSpline};
+    ///
+    /// let vertices = [Point::new(0, 10), Point::new(10, 0), Point::new(20, 10)];
+    /// let spline = CatmullRomSpline::new(&vertices);
+    ///
+    /// let mut buffer = [Point::zero(); 32];
+    /// assert!(spline.subdivided_len() <= buffer.len());
+    ///
+    /// let polyline = spline.subdivide(&mut buffer).unwrap();
+    ///
+    /// // The subdivided polyline passes through all vertices of the spline.
+    /// assert_eq!(polyline.vertices.first(), Some(&Point::new(0, 10)));
+    /// assert!(polyline.vertices.contains(&Point::new(10, 0)));
+    /// assert_eq!(polyline.vertices.last(), Some(&Point::new(20, 10)));
+    /// ```
+    ///
+    /// [`subdivided_len`]: #method.subdivided_len
+    pub fn subdivide<'b>(&self, buffer: &'b mut [Point]) -> Option<Polyline<'b>> {
+        let mut len = 0;
+
+        for vertex in self.subdivided_vertices() {
+            *buffer.get_mut(len)? = vertex;
+            len += 1;
+        }
+
+        Some(Polyline::new(&buffer[0..len]).translate(self.translate))
+    }
+
+    /// Returns an iterator over the untranslated vertices of the subdivided spline.
+    fn subdivided_vertices(&self) -> SubdividedVertices<'a> {
+        SubdividedVertices::new(self.vertices)
+    }
+}
+
+impl<'a> Primitive for CatmullRomSpline<'a> {}
+
+impl<'a> PointsIter for CatmullRomSpline<'a> {
+    type Iter = Points<'a>;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl<'a> Dimensions for CatmullRomSpline<'a> {
+    fn bounding_box(&self) -> Rectangle {
+        match self.vertices {
+            [] => Rectangle::zero(),
+            [v] => Rectangle::new(*v + self.translate, Size::zero()),
+            _ => Rectangle::from_points(self.subdivided_vertices().map(|v| v + self.translate)),
+        }
+    }
+}
+
+impl<'a> Transform for CatmullRomSpline<'a> {
+    /// Translate the spline from its current position to a new position by (x, y) pixels,
+    /// returning a new `CatmullRomSpline`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CatmullRomSpline;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let spline = CatmullRomSpline::new(&points);
+    /// let moved = spline.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(spline.bounding_box().top_left, Point::new(5, 7));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the spline from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CatmullRomSpline;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let mut spline = CatmullRomSpline::new(&points);
+    /// spline.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(spline.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+/// Single segment of a spline between two neighboring vertices.
+///
+/// The segment is stored as a cubic Bézier curve. The control points are scaled by 6 to make
+/// them exact integers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+struct Segment {
+    control_points: [(i64, i64); 4],
+    lines: u32,
+}
+
+impl Segment {
+    /// Creates the segment that starts at the vertex with the given index.
+    fn new(vertices: &[Point], index: usize) -> Self {
+        let vertex = |index: usize| {
+            let v = vertices[index.min(vertices.len() - 1)];
+            (i64::from(v.x), i64::from(v.y))
+        };
+
+        let p0 = vertex(index.saturating_sub(1));
+        let p1 = vertex(index);
+        let p2 = vertex(index + 1);
+        let p3 = vertex(index + 2);
+
+        let control_points = [
+            (6 * p1.0, 6 * p1.1),
+            (6 * p1.0 + p2.0 - p0.0, 6 * p1.1 + p2.1 - p0.1),
+            (6 * p2.0 - p3.0 + p1.0, 6 * p2.1 - p3.1 + p1.1),
+            (6 * p2.0, 6 * p2.1),
+        ];
+
+        // The second differences of the scaled control points, which are used to determine the
+        // number of lines, are scaled by 6 as well.
+        let second_difference = |a: (i64, i64), b: (i64, i64), c: (i64, i64)| {
+            let dx = (a.0 - 2 * b.0 + c.0).abs() as u64;
+            let dy = (a.1 - 2 * b.1 + c.1).abs() as u64;
+
+            let length_squared = dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy));
+
+            (length_squared + 35) / 36
+        };
+
+        let second_differences =
+            second_difference(control_points[0], control_points[1], control_points[2]).max(
+                second_difference(control_points[1], control_points[2], control_points[3]),
+            );
+
+        Self {
+            control_points,
+            lines: segment_count(second_differences, 3),
+        }
+    }
+
+    /// Returns the end point of the given line.
+    fn line_end(&self, line: u32) -> Point {
+        let t = i64::from(line);
+        let u = i64::from(self.lines) - t;
+
+        let weights = [u * u * u, 3 * u * u * t, 3 * u * t * t, t * t * t];
+
+        let (x, y) = weights.iter().zip(self.control_points.iter()).fold(
+            (0, 0),
+            |(x, y), (weight, control_point)| {
+                (x + weight * control_point.0, y + weight * control_point.1)
+            },
+        );
+
+        let denominator = 6 * i64::from(self.lines).pow(3);
+
+        Point::new(
+            div_round(x, denominator) as i32,
+            div_round(y, denominator) as i32,
+        )
+    }
+}
+
+/// Iterator over the vertices of a subdivided spline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub(in crate::primitives) struct SubdividedVertices<'a> {
+    vertices: &'a [Point],
+    first: Option<Point>,
+    next_segment: usize,
+    segment: Segment,
+    line: u32,
+}
+
+impl<'a> SubdividedVertices<'a> {
+    /// Creates a new iterator.
+    fn new(vertices: &'a [Point]) -> Self {
+        Self {
+            vertices,
+            first: vertices.first().copied(),
+            next_segment: 0,
+            segment: Segment::default(),
+            line: 0,
+        }
+    }
+}
+
+impl Iterator for SubdividedVertices<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+
+        if self.line >= self.segment.lines {
+            if self.next_segment + 1 >= self.vertices.len() {
+                return None;
+            }
+
+            self.segment = Segment::new(self.vertices, self.next_segment);
+            self.next_segment += 1;
+            self.line = 0;
+        }
+
+        self.line += 1;
+
+        Some(self.segment.line_end(self.line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, PrimitiveStyle},
+        Drawable,
+    };
+
+    const SAMPLES: [Point; 6] = [
+        Point::new(5, 40),
+        Point::new(15, 20),
+        Point::new(25, 30),
+        Point::new(35, 10),
+        Point::new(45, 25),
+        Point::new(55, 20),
+    ];
+
+    #[test]
+    fn special_cases() {
+        let empty = CatmullRomSpline::new(&[]);
+        assert_eq!(empty.subdivided_len(), 0);
+        assert_eq!(empty.points().next(), None);
+        assert_eq!(empty.bounding_box(), Rectangle::zero());
+
+        let vertices = [Point::new(3, 4)];
+        let single = CatmullRomSpline::new(&vertices);
+        assert_eq!(single.subdivided_len(), 1);
+        assert_eq!(single.points().next(), None);
+        assert_eq!(
+            single.bounding_box(),
+            Rectangle::new(Point::new(3, 4), Size::zero())
+        );
+    }
+
+    #[test]
+    fn passes_through_vertices() {
+        let spline = CatmullRomSpline::new(&SAMPLES);
+
+        let mut buffer = [Point::zero(); 128];
+        let polyline = spline.subdivide(&mut buffer).unwrap();
+
+        assert_eq!(polyline.vertices.len(), spline.subdivided_len());
+
+        let mut subdivided = polyline.vertices.iter();
+        for vertex in SAMPLES.iter() {
+            assert!(
+                subdivided.any(|v| v == vertex),
+                "vertex {:?} not in subdivided spline",
+                vertex
+            );
+        }
+        assert_eq!(subdivided.next(), None);
+    }
+
+    #[test]
+    fn colinear_vertices() {
+        let vertices = [Point::new(0, 5), Point::new(10, 5), Point::new(20, 5)];
+        let spline = CatmullRomSpline::new(&vertices);
+
+        assert!(spline
+            .points()
+            .eq(Line::new(Point::new(0, 5), Point::new(20, 5)).points()));
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let spline = CatmullRomSpline::new(&SAMPLES);
+
+        let mut buffer = [Point::zero(); 128];
+        let len = spline.subdivided_len();
+
+        assert!(spline.subdivide(&mut buffer[0..len - 1]).is_none());
+        assert!(spline.subdivide(&mut buffer[0..len]).is_some());
+    }
+
+    #[test]
+    fn points_equals_polyline() {
+        let spline = CatmullRomSpline::new(&SAMPLES).translate(Point::new(1, 2));
+
+        let mut buffer = [Point::zero(); 128];
+        let polyline = spline.subdivide(&mut buffer).unwrap();
+
+        assert!(spline.points().eq(polyline.points()));
+        assert_eq!(spline.bounding_box(), polyline.bounding_box());
+    }
+
+    #[test]
+    fn thin_stroke() {
+        let mut display = MockDisplay::new();
+
+        let mut buffer = [Point::zero(); 32];
+        CatmullRomSpline::new(&[
+            Point::new(0, 8),
+            Point::new(6, 2),
+            Point::new(12, 8),
+            Point::new(18, 2),
+        ])
+        .subdivide(&mut buffer)
+        .unwrap()
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "                   ",
+            "                   ",
+            "      #           #",
+            "    ## #         # ",
+            "   #    #       #  ",
+            "   #     #     #   ",
+            "  #       #    #   ",
+            " #         #  #    ",
+            "#           ##     ",
+        ]);
+    }
+
+    #[test]
+    fn thick_stroke() {
+        let spline = CatmullRomSpline::new(&SAMPLES);
+
+        let mut buffer = [Point::zero(); 128];
+        let polyline = spline.subdivide(&mut buffer).unwrap();
+
+        for stroke_width in 2..6 {
+            let styled =
+                polyline.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let affected_area = display.affected_area();
+            assert_eq!(
+                styled.bounding_box().intersection(&affected_area),
+                affected_area,
+                "{}",
+                stroke_width
+            );
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    geometry::Point,
+    primitives::{
+        catmull_rom_spline::{CatmullRomSpline, SubdividedVertices},
+        line::{self, Line},
+        PointsIter,
+    },
+};
+
+/// An iterator over all pixel positions on the spline
+///
+/// The subdivided vertices are calculated while the iterator advances, which means that no
+/// buffer is required to iterate over the points.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Points<'a> {
+    vertices: SubdividedVertices<'a>,
+    translate: Point,
+    segment_start: Point,
+    segment_iter: line::Points,
+    first_line: bool,
+}
+
+impl<'a> Points<'a> {
+    pub(in crate::primitives) fn new(spline: &CatmullRomSpline<'a>) -> Self {
+        let mut vertices = spline.subdivided_vertices();
+        let segment_start = vertices.next().unwrap_or_default() + spline.translate;
+
+        Self {
+            vertices,
+            translate: spline.translate,
+            segment_start,
+            segment_iter: line::Points::empty(),
+            first_line: true,
+        }
+    }
+}
+
+impl<'a> Iterator for Points<'a> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(p) = self.segment_iter.next() {
+                return Some(p);
+            }
+
+            let segment_end = self.vertices.next()? + self.translate;
+            self.segment_iter = Line::new(self.segment_start, segment_end).points();
+            self.segment_start = segment_end;
+
+            // Skip first point of all but the first line, otherwise we overlap with the previous
+            // line
+            if !self.first_line {
+                self.segment_iter.next();
+            }
+            self.first_line = false;
+        }
+    }
+}
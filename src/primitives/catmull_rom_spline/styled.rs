@@ -0,0 +1,279 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        catmull_rom_spline::CatmullRomSpline,
+        styled::{StyledDimensions, StyledDrawable},
+        PointsIter, Polyline, PrimitiveStyle, Rectangle,
+    },
+    transform::Transform,
+    Pixel,
+};
+
+/// Number of vertices in the buffer which is used to draw thick splines.
+const CHUNK_LEN: usize = 32;
+
+impl CatmullRomSpline<'_> {
+    /// Calls `f` for consecutive chunks of the subdivided spline.
+    ///
+    /// Each chunk is passed to `f` as a polyline of up to `CHUNK_LEN` vertices and neighboring
+    /// chunks share one vertex. The stroke pattern in the style passed to `f` is shifted by the
+    /// length of the previous chunks, which continues a dashed stroke across the chunks.
+    fn for_each_chunk<C, E, F>(&self, style: &PrimitiveStyle<C>, mut f: F) -> Result<(), E>
+    where
+        C: PixelColor,
+        F: FnMut(Polyline<'_>, &PrimitiveStyle<C>) -> Result<(), E>,
+    {
+        let mut buffer = [Point::zero(); CHUNK_LEN];
+        let mut style = *style;
+        let mut len = 0;
+
+        let mut vertices = self.subdivided_vertices().peekable();
+        while let Some(vertex) = vertices.next() {
+            buffer[len] = vertex;
+            len += 1;
+
+            if len < CHUNK_LEN && vertices.peek().is_some() {
+                continue;
+            }
+
+            let chunk = &buffer[0..len];
+            f(Polyline::new(chunk).translate(self.translate), &style)?;
+
+            // Dash positions on thick polylines are measured along the major axis of each line.
+            let length = chunk
+                .windows(2)
+                .map(|line| {
+                    let delta = line[1] - line[0];
+                    u64::from(delta.x.abs().max(delta.y.abs()) as u32)
+                })
+                .sum();
+            style.stroke_pattern = style.stroke_pattern.advanced_by(length);
+
+            buffer[0] = buffer[len - 1];
+            len = 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for CatmullRomSpline<'_> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.stroke_width > 1 {
+            return self
+                .for_each_chunk(style, |polyline, style| polyline.draw_styled(style, target));
+        }
+
+        // Thin splines are drawn in the same way as a thin polyline, which uses the index of each
+        // point as its position along the stroke.
+        if let Some(stroke_color) = style.effective_stroke_color() {
+            let stroke_pattern = style.stroke_pattern;
+
+            target.draw_iter(
+                self.points()
+                    .zip(0..)
+                    .filter(|(_, position)| stroke_pattern.is_dash(*position))
+                    .map(|(point, _)| Pixel(point, stroke_color)),
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for CatmullRomSpline<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let mut corners: Option<(Point, Point)> = None;
+
+        let _ = self.for_each_chunk(style, |polyline, style| {
+            let bounding_box = polyline.styled_bounding_box(style);
+
+            // Polylines without a visible stroke return a zero sized bounding box.
+            if let Some(bottom_right) = bounding_box.bottom_right() {
+                let top_left = bounding_box.top_left;
+
+                corners = Some(match corners {
+                    Some((min, max)) => {
+                        (min.component_min(top_left), max.component_max(bottom_right))
+                    }
+                    None => (top_left, bottom_right),
+                });
+            }
+
+            Ok::<(), core::convert::Infallible>(())
+        });
+
+        if let Some((min, max)) = corners {
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::new(self.bounding_box().center(), Size::zero())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Dimensions,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment, StrokePattern},
+        Drawable,
+    };
+
+    const SAMPLES: [Point; 6] = [
+        Point::new(5, 40),
+        Point::new(15, 20),
+        Point::new(25, 30),
+        Point::new(35, 10),
+        Point::new(45, 25),
+        Point::new(55, 20),
+    ];
+
+    #[test]
+    fn styled_equals_subdivided_polyline() {
+        let spline = CatmullRomSpline::new(&SAMPLES).translate(Point::new(2, 3));
+
+        let mut buffer = [Point::zero(); 128];
+        let polyline = spline.subdivide(&mut buffer).unwrap();
+
+        for stroke_width in 1..6 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let mut expected = MockDisplay::new();
+            polyline.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            spline.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{}", stroke_width));
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let spline = CatmullRomSpline::new(&SAMPLES);
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            let styled = spline.into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(3)
+                    .stroke_alignment(*alignment)
+                    .build(),
+            );
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                styled.bounding_box(),
+                "{:?}",
+                alignment
+            );
+        }
+    }
+
+    #[test]
+    fn thin_long_spline_equals_subdivided_polyline() {
+        let mut vertices = [Point::zero(); 200];
+        for (i, vertex) in vertices.iter_mut().enumerate() {
+            *vertex = Point::new(i as i32 % 32 + 16, (i as i32 * 7) % 32 + 16);
+        }
+        let spline = CatmullRomSpline::new(&vertices).translate(Point::new(1, 2));
+
+        let mut buffer = [Point::zero(); 1024];
+        let polyline = spline.subdivide(&mut buffer).unwrap();
+        assert!(polyline.vertices.len() > 2 * CHUNK_LEN);
+
+        for &stroke_pattern in &[StrokePattern::Solid, StrokePattern::dashed(5, 3)] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(1)
+                .stroke_pattern(stroke_pattern)
+                .build();
+
+            let mut expected = MockDisplay::new();
+            expected.set_allow_overdraw(true);
+            polyline.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            spline.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", stroke_pattern));
+            assert_eq!(
+                spline.into_styled(style).bounding_box(),
+                polyline.into_styled(style).bounding_box()
+            );
+        }
+    }
+
+    #[test]
+    fn thick_long_spline() {
+        let mut vertices = [Point::zero(); 200];
+        for (i, vertex) in vertices.iter_mut().enumerate() {
+            *vertex = Point::new(i as i32 % 32 + 16, (i as i32 * 7) % 32 + 16);
+        }
+        let spline = CatmullRomSpline::new(&vertices);
+        let styled = spline.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        styled.draw(&mut display).unwrap();
+
+        // The spline isn't cut off.
+        for vertex in vertices.iter() {
+            assert_eq!(display.get_pixel(*vertex), Some(BinaryColor::On));
+        }
+        assert_eq!(display.affected_area(), styled.bounding_box());
+    }
+
+    #[test]
+    fn dashed_thick_stroke_continues_across_chunks() {
+        let mut vertices = [Point::zero(); 40];
+        for (i, vertex) in vertices.iter_mut().enumerate() {
+            *vertex = Point::new(i as i32, 5);
+        }
+        let spline = CatmullRomSpline::new(&vertices);
+        assert!(spline.subdivided_len() > CHUNK_LEN);
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_pattern(StrokePattern::dashed(5, 3))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        spline.into_styled(style).draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        Line::new(vertices[0], vertices[39])
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+}
@@ -0,0 +1,362 @@
+//! The chord primitive
+
+use crate::{
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_360DEG, ANGLE_90DEG},
+        Angle, Dimensions, Point, Real, Size, Trigonometry,
+    },
+    primitives::{
+        common::{LineSide, LinearEquation, NORMAL_VECTOR_SCALE},
+        Circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
+    },
+    transform::{Mirror, Rotate, Scale, Transform},
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Chord primitive
+///
+/// A chord is the part of a circle that is cut off by a straight line. The line connects the
+/// start and end points of the arc defined by `angle_start` and `angle_sweep`, which means that a
+/// chord has the same outline as an [`Arc`] with the same parameters, closed by a straight line.
+///
+/// [`Arc`]: ../arc/struct.Arc.html
+///
+/// # Examples
+///
+/// ## Create some chords with different styles
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Chord, PrimitiveStyle, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+///
+/// // Chord with 1 pixel wide white stroke with top-left point at (10, 20) with a diameter of 30
+/// Chord::new(Point::new(10, 20), 30, 0.0.deg(), 90.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Chord with styled stroke and fill with top-left point at (10, 20) with a diameter of 30
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// Chord::new(Point::new(10, 20), 30, 180.0.deg(), -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw a liquid level indicator
+///
+/// The filled part of a round tank is a chord that is symmetric around the bottom of the circle.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Chord, Circle, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+///
+/// let tank = Circle::with_center(Point::new(32, 32), 40);
+///
+/// // The liquid covers the bottom 120° of the tank.
+/// Chord::from_circle(tank, 210.0.deg(), 120.0.deg())
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///     .draw(&mut display)?;
+///
+/// tank.into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Chord {
+    /// Top-left point of the bounding-box of the circle supporting the chord
+    pub top_left: Point,
+
+    /// Diameter of the circle supporting the chord
+    pub diameter: u32,
+
+    /// Angle at which the arc of the chord starts
+    pub angle_start: Angle,
+
+    /// Angle defining the arc sweep starting at angle_start
+    pub angle_sweep: Angle,
+}
+
+impl Chord {
+    /// Create a new chord delimited with a top-left point with a specific diameter and start and sweep angles
+    pub const fn new(
+        top_left: Point,
+        diameter: u32,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        Chord {
+            top_left,
+            diameter,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Create a new chord centered around a given point with a specific diameter and start and sweep angles
+    pub fn with_center(
+        center: Point,
+        diameter: u32,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        Self::from_circle(
+            Circle::with_center(center, diameter),
+            angle_start,
+            angle_sweep,
+        )
+    }
+
+    /// Creates a chord based on a circle.
+    ///
+    /// The resulting chord will match the `top_left` and `diameter` of the base circle.
+    pub fn from_circle(circle: Circle, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Chord {
+            top_left: circle.top_left,
+            diameter: circle.diameter,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Returns a circle with the same `top_left` and `diameter` as this chord.
+    pub fn to_circle(&self) -> Circle {
+        Circle::new(self.top_left, self.diameter)
+    }
+
+    /// Return the center point of the chord
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns the straight edge of the chord.
+    ///
+    /// The returned equation uses coordinates relative to the center of the circle, which are
+    /// scaled up by a factor of 2. Points inside the chord are on the left side of the line.
+    /// `None` is returned if the absolute value of the sweep angle is >= 360°, because the chord
+    /// is equal to the whole circle in this case.
+    pub(in crate::primitives) fn straight_edge(&self) -> Option<LinearEquation> {
+        if self.angle_sweep.abs() >= ANGLE_360DEG {
+            return None;
+        }
+
+        let angle_mid = self.angle_start + Angle::from_radians(self.angle_sweep.to_radians() / 2.0);
+        let half_sweep = Angle::from_radians(self.angle_sweep.to_radians().abs() / 2.0);
+
+        // The radius scaled up by a factor of 2 is equal to the diameter.
+        let origin_distance =
+            Real::from(self.diameter) * half_sweep.cos() * Real::from(NORMAL_VECTOR_SCALE);
+
+        Some(LinearEquation::with_angle_and_distance(
+            angle_mid - ANGLE_90DEG,
+            i32::from(origin_distance),
+        ))
+    }
+}
+
+impl OffsetOutline for Chord {
+    fn offset(&self, offset: i32) -> Self {
+        let circle = self.to_circle().offset(offset);
+
+        Self::from_circle(circle, self.angle_start, self.angle_sweep)
+    }
+}
+
+impl Primitive for Chord {}
+
+impl PointsIter for Chord {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Chord {
+    fn contains(&self, point: Point) -> bool {
+        let circle = self.to_circle();
+
+        if circle.contains(point) {
+            let delta = point * 2 - circle.center_2x();
+
+            self.straight_edge()
+                .map_or(true, |edge| edge.check_side(delta, LineSide::Left))
+        } else {
+            false
+        }
+    }
+}
+
+impl Dimensions for Chord {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, Size::new_equal(self.diameter))
+    }
+}
+
+impl Transform for Chord {
+    /// Translate the chord from its current position to a new position by (x, y) pixels,
+    /// returning a new `Chord`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Chord;
+    /// # use embedded_graphics::prelude::*;
+    /// let chord = Chord::new(Point::new(5, 10), 10, 0.0.deg(), 90.0.deg());
+    /// let moved = chord.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the chord from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Chord;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut chord = Chord::new(Point::new(5, 10), 10, 0.0.deg(), 90.0.deg());
+    /// chord.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(chord.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+impl Rotate for Chord {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::from_circle(
+            self.to_circle().rotate_90(center),
+            (self.angle_start - ANGLE_90DEG).normalize(),
+            self.angle_sweep,
+        )
+    }
+}
+
+impl Mirror for Chord {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_x(axis),
+            (ANGLE_180DEG - self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_y(axis),
+            (-self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+}
+
+impl Scale for Chord {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::from_circle(
+            self.to_circle().scale_around(center, num, denom),
+            self.angle_start,
+            self.angle_sweep,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn dimensions() {
+        let chord = Chord::new(Point::new(5, 15), 10, 0.0.deg(), 90.0.deg());
+
+        assert_eq!(
+            chord.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(10, 10))
+        );
+    }
+
+    #[test]
+    fn center_is_correct() {
+        let chord = Chord::with_center(Point::new(10, 10), 5, 0.0.deg(), 90.0.deg());
+        assert_eq!(chord.center(), Point::new(10, 10));
+
+        let chord = Chord::with_center(Point::new(10, 10), 6, 0.0.deg(), 90.0.deg());
+        assert_eq!(chord.center(), Point::new(10, 10));
+    }
+
+    #[test]
+    fn contains() {
+        let chord = Chord::new(Point::zero(), 10, 30.0.deg(), 200.0.deg());
+
+        let contained_points = Rectangle::new(Point::new(-10, -10), Size::new(30, 30))
+            .points()
+            .filter(|p| chord.contains(*p));
+
+        assert!(contained_points.eq(chord.points()));
+    }
+
+    #[test]
+    fn full_circle() {
+        let circle = Circle::new(Point::new(1, 2), 9);
+
+        for &angle_sweep in &[360.0.deg(), -360.0.deg(), 400.0.deg()] {
+            let chord = Chord::from_circle(circle, 45.0.deg(), angle_sweep);
+
+            assert!(chord.points().eq(circle.points()));
+        }
+    }
+
+    #[test]
+    fn semicircle() {
+        let circle = Circle::new(Point::new(0, 0), 11);
+
+        let chord = Chord::from_circle(circle, 0.0.deg(), 180.0.deg());
+
+        // The upper half of the circle, including the center row.
+        assert!(chord.points().eq(circle.points().filter(|p| p.y <= 5)));
+    }
+
+    #[test]
+    fn offset() {
+        let center = Point::new(5, 7);
+        let chord = Chord::with_center(center, 3, 0.0.deg(), 90.0.deg());
+
+        assert_eq!(chord.offset(0), chord);
+        assert_eq!(
+            chord.offset(2),
+            Chord::with_center(center, 7, 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            chord.offset(-2),
+            Chord::with_center(center, 0, 0.0.deg(), 90.0.deg())
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    geometry::Point,
+    primitives::{
+        chord::Chord,
+        common::{DistanceIterator, LineSide, LinearEquation},
+    },
+};
+
+/// Iterator over all points inside the chord.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Points {
+    iter: DistanceIterator,
+
+    straight_edge: Option<LinearEquation>,
+
+    threshold: u32,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(chord: &Chord) -> Self {
+        let circle = chord.to_circle();
+
+        Self {
+            // PERF: The distance iterator should use the smaller chord bounding box
+            iter: circle.distances(),
+            straight_edge: chord.straight_edge(),
+            threshold: circle.threshold(),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let threshold = self.threshold;
+        let straight_edge = self.straight_edge;
+
+        self.iter
+            .find(|(_, delta, distance)| {
+                *distance < threshold
+                    && straight_edge.map_or(true, |edge| edge.check_side(*delta, LineSide::Left))
+            })
+            .map(|(point, ..)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Pixel,
+    };
+
+    #[test]
+    fn points_equals_filled() {
+        let chord = Chord::with_center(Point::new(10, 10), 15, 20.0.deg(), 135.0.deg());
+
+        let styled_points = chord
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .pixels()
+            .map(|Pixel(p, _)| p);
+
+        assert!(chord.points().eq(styled_points));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Size},
+    iterator::PixelIteratorExt,
+    pixelcolor::PixelColor,
+    primitives::{
+        chord::Chord,
+        common::{DistanceIterator, LinearEquation, NORMAL_VECTOR_SCALE},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        FillStyle, PrimitiveStyle, Rectangle,
+    },
+    Pixel, SaturatingCast,
+};
+
+/// Pixel iterator for each pixel in the chord border and fill
+#[derive(Clone, PartialEq, Debug)]
+pub struct StyledPixelsIterator<C> {
+    iter: DistanceIterator,
+
+    straight_edge: Option<LinearEquation>,
+
+    outer_threshold: u32,
+    inner_threshold: u32,
+
+    edge_threshold_outside: i32,
+    edge_threshold_inside: i32,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(primitive: &Chord, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = style.stroke_area(primitive).to_circle();
+        let fill_area = style.fill_area(primitive).to_circle();
+
+        let iter = if !style.is_transparent() {
+            // PERF: The distance iterator should use the smaller chord bounding box
+            stroke_area.distances()
+        } else {
+            DistanceIterator::empty()
+        };
+
+        // The distance to the straight edge is scaled by `NORMAL_VECTOR_SCALE` and by a factor
+        // of 2, because the coordinates are scaled up by a factor of 2.
+        let edge_threshold_outside =
+            style.outside_stroke_width().saturating_cast() * NORMAL_VECTOR_SCALE * 2;
+        let edge_threshold_inside =
+            style.inside_stroke_width().saturating_cast() * NORMAL_VECTOR_SCALE * 2;
+
+        Self {
+            iter,
+            straight_edge: primitive.straight_edge(),
+            outer_threshold: stroke_area.threshold(),
+            inner_threshold: fill_area.threshold(),
+            edge_threshold_outside,
+            edge_threshold_inside,
+            stroke_color: style.stroke_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_threshold = self.outer_threshold;
+
+        loop {
+            let (point, delta, distance) = self
+                .iter
+                .find(|(_, _, distance)| *distance < outer_threshold)?;
+
+            // Check if the point is inside the stroke along the straight edge or the fill.
+            let inside_edge = match self.straight_edge {
+                Some(edge) => {
+                    let edge_distance = edge.distance(delta);
+
+                    if edge_distance < -self.edge_threshold_outside {
+                        continue;
+                    }
+
+                    edge_distance >= self.edge_threshold_inside
+                }
+                None => true,
+            };
+
+            let color = if inside_edge && distance < self.inner_threshold {
+                self.fill_color.and_then(|fill_color| {
+                    self.fill_style
+                        .color_at(point, fill_color, &self.bounding_box)
+                })
+            } else {
+                self.stroke_color
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Chord {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Chord {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Chord {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        // The bounding box is determined by the drawn pixels, because the area covered by the
+        // start and end angles can't be calculated exactly without rasterizing the shape.
+        let bounding_box = StyledPixelsIterator::new(self, &style.coverage_style()).bounding_box();
+
+        if bounding_box.is_zero_sized() {
+            Rectangle::new(self.center(), Size::zero())
+        } else {
+            bounding_box
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Point},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn tiny_chord() {
+        let mut display = MockDisplay::new();
+
+        Chord::new(Point::zero(), 9, 30.0.deg(), 120.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  #####  ", //
+            " ####### ", //
+        ]);
+    }
+
+    #[test]
+    fn tiny_chord_filled() {
+        let mut display = MockDisplay::new();
+
+        Chord::new(Point::zero(), 11, 20.0.deg(), 140.0.deg())
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(1)
+                    .fill_color(BinaryColor::Off)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   #####   ", //
+            "  #.....#  ", //
+            " #.......# ", //
+            "###########", //
+        ]);
+    }
+
+    #[test]
+    fn stroke_and_fill_dont_overlap() {
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 0..5 {
+                let styled = Chord::new(Point::new(10, 10), 30, 200.0.deg(), -250.0.deg())
+                    .into_styled(
+                        PrimitiveStyleBuilder::new()
+                            .stroke_color(BinaryColor::On)
+                            .stroke_width(stroke_width)
+                            .stroke_alignment(*alignment)
+                            .fill_color(BinaryColor::Off)
+                            .build(),
+                    );
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                assert_eq!(
+                    styled.bounding_box(),
+                    display.affected_area(),
+                    "{:?}, {}",
+                    alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+}
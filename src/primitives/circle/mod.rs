@@ -1,11 +1,12 @@
 //! The circle primitive
 
 use crate::{
-    geometry::{Dimensions, Point, PointExt, Size},
+    geometry::{Dimensions, Point, Size},
     primitives::{
-        common::DistanceIterator, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
+        common::{DistanceIterator, Scanline},
+        ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{scale_length, scale_point, Mirror, Rotate, Scale, Transform},
 };
 
 mod points;
@@ -54,6 +55,26 @@ pub use styled::StyledPixelsIterator;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// ## Hit testing
+///
+/// The [`ContainsPoint`] trait can be used to check if a point, e.g. the position of a touch
+/// event, is inside a circle. The test uses integer math only and returns `true` for exactly the
+/// points that are drawn by a filled circle.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::Point,
+///     primitives::{Circle, ContainsPoint},
+/// };
+///
+/// let button = Circle::with_center(Point::new(20, 20), 21);
+///
+/// assert!(button.contains(Point::new(25, 27)));
+/// assert!(!button.contains(Point::new(28, 28)));
+/// ```
+///
+/// [`ContainsPoint`]: ../trait.ContainsPoint.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Circle {
     /// Top-left point of circle's bounding box
@@ -97,6 +118,28 @@ impl Circle {
         diameter_to_threshold(self.diameter)
     }
 
+    /// Returns the intersection of the circle with the horizontal line at `y`.
+    pub(in crate::primitives) fn scanline(&self, y: i32) -> Scanline {
+        let bounding_box = self.bounding_box();
+        let columns = bounding_box.columns();
+
+        if !bounding_box.rows().contains(&y) {
+            return Scanline::new_empty(y);
+        }
+
+        let center_2x = self.center_2x();
+        let threshold = self.threshold();
+
+        columns
+            .clone()
+            .find(|x| {
+                let delta = Point::new(*x, y) * 2 - center_2x;
+                (delta.length_squared() as u32) < threshold
+            })
+            .map(|x| Scanline::new(y, x..columns.end - (x - columns.start)))
+            .unwrap_or_else(|| Scanline::new_empty(y))
+    }
+
     /// Returns the squared distance for every point in the bounding box.
     pub(in crate::primitives) fn distances(&self) -> DistanceIterator {
         DistanceIterator::new(self.center_2x(), &self.bounding_box())
@@ -176,6 +219,34 @@ impl Transform for Circle {
     }
 }
 
+impl Rotate for Circle {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::new(
+            self.bounding_box().rotate_90(center).top_left,
+            self.diameter,
+        )
+    }
+}
+
+impl Mirror for Circle {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(self.bounding_box().mirror_x(axis).top_left, self.diameter)
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(self.bounding_box().mirror_y(axis).top_left, self.diameter)
+    }
+}
+
+impl Scale for Circle {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::new(
+            scale_point(self.top_left, center, num, denom),
+            scale_length(self.diameter, num, denom),
+        )
+    }
+}
+
 pub(in crate::primitives) fn diameter_to_threshold(diameter: u32) -> u32 {
     if diameter <= 4 {
         diameter.pow(2) - diameter / 2
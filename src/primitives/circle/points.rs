@@ -1,7 +1,7 @@
 use core::ops::Range;
 
 use crate::{
-    geometry::{Dimensions, Point, PointExt},
+    geometry::{Dimensions, Point},
     primitives::{circle::Circle, common::Scanline},
 };
 
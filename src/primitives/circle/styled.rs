@@ -1,13 +1,13 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::{Dimensions, Point, PointExt},
+    geometry::{quadrant_atan2, Dimensions, Point, OCTANT},
     pixelcolor::PixelColor,
     primitives::{
         circle::{points::Scanlines, Circle},
         common::{Scanline, StyledScanline},
         rectangle::Rectangle,
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle,
+        FillStyle, PrimitiveStyle, StrokePattern,
     },
     Pixel, SaturatingCast,
 };
@@ -23,6 +23,12 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+
+    stroke_pattern: StrokePattern,
+    center_2x: Point,
+    perimeter: u32,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -36,11 +42,72 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             fill: Scanline::new_empty(0),
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
-            fill_color: style.fill_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+            stroke_pattern: style.stroke_pattern,
+            center_2x: primitive.center_2x(),
+            perimeter: style
+                .stroke_pattern
+                .closed_length(perimeter(primitive.diameter)),
+        }
+    }
+
+    /// Returns the next pixel in the left stroke segment of the current scanline.
+    fn next_stroke_left(&mut self) -> Option<Point> {
+        if self.stroke_pattern.is_solid() {
+            return self.stroke_left.next();
+        }
+
+        let (stroke_pattern, center_2x, perimeter) =
+            (self.stroke_pattern, self.center_2x, self.perimeter);
+
+        self.stroke_left
+            .find(|p| stroke_pattern.is_dash(dash_position(*p, center_2x, perimeter)))
+    }
+
+    /// Returns the next pixel in the right stroke segment of the current scanline.
+    fn next_stroke_right(&mut self) -> Option<Point> {
+        if self.stroke_pattern.is_solid() {
+            return self.stroke_right.next();
         }
+
+        let (stroke_pattern, center_2x, perimeter) =
+            (self.stroke_pattern, self.center_2x, self.perimeter);
+
+        self.stroke_right
+            .find(|p| stroke_pattern.is_dash(dash_position(*p, center_2x, perimeter)))
     }
 }
 
+/// Returns the approximate perimeter of a circle with the given diameter.
+fn perimeter(diameter: u32) -> u32 {
+    // 355 / 113 is a close approximation of PI.
+    (u64::from(diameter) * 355 / 113) as u32
+}
+
+/// Returns the position of a point along the circle outline.
+///
+/// The position increases clockwise, starting at the top of the circle. The angle is
+/// approximated by an integer only version of `atan`, which is sufficiently accurate to place the
+/// dashes evenly.
+fn dash_position(point: Point, center_2x: Point, perimeter: u32) -> i32 {
+    let delta = point * 2 - center_2x;
+
+    let (quadrant, along, across) = match (delta.x, delta.y) {
+        (0, 0) => return 0,
+        (x, y) if x >= 0 && y < 0 => (0, x, -y),
+        (x, y) if x > 0 && y >= 0 => (1, y, x),
+        (x, y) if x <= 0 && y > 0 => (2, -x, y),
+        (x, y) => (3, -y, -x),
+    };
+    let (along, across) = (i64::from(along), i64::from(across));
+
+    let angle = quadrant * 2 * OCTANT + quadrant_atan2(along, across);
+
+    (angle * i64::from(perimeter) / (8 * OCTANT)) as i32
+}
+
 impl<C> Iterator for StyledPixelsIterator<C>
 where
     C: PixelColor,
@@ -51,9 +118,8 @@ where
         match (self.stroke_color, self.fill_color) {
             (Some(stroke_color), None) => loop {
                 if let Some(pixel) = self
-                    .stroke_left
-                    .next()
-                    .or_else(|| self.stroke_right.next())
+                    .next_stroke_left()
+                    .or_else(|| self.next_stroke_right())
                     .map(|p| Pixel(p, stroke_color))
                 {
                     return Some(pixel);
@@ -65,11 +131,13 @@ where
             },
             (Some(stroke_color), Some(fill_color)) => loop {
                 if let Some(pixel) = self
-                    .stroke_left
-                    .next()
+                    .next_stroke_left()
                     .map(|p| Pixel(p, stroke_color))
-                    .or_else(|| self.fill.next().map(|p| Pixel(p, fill_color)))
-                    .or_else(|| self.stroke_right.next().map(|p| Pixel(p, stroke_color)))
+                    .or_else(|| {
+                        self.fill
+                            .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                    })
+                    .or_else(|| self.next_stroke_right().map(|p| Pixel(p, stroke_color)))
                 {
                     return Some(pixel);
                 }
@@ -80,7 +148,10 @@ where
                 self.stroke_right = scanline.stroke_right();
             },
             (None, Some(fill_color)) => loop {
-                if let Some(pixel) = self.fill.next().map(|p| Pixel(p, fill_color)) {
+                if let Some(pixel) =
+                    self.fill
+                        .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                {
                     return Some(pixel);
                 }
 
@@ -112,7 +183,14 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Circle {
     where
         D: DrawTarget<Color = C>,
     {
-        match (style.effective_stroke_color(), style.fill_color) {
+        // Dashed strokes and patterned fills can't be drawn using scanlines
+        if (!style.stroke_pattern.is_solid() && style.effective_stroke_color().is_some())
+            || (!style.fill_style.is_solid() && style.is_filled())
+        {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        match (style.effective_stroke_color(), style.effective_fill_color()) {
             (Some(stroke_color), None) => {
                 for scanline in
                     StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
@@ -189,7 +267,8 @@ mod tests {
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
         primitives::{
-            OffsetOutline, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment, Styled,
+            OffsetOutline, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            StrokePattern, Styled,
         },
         Drawable,
     };
@@ -312,7 +391,6 @@ mod tests {
         let circle = Circle::with_center(Point::new(10, 10), 5);
 
         let styled_points = circle
-            .clone()
             .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
             .pixels()
             .map(|Pixel(p, _)| p);
@@ -489,4 +567,100 @@ mod tests {
             filled_circle.bounding_box(),
         );
     }
+
+    #[test]
+    fn dashed_stroke() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(3, 3))
+            .build();
+
+        let mut display = MockDisplay::new();
+        Circle::new(Point::new(0, 0), 11)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        // The perimeter is rounded to a whole number of periods, which prevents the first and the
+        // last dash from merging at the top of the circle.
+        display.assert_pattern(&[
+            "     ###   ",
+            "  #        ",
+            " #         ",
+            "#          ",
+            "          #",
+            "          #",
+            "          #",
+            "#          ",
+            " #         ",
+            "  #        ",
+            "      ##   ",
+        ]);
+    }
+
+    #[test]
+    fn dashed_stroke_max_dash_length() {
+        let circle = Circle::new(Point::new(0, 0), 11);
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(u32::max_value(), 1))
+            .build();
+
+        let mut display = MockDisplay::new();
+        circle.into_styled(style).draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut expected)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn dashed_drawable_vs_iterator() {
+        for diameter in 1..20 {
+            for stroke_width in 1..4 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_pattern(StrokePattern::dashed(4, 2))
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                let circle = Circle::with_center(Point::new_equal(15), diameter).into_styled(style);
+
+                let mut drawable = MockDisplay::new();
+                circle.draw(&mut drawable).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                circle.pixels().draw(&mut pixels).unwrap();
+
+                drawable.assert_eq(&pixels);
+            }
+        }
+    }
+
+    #[test]
+    fn dash_position_is_clockwise() {
+        let circle = Circle::with_center(Point::new_equal(10), 21);
+        let center_2x = circle.center_2x();
+        let perimeter = perimeter(circle.diameter);
+
+        let top = dash_position(Point::new(10, 0), center_2x, perimeter);
+        let right = dash_position(Point::new(20, 10), center_2x, perimeter);
+        let bottom = dash_position(Point::new(10, 20), center_2x, perimeter);
+        let left = dash_position(Point::new(0, 10), center_2x, perimeter);
+        let top_left = dash_position(Point::new(3, 3), center_2x, perimeter);
+
+        assert_eq!(top, 0);
+        assert_eq!(right, perimeter as i32 / 4);
+        assert_eq!(bottom, perimeter as i32 / 2);
+        assert_eq!(left, perimeter as i32 * 3 / 4);
+        assert_eq!(top_left, perimeter as i32 * 7 / 8);
+    }
 }
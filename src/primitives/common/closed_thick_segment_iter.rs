@@ -2,7 +2,10 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, StrokeOffset, ThickSegment},
+    primitives::{
+        common::{LineJoint, StrokeOffset, ThickSegment},
+        LineJoin,
+    },
 };
 
 /// Closed shape thick segments iterator.
@@ -18,10 +21,11 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct ClosedThickSegmentIter<'a> {
     windows: core::slice::Windows<'a, Point>,
-    first_join: LineJoin,
-    start_join: LineJoin,
+    first_join: LineJoint,
+    start_join: LineJoint,
     width: u32,
     stroke_offset: StrokeOffset,
+    line_join: LineJoin,
     points: &'a [Point],
     stop: bool,
     idx: usize,
@@ -31,16 +35,22 @@ static EMPTY: &[Point; 0] = &[];
 
 impl<'a> ClosedThickSegmentIter<'a> {
     /// Create a new thick segments iterator.
-    pub fn new(points: &'a [Point], width: u32, stroke_offset: StrokeOffset) -> Self {
+    pub fn new(
+        points: &'a [Point],
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+    ) -> Self {
         if let [start, end] = points {
             // Single line segment.
-            let start_join = LineJoin::start(*start, *end, width, stroke_offset);
+            let start_join = LineJoint::start(*start, *end, width, stroke_offset);
 
             Self {
                 windows: EMPTY.windows(3),
                 start_join,
                 width,
                 stroke_offset,
+                line_join,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -51,12 +61,13 @@ impl<'a> ClosedThickSegmentIter<'a> {
         } else {
             let windows = points.windows(3);
 
-            let start_join = LineJoin::from_points(
+            let start_join = LineJoint::from_points_with_join(
                 *points.last().unwrap(),
                 points[0],
                 points[1],
                 width,
                 stroke_offset,
+                line_join,
             );
 
             Self {
@@ -64,6 +75,7 @@ impl<'a> ClosedThickSegmentIter<'a> {
                 start_join,
                 width,
                 stroke_offset,
+                line_join,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -76,12 +88,13 @@ impl<'a> ClosedThickSegmentIter<'a> {
     fn empty() -> Self {
         Self {
             windows: EMPTY.windows(3),
-            start_join: LineJoin::empty(),
+            start_join: LineJoint::empty(),
             width: 0,
             stroke_offset: StrokeOffset::None,
+            line_join: LineJoin::default(),
             points: EMPTY,
             stop: true,
-            first_join: LineJoin::empty(),
+            first_join: LineJoint::empty(),
             idx: 1,
         }
     }
@@ -98,7 +111,14 @@ impl<'a> Iterator for ClosedThickSegmentIter<'a> {
         self.idx += 1;
 
         let end_join = if let Some([start, mid, end]) = self.windows.next() {
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            LineJoint::from_points_with_join(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            )
         } else if self.idx == self.points.len() {
             // The join at the end of the line. This will become the start join of the closing
             // segment.
@@ -106,7 +126,14 @@ impl<'a> Iterator for ClosedThickSegmentIter<'a> {
             let mid = self.points.last()?;
             let end = self.points.first()?;
 
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            LineJoint::from_points_with_join(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            )
         } else {
             // Final closing line between start/end.
             self.stop = true;
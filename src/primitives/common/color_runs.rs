@@ -0,0 +1,106 @@
+use crate::{
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Iterator which combines horizontally adjacent pixels with the same color into runs.
+///
+/// A pixel is added to the current run if it has the same color and is located directly to the
+/// right of the previous pixel. Styled pixel iterators return filled areas in row-major order,
+/// which allows them to be drawn by using `fill_solid` instead of drawing individual pixels.
+/// Runs of a single pixel are returned as a 1x1 rectangle.
+#[derive(Debug, Clone)]
+pub struct ColorRuns<I, C>
+where
+    C: PixelColor,
+{
+    pixels: I,
+    next: Option<Pixel<C>>,
+}
+
+impl<I, C> ColorRuns<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    /// Creates a new color runs iterator.
+    pub fn new(pixels: I) -> Self {
+        Self { pixels, next: None }
+    }
+}
+
+impl<I, C> Iterator for ColorRuns<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = (Rectangle, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(start, color) = self.next.take().or_else(|| self.pixels.next())?;
+
+        let mut width = 1;
+        loop {
+            match self.pixels.next() {
+                Some(Pixel(point, c)) if c == color && point == start + Point::new(width, 0) => {
+                    width += 1
+                }
+                next => {
+                    self.next = next;
+                    break;
+                }
+            }
+        }
+
+        Some((Rectangle::new(start, Size::new(width as u32, 1)), color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::BinaryColor;
+
+    #[test]
+    fn runs() {
+        let pixels = [
+            Pixel(Point::new(1, 2), BinaryColor::On),
+            Pixel(Point::new(2, 2), BinaryColor::On),
+            Pixel(Point::new(3, 2), BinaryColor::Off),
+            Pixel(Point::new(5, 2), BinaryColor::Off),
+            Pixel(Point::new(1, 3), BinaryColor::Off),
+            Pixel(Point::new(2, 3), BinaryColor::Off),
+        ];
+
+        let expected = [
+            (
+                Rectangle::new(Point::new(1, 2), Size::new(2, 1)),
+                BinaryColor::On,
+            ),
+            (
+                Rectangle::new(Point::new(3, 2), Size::new(1, 1)),
+                BinaryColor::Off,
+            ),
+            (
+                Rectangle::new(Point::new(5, 2), Size::new(1, 1)),
+                BinaryColor::Off,
+            ),
+            (
+                Rectangle::new(Point::new(1, 3), Size::new(2, 1)),
+                BinaryColor::Off,
+            ),
+        ];
+
+        assert!(ColorRuns::new(pixels.iter().copied()).eq(expected.iter().copied()));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            ColorRuns::new(core::iter::empty::<Pixel<BinaryColor>>()).next(),
+            None
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    geometry::{Point, PointExt},
+    geometry::Point,
     primitives::{
         rectangle::{self, Rectangle},
         PointsIter,
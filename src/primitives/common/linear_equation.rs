@@ -35,7 +35,7 @@ impl LinearEquation {
     /// Creates a new linear equation from a line.
     pub fn from_line(line: &Line) -> Self {
         let normal_vector = line.delta().rotate_90();
-        let origin_distance = line.start.dot_product(normal_vector);
+        let origin_distance = line.start.dot(normal_vector);
 
         Self {
             normal_vector,
@@ -49,7 +49,7 @@ impl LinearEquation {
     /// Positive values will be returned for points on the left side of the line and negative
     /// values for points on the right.
     pub fn distance(&self, point: Point) -> i32 {
-        point.dot_product(self.normal_vector) - self.origin_distance
+        point.dot(self.normal_vector) - self.origin_distance
     }
 
     /// Checks if a point is on the given side of the line.
@@ -101,7 +101,7 @@ impl OriginLinearEquation {
     /// Positive values will be returned for points on the left side of the line and negative
     /// values for points on the right.
     pub fn distance(&self, point: Point) -> i32 {
-        point.dot_product(self.normal_vector)
+        point.dot(self.normal_vector)
     }
 
     /// Checks if a point is on the given side of the line.
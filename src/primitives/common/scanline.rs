@@ -1,7 +1,9 @@
 use crate::{
     draw_target::DrawTarget,
     geometry::{Point, Size},
-    primitives::{Line, PointsIter, Rectangle},
+    pixelcolor::PixelColor,
+    primitives::{FillStyle, Line, PointsIter, Rectangle},
+    Pixel,
 };
 use core::ops::Range;
 
@@ -26,7 +28,7 @@ impl Scanline {
     /// Returns `true` if the x range of the scanline is empty.
     pub fn is_empty(&self) -> bool {
         // MSRV: use `Range::is_empty` on version >= 1.47.0
-        !(self.x.start < self.x.end)
+        self.x.start >= self.x.end
     }
 
     /// Extends the scanline to include the given x coordinate.
@@ -138,6 +140,22 @@ impl Scanline {
         }
     }
 
+    /// Returns the next fill pixel.
+    ///
+    /// Pixels which are transparent because of the fill style are skipped.
+    pub fn next_fill_pixel<C: PixelColor>(
+        &mut self,
+        fill_color: C,
+        fill_style: FillStyle<C>,
+        bounding_box: &Rectangle,
+    ) -> Option<Pixel<C>> {
+        self.find_map(|p| {
+            fill_style
+                .color_at(p, fill_color, bounding_box)
+                .map(|c| Pixel(p, c))
+        })
+    }
+
     /// Draws the scanline.
     pub fn draw<T>(&self, target: &mut T, color: T::Color) -> Result<(), T::Error>
     where
@@ -201,11 +219,11 @@ mod tests {
         let mut l1 = Scanline { y: 5, x: 18..20 };
         let l2 = Scanline { y: 5, x: 11..26 };
 
-        assert_eq!(l1.touches(&l2), true, "l1 touches l2");
+        assert!(l1.touches(&l2), "l1 touches l2");
 
         let result = l1.try_extend(&l2);
 
-        assert_eq!(result, true);
+        assert!(result);
         assert_eq!(l1, Scanline { y: 5, x: 11..26 });
     }
 }
@@ -0,0 +1,185 @@
+//! Polyline simplification.
+
+use crate::{geometry::Point, primitives::Line};
+
+/// Simplifies a list of vertices using the Ramer–Douglas–Peucker algorithm.
+///
+/// Vertices are removed if their distance to the simplified line is less than or equal to
+/// `tolerance`. If `closed` is `true` the vertices are treated as a closed shape and the last
+/// vertex is connected back to the first vertex. The first vertex and, for open lines, the last
+/// vertex are always kept.
+///
+/// The simplified vertices are written to the start of `buffer` and the number of vertices is
+/// returned. `None` is returned if `buffer` is shorter than `vertices`.
+pub fn simplify(
+    vertices: &[Point],
+    closed: bool,
+    tolerance: u32,
+    buffer: &mut [Point],
+) -> Option<usize> {
+    let n = vertices.len();
+
+    let buffer = buffer.get_mut(0..n)?;
+
+    if n < 3 {
+        buffer.copy_from_slice(vertices);
+        return Some(n);
+    }
+
+    // The end index of a closed shape is `n`, which refers to the first vertex.
+    let vertex = |index: usize| vertices[index % n];
+    let last = if closed { n } else { n - 1 };
+    let tolerance_squared = u64::from(tolerance).pow(2);
+
+    // The algorithm is implemented without recursion to limit the stack usage for long lines.
+    // Segments are split at their farthest vertex until all vertices are inside the tolerance,
+    // always continuing with the leftmost segment. The end indices of the pending segments on the
+    // right are stored at the end of `buffer`. This can't overwrite the output at the start of the
+    // buffer, because every pending end index is a different vertex that will be output later.
+    let mut len = 0;
+    let mut stack_start = n;
+    let mut start = 0;
+
+    while start < last {
+        let end = buffer
+            .get(stack_start)
+            .map_or(last, |index| index.x as usize);
+
+        let line = Line::new(vertex(start), vertex(end));
+
+        let farthest = (start + 1..end)
+            .map(|index| (index, line.distance_squared_to_point(vertex(index))))
+            .max_by_key(|(_, distance_squared)| *distance_squared);
+
+        match farthest {
+            Some((index, distance_squared)) if u64::from(distance_squared) > tolerance_squared => {
+                stack_start -= 1;
+                buffer[stack_start] = Point::new(index as i32, 0);
+            }
+            _ => {
+                buffer[len] = vertex(start);
+                len += 1;
+
+                start = end;
+                stack_start = (stack_start + 1).min(n);
+            }
+        }
+    }
+
+    if !closed {
+        buffer[len] = vertex(last);
+        len += 1;
+    }
+
+    Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(vertices: &[Point], closed: bool, tolerance: u32, expected: &[Point]) {
+        let mut buffer = [Point::zero(); 16];
+        let len = simplify(vertices, closed, tolerance, &mut buffer).unwrap();
+
+        assert_eq!(&buffer[0..len], expected);
+    }
+
+    #[test]
+    fn straight_line() {
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(5, 1),
+            Point::new(10, 0),
+            Point::new(15, -1),
+            Point::new(20, 0),
+        ];
+
+        check(&vertices, false, 1, &[Point::new(0, 0), Point::new(20, 0)]);
+        check(&vertices, false, 0, &vertices);
+    }
+
+    #[test]
+    fn zigzag() {
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(1, 1),
+            Point::new(10, 10),
+            Point::new(20, 0),
+            Point::new(21, 1),
+            Point::new(30, 10),
+        ];
+
+        check(
+            &vertices,
+            false,
+            2,
+            &[
+                Point::new(0, 0),
+                Point::new(10, 10),
+                Point::new(20, 0),
+                Point::new(30, 10),
+            ],
+        );
+    }
+
+    #[test]
+    fn closed() {
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(10, 1),
+            Point::new(20, 0),
+            Point::new(20, 20),
+            Point::new(10, 21),
+            Point::new(0, 20),
+        ];
+
+        check(
+            &vertices,
+            true,
+            1,
+            &[
+                Point::new(0, 0),
+                Point::new(20, 0),
+                Point::new(20, 20),
+                Point::new(0, 20),
+            ],
+        );
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut buffer = [Point::zero(); 2];
+
+        assert_eq!(
+            simplify(
+                &[Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)],
+                false,
+                5,
+                &mut buffer
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn no_vertices_removed() {
+        // Every vertex is a split point, which fills the whole buffer with pending indices.
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(1, 10),
+            Point::new(2, 0),
+            Point::new(3, 10),
+            Point::new(4, 0),
+            Point::new(5, 10),
+        ];
+
+        let mut buffer = [Point::zero(); 6];
+        assert_eq!(simplify(&vertices, false, 1, &mut buffer), Some(6));
+        assert_eq!(buffer, vertices);
+
+        let mut buffer = [Point::zero(); 6];
+        assert_eq!(simplify(&vertices, true, 1, &mut buffer), Some(6));
+        assert_eq!(buffer, vertices);
+    }
+}
@@ -3,20 +3,20 @@
 use crate::{
     geometry::Dimensions,
     primitives::{
-        common::{LineJoin, Scanline},
+        common::{LineJoint, Scanline},
         Line, Rectangle,
     },
 };
 
 #[derive(Debug, Clone, Copy)]
 pub struct ThickSegment {
-    start_join: LineJoin,
-    end_join: LineJoin,
+    start_join: LineJoint,
+    end_join: LineJoint,
 }
 
 impl ThickSegment {
     /// Create a new thick segment from two joints.
-    pub fn new(start_join: LineJoin, end_join: LineJoin) -> Self {
+    pub fn new(start_join: LineJoint, end_join: LineJoint) -> Self {
         Self {
             start_join,
             end_join,
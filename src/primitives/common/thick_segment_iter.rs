@@ -2,7 +2,10 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{JoinKind, LineJoin, StrokeOffset, ThickSegment},
+    primitives::{
+        common::{JointKind, LineJoint, StrokeOffset, ThickSegment},
+        LineCap, LineJoin,
+    },
 };
 
 /// Thick segments iterator.
@@ -14,10 +17,12 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct ThickSegmentIter<'a> {
     windows: core::slice::Windows<'a, Point>,
-    start_join: LineJoin,
-    end_join: LineJoin,
+    start_join: LineJoint,
+    end_join: LineJoint,
     width: u32,
     stroke_offset: StrokeOffset,
+    line_join: LineJoin,
+    line_cap: LineCap,
     points: &'a [Point],
     stop: bool,
 }
@@ -26,16 +31,26 @@ static EMPTY: &[Point; 0] = &[];
 
 impl<'a> ThickSegmentIter<'a> {
     /// Create a new thick segments iterator.
-    pub fn new(points: &'a [Point], width: u32, _stroke_offset: StrokeOffset) -> Self {
-        // Fix stroke alignment to None. There are issues with degenerate joints when using
-        // Inside/Outside stroke alignment on polylines, so this is disabled for now.
-        let stroke_offset = StrokeOffset::None;
-
+    pub fn new(
+        points: &'a [Point],
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        line_cap: LineCap,
+    ) -> Self {
         let mut windows = points.windows(3);
 
         if let Some([start, mid, end]) = windows.next() {
-            let start_join = LineJoin::start(*start, *mid, width, stroke_offset);
-            let end_join = LineJoin::from_points(*start, *mid, *end, width, stroke_offset);
+            let start_join =
+                LineJoint::start_with_cap(*start, *mid, width, stroke_offset, line_cap);
+            let end_join = LineJoint::from_points_with_join(
+                *start,
+                *mid,
+                *end,
+                width,
+                stroke_offset,
+                line_join,
+            );
 
             Self {
                 windows,
@@ -43,13 +58,16 @@ impl<'a> ThickSegmentIter<'a> {
                 end_join,
                 width,
                 stroke_offset,
+                line_join,
+                line_cap,
                 points,
                 stop: false,
             }
         } else if let [start, end] = points {
             // Single line segment.
-            let start_join = LineJoin::start(*start, *end, width, stroke_offset);
-            let end_join = LineJoin::end(*start, *end, width, stroke_offset);
+            let start_join =
+                LineJoint::start_with_cap(*start, *end, width, stroke_offset, line_cap);
+            let end_join = LineJoint::end_with_cap(*start, *end, width, stroke_offset, line_cap);
 
             Self {
                 windows: EMPTY.windows(3),
@@ -57,6 +75,8 @@ impl<'a> ThickSegmentIter<'a> {
                 end_join,
                 width,
                 stroke_offset,
+                line_join,
+                line_cap,
                 points,
                 stop: false,
             }
@@ -70,10 +90,12 @@ impl<'a> ThickSegmentIter<'a> {
     fn empty() -> Self {
         Self {
             windows: EMPTY.windows(3),
-            start_join: LineJoin::empty(),
-            end_join: LineJoin::empty(),
+            start_join: LineJoint::empty(),
+            end_join: LineJoint::empty(),
             width: 0,
             stroke_offset: StrokeOffset::None,
+            line_join: LineJoin::default(),
+            line_cap: LineCap::default(),
             points: EMPTY,
             stop: true,
         }
@@ -93,13 +115,20 @@ impl<'a> Iterator for ThickSegmentIter<'a> {
         self.start_join = self.end_join;
 
         if let Some([start, mid, end]) = self.windows.next() {
-            self.end_join =
-                LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset);
-        } else if self.end_join.kind != JoinKind::End {
+            self.end_join = LineJoint::from_points_with_join(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            );
+        } else if self.end_join.kind != JointKind::End {
             let start = *self.points.get(self.points.len() - 2)?;
             let end = *self.points.last()?;
 
-            self.end_join = LineJoin::end(start, end, self.width, self.stroke_offset);
+            self.end_join =
+                LineJoint::end_with_cap(start, end, self.width, self.stroke_offset, self.line_cap);
         } else {
             self.stop = true;
         }
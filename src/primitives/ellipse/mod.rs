@@ -3,7 +3,7 @@
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{Mirror, Rotate, Scale, Transform},
 };
 
 mod points;
@@ -52,6 +52,25 @@ pub use styled::StyledPixelsIterator;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// ## Hit testing
+///
+/// Ellipses implement the [`ContainsPoint`] trait, which uses integer math to check if a point
+/// is inside the ellipse.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::{Point, Size},
+///     primitives::{ContainsPoint, Ellipse},
+/// };
+///
+/// let button = Ellipse::with_center(Point::new(30, 20), Size::new(41, 21));
+///
+/// assert!(button.contains(Point::new(45, 20)));
+/// assert!(!button.contains(Point::new(30, 32)));
+/// ```
+///
+/// [`ContainsPoint`]: ../trait.ContainsPoint.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Ellipse {
     /// Top-left point of ellipse's bounding box
@@ -170,6 +189,32 @@ impl Transform for Ellipse {
     }
 }
 
+impl Rotate for Ellipse {
+    fn rotate_90(&self, center: Point) -> Self {
+        let rectangle = self.bounding_box().rotate_90(center);
+
+        Self::new(rectangle.top_left, rectangle.size)
+    }
+}
+
+impl Mirror for Ellipse {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(self.bounding_box().mirror_x(axis).top_left, self.size)
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(self.bounding_box().mirror_y(axis).top_left, self.size)
+    }
+}
+
+impl Scale for Ellipse {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let rectangle = self.bounding_box().scale_around(center, num, denom);
+
+        Self::new(rectangle.top_left, rectangle.size)
+    }
+}
+
 /// Determines if a point is inside an ellipse.
 // TODO: Make this available to the user as part of #343
 #[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Debug)]
@@ -6,7 +6,7 @@ use crate::{
         common::{Scanline, StyledScanline},
         ellipse::{points::Scanlines, Ellipse, EllipseContains},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle, Rectangle,
+        FillStyle, PrimitiveStyle, Rectangle,
     },
     Pixel, SaturatingCast,
 };
@@ -22,6 +22,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -35,7 +37,9 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             fill: Scanline::new_empty(0),
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
-            fill_color: style.fill_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
         }
     }
 }
@@ -64,7 +68,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                     .stroke_left
                     .next()
                     .map(|p| Pixel(p, stroke_color))
-                    .or_else(|| self.fill.next().map(|p| Pixel(p, fill_color)))
+                    .or_else(|| {
+                        self.fill
+                            .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                    })
                     .or_else(|| self.stroke_right.next().map(|p| Pixel(p, stroke_color)))
                 {
                     return Some(pixel);
@@ -76,7 +83,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                 self.stroke_right = scanline.stroke_right();
             },
             (None, Some(fill_color)) => loop {
-                if let Some(pixel) = self.fill.next().map(|p| Pixel(p, fill_color)) {
+                if let Some(pixel) =
+                    self.fill
+                        .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                {
                     return Some(pixel);
                 }
 
@@ -108,7 +118,12 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Ellipse {
     where
         D: DrawTarget<Color = C>,
     {
-        match (style.effective_stroke_color(), style.fill_color) {
+        // Patterned fills can't be drawn using scanlines
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        match (style.effective_stroke_color(), style.effective_fill_color()) {
             (Some(stroke_color), None) => {
                 for scanline in
                     StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
@@ -133,6 +133,11 @@ impl Bresenham {
         }
     }
 
+    /// Returns the current error accumulator.
+    pub const fn error(&self) -> i32 {
+        self.error
+    }
+
     /// Returns the next point on the line.
     pub fn next(&mut self, parameters: &BresenhamParameters) -> Point {
         if self.error > parameters.error_threshold {
@@ -207,6 +212,92 @@ pub enum BresenhamPoint {
     Extra(Point),
 }
 
+/// Iterator which steps along a line using the Bresenham algorithm.
+///
+/// `BresenhamSteps` returns the same points as [`Line::points`], but additionally provides access
+/// to the error of each returned point. The error can be used to calculate the subpixel position
+/// of the ideal line, for example to compute the spans of a line in a display driver.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     prelude::*,
+///     primitives::{line::BresenhamSteps, Line},
+/// };
+///
+/// let line = Line::new(Point::new(0, 0), Point::new(7, 3));
+/// let mut steps = BresenhamSteps::new(&line);
+///
+/// assert_eq!(steps.next(), Some(Point::new(0, 0)));
+/// assert_eq!(steps.error(), 0);
+///
+/// assert_eq!(steps.next(), Some(Point::new(1, 0)));
+/// assert_eq!(steps.error(), 6);
+///
+/// // The ideal line passes through `(3, 9/7)`, which is offset by `2/7` from the fourth point.
+/// // The error is scaled by twice the length of the line along the X axis: `2/7 * 2 * 7 = 4`.
+/// assert_eq!(steps.nth(1), Some(Point::new(3, 1)));
+/// assert_eq!(steps.error(), 4);
+/// ```
+///
+/// [`Line::points`]: struct.Line.html#method.points
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BresenhamSteps {
+    parameters: BresenhamParameters,
+    bresenham: Bresenham,
+    error: i32,
+    points_remaining: u32,
+}
+
+impl BresenhamSteps {
+    /// Creates a new iterator over the points of a line.
+    pub fn new(line: &Line) -> Self {
+        Self {
+            parameters: BresenhamParameters::new(line),
+            bresenham: Bresenham::new(line.start),
+            error: 0,
+            points_remaining: major_length(line),
+        }
+    }
+
+    /// Returns the error of the last returned point.
+    ///
+    /// The error is the signed distance from the last returned point to the ideal line along the
+    /// minor axis, multiplied by twice the length of the line along the major axis. Positive
+    /// values indicate that the ideal line is offset in the direction of the line's minor steps.
+    ///
+    /// The major axis is the axis along which the line has the larger extent. Before the first
+    /// point is returned the error is `0`.
+    pub fn error(&self) -> i32 {
+        self.error
+    }
+}
+
+impl Iterator for BresenhamSteps {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.points_remaining == 0 {
+            return None;
+        }
+        self.points_remaining -= 1;
+
+        let point = self.bresenham.next(&self.parameters);
+        self.error = self.bresenham.error() - self.parameters.error_step.major;
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.points_remaining as usize;
+
+        (length, Some(length))
+    }
+}
+
+impl ExactSizeIterator for BresenhamSteps {}
+
 /// Returns the length of a line in bresenham major direction steps.
 pub fn major_length(line: &Line) -> u32 {
     let delta = (line.end - line.start).abs();
@@ -217,7 +308,9 @@ pub fn major_length(line: &Line) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{mock_display::MockDisplay, pixelcolor::BinaryColor, Drawable, Pixel};
+    use crate::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, primitives::PointsIter, Drawable, Pixel,
+    };
 
     #[test]
     fn bresenham() {
@@ -232,6 +325,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn continue_with_error() {
+        let line = Line::new(Point::new(0, 0), Point::new(7, 3));
+        let parameters = BresenhamParameters::new(&line);
+
+        let mut bresenham = Bresenham::new(line.start);
+        bresenham.next(&parameters);
+        bresenham.next(&parameters);
+        bresenham.next(&parameters);
+
+        let mut continued = Bresenham::with_initial_error(bresenham.point, bresenham.error());
+        for _ in 0..5 {
+            assert_eq!(continued.next(&parameters), bresenham.next(&parameters));
+        }
+    }
+
+    #[test]
+    fn steps_equal_points() {
+        for &end in &[
+            Point::new(7, 3),
+            Point::new(-3, 7),
+            Point::new(-7, -3),
+            Point::new(3, -7),
+            Point::new(5, 5),
+            Point::zero(),
+        ] {
+            let line = Line::new(Point::new(1, 2), Point::new(1, 2) + end);
+
+            assert!(BresenhamSteps::new(&line).eq(line.points()), "{:?}", line);
+            assert_eq!(BresenhamSteps::new(&line).len(), line.points().count());
+        }
+    }
+
+    #[test]
+    fn steps_error() {
+        let line = Line::new(Point::new(0, 0), Point::new(7, 3));
+
+        let mut steps = BresenhamSteps::new(&line);
+        let mut index = 0;
+        while let Some(point) = steps.next() {
+            // Distance to the ideal line, which is at `y = index * 3 / 7`, multiplied by `2 * 7`.
+            assert_eq!(
+                steps.error(),
+                index * 3 * 2 - point.y * 2 * 7,
+                "{:?}",
+                point
+            );
+
+            index += 1;
+        }
+        assert_eq!(index, 8);
+    }
+
     /// Draws all lines in the iterator including extra points.
     ///
     /// Normal points and extra points are distinguished by drawing normal points using
@@ -242,7 +388,7 @@ mod tests {
 
         for line in lines {
             let mut bresenham = Bresenham::new(line.start);
-            let parameters = BresenhamParameters::new(&line);
+            let parameters = BresenhamParameters::new(line);
 
             for point in core::iter::from_fn(|| Some(bresenham.next_all(&parameters))).take(7) {
                 match point {
@@ -1,7 +1,7 @@
 //! Line intersection parameters.
 
 use crate::{
-    geometry::{Point, PointExt},
+    geometry::{div_round, Point},
     primitives::{
         common::{LineSide, LinearEquation},
         Line,
@@ -35,7 +35,10 @@ pub enum Intersection {
         outer_side: LineSide,
     },
 
-    /// No intersection: lines are colinear or parallel.
+    /// No intersection: lines are parallel, but not colinear.
+    Parallel,
+
+    /// Lines are colinear.
     Colinear,
 }
 /// Line intersection parameters.
@@ -54,7 +57,7 @@ impl<'a> IntersectionParams<'a> {
     pub fn from_lines(line1: &'a Line, line2: &'a Line) -> Self {
         let le1 = LinearEquation::from_line(line1);
         let le2 = LinearEquation::from_line(line2);
-        let denominator = le1.normal_vector.determinant(le2.normal_vector);
+        let denominator = le1.normal_vector.cross(le2.normal_vector);
 
         Self {
             line1,
@@ -68,7 +71,11 @@ impl<'a> IntersectionParams<'a> {
     /// Check whether two almost-colinear lines are intersecting in the wrong place due to numerical
     /// innacuracies.
     pub fn nearly_colinear_has_error(&self) -> bool {
-        self.denominator.pow(2) < self.line1.delta().dot_product(self.line2.delta())
+        let (delta1, delta2) = (self.line1.delta(), self.line2.delta());
+        let dot =
+            i64::from(delta1.x) * i64::from(delta2.x) + i64::from(delta1.y) * i64::from(delta2.y);
+
+        i64::from(self.denominator).pow(2) < dot
     }
 
     /// Compute the intersection point.
@@ -81,9 +88,13 @@ impl<'a> IntersectionParams<'a> {
         } = *self;
 
         // The system of linear equations has no solutions if the determinant is zero. In this case,
-        // the lines must be colinear.
+        // the lines must be colinear or parallel.
         if denominator == 0 {
-            return Intersection::Colinear;
+            return if self.is_colinear() {
+                Intersection::Colinear
+            } else {
+                Intersection::Parallel
+            };
         }
 
         let outer_side = if denominator > 0 {
@@ -94,31 +105,269 @@ impl<'a> IntersectionParams<'a> {
 
         // If we got here, line segments intersect. Compute intersection point using method similar
         // to that described here: http://paulbourke.net/geometry/pointlineplane/#i2l
+        //
+        // The products are calculated using 64 bit integers to prevent overflows for long lines
+        // and the intersection point is rounded to the nearest pixel.
+        let cross = |a: (i32, i32), b: (i32, i32)| {
+            i64::from(a.0) * i64::from(b.1) - i64::from(a.1) * i64::from(b.0)
+        };
+
+        let origin_distances = (line1.origin_distance, line2.origin_distance);
+
+        let x_numerator = cross(
+            origin_distances,
+            (line1.normal_vector.y, line2.normal_vector.y),
+        );
+        let y_numerator = cross(
+            (line1.normal_vector.x, line2.normal_vector.x),
+            origin_distances,
+        );
+
+        let denominator = i64::from(denominator);
+
+        Intersection::Point {
+            point: Point::new(
+                div_round(x_numerator, denominator) as i32,
+                div_round(y_numerator, denominator) as i32,
+            ),
+            outer_side,
+        }
+    }
+
+    /// Returns `true` if both lines lie on the same infinite line.
+    fn is_colinear(&self) -> bool {
+        self.denominator == 0
+            && self.le1.distance(self.line2.start) == 0
+            && self.le2.distance(self.line1.start) == 0
+    }
+
+    /// Returns the overlapping part of two colinear line segments.
+    ///
+    /// The overlap has the same direction as the first line and is `None` if the lines aren't
+    /// colinear or if the line segments don't share any points.
+    pub fn colinear_overlap(&self) -> Option<Line> {
+        if !self.is_colinear() {
+            return None;
+        }
+
+        let (line1, line2) = (*self.line1, *self.line2);
 
-        // The denominator/2 is to get rounding instead of truncating.
-        let offset = denominator.abs() / 2;
+        // Use the direction of the second line if the first line has zero length.
+        let delta = if line1.delta() != Point::zero() {
+            line1.delta()
+        } else {
+            line2.delta()
+        };
+
+        if delta == Point::zero() {
+            return if line1.start == line2.start {
+                Some(line1)
+            } else {
+                None
+            };
+        }
 
-        let origin_distances = Point::new(line1.origin_distance, line2.origin_distance);
+        // Position of a point along the reference direction. The position is calculated using 64 bit
+        // integers to prevent overflows for long lines.
+        let position = |point: Point| {
+            let offset = point - line1.start;
 
-        let numerator =
-            origin_distances.determinant(Point::new(line1.normal_vector.y, line2.normal_vector.y));
-        let x_numerator = if numerator < 0 {
-            numerator - offset
+            i64::from(offset.x) * i64::from(delta.x) + i64::from(offset.y) * i64::from(delta.y)
+        };
+
+        let (start1, end1) = (position(line1.start), position(line1.end));
+        let (start2, end2) = (position(line2.start), position(line2.end));
+
+        // Points of the second line, ordered in the direction of the first line.
+        let same_direction = (end1 - start1).signum() * (end2 - start2).signum() >= 0;
+        let ((min2, min2_point), (max2, max2_point)) = if same_direction {
+            ((start2, line2.start), (end2, line2.end))
         } else {
-            numerator + offset
+            ((end2, line2.end), (start2, line2.start))
         };
 
-        let numerator =
-            Point::new(line1.normal_vector.x, line2.normal_vector.x).determinant(origin_distances);
-        let y_numerator = if numerator < 0 {
-            numerator - offset
+        let (start, end) = if start1 <= end1 {
+            let start = if min2 > start1 {
+                min2_point
+            } else {
+                line1.start
+            };
+            let end = if max2 < end1 { max2_point } else { line1.end };
+
+            if min2.max(start1) > max2.min(end1) {
+                return None;
+            }
+
+            (start, end)
         } else {
-            numerator + offset
+            let start = if max2 < start1 {
+                max2_point
+            } else {
+                line1.start
+            };
+            let end = if min2 > end1 { min2_point } else { line1.end };
+
+            if max2.min(start1) < min2.max(end1) {
+                return None;
+            }
+
+            (start, end)
         };
 
-        Intersection::Point {
-            point: Point::new(x_numerator, y_numerator) / denominator,
-            outer_side,
+        Some(Line::new(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intersection(line1: Line, line2: Line) -> Intersection {
+        IntersectionParams::from_lines(&line1, &line2).intersection()
+    }
+
+    fn overlap(line1: Line, line2: Line) -> Option<Line> {
+        IntersectionParams::from_lines(&line1, &line2).colinear_overlap()
+    }
+
+    #[test]
+    fn point() {
+        let result = intersection(
+            Line::new(Point::new(0, 0), Point::new(10, 10)),
+            Line::new(Point::new(10, 0), Point::new(0, 10)),
+        );
+
+        match result {
+            Intersection::Point { point, .. } => assert_eq!(point, Point::new(5, 5)),
+            other => panic!("expected intersection point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn nearly_parallel_long_lines() {
+        let result = intersection(
+            Line::new(Point::new(0, 0), Point::new(60000, 1)),
+            Line::new(Point::new(0, 1), Point::new(60000, -1)),
+        );
+
+        match result {
+            Intersection::Point { point, .. } => assert_eq!(point, Point::new(20000, 0)),
+            other => panic!("expected intersection point, got {:?}", other),
         }
     }
+
+    #[test]
+    fn parallel() {
+        let result = intersection(
+            Line::new(Point::new(0, 0), Point::new(10, 0)),
+            Line::new(Point::new(0, 5), Point::new(10, 5)),
+        );
+
+        assert!(
+            if let Intersection::Parallel = result {
+                true
+            } else {
+                false
+            },
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn colinear_overlap() {
+        let line = Line::new(Point::new(0, 3), Point::new(10, 3));
+
+        // Partial overlap
+        assert_eq!(
+            overlap(line, Line::new(Point::new(5, 3), Point::new(20, 3))),
+            Some(Line::new(Point::new(5, 3), Point::new(10, 3)))
+        );
+
+        // Partial overlap with reversed second line
+        assert_eq!(
+            overlap(line, Line::new(Point::new(20, 3), Point::new(5, 3))),
+            Some(Line::new(Point::new(5, 3), Point::new(10, 3)))
+        );
+
+        // Second line contained in first line
+        assert_eq!(
+            overlap(line, Line::new(Point::new(7, 3), Point::new(2, 3))),
+            Some(Line::new(Point::new(2, 3), Point::new(7, 3)))
+        );
+
+        // First line contained in second line
+        assert_eq!(
+            overlap(line, Line::new(Point::new(-5, 3), Point::new(15, 3))),
+            Some(line)
+        );
+
+        // Touching end points
+        assert_eq!(
+            overlap(line, Line::new(Point::new(10, 3), Point::new(15, 3))),
+            Some(Line::new(Point::new(10, 3), Point::new(10, 3)))
+        );
+
+        // No overlap
+        assert_eq!(
+            overlap(line, Line::new(Point::new(11, 3), Point::new(15, 3))),
+            None
+        );
+    }
+
+    #[test]
+    fn colinear_zero_length() {
+        let point = Line::new(Point::new(4, 4), Point::new(4, 4));
+
+        assert_eq!(
+            overlap(point, Line::new(Point::new(0, 0), Point::new(8, 8))),
+            Some(point)
+        );
+        assert_eq!(overlap(point, point), Some(point));
+        assert_eq!(
+            overlap(point, Line::new(Point::new(5, 5), Point::new(5, 5))),
+            None
+        );
+    }
+
+    #[test]
+    fn colinear_overlap_reversed_first_line() {
+        let line = Line::new(Point::new(10, 10), Point::new(0, 0));
+
+        assert_eq!(
+            overlap(line, Line::new(Point::new(5, 5), Point::new(20, 20))),
+            Some(Line::new(Point::new(10, 10), Point::new(5, 5)))
+        );
+        assert_eq!(
+            overlap(line, Line::new(Point::new(-5, -5), Point::new(3, 3))),
+            Some(Line::new(Point::new(3, 3), Point::new(0, 0)))
+        );
+        assert_eq!(
+            overlap(line, Line::new(Point::new(-5, -5), Point::new(-1, -1))),
+            None
+        );
+    }
+
+    #[test]
+    fn colinear_overlap_long_lines() {
+        let line = Line::new(Point::new(0, 0), Point::new(60000, 30000));
+
+        assert_eq!(
+            overlap(
+                line,
+                Line::new(Point::new(70000, 35000), Point::new(20000, 10000))
+            ),
+            Some(Line::new(
+                Point::new(20000, 10000),
+                Point::new(60000, 30000)
+            ))
+        );
+        assert!(if let Intersection::Colinear =
+            intersection(line, Line::new(Point::new(300, 150), Point::new(600, 300)))
+        {
+            true
+        } else {
+            false
+        });
+    }
 }
@@ -1,13 +1,19 @@
 //! The line primitive
 
 use crate::{
-    geometry::{Dimensions, Point},
+    geometry::{div_round, Dimensions, Point},
     primitives::{
         common::StrokeOffset,
-        line::thick_points::{ParallelLineType, ParallelsIterator},
+        line::{
+            intersection_params::IntersectionParams,
+            thick_points::{ParallelLineType, ParallelsIterator},
+        },
         PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
+    },
     SaturatingCast,
 };
 
@@ -17,6 +23,7 @@ mod points;
 mod styled;
 mod thick_points;
 
+pub use bresenham::BresenhamSteps;
 pub use points::Points;
 pub use styled::StyledPixelsIterator;
 
@@ -103,19 +110,17 @@ impl Line {
         let mut right = (self.start, ParallelLineType::Normal);
 
         match stroke_offset {
-            StrokeOffset::None => loop {
-                if let Some((bresenham, reduce)) = it.next() {
+            StrokeOffset::None => {
+                while let Some((bresenham, reduce)) = it.next() {
                     right = (bresenham.point, reduce);
-                } else {
-                    break;
-                }
 
-                if let Some((bresenham, reduce)) = it.next() {
-                    left = (bresenham.point, reduce);
-                } else {
-                    break;
+                    if let Some((bresenham, reduce)) = it.next() {
+                        left = (bresenham.point, reduce);
+                    } else {
+                        break;
+                    }
                 }
-            },
+            }
             StrokeOffset::Left => {
                 if let Some((bresenham, reduce)) = it.last() {
                     left = (bresenham.point, reduce);
@@ -153,15 +158,317 @@ impl Line {
         (left_line, right_line)
     }
 
+    /// Returns a copy of this line which is extended by half the thickness at both ends.
+    ///
+    /// The extension is calculated using the same algorithm as the width of thick lines, which
+    /// makes the added length match the width of the line.
+    pub(in crate::primitives) fn extend(&self, thickness: u32) -> Self {
+        let delta = self.delta();
+
+        let start = Line::new(self.start, self.start + delta).perpendicular();
+        let (left, right) = start.extents(thickness, StrokeOffset::None);
+        let start = if (left.start - self.start).dot(delta) < 0 {
+            left.start
+        } else {
+            right.start
+        };
+
+        let end = Line::new(self.end, self.end + delta).perpendicular();
+        let (left, right) = end.extents(thickness, StrokeOffset::None);
+        let end = if (left.start - self.end).dot(delta) > 0 {
+            left.start
+        } else {
+            right.start
+        };
+
+        Line::new(start, end)
+    }
+
     /// Compute the midpoint of the line.
+    ///
+    /// The coordinates of the midpoint are rounded towards the start point if the line has an
+    /// odd length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Line};
+    ///
+    /// let line = Line::new(Point::new(10, 20), Point::new(21, 40));
+    ///
+    /// assert_eq!(line.midpoint(), Point::new(15, 30));
+    /// ```
     pub fn midpoint(&self) -> Point {
         self.start + (self.end - self.start) / 2
     }
 
+    /// Returns the point at the fraction `numerator / denominator` along the line.
+    ///
+    /// A fraction of `0` returns the start point and a fraction of `1` returns the end point.
+    /// Fractions outside the range `0..=1` return points on the extension of the line. The
+    /// coordinates are rounded to the nearest integer, without using floating point math.
+    ///
+    /// If `denominator` is `0` the start point is returned.
+    ///
+    /// # Examples
+    ///
+    /// Tick marks at every quarter of a line:
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Line};
+    ///
+    /// let line = Line::new(Point::new(0, 0), Point::new(10, 30));
+    ///
+    /// let ticks = [
+    ///     line.point_at(0, 4),
+    ///     line.point_at(1, 4),
+    ///     line.point_at(2, 4),
+    ///     line.point_at(3, 4),
+    ///     line.point_at(4, 4),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ticks,
+    ///     [
+    ///         Point::new(0, 0),
+    ///         Point::new(3, 8),
+    ///         Point::new(5, 15),
+    ///         Point::new(8, 23),
+    ///         Point::new(10, 30),
+    ///     ]
+    /// );
+    /// ```
+    pub fn point_at(&self, numerator: i32, denominator: i32) -> Point {
+        self.interpolate(i64::from(numerator), i64::from(denominator))
+    }
+
+    /// Returns the point at the fraction `numerator / denominator` along the line.
+    fn interpolate(&self, numerator: i64, denominator: i64) -> Point {
+        if denominator == 0 {
+            return self.start;
+        }
+
+        let delta = self.delta();
+
+        // Rounds `value * numerator / denominator` to the nearest integer, with ties rounded away
+        // from zero.
+        let scale = |value: i32| div_round(i64::from(value) * numerator, denominator) as i32;
+
+        self.start + Point::new(scale(delta.x), scale(delta.y))
+    }
+
+    /// Returns the point on the line segment which is closest to the given point.
+    ///
+    /// The returned point is rounded to the nearest integer coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Line};
+    ///
+    /// let line = Line::new(Point::new(10, 10), Point::new(30, 10));
+    ///
+    /// assert_eq!(line.closest_point(Point::new(15, 25)), Point::new(15, 10));
+    /// assert_eq!(line.closest_point(Point::new(0, 0)), Point::new(10, 10));
+    /// assert_eq!(line.closest_point(Point::new(50, 12)), Point::new(30, 10));
+    /// ```
+    pub fn closest_point(&self, point: Point) -> Point {
+        let delta = self.delta();
+        let offset = point - self.start;
+
+        let length_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+        let dot_product =
+            i64::from(offset.x) * i64::from(delta.x) + i64::from(offset.y) * i64::from(delta.y);
+
+        if dot_product <= 0 || length_squared == 0 {
+            self.start
+        } else if dot_product >= length_squared {
+            self.end
+        } else {
+            self.interpolate(dot_product, length_squared)
+        }
+    }
+
+    /// Returns the squared distance between the line segment and a point.
+    ///
+    /// The distance is measured between `point` and the [`closest_point`] on the line segment.
+    /// Because the closest point is rounded to integer coordinates, the result is an
+    /// approximation of the exact squared distance.
+    ///
+    /// Comparing squared distances avoids the calculation of square roots, e.g. when testing if a
+    /// touch point is close enough to a line:
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Line};
+    ///
+    /// let line = Line::new(Point::new(10, 10), Point::new(30, 20));
+    /// let touch_point = Point::new(21, 13);
+    ///
+    /// // Check if the touch point is within 3 pixels of the line.
+    /// assert!(line.distance_squared_to_point(touch_point) <= 3 * 3);
+    /// ```
+    ///
+    /// [`closest_point`]: #method.closest_point
+    pub fn distance_squared_to_point(&self, point: Point) -> u32 {
+        let offset = point - self.closest_point(point);
+
+        let distance_squared = i64::from(offset.x).pow(2) + i64::from(offset.y).pow(2);
+
+        distance_squared.min(i64::from(core::u32::MAX)) as u32
+    }
+
+    /// Clips the line to the given area.
+    ///
+    /// Returns the part of the line which is inside `area`, or `None` if the line lies completely
+    /// outside of the area. The line is clipped using the Cohen-Sutherland algorithm with integer
+    /// only math and the new end points are rounded to the nearest pixel. This means that the
+    /// pixels of a clipped line can differ slightly from the pixels of the original line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     geometry::{Point, Size},
+    ///     primitives::{Line, Rectangle},
+    /// };
+    ///
+    /// let area = Rectangle::new(Point::zero(), Size::new(64, 48));
+    ///
+    /// let line = Line::new(Point::new(-100, 10), Point::new(1000, 10));
+    /// assert_eq!(
+    ///     line.clipped(&area),
+    ///     Some(Line::new(Point::new(0, 10), Point::new(63, 10)))
+    /// );
+    ///
+    /// let line = Line::new(Point::new(-100, -10), Point::new(1000, -10));
+    /// assert_eq!(line.clipped(&area), None);
+    /// ```
+    pub fn clipped(&self, area: &Rectangle) -> Option<Line> {
+        let bottom_right = area.bottom_right()?;
+        let top_left = area.top_left;
+
+        let outcode = |point: Point| {
+            let mut code = 0;
+
+            if point.x < top_left.x {
+                code |= OUTCODE_LEFT;
+            } else if point.x > bottom_right.x {
+                code |= OUTCODE_RIGHT;
+            }
+
+            if point.y < top_left.y {
+                code |= OUTCODE_TOP;
+            } else if point.y > bottom_right.y {
+                code |= OUTCODE_BOTTOM;
+            }
+
+            code
+        };
+
+        let mut start = self.start;
+        let mut end = self.end;
+        let mut start_code = outcode(start);
+        let mut end_code = outcode(end);
+
+        loop {
+            if start_code | end_code == 0 {
+                return Some(Line::new(start, end));
+            }
+
+            if start_code & end_code != 0 {
+                return None;
+            }
+
+            // Move the end point which is outside the area onto the edge of the area.
+            let code = if start_code != 0 {
+                start_code
+            } else {
+                end_code
+            };
+
+            let point = if code & OUTCODE_TOP != 0 {
+                Point::new(interpolate(start, end, top_left.y, true), top_left.y)
+            } else if code & OUTCODE_BOTTOM != 0 {
+                Point::new(
+                    interpolate(start, end, bottom_right.y, true),
+                    bottom_right.y,
+                )
+            } else if code & OUTCODE_LEFT != 0 {
+                Point::new(top_left.x, interpolate(start, end, top_left.x, false))
+            } else {
+                Point::new(
+                    bottom_right.x,
+                    interpolate(start, end, bottom_right.x, false),
+                )
+            };
+
+            if code == start_code {
+                start = point;
+                start_code = outcode(start);
+            } else {
+                end = point;
+                end_code = outcode(end);
+            }
+        }
+    }
+
+    /// Returns the overlapping part of two colinear line segments.
+    ///
+    /// Returns `None` if the lines aren't colinear or if the line segments don't share any
+    /// points. The returned line has the same direction as `self`.
+    ///
+    /// This can be used to handle horizontal edges in scanline based polygon fill algorithms,
+    /// which can't be handled by calculating the intersection point of two lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Line};
+    ///
+    /// let scanline = Line::new(Point::new(0, 10), Point::new(63, 10));
+    /// let edge = Line::new(Point::new(40, 10), Point::new(20, 10));
+    ///
+    /// assert_eq!(
+    ///     scanline.colinear_overlap(&edge),
+    ///     Some(Line::new(Point::new(20, 10), Point::new(40, 10)))
+    /// );
+    ///
+    /// let edge = Line::new(Point::new(20, 11), Point::new(40, 11));
+    ///
+    /// assert_eq!(scanline.colinear_overlap(&edge), None);
+    /// ```
+    pub fn colinear_overlap(&self, other: &Line) -> Option<Line> {
+        IntersectionParams::from_lines(self, other).colinear_overlap()
+    }
+
     /// Compute the delta (`end - start`) of the line.
     pub fn delta(&self) -> Point {
         self.end - self.start
     }
+
+    /// Returns the position of a point along a line, measured in pixels along the major axis.
+    ///
+    /// The point is projected onto the line, which makes all pixels across the width of a thick
+    /// line share the same position. The start point is at position `0`.
+    pub(in crate::primitives) fn dash_position(&self, point: Point) -> i32 {
+        let delta = self.delta();
+        let major = i64::from(delta.x.abs().max(delta.y.abs()));
+        let length_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+
+        if length_squared == 0 {
+            return 0;
+        }
+
+        let offset = point - self.start;
+        let dot_product =
+            i64::from(offset.x) * i64::from(delta.x) + i64::from(offset.y) * i64::from(delta.y);
+
+        // Round to the nearest integer position.
+        let numerator = dot_product * major * 2 + length_squared;
+        let denominator = length_squared * 2;
+
+        numerator.div_euclid(denominator) as i32
+    }
 }
 
 impl Transform for Line {
@@ -203,6 +510,77 @@ impl Transform for Line {
     }
 }
 
+impl Rotate for Line {
+    /// Rotate the line by 90° clockwise around `center`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Line;
+    /// # use embedded_graphics::prelude::*;
+    /// let line = Line::new(Point::new(5, 10), Point::new(15, 20));
+    /// let rotated = line.rotate_90(Point::new(5, 10));
+    ///
+    /// assert_eq!(rotated.start, Point::new(5, 10));
+    /// assert_eq!(rotated.end, Point::new(-5, 20));
+    /// ```
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::new(
+            rotate_point_90(self.start, center),
+            rotate_point_90(self.end, center),
+        )
+    }
+}
+
+impl Mirror for Line {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_x(self.start, axis),
+            mirror_point_x(self.end, axis),
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(
+            mirror_point_y(self.start, axis),
+            mirror_point_y(self.end, axis),
+        )
+    }
+}
+
+impl Scale for Line {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::new(
+            scale_point(self.start, center, num, denom),
+            scale_point(self.end, center, num, denom),
+        )
+    }
+}
+
+const OUTCODE_LEFT: u8 = 0b0001;
+const OUTCODE_RIGHT: u8 = 0b0010;
+const OUTCODE_TOP: u8 = 0b0100;
+const OUTCODE_BOTTOM: u8 = 0b1000;
+
+/// Calculates the intersection of the line through `start` and `end` with a horizontal or
+/// vertical line.
+///
+/// If `horizontal` is `true` the x coordinate of the intersection with the horizontal line at
+/// `y = value` is returned, otherwise the y coordinate of the intersection with the vertical line
+/// at `x = value`. The result is rounded to the nearest integer.
+fn interpolate(start: Point, end: Point, value: i32, horizontal: bool) -> i32 {
+    let (start, end) = if horizontal {
+        (start, end)
+    } else {
+        (Point::new(start.y, start.x), Point::new(end.y, end.x))
+    };
+
+    let dx = i64::from(end.x) - i64::from(start.x);
+    let dy = i64::from(end.y) - i64::from(start.y);
+    let offset = i64::from(value) - i64::from(start.y);
+
+    // `dy` can't be zero here, because the outcodes ensure that the line crosses `value`.
+    (i64::from(start.x) + div_round(dx * offset, dy)) as i32
+}
+
 /// Pixel iterator for each pixel in the line
 #[cfg(test)]
 mod tests {
@@ -407,7 +785,6 @@ mod tests {
         let line = Line::new(Point::new(10, 10), Point::new(20, 30));
 
         let styled_points: ArrayVec<[_; 32]> = line
-            .clone()
             .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
             .pixels()
             .map(|Pixel(p, _)| p)
@@ -445,4 +822,144 @@ mod tests {
         assert_eq!(l, line);
         assert_eq!(r, line);
     }
+
+    #[test]
+    fn point_at() {
+        let line = Line::new(Point::new(10, 20), Point::new(20, 5));
+
+        assert_eq!(line.point_at(0, 3), line.start);
+        assert_eq!(line.point_at(3, 3), line.end);
+        assert_eq!(line.point_at(1, 3), Point::new(13, 15));
+        assert_eq!(line.point_at(2, 3), Point::new(17, 10));
+        assert_eq!(line.point_at(1, 2), Point::new(15, 12));
+        assert_eq!(line.point_at(-1, -2), Point::new(15, 12));
+    }
+
+    #[test]
+    fn point_at_extrapolates() {
+        let line = Line::new(Point::new(10, 20), Point::new(20, 5));
+
+        assert_eq!(line.point_at(2, 1), Point::new(30, -10));
+        assert_eq!(line.point_at(-1, 1), Point::new(0, 35));
+        assert_eq!(line.point_at(1, -2), Point::new(5, 28));
+    }
+
+    #[test]
+    fn point_at_zero_denominator() {
+        let line = Line::new(Point::new(10, 20), Point::new(20, 5));
+
+        assert_eq!(line.point_at(1, 0), line.start);
+    }
+
+    #[test]
+    fn point_at_large_values() {
+        let line = Line::new(Point::new(-1000, 0), Point::new(1000, 0));
+
+        assert_eq!(
+            line.point_at(core::i32::MAX / 2, core::i32::MAX),
+            Point::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn clipped_inside() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+        let line = Line::new(Point::new(12, 11), Point::new(29, 19));
+
+        assert_eq!(line.clipped(&area), Some(line));
+    }
+
+    #[test]
+    fn clipped_outside() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+
+        // Left of the area
+        assert_eq!(
+            Line::new(Point::new(0, 0), Point::new(9, 30)).clipped(&area),
+            None
+        );
+
+        // Crosses the extension of the corner, but not the area
+        assert_eq!(
+            Line::new(Point::new(0, 15), Point::new(15, 0)).clipped(&area),
+            None
+        );
+    }
+
+    #[test]
+    fn clipped_horizontal_and_vertical() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+
+        assert_eq!(
+            Line::new(Point::new(40, 15), Point::new(0, 15)).clipped(&area),
+            Some(Line::new(Point::new(29, 15), Point::new(10, 15)))
+        );
+        assert_eq!(
+            Line::new(Point::new(20, -5), Point::new(20, 100)).clipped(&area),
+            Some(Line::new(Point::new(20, 10), Point::new(20, 19)))
+        );
+    }
+
+    #[test]
+    fn clipped_diagonal() {
+        let area = Rectangle::new(Point::zero(), Size::new(10, 10));
+
+        assert_eq!(
+            Line::new(Point::new(-5, -5), Point::new(15, 15)).clipped(&area),
+            Some(Line::new(Point::new(0, 0), Point::new(9, 9)))
+        );
+        assert_eq!(
+            Line::new(Point::new(-4, 12), Point::new(12, -4)).clipped(&area),
+            Some(Line::new(Point::new(0, 8), Point::new(8, 0)))
+        );
+    }
+
+    #[test]
+    fn clipped_one_end_inside() {
+        let area = Rectangle::new(Point::zero(), Size::new(10, 10));
+
+        assert_eq!(
+            Line::new(Point::new(5, 5), Point::new(25, 15)).clipped(&area),
+            Some(Line::new(Point::new(5, 5), Point::new(9, 7)))
+        );
+    }
+
+    #[test]
+    fn clipped_zero_sized_area() {
+        let area = Rectangle::new(Point::zero(), Size::zero());
+
+        assert_eq!(
+            Line::new(Point::new(-5, -5), Point::new(15, 15)).clipped(&area),
+            None
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let line = Line::new(Point::new(10, 20), Point::new(30, 10));
+
+        assert_eq!(line.closest_point(Point::new(0, 20)), line.start);
+        assert_eq!(line.closest_point(Point::new(40, 0)), line.end);
+        assert_eq!(line.closest_point(Point::new(20, 15)), Point::new(20, 15));
+        assert_eq!(line.closest_point(Point::new(25, 25)), Point::new(20, 15));
+        assert_eq!(line.closest_point(Point::new(16, 4)), Point::new(21, 14));
+    }
+
+    #[test]
+    fn closest_point_zero_length() {
+        let line = Line::new(Point::new(10, 20), Point::new(10, 20));
+
+        assert_eq!(line.closest_point(Point::new(0, 0)), line.start);
+    }
+
+    #[test]
+    fn distance_squared_to_point() {
+        let line = Line::new(Point::new(10, 10), Point::new(30, 10));
+
+        assert_eq!(line.distance_squared_to_point(Point::new(20, 10)), 0);
+        assert_eq!(line.distance_squared_to_point(Point::new(20, 15)), 25);
+        assert_eq!(line.distance_squared_to_point(Point::new(20, 5)), 25);
+        assert_eq!(line.distance_squared_to_point(Point::new(7, 14)), 25);
+        assert_eq!(line.distance_squared_to_point(Point::new(33, 6)), 25);
+    }
 }
@@ -8,6 +8,9 @@ use crate::{
 
 /// Iterator over all points on the line.
 ///
+/// The iterator can also be used in reverse, which returns the same points as the forward
+/// iterator in the opposite order.
+///
 /// See the [`points`] method documentation for more details.
 ///
 /// [`points`]: struct.Line.html#method.points
@@ -16,6 +19,12 @@ pub struct Points {
     parameters: BresenhamParameters,
     bresenham: Bresenham,
     points_remaining: u32,
+
+    /// Start point of the line, used to calculate points for the reverse iterator.
+    start: Point,
+
+    /// Index of the last remaining point.
+    back_index: u32,
 }
 
 impl Points {
@@ -29,9 +38,33 @@ impl Points {
             parameters,
             bresenham,
             points_remaining: length,
+            start: line.start,
+            back_index: length - 1,
         }
     }
 
+    /// Returns the point with the given index.
+    ///
+    /// This calculates the same point as the `index`th call to `Bresenham::next` without
+    /// iterating over the preceding points.
+    fn point_at_index(&self, index: u32) -> Point {
+        let major_delta = i64::from(self.parameters.error_threshold);
+        let minor_error_step = i64::from(self.parameters.error_step.major);
+
+        // Number of minor steps, which is the smallest `m` that satisfies
+        // `index * minor_error_step - m * 2 * major_delta <= major_delta`.
+        let error = i64::from(index) * minor_error_step - major_delta;
+        let minor_steps = if error > 0 {
+            (error + 2 * major_delta - 1) / (2 * major_delta)
+        } else {
+            0
+        };
+
+        self.start
+            + self.parameters.position_step.major * index as i32
+            + self.parameters.position_step.minor * minor_steps as i32
+    }
+
     /// Creates an empty iterator.
     pub(in crate::primitives) fn empty() -> Self {
         let dummy = Line::new(Point::zero(), Point::zero());
@@ -55,8 +88,31 @@ impl Iterator for Points {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.points_remaining as usize;
+
+        (len, Some(len))
+    }
 }
 
+impl DoubleEndedIterator for Points {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.points_remaining > 0 {
+            self.points_remaining -= 1;
+
+            let point = self.point_at_index(self.back_index);
+            self.back_index = self.back_index.wrapping_sub(1);
+
+            Some(point)
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for Points {}
+
 #[cfg(test)]
 mod tests {
 
@@ -65,6 +121,7 @@ mod tests {
         iterator::PixelIteratorExt, mock_display::MockDisplay, pixelcolor::BinaryColor,
         primitives::PointsIter, Pixel,
     };
+    use arrayvec::ArrayVec;
 
     fn test_points(start: Point, end: Point, expected: &[(i32, i32)]) {
         let expected = expected.iter().copied().map(Point::from);
@@ -241,5 +298,51 @@ mod tests {
     #[test]
     fn empty() {
         assert!(Points::empty().eq(core::iter::empty()));
+        assert!(Points::empty().rev().eq(core::iter::empty()));
+        assert_eq!(Points::empty().len(), 0);
+    }
+
+    #[test]
+    fn reverse() {
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let line = Line::new(Point::new(3, -5), Point::new(3 + x, -5 + y));
+
+                let forward: ArrayVec<[Point; 21]> = line.points().collect();
+                let mut reverse: ArrayVec<[Point; 21]> = line.points().rev().collect();
+                reverse.reverse();
+
+                assert_eq!(forward, reverse, "{:?}", line);
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_directions() {
+        let line = Line::new(Point::new(10, 10), Point::new(15, 13));
+        let mut points = line.points();
+
+        assert_eq!(points.len(), 6);
+        assert_eq!(points.next(), Some(Point::new(10, 10)));
+        assert_eq!(points.next_back(), Some(Point::new(15, 13)));
+        assert_eq!(points.next_back(), Some(Point::new(14, 12)));
+        assert_eq!(points.len(), 3);
+        assert_eq!(points.next(), Some(Point::new(11, 11)));
+        assert_eq!(points.next(), Some(Point::new(12, 11)));
+        assert_eq!(points.next_back(), Some(Point::new(13, 12)));
+        assert_eq!(points.len(), 0);
+        assert_eq!(points.next(), None);
+        assert_eq!(points.next_back(), None);
+    }
+
+    #[test]
+    fn exact_size() {
+        let line = Line::new(Point::new(10, 10), Point::new(-7, 3));
+        let mut points = line.points();
+
+        for remaining in (0..=18).rev() {
+            assert_eq!(points.len(), remaining);
+            points.next();
+        }
     }
 }
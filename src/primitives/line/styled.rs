@@ -1,10 +1,12 @@
 use crate::{
     draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
     primitives::{
+        circle::{self, Circle},
         line::{thick_points::ThickPoints, Line, StrokeOffset},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle, Rectangle,
+        LineCap, PointsIter, PrimitiveStyle, Rectangle, StrokePattern,
     },
     Pixel, SaturatingCast,
 };
@@ -13,7 +15,12 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct StyledPixelsIterator<C> {
     stroke_color: Option<C>,
+    stroke_pattern: StrokePattern,
+    line_cap: LineCap,
+    line: Line,
     line_iter: ThickPoints,
+    start_cap: circle::Points,
+    end_cap: circle::Points,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -22,11 +29,65 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let stroke_color = style.effective_stroke_color();
         let stroke_width = style.stroke_width.saturating_cast();
 
+        let (body, start_cap, end_cap) = match style.line_cap {
+            LineCap::Butt => (
+                *primitive,
+                Circle::new(primitive.start, 0),
+                Circle::new(primitive.end, 0),
+            ),
+            LineCap::Square => (
+                primitive.extend(style.stroke_width),
+                Circle::new(primitive.start, 0),
+                Circle::new(primitive.end, 0),
+            ),
+            LineCap::Round => (
+                *primitive,
+                Circle::with_center(primitive.start, style.stroke_width),
+                Circle::with_center(primitive.end, style.stroke_width),
+            ),
+        };
+
         Self {
             stroke_color,
-            line_iter: ThickPoints::new(&primitive, stroke_width),
+            stroke_pattern: style.stroke_pattern,
+            line_cap: style.line_cap,
+            line: *primitive,
+            line_iter: ThickPoints::new(&body, stroke_width),
+            start_cap: start_cap.points(),
+            end_cap: end_cap.points(),
         }
     }
+
+    /// Returns the next point of the line, including the caps.
+    fn next_point(&mut self) -> Option<Point> {
+        if self.line_cap != LineCap::Round {
+            return self.line_iter.next();
+        }
+
+        // Round caps overlap the body of the line. To prevent pixels from being drawn twice the
+        // caps are limited to the area in front of the start point and behind the end point.
+        let delta = self.line.delta();
+        let start = self.line.start;
+        let length_squared = delta.length_squared();
+        let position = |point: Point| (point - start).dot(delta);
+
+        if let Some(point) = self
+            .line_iter
+            .find(|p| length_squared != 0 && position(*p) >= 0 && position(*p) <= length_squared)
+        {
+            return Some(point);
+        }
+
+        if let Some(point) = self
+            .start_cap
+            .find(|p| length_squared == 0 || position(*p) < 0)
+        {
+            return Some(point);
+        }
+
+        self.end_cap
+            .find(|p| length_squared != 0 && position(*p) > length_squared)
+    }
 }
 
 impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
@@ -36,9 +97,15 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
         // Return none if stroke color is none
         let stroke_color = self.stroke_color?;
 
-        self.line_iter
-            .next()
-            .map(|point| Pixel(point, stroke_color))
+        loop {
+            let point = self.next_point()?;
+
+            if self.stroke_pattern.is_solid()
+                || self.stroke_pattern.is_dash(self.line.dash_position(point))
+            {
+                return Some(Pixel(point, stroke_color));
+            }
+        }
     }
 }
 
@@ -68,19 +135,41 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Line {
 
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Line {
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let (l, r) = self.extents(style.stroke_width, StrokeOffset::None);
+        // Zero length lines with round caps are drawn as a circle
+        if style.line_cap == LineCap::Round && self.start == self.end && style.stroke_width > 0 {
+            return Circle::with_center(self.start, style.stroke_width).bounding_box();
+        }
+
+        let line = if style.line_cap == LineCap::Square {
+            self.extend(style.stroke_width)
+        } else {
+            *self
+        };
+
+        let (l, r) = line.extents(style.stroke_width, StrokeOffset::None);
 
-        let min = l
+        let mut min = l
             .start
             .component_min(l.end)
             .component_min(r.start)
             .component_min(r.end);
-        let max = l
+        let mut max = l
             .start
             .component_max(l.end)
             .component_max(r.start)
             .component_max(r.end);
 
+        if style.line_cap == LineCap::Round && style.stroke_width > 1 {
+            for center in [self.start, self.end].iter() {
+                let cap = Circle::with_center(*center, style.stroke_width).bounding_box();
+
+                if let Some(bottom_right) = cap.bottom_right() {
+                    min = min.component_min(cap.top_left);
+                    max = max.component_max(bottom_right);
+                }
+            }
+        }
+
         Rectangle::with_corners(min, max)
     }
 }
@@ -91,8 +180,8 @@ mod tests {
     use crate::{
         geometry::{Dimensions, Point},
         mock_display::MockDisplay,
-        pixelcolor::{Rgb888, RgbColor},
-        primitives::{Primitive, PrimitiveStyleBuilder},
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{LineCap, Primitive, PrimitiveStyleBuilder},
         Drawable,
     };
 
@@ -153,4 +242,183 @@ mod tests {
 
         assert_eq!(transparent_line.bounding_box(), stroked_line.bounding_box(),);
     }
+
+    #[test]
+    fn dashed_thin_line() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(3, 2))
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 0), Point::new(11, 0))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+        Line::new(Point::new(0, 2), Point::new(11, 7))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "###  ###  ##",
+            "            ",
+            "##          ",
+            "  #         ",
+            "     #      ",
+            "      ##    ",
+            "            ",
+            "          ##",
+        ]);
+    }
+
+    #[test]
+    fn dashed_thick_line() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_pattern(StrokePattern::Dashed {
+                dash_length: 2,
+                gap_length: 2,
+                offset: 1,
+            })
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 1), Point::new(9, 1))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#  ##  ## ", //
+            "#  ##  ## ", //
+            "#  ##  ## ", //
+        ]);
+    }
+
+    #[test]
+    fn solid_pattern_is_unchanged() {
+        let line = Line::new(Point::new(2, 3), Point::new(17, 9));
+
+        for width in 1..5 {
+            let mut expected = MockDisplay::new();
+            line.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, width))
+                .draw(&mut expected)
+                .unwrap();
+
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(width)
+                .stroke_pattern(StrokePattern::Solid)
+                .build();
+            let mut display = MockDisplay::new();
+            line.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn line_cap_bounding_box() {
+        let lines = [
+            Line::new(Point::new(10, 20), Point::new(10, 50)),
+            Line::new(Point::new(20, 20), Point::new(50, 20)),
+            Line::new(Point::new(20, 20), Point::new(55, 55)),
+            Line::new(Point::new(40, 40), Point::new(13, 14)),
+            Line::new(Point::new(30, 30), Point::new(12, 53)),
+            Line::new(Point::new(30, 30), Point::new(30, 30)),
+        ];
+
+        for line in lines.iter() {
+            for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round].iter() {
+                for thickness in 1..10 {
+                    let style = PrimitiveStyleBuilder::new()
+                        .stroke_color(Rgb888::RED)
+                        .stroke_width(thickness)
+                        .line_cap(*line_cap)
+                        .build();
+                    let styled = line.into_styled(style);
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{:?}, {:?}, {} px",
+                        line,
+                        line_cap,
+                        thickness
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn square_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .line_cap(LineCap::Square)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(2, 2), Point::new(8, 2))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+        Line::new(Point::new(3, 6), Point::new(6, 9))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "          ",
+            " #########",
+            " #########",
+            " #########",
+            "   #      ",
+            "  ####    ",
+            "  #####   ",
+            "   #####  ",
+            "    ##### ",
+            "      ##  ",
+        ]);
+    }
+
+    #[test]
+    fn round_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_cap(LineCap::Round)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(3, 3), Point::new(3, 3))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+        Line::new(Point::new(12, 3), Point::new(18, 9))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "                     ",
+            "  ###      ###       ",
+            " #####    #####      ",
+            " #####    ######     ",
+            " #####    #######    ",
+            "  ###      #######   ",
+            "            #######  ",
+            "             ####### ",
+            "              #######",
+            "               ######",
+            "                #####",
+            "                 ### ",
+        ]);
+    }
 }
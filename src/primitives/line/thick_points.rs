@@ -1,5 +1,5 @@
 use crate::{
-    geometry::{Point, PointExt},
+    geometry::Point,
     primitives::{
         common::LineSide,
         line::{
@@ -40,7 +40,7 @@ pub(in crate::primitives::line) struct ParallelsIterator {
     ///
     /// The thickness threshold is compared with the thickness accumulator to stop the iterator once
     /// the desired line thickness is reached.
-    thickness_threshold: i32,
+    thickness_threshold: i64,
 
     /// Changes the sign of initial error variables.
     ///
@@ -90,8 +90,11 @@ impl ParallelsIterator {
         let perpendicular_parameters = BresenhamParameters::new(&line.perpendicular());
 
         // Thickness threshold, taking into account that fewer pixels are required to draw a
-        // diagonal line of the same perceived width.
-        let thickness_threshold = (thickness * 2).pow(2) * line.delta().length_squared();
+        // diagonal line of the same perceived width. The threshold is calculated using 64 bit
+        // integers to prevent overflows for long lines.
+        let delta = line.delta();
+        let thickness_threshold = (i64::from(thickness) * 2).pow(2)
+            * (i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2));
         let thickness_accumulator =
             (parallel_parameters.error_step.minor + parallel_parameters.error_step.major) / 2;
 
@@ -163,7 +166,7 @@ impl Iterator for ParallelsIterator {
     type Item = (Bresenham, ParallelLineType);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.thickness_accumulator.pow(2) > self.thickness_threshold {
+        if i64::from(self.thickness_accumulator).pow(2) > self.thickness_threshold {
             return None;
         }
 
@@ -199,6 +202,10 @@ impl Iterator for ParallelsIterator {
 }
 
 /// Iterator over all pixels in the stroke of a thick line.
+///
+/// The parallel lines are kept in phase, which guarantees that each pixel is returned exactly
+/// once. This is required for drawing operations which don't allow overdraw, like XOR drawing or
+/// translucent colors.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct ThickPoints {
     parallel: Bresenham,
@@ -499,4 +506,34 @@ mod tests {
             "        ",
         ]);
     }
+
+    #[test]
+    fn no_overdraw() {
+        for thickness in 1..12 {
+            for x in -20..=20 {
+                for y in -20..=20 {
+                    let line = Line::new(Point::new(32, 32), Point::new(32 + x, 32 + y));
+
+                    // Drawing to the mock display will panic on overdraw.
+                    let mut display = MockDisplay::new();
+                    for point in ThickPoints::new(&line, thickness) {
+                        display.draw_pixel(point, Gray8::new(0xFF));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_line() {
+        let short = Line::new(Point::zero(), Point::new(30, 10));
+        let long = Line::new(Point::zero(), Point::new(3000, 1000));
+
+        // Same direction results in the same number of parallels, even if the thickness
+        // calculations would overflow for 32 bit integers.
+        assert_eq!(
+            ParallelsIterator::new(&long, 30, StrokeOffset::None).count(),
+            ParallelsIterator::new(&short, 30, StrokeOffset::None).count()
+        );
+    }
 }
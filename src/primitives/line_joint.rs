@@ -0,0 +1,634 @@
+//! Line joints.
+//!
+//! A [`LineJoint`] describes the corner between two consecutive segments of a thick line, e.g.
+//! the joints between the segments of a [`Polyline`] or the corners of a [`Triangle`]. The joint
+//! contains the corner points of the end edge of the first segment and the start edge of the
+//! second segment, which can be used to render the outline of thick line segments without gaps or
+//! overlaps.
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_graphics::{
+//!     prelude::*,
+//!     primitives::line_joint::{EdgeCorners, JointKind, LineJoint, StrokeOffset},
+//! };
+//!
+//! // Right angle corner of two lines with a stroke width of 4px.
+//! let joint = LineJoint::from_points(
+//!     Point::new(0, 10),
+//!     Point::new(10, 10),
+//!     Point::new(10, 0),
+//!     4,
+//!     StrokeOffset::None,
+//! );
+//!
+//! assert_eq!(joint.kind, JointKind::Miter);
+//! assert_eq!(
+//!     joint.first_edge_end,
+//!     EdgeCorners {
+//!         left: Point::new(8, 8),
+//!         right: Point::new(11, 11),
+//!     }
+//! );
+//! assert_eq!(joint.first_edge_end, joint.second_edge_start);
+//! ```
+//!
+//! [`LineJoint`]: struct.LineJoint.html
+//! [`Polyline`]: ../polyline/struct.Polyline.html
+//! [`Triangle`]: ../triangle/struct.Triangle.html
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::LinearEquation,
+        line::intersection_params::{Intersection, IntersectionParams},
+        Line, LineCap, LineJoin, StrokeAlignment,
+    },
+};
+
+/// Stroke offset.
+///
+/// The stroke offset determines on which side of the line skeleton the stroke of a thick line is
+/// drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum StrokeOffset {
+    /// Stroke is centered around the line skeleton.
+    None,
+
+    /// Stroke is offset to the left of the line.
+    Left,
+
+    /// Stroke is offset to the right of the line.
+    Right,
+}
+
+impl From<StrokeAlignment> for StrokeOffset {
+    fn from(alignment: StrokeAlignment) -> Self {
+        match alignment {
+            StrokeAlignment::Inside => Self::Right,
+            StrokeAlignment::Outside => Self::Left,
+            StrokeAlignment::Center => Self::None,
+        }
+    }
+}
+
+/// Which side of the center line to draw on.
+///
+/// Imagine standing on `start`, looking ahead to where `end` is. `Left` is to your left, `Right` to
+/// your right.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum LineSide {
+    /// Left side of the line
+    Left,
+
+    /// Right side of the line
+    Right,
+}
+
+impl LineSide {
+    /// Swap side.
+    pub fn swap(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Joint kind.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum JointKind {
+    /// Mitered (sharp point)
+    Miter,
+
+    /// Bevelled (flattened point)
+    Bevel {
+        /// Left side or right side?
+        outer_side: LineSide,
+    },
+
+    /// Degenerate (angle between lines is too small to properly render stroke).
+    ///
+    /// Degenerate corners are rendered with a bevel.
+    Degenerate {
+        /// Left side or right side?
+        outer_side: LineSide,
+    },
+
+    /// Lines are colinear.
+    ///
+    /// Start and end points for this join will be equal.
+    Colinear,
+
+    /// The starting cap of a line.
+    Start,
+
+    /// The ending cap of a line.
+    End,
+}
+
+/// The left/right corners that make up the start or end edge of a thick line.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct EdgeCorners {
+    /// Left side point.
+    pub left: Point,
+
+    /// Right side point.
+    pub right: Point,
+}
+
+/// A joint between two thick line segments.
+///
+/// The points of the joint are the corners of the thick segments at the joint. Use the
+/// [`from_points`] constructor to create a joint between two segments and the [`start`] and
+/// [`end`] constructors to create the caps of an open line.
+///
+/// [`from_points`]: #method.from_points
+/// [`start`]: #method.start
+/// [`end`]: #method.end
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LineJoint {
+    /// Joint kind.
+    pub kind: JointKind,
+
+    /// Corners comprising the ending edge of the line that ends at this join.
+    pub first_edge_end: EdgeCorners,
+
+    /// Corners comprising the start edge of the line that begins at this join.
+    pub second_edge_start: EdgeCorners,
+}
+
+/// Extends a line by half the stroke width at both ends if square caps are used.
+fn capped_line(line: Line, width: u32, line_cap: LineCap) -> Line {
+    if line_cap == LineCap::Square {
+        line.extend(width)
+    } else {
+        line
+    }
+}
+
+impl LineJoint {
+    /// Create a starting joint.
+    ///
+    /// `first_edge_end` and `second_edge_start` are set to the same points.
+    pub fn start(start: Point, mid: Point, width: u32, stroke_offset: StrokeOffset) -> Self {
+        Self::start_with_cap(start, mid, width, stroke_offset, LineCap::default())
+    }
+
+    /// Create a starting joint using the given line cap.
+    ///
+    /// [`LineCap::Square`] moves the joint by half the stroke width away from `mid`. All other
+    /// line caps return the same joint as [`start`], with round caps requiring an additional
+    /// circle to be drawn around `start`.
+    ///
+    /// [`LineCap::Square`]: ../enum.LineCap.html#variant.Square
+    /// [`start`]: #method.start
+    pub fn start_with_cap(
+        start: Point,
+        mid: Point,
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_cap: LineCap,
+    ) -> Self {
+        let line = capped_line(Line::new(start, mid), width, line_cap);
+
+        let (l, r) = line.extents(width, stroke_offset);
+
+        let points = EdgeCorners {
+            left: l.start,
+            right: r.start,
+        };
+
+        Self {
+            kind: JointKind::Start,
+            first_edge_end: points,
+            second_edge_start: points,
+        }
+    }
+
+    /// Create an ending joint.
+    ///
+    /// `first_edge_end` and `second_edge_start` are set to the same points.
+    pub fn end(mid: Point, end: Point, width: u32, stroke_offset: StrokeOffset) -> Self {
+        Self::end_with_cap(mid, end, width, stroke_offset, LineCap::default())
+    }
+
+    /// Create an ending joint using the given line cap.
+    ///
+    /// [`LineCap::Square`] moves the joint by half the stroke width away from `mid`. All other
+    /// line caps return the same joint as [`end`], with round caps requiring an additional circle
+    /// to be drawn around `end`.
+    ///
+    /// [`LineCap::Square`]: ../enum.LineCap.html#variant.Square
+    /// [`end`]: #method.end
+    pub fn end_with_cap(
+        mid: Point,
+        end: Point,
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_cap: LineCap,
+    ) -> Self {
+        let line = capped_line(Line::new(mid, end), width, line_cap);
+
+        let (l, r) = line.extents(width, stroke_offset);
+
+        let points = EdgeCorners {
+            left: l.end,
+            right: r.end,
+        };
+
+        Self {
+            kind: JointKind::End,
+            first_edge_end: points,
+            second_edge_start: points,
+        }
+    }
+
+    /// Empty joint.
+    pub(in crate::primitives) fn empty() -> Self {
+        Self {
+            kind: JointKind::End,
+            first_edge_end: EdgeCorners {
+                left: Point::zero(),
+                right: Point::zero(),
+            },
+            second_edge_start: EdgeCorners {
+                left: Point::zero(),
+                right: Point::zero(),
+            },
+        }
+    }
+
+    /// Compute the joint between the line from `start` to `mid` and the line from `mid` to `end`.
+    ///
+    /// The joint is a miter joint if the miter length doesn't exceed twice the stroke width. Longer
+    /// miters are replaced by a bevel. If the segments overlap because the angle between them is
+    /// too small, a degenerate joint is returned.
+    pub fn from_points(
+        start: Point,
+        mid: Point,
+        end: Point,
+        width: u32,
+        stroke_offset: StrokeOffset,
+    ) -> Self {
+        Self::from_points_with_join(start, mid, end, width, stroke_offset, LineJoin::default())
+    }
+
+    /// Compute the joint between two lines using the given line join.
+    ///
+    /// Miter joints are only returned for [`LineJoin::Miter`] if the miter limit isn't exceeded.
+    /// All other line joins return bevel joints, with round joins requiring an additional circle
+    /// to be drawn around `mid`. Colinear and degenerate joints are returned regardless of the
+    /// line join.
+    ///
+    /// [`LineJoin::Miter`]: ../enum.LineJoin.html#variant.Miter
+    pub fn from_points_with_join(
+        start: Point,
+        mid: Point,
+        end: Point,
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+    ) -> Self {
+        let first_line = Line::new(start, mid);
+        let second_line = Line::new(mid, end);
+
+        // Left and right edges of thick first segment
+        let (first_edge_left, first_edge_right) = first_line.extents(width, stroke_offset);
+        // Left and right edges of thick second segment
+        let (second_edge_left, second_edge_right) = second_line.extents(width, stroke_offset);
+
+        if let Some((l_intersection, outer_side, r_intersection)) = intersections(
+            &first_edge_left,
+            &first_edge_right,
+            &second_edge_left,
+            &second_edge_right,
+        ) {
+            // Check if the inside end point of the second line lies inside the first segment.
+            let self_intersection = match outer_side {
+                LineSide::Right => LinearEquation::from_line(&first_edge_left)
+                    .check_side(second_edge_left.end, LineSide::Right),
+                LineSide::Left => LinearEquation::from_line(&first_edge_right)
+                    .check_side(second_edge_right.end, LineSide::Left),
+            };
+
+            // Normal line: non-overlapping line end caps
+            if !self_intersection {
+                // Distance from midpoint to miter outside end point.
+                let miter_length_squared = Line::new(
+                    mid,
+                    match outer_side {
+                        LineSide::Left => l_intersection,
+                        LineSide::Right => r_intersection,
+                    },
+                )
+                .delta()
+                .length_squared() as u64;
+
+                // The miter length from the inner to the outer corner is twice the distance from
+                // the midpoint. Both sides are squared to avoid sqrt() costs.
+                let is_miter = match line_join {
+                    LineJoin::Miter { limit } => {
+                        4 * miter_length_squared <= (u64::from(limit) * u64::from(width)).pow(2)
+                    }
+                    LineJoin::Bevel | LineJoin::Round => false,
+                };
+
+                // Intersection is within limit at which it will be chopped off into a bevel, so
+                // return a miter.
+                if is_miter {
+                    let corners = EdgeCorners {
+                        left: l_intersection,
+                        right: r_intersection,
+                    };
+
+                    Self {
+                        kind: JointKind::Miter,
+                        first_edge_end: corners,
+                        second_edge_start: corners,
+                    }
+                }
+                // Miter is too long, chop it into bevel-style corner
+                else {
+                    match outer_side {
+                        LineSide::Right => Self {
+                            kind: JointKind::Bevel { outer_side },
+                            first_edge_end: EdgeCorners {
+                                left: l_intersection,
+                                right: first_edge_right.end,
+                            },
+                            second_edge_start: EdgeCorners {
+                                left: l_intersection,
+                                right: second_edge_right.start,
+                            },
+                        },
+                        LineSide::Left => Self {
+                            kind: JointKind::Bevel { outer_side },
+                            first_edge_end: EdgeCorners {
+                                left: first_edge_left.end,
+                                right: r_intersection,
+                            },
+                            second_edge_start: EdgeCorners {
+                                left: second_edge_left.start,
+                                right: r_intersection,
+                            },
+                        },
+                    }
+                }
+            }
+            // Line segments overlap (degenerate)
+            else {
+                Self {
+                    kind: match outer_side {
+                        LineSide::Left => JointKind::Degenerate { outer_side },
+                        LineSide::Right => JointKind::Degenerate { outer_side },
+                    },
+                    first_edge_end: EdgeCorners {
+                        left: first_edge_left.end,
+                        right: first_edge_right.end,
+                    },
+                    second_edge_start: EdgeCorners {
+                        left: second_edge_left.start,
+                        right: second_edge_right.start,
+                    },
+                }
+            }
+        }
+        // Lines are colinear
+        else {
+            Self {
+                kind: JointKind::Colinear,
+                first_edge_end: EdgeCorners {
+                    left: first_edge_left.end,
+                    right: first_edge_right.end,
+                },
+                second_edge_start: EdgeCorners {
+                    left: second_edge_left.start,
+                    right: second_edge_right.start,
+                },
+            }
+        }
+    }
+
+    /// The filler line (if any) for bevel and degenerate joints.
+    fn filler_line(&self) -> Option<Line> {
+        match self.kind {
+            JointKind::Bevel { outer_side, .. } | JointKind::Degenerate { outer_side, .. } => {
+                let line = match outer_side {
+                    LineSide::Left => {
+                        Line::new(self.first_edge_end.left, self.second_edge_start.left)
+                    }
+                    LineSide::Right => {
+                        Line::new(self.first_edge_end.right, self.second_edge_start.right)
+                    }
+                };
+
+                Some(line)
+            }
+            _ => None,
+        }
+    }
+
+    fn cap(&self, cap: &EdgeCorners) -> (Line, Option<Line>) {
+        if let Some(filler) = self.filler_line() {
+            let midpoint = filler.midpoint();
+
+            let l1 = Line::new(cap.left, midpoint);
+            let l2 = Line::new(midpoint, cap.right);
+
+            (l1, l2.into())
+        } else {
+            (Line::new(cap.left, cap.right), None)
+        }
+    }
+
+    /// Start node bevel line(s).
+    ///
+    /// If the join is a bevel join, this will return two lines, otherwise one.
+    pub fn start_cap_lines(&self) -> (Line, Option<Line>) {
+        self.cap(&self.second_edge_start)
+    }
+
+    /// End node bevel line(s).
+    ///
+    /// If the join is a bevel join, this will return two lines, otherwise one.
+    pub fn end_cap_lines(&self) -> (Line, Option<Line>) {
+        self.cap(&self.first_edge_end)
+    }
+
+    /// Whether the joint is degenerate (segments self-intersect) or not.
+    pub fn is_degenerate(&self) -> bool {
+        // MSRV: Use matches!() macro when we're at 1.42.0 or greater.
+        if let JointKind::Degenerate { .. } = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn intersections(
+    first_edge_left: &Line,
+    first_edge_right: &Line,
+    second_edge_left: &Line,
+    second_edge_right: &Line,
+) -> Option<(Point, LineSide, Point)> {
+    let params = IntersectionParams::from_lines(second_edge_left, first_edge_left);
+
+    let (l_intersection, outer_side) = if let Intersection::Point {
+        point, outer_side, ..
+    } = params.intersection()
+    {
+        if !params.nearly_colinear_has_error() {
+            (point, outer_side)
+        } else {
+            (first_edge_left.end, outer_side)
+        }
+    } else {
+        return None;
+    };
+
+    let params = IntersectionParams::from_lines(second_edge_right, first_edge_right);
+
+    let r_intersection = if let Intersection::Point { point, .. } = params.intersection() {
+        if !params.nearly_colinear_has_error() {
+            point
+        } else {
+            first_edge_right.end
+        }
+    } else {
+        return None;
+    };
+
+    Some((l_intersection, outer_side, r_intersection))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn miter() {
+        let joint = LineJoint::from_points(
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(10, 10),
+            3,
+            StrokeOffset::None,
+        );
+
+        assert_eq!(joint.kind, JointKind::Miter);
+        assert_eq!(joint.first_edge_end, joint.second_edge_start);
+        assert!(!joint.is_degenerate());
+        assert_eq!(joint.end_cap_lines().1, None);
+    }
+
+    #[test]
+    fn bevel() {
+        let joint = LineJoint::from_points(
+            Point::new(0, 0),
+            Point::new(20, 0),
+            Point::new(0, 5),
+            3,
+            StrokeOffset::None,
+        );
+
+        assert_eq!(
+            joint.kind,
+            JointKind::Bevel {
+                outer_side: LineSide::Left
+            }
+        );
+        assert_ne!(joint.first_edge_end, joint.second_edge_start);
+        assert!(joint.start_cap_lines().1.is_some());
+        assert!(!joint.is_degenerate());
+    }
+
+    #[test]
+    fn degenerate() {
+        let joint = LineJoint::from_points(
+            Point::new(0, 0),
+            Point::new(20, 0),
+            Point::new(0, 1),
+            3,
+            StrokeOffset::None,
+        );
+
+        assert_eq!(
+            joint.kind,
+            JointKind::Degenerate {
+                outer_side: LineSide::Left
+            }
+        );
+        assert!(joint.is_degenerate());
+    }
+
+    #[test]
+    fn colinear() {
+        let joint = LineJoint::from_points(
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(20, 0),
+            3,
+            StrokeOffset::None,
+        );
+
+        assert_eq!(joint.kind, JointKind::Colinear);
+        assert_eq!(joint.first_edge_end, joint.second_edge_start);
+    }
+
+    #[test]
+    fn start_and_end() {
+        let start = LineJoint::start(Point::new(0, 0), Point::new(10, 0), 3, StrokeOffset::None);
+        let end = LineJoint::end(Point::new(0, 0), Point::new(10, 0), 3, StrokeOffset::None);
+
+        assert_eq!(start.kind, JointKind::Start);
+        assert_eq!(end.kind, JointKind::End);
+        assert_eq!(
+            start.second_edge_start.left - start.second_edge_start.right,
+            end.first_edge_end.left - end.first_edge_end.right
+        );
+    }
+
+    #[test]
+    fn start_and_end_with_square_cap() {
+        let start = LineJoint::start_with_cap(
+            Point::new(0, 0),
+            Point::new(10, 0),
+            3,
+            StrokeOffset::None,
+            LineCap::Square,
+        );
+        let end = LineJoint::end_with_cap(
+            Point::new(0, 0),
+            Point::new(10, 0),
+            3,
+            StrokeOffset::None,
+            LineCap::Square,
+        );
+
+        assert_eq!(start.kind, JointKind::Start);
+        assert_eq!(end.kind, JointKind::End);
+        assert_eq!(start.second_edge_start.left.x, -1);
+        assert_eq!(start.second_edge_start.right.x, -1);
+        assert_eq!(end.first_edge_end.left.x, 11);
+        assert_eq!(end.first_edge_end.right.x, 11);
+    }
+
+    #[test]
+    fn stroke_offset_from_alignment() {
+        assert_eq!(
+            StrokeOffset::from(StrokeAlignment::Inside),
+            StrokeOffset::Right
+        );
+        assert_eq!(
+            StrokeOffset::from(StrokeAlignment::Center),
+            StrokeOffset::None
+        );
+        assert_eq!(
+            StrokeOffset::from(StrokeAlignment::Outside),
+            StrokeOffset::Left
+        );
+    }
+}
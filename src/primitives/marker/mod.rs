@@ -0,0 +1,313 @@
+//! The marker primitive
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{Circle, ContainsPoint, PointsIter, Primitive, Rectangle},
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_length, scale_point, Mirror, Rotate,
+        Scale, Transform,
+    },
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Marker primitive
+///
+/// Markers are small symbols which are centered on a point, for example to mark data points in a
+/// plot or positions in a debug overlay.
+///
+/// Styled markers are drawn using the stroke color and the stroke width of the style. The fill
+/// color, stroke alignment and stroke pattern are ignored. Markers are symmetric around their
+/// center point, which is why even sizes and stroke widths are rounded up to the next odd number.
+///
+/// # Examples
+///
+/// ## Mark data points in a plot
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Marker, MarkerShape, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let data = [Point::new(5, 40), Point::new(20, 25), Point::new(35, 30), Point::new(50, 10)];
+///
+/// let style = PrimitiveStyle::with_stroke(Rgb565::YELLOW, 1);
+///
+/// for point in data.iter() {
+///     Marker::new(*point, 5, MarkerShape::X)
+///         .into_styled(style)
+///         .draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw a crosshair
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Marker, MarkerShape, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// Marker::new(Point::new(32, 32), 15, MarkerShape::EmptyCrosshair)
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Marker {
+    /// Center point
+    pub center: Point,
+
+    /// Width and height of the marker
+    pub size: u32,
+
+    /// Shape of the marker
+    pub shape: MarkerShape,
+}
+
+impl Marker {
+    /// Creates a new marker.
+    pub const fn new(center: Point, size: u32, shape: MarkerShape) -> Self {
+        Self {
+            center,
+            size,
+            shape,
+        }
+    }
+
+    /// Returns the distance between the center and the edges of the bounding box.
+    fn radius(&self) -> i32 {
+        (self.size / 2) as i32
+    }
+
+    /// Returns `true` if the point is part of the marker.
+    ///
+    /// `stroke_width` is the thickness of the lines the marker consists of.
+    pub(in crate::primitives) fn contains_with_width(
+        &self,
+        point: Point,
+        stroke_width: u32,
+    ) -> bool {
+        if self.size == 0 || stroke_width == 0 {
+            return false;
+        }
+
+        let radius = self.radius();
+        let Point { x, y } = point - self.center;
+        if x.abs() > radius || y.abs() > radius {
+            return false;
+        }
+
+        // Returns `true` if the point is inside a line, which has the perpendicular distance
+        // `distance / sqrt(length_squared)` to the point.
+        let in_line = |distance: i32, length_squared: i64| {
+            4 * i64::from(distance).pow(2) <= i64::from(stroke_width).pow(2) * length_squared
+        };
+
+        let plus = || in_line(x, 1) || in_line(y, 1);
+        let x_shape = || in_line(x - y, 2) || in_line(x + y, 2);
+
+        match self.shape {
+            MarkerShape::Plus => plus(),
+            MarkerShape::X => x_shape(),
+            MarkerShape::Cross => plus() || x_shape(),
+            MarkerShape::Dot => {
+                Circle::with_center(self.center, (2 * radius + 1) as u32).contains(point)
+            }
+            MarkerShape::EmptyCrosshair => x.abs().max(y.abs()) > radius / 2 && plus(),
+        }
+    }
+}
+
+impl Primitive for Marker {}
+
+impl PointsIter for Marker {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Marker {
+    fn contains(&self, point: Point) -> bool {
+        self.contains_with_width(point, 1)
+    }
+}
+
+impl Dimensions for Marker {
+    fn bounding_box(&self) -> Rectangle {
+        if self.size > 0 {
+            Rectangle::with_center(self.center, Size::new_equal(2 * self.radius() as u32 + 1))
+        } else {
+            Rectangle::new(self.center, Size::zero())
+        }
+    }
+}
+
+impl Transform for Marker {
+    /// Translate the marker from its current position to a new position by (x, y) pixels,
+    /// returning a new `Marker`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Marker, MarkerShape};
+    /// # use embedded_graphics::prelude::*;
+    /// let marker = Marker::new(Point::new(5, 10), 7, MarkerShape::Plus);
+    /// let moved = marker.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the marker from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Marker, MarkerShape};
+    /// # use embedded_graphics::prelude::*;
+    /// let mut marker = Marker::new(Point::new(5, 10), 7, MarkerShape::Plus);
+    /// marker.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(marker.center, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+impl Rotate for Marker {
+    /// Rotate the marker by 90° clockwise around `center`.
+    ///
+    /// All marker shapes are symmetric, which means that only the center point is rotated.
+    fn rotate_90(&self, center: Point) -> Self {
+        Self {
+            center: rotate_point_90(self.center, center),
+            ..*self
+        }
+    }
+}
+
+impl Mirror for Marker {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_x(self.center, axis),
+            ..*self
+        }
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_y(self.center, axis),
+            ..*self
+        }
+    }
+}
+
+impl Scale for Marker {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self {
+            center: scale_point(self.center, center, num, denom),
+            size: scale_length(self.size, num, denom),
+            ..*self
+        }
+    }
+}
+
+/// Marker shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MarkerShape {
+    /// Combination of the `Plus` and `X` shapes.
+    Cross,
+
+    /// Horizontal and vertical line.
+    Plus,
+
+    /// Two diagonal lines.
+    X,
+
+    /// Filled circle.
+    Dot,
+
+    /// Horizontal and vertical line with a gap in the center.
+    ///
+    /// The gap keeps the marked point visible.
+    EmptyCrosshair,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    const SHAPES: [MarkerShape; 5] = [
+        MarkerShape::Cross,
+        MarkerShape::Plus,
+        MarkerShape::X,
+        MarkerShape::Dot,
+        MarkerShape::EmptyCrosshair,
+    ];
+
+    #[test]
+    fn bounding_box() {
+        let center = Point::new(10, 20);
+
+        for &shape in SHAPES.iter() {
+            for &(size, expected) in &[(0, 0), (1, 1), (5, 5), (6, 7), (7, 7)] {
+                let marker = Marker::new(center, size, shape);
+
+                let bounding_box = marker.bounding_box();
+                assert_eq!(bounding_box.size, Size::new_equal(expected));
+                if size > 0 {
+                    assert_eq!(bounding_box.center(), center);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn points_equals_contains() {
+        for &shape in SHAPES.iter() {
+            let marker = Marker::new(Point::new(10, 10), 9, shape);
+
+            let expected = MockDisplay::from_points(marker.points(), BinaryColor::On);
+
+            for point in Rectangle::new(Point::zero(), Size::new(21, 21)).points() {
+                assert_eq!(
+                    marker.contains(point),
+                    expected.get_pixel(point).is_some(),
+                    "{:?}, {:?}",
+                    shape,
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_size() {
+        for &shape in SHAPES.iter() {
+            let marker = Marker::new(Point::new(10, 10), 0, shape);
+
+            assert_eq!(marker.points().next(), None);
+            assert!(!marker.contains(Point::new(10, 10)));
+        }
+    }
+}
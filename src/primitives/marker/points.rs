@@ -0,0 +1,31 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{marker::Marker, rectangle, PointsIter},
+};
+
+/// Iterator over all points in the marker.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Points {
+    iter: rectangle::Points,
+    marker: Marker,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(marker: &Marker) -> Self {
+        Self {
+            iter: marker.bounding_box().points(),
+            marker: *marker,
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let marker = self.marker;
+
+        self.iter
+            .find(|point| marker.contains_with_width(*point, 1))
+    }
+}
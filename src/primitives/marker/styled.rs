@@ -0,0 +1,241 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        marker::Marker,
+        rectangle,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PointsIter, PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+/// Pixel iterator for each pixel in a styled marker.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StyledPixelsIterator<C> {
+    iter: rectangle::Points,
+    marker: Marker,
+    stroke_width: u32,
+    stroke_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(marker: &Marker, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_color = style.effective_stroke_color();
+
+        let iter = if stroke_color.is_some() {
+            marker.bounding_box().points()
+        } else {
+            rectangle::Points::empty()
+        };
+
+        Self {
+            iter,
+            marker: *marker,
+            stroke_width: style.stroke_width,
+            stroke_color,
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.stroke_color?;
+        let marker = self.marker;
+        let stroke_width = self.stroke_width;
+
+        self.iter
+            .find(|point| marker.contains_with_width(*point, stroke_width))
+            .map(|point| Pixel(point, color))
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Marker {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Marker {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Marker {
+    fn styled_bounding_box(&self, _style: &PrimitiveStyle<C>) -> Rectangle {
+        // The stroke width doesn't affect the size of the marker.
+        self.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Point,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{MarkerShape, Primitive},
+        Drawable,
+    };
+
+    fn draw(shape: MarkerShape, size: u32, stroke_width: u32) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+
+        Marker::new(Point::new(4, 4), size, shape)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width))
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn shapes() {
+        draw(MarkerShape::Plus, 9, 1).assert_pattern(&[
+            "    #    ",
+            "    #    ",
+            "    #    ",
+            "    #    ",
+            "#########",
+            "    #    ",
+            "    #    ",
+            "    #    ",
+            "    #    ",
+        ]);
+        draw(MarkerShape::X, 9, 1).assert_pattern(&[
+            "#       #",
+            " #     # ",
+            "  #   #  ",
+            "   # #   ",
+            "    #    ",
+            "   # #   ",
+            "  #   #  ",
+            " #     # ",
+            "#       #",
+        ]);
+        draw(MarkerShape::Cross, 9, 1).assert_pattern(&[
+            "#   #   #",
+            " #  #  # ",
+            "  # # #  ",
+            "   ###   ",
+            "#########",
+            "   ###   ",
+            "  # # #  ",
+            " #  #  # ",
+            "#   #   #",
+        ]);
+        draw(MarkerShape::Dot, 9, 1).assert_pattern(&[
+            "  #####  ",
+            " ####### ",
+            "#########",
+            "#########",
+            "#########",
+            "#########",
+            "#########",
+            " ####### ",
+            "  #####  ",
+        ]);
+        draw(MarkerShape::EmptyCrosshair, 9, 1).assert_pattern(&[
+            "    #    ",
+            "    #    ",
+            "         ",
+            "         ",
+            "##     ##",
+            "         ",
+            "         ",
+            "    #    ",
+            "    #    ",
+        ]);
+    }
+
+    #[test]
+    fn thick_shapes() {
+        draw(MarkerShape::Plus, 9, 3).assert_pattern(&[
+            "   ###   ",
+            "   ###   ",
+            "   ###   ",
+            "#########",
+            "#########",
+            "#########",
+            "   ###   ",
+            "   ###   ",
+            "   ###   ",
+        ]);
+        draw(MarkerShape::X, 9, 2).assert_pattern(&[
+            "##     ##",
+            "###   ###",
+            " ### ### ",
+            "  #####  ",
+            "   ###   ",
+            "  #####  ",
+            " ### ### ",
+            "###   ###",
+            "##     ##",
+        ]);
+        draw(MarkerShape::EmptyCrosshair, 9, 3).assert_pattern(&[
+            "   ###   ",
+            "   ###   ",
+            "         ",
+            "##     ##",
+            "##     ##",
+            "##     ##",
+            "         ",
+            "   ###   ",
+            "   ###   ",
+        ]);
+    }
+
+    #[test]
+    fn thin_stroke_equals_points() {
+        for &shape in &[
+            MarkerShape::Cross,
+            MarkerShape::Plus,
+            MarkerShape::X,
+            MarkerShape::Dot,
+            MarkerShape::EmptyCrosshair,
+        ] {
+            let marker = Marker::new(Point::new(10, 10), 11, shape);
+
+            let expected = MockDisplay::from_points(marker.points(), BinaryColor::On);
+
+            let mut display = MockDisplay::new();
+            marker
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq(&expected);
+            assert_eq!(display.affected_area(), marker.bounding_box());
+        }
+    }
+
+    #[test]
+    fn transparent() {
+        let marker = Marker::new(Point::new(10, 10), 11, MarkerShape::Dot);
+
+        assert_eq!(
+            marker
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .pixels()
+                .next(),
+            None
+        );
+    }
+}
@@ -1,29 +1,55 @@
 //! Graphics primitives
 
+pub mod antialiased;
 pub mod arc;
+pub mod arrow;
+pub mod bezier;
+pub mod catmull_rom_spline;
+pub mod chord;
 pub mod circle;
 mod common;
 pub mod ellipse;
 pub mod line;
+pub mod line_joint;
+pub mod marker;
+pub mod polygon;
 pub mod polyline;
 mod primitive_style;
 pub mod rectangle;
+pub mod regular_polygon;
 pub mod rounded_rectangle;
+pub mod rounded_triangle;
 pub mod sector;
+pub mod star;
 mod styled;
+pub mod superellipse;
 pub mod triangle;
 
 #[doc(no_inline)]
 pub use self::rectangle::Rectangle;
 pub use self::{
+    antialiased::AntialiasedStyle,
     arc::Arc,
+    arrow::{Arrow, ArrowHead, ArrowHeadShape},
+    bezier::{CubicBezier, QuadraticBezier},
+    catmull_rom_spline::CatmullRomSpline,
+    chord::Chord,
     circle::Circle,
     ellipse::Ellipse,
     line::Line,
-    polyline::Polyline,
-    primitive_style::{PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+    marker::{Marker, MarkerShape},
+    polygon::{FillRule, Polygon},
+    polyline::{Polyline, PolylineBuf},
+    primitive_style::{
+        FillPattern, FillStyle, GradientDirection, LineCap, LineJoin, LinearGradient,
+        PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment, StrokePattern,
+    },
+    regular_polygon::RegularPolygon,
     rounded_rectangle::{CornerRadii, CornerRadiiBuilder, RoundedRectangle},
+    rounded_triangle::RoundedTriangle,
     sector::Sector,
+    star::Star,
+    superellipse::Superellipse,
     triangle::Triangle,
 };
 use crate::geometry::{Dimensions, Point};
@@ -39,6 +65,43 @@ pub trait Primitive: Dimensions {
     {
         Styled::new(self, style)
     }
+
+    /// Converts a reference to this primitive into a `Styled` which borrows the style.
+    ///
+    /// Unlike [`into_styled`] this method doesn't consume the primitive or the style, which makes
+    /// it possible to draw the same primitive with different styles, like the pressed and released
+    /// states of a button.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// const RELEASED: PrimitiveStyle<Rgb565> = PrimitiveStyle::with_stroke(Rgb565::WHITE, 1);
+    /// const PRESSED: PrimitiveStyle<Rgb565> =
+    ///     PrimitiveStyle::with_fill_and_stroke(Rgb565::BLUE, Rgb565::WHITE, 1);
+    ///
+    /// let button = Rectangle::new(Point::new(10, 10), Size::new(40, 15));
+    /// let pressed = true;
+    ///
+    /// button
+    ///     .into_styled_ref(if pressed { &PRESSED } else { &RELEASED })
+    ///     .draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`into_styled`]: #method.into_styled
+    // The name mirrors `into_styled`, even though the primitive is borrowed.
+    #[allow(clippy::wrong_self_convention)]
+    fn into_styled_ref<'a, S>(&'a self, style: &'a S) -> Styled<&'a Self, &'a S> {
+        Styled::new(self, style)
+    }
 }
 
 /// Trait to check if a point is inside a closed shape.
@@ -0,0 +1,54 @@
+use crate::{
+    geometry::Point,
+    primitives::{polygon::Polygon, Line},
+};
+
+/// Iterator over the edges of a polygon.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Edges<'a> {
+    vertices: &'a [Point],
+    translate: Point,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> Edges<'a> {
+    pub(in crate::primitives::polygon) fn new(polygon: &Polygon<'a>) -> Self {
+        let count = match polygon.vertices.len() {
+            0 | 1 => 0,
+            2 => 1,
+            n => n,
+        };
+
+        Self {
+            vertices: polygon.vertices,
+            translate: polygon.translate,
+            index: 0,
+            count,
+        }
+    }
+}
+
+impl<'a> Iterator for Edges<'a> {
+    type Item = Line;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let start = self.vertices[self.index];
+        let end = self.vertices[(self.index + 1) % self.vertices.len()];
+        self.index += 1;
+
+        Some(Line::new(start + self.translate, end + self.translate))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.count - self.index;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Edges<'_> {}
@@ -0,0 +1,579 @@
+//! The polygon primitive.
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{
+        common::{simplify, Scanline, StrokeOffset},
+        ContainsPoint, PointsIter, Primitive, Rectangle, StrokeAlignment,
+    },
+    transform::Transform,
+};
+
+mod edges;
+mod points;
+pub(in crate::primitives) mod scanline_iterator;
+mod styled;
+mod triangulation;
+
+pub use edges::Edges;
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+pub use triangulation::Triangulation;
+
+/// Polygon primitive
+///
+/// A polygon is a closed shape that is defined by a slice of vertices. The last vertex is
+/// automatically connected to the first vertex, which means that the first vertex doesn't need
+/// to be repeated at the end of the slice. Unlike [`Polyline`], polygons can be filled.
+///
+/// Polygons can be self-intersecting. The interior of the polygon is determined by the
+/// [`FillRule`], which can be changed using [`with_fill_rule`]. By default the even-odd rule is
+/// used.
+///
+/// # Examples
+///
+/// ## Draw a filled arrow with a stroke
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Polygon, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let vertices = [
+///     Point::new(10, 20),
+///     Point::new(30, 20),
+///     Point::new(30, 10),
+///     Point::new(50, 30),
+///     Point::new(30, 50),
+///     Point::new(30, 40),
+///     Point::new(10, 40),
+/// ];
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// Polygon::new(&vertices)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Iterate over the edges of a polygon
+///
+/// ```rust
+/// use embedded_graphics::{
+///     prelude::*,
+///     primitives::{Line, Polygon},
+/// };
+///
+/// let vertices = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+///
+/// let polygon = Polygon::new(&vertices);
+///
+/// let mut edges = polygon.edges();
+/// assert_eq!(edges.next(), Some(Line::new(Point::new(0, 0), Point::new(10, 0))));
+/// assert_eq!(edges.next(), Some(Line::new(Point::new(10, 0), Point::new(10, 5))));
+/// assert_eq!(edges.next(), Some(Line::new(Point::new(10, 5), Point::new(0, 0))));
+/// assert_eq!(edges.next(), None);
+/// ```
+///
+/// [`Polyline`]: ../polyline/struct.Polyline.html
+/// [`FillRule`]: enum.FillRule.html
+/// [`with_fill_rule`]: #method.with_fill_rule
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Polygon<'a> {
+    /// An offset to apply to the polygon as a whole
+    pub translate: Point,
+
+    /// All vertices in the polygon
+    pub vertices: &'a [Point],
+
+    /// The rule used to determine the interior of the polygon
+    pub fill_rule: FillRule,
+}
+
+impl<'a> Polygon<'a> {
+    /// Create a new polygon from a list of vertices
+    pub const fn new(vertices: &'a [Point]) -> Self {
+        Self {
+            vertices,
+            translate: Point::zero(),
+            fill_rule: FillRule::EvenOdd,
+        }
+    }
+
+    /// Sets the fill rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     prelude::*,
+    ///     primitives::{polygon::FillRule, ContainsPoint, Polygon},
+    /// };
+    ///
+    /// // A pentagram, which overlaps itself in the center.
+    /// let vertices = [
+    ///     Point::new(20, 0),
+    ///     Point::new(32, 38),
+    ///     Point::new(0, 14),
+    ///     Point::new(40, 14),
+    ///     Point::new(8, 38),
+    /// ];
+    ///
+    /// let even_odd = Polygon::new(&vertices);
+    /// let non_zero = Polygon::new(&vertices).with_fill_rule(FillRule::NonZero);
+    ///
+    /// // The center of the pentagram is only filled if the non-zero rule is used.
+    /// assert!(!even_odd.contains(Point::new(20, 20)));
+    /// assert!(non_zero.contains(Point::new(20, 20)));
+    ///
+    /// // The tips of the star are filled by both rules.
+    /// assert!(even_odd.contains(Point::new(20, 5)));
+    /// assert!(non_zero.contains(Point::new(20, 5)));
+    /// ```
+    pub const fn with_fill_rule(self, fill_rule: FillRule) -> Self {
+        Self { fill_rule, ..self }
+    }
+
+    /// Returns an iterator over the edges of the polygon.
+    ///
+    /// The last returned edge connects the last vertex to the first vertex. Polygons with less
+    /// than 2 vertices have no edges and a polygon with 2 vertices has a single edge.
+    pub fn edges(&self) -> Edges<'a> {
+        Edges::new(self)
+    }
+
+    /// Splits the polygon into triangles.
+    ///
+    /// The triangles are generated using the ear clipping algorithm, which requires a buffer to
+    /// keep track of the remaining vertices. The buffer must contain at least as many elements as
+    /// the polygon has vertices, otherwise `None` is returned. A polygon with `n` vertices is
+    /// split into `n - 2` triangles, with fewer triangles being returned if some vertices lie on a
+    /// straight line.
+    ///
+    /// The triangulation is only valid for polygons which don't intersect themselves. The
+    /// returned triangles for self-intersecting polygons don't match the interior of the polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     prelude::*,
+    ///     primitives::{Polygon, Triangle},
+    /// };
+    ///
+    /// let vertices = [
+    ///     Point::new(0, 0),
+    ///     Point::new(20, 0),
+    ///     Point::new(20, 20),
+    ///     Point::new(10, 5),
+    ///     Point::new(0, 20),
+    /// ];
+    ///
+    /// let polygon = Polygon::new(&vertices);
+    ///
+    /// let mut buffer = [0; 5];
+    /// let mut triangles = polygon.triangulate(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(
+    ///     triangles.next(),
+    ///     Some(Triangle::new(Point::new(20, 0), Point::new(20, 20), Point::new(10, 5)))
+    /// );
+    /// assert_eq!(triangles.count(), 2);
+    /// ```
+    // MSRV: store the buffer inside the iterator using const generics for rust >= 1.51.0
+    pub fn triangulate<'b>(&self, buffer: &'b mut [usize]) -> Option<Triangulation<'a, 'b>> {
+        if buffer.len() < self.vertices.len() {
+            return None;
+        }
+
+        Some(Triangulation::new(self.vertices, self.translate, buffer))
+    }
+
+    /// Simplifies the polygon by removing vertices.
+    ///
+    /// The vertices are reduced using the Ramer–Douglas–Peucker algorithm. A vertex is removed if
+    /// its distance to the simplified outline is less than or equal to `tolerance` pixels. The
+    /// first vertex is always kept.
+    ///
+    /// The remaining vertices are written to `buffer`, which must contain at least as many
+    /// elements as the polygon has vertices, otherwise `None` is returned. The returned polygon
+    /// borrows its vertices from `buffer` and uses the same translation and fill rule as this
+    /// polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Polygon};
+    ///
+    /// let vertices = [
+    ///     Point::new(0, 0),
+    ///     Point::new(10, 1),
+    ///     Point::new(20, 0),
+    ///     Point::new(20, 20),
+    ///     Point::new(0, 20),
+    /// ];
+    ///
+    /// let mut buffer = [Point::zero(); 5];
+    /// let simplified = Polygon::new(&vertices).simplify(1, &mut buffer).unwrap();
+    ///
+    /// assert_eq!(
+    ///     simplified.vertices,
+    ///     &[
+    ///         Point::new(0, 0),
+    ///         Point::new(20, 0),
+    ///         Point::new(20, 20),
+    ///         Point::new(0, 20),
+    ///     ]
+    /// );
+    /// ```
+    pub fn simplify<'b>(&self, tolerance: u32, buffer: &'b mut [Point]) -> Option<Polygon<'b>> {
+        let len = simplify(self.vertices, true, tolerance, buffer)?;
+
+        Some(Polygon {
+            translate: self.translate,
+            vertices: &buffer[0..len],
+            fill_rule: self.fill_rule,
+        })
+    }
+
+    /// Returns the stroke offset for the given stroke alignment.
+    ///
+    /// The offset depends on the winding order of the vertices, because the offset is relative to
+    /// the direction of the edges.
+    pub(in crate::primitives::polygon) fn stroke_offset(
+        &self,
+        stroke_alignment: StrokeAlignment,
+    ) -> StrokeOffset {
+        let offset = StrokeOffset::from(stroke_alignment);
+
+        // `StrokeOffset::Right` is on the inside of the polygon if the vertices are sorted
+        // clockwise.
+        if area_doubled(self.vertices) < 0 {
+            match offset {
+                StrokeOffset::Left => StrokeOffset::Right,
+                StrokeOffset::Right => StrokeOffset::Left,
+                StrokeOffset::None => StrokeOffset::None,
+            }
+        } else {
+            offset
+        }
+    }
+}
+
+/// Fill rule.
+///
+/// The fill rule determines which parts of a self-intersecting polygon are part of the interior.
+/// Both rules cast a ray from a point to the right and check the edges which are crossed by this
+/// ray. For polygons which don't intersect themselves both rules produce the same result.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FillRule {
+    /// Even-odd rule.
+    ///
+    /// A point is inside the polygon if the ray crosses an odd number of edges.
+    EvenOdd,
+    /// Non-zero winding rule.
+    ///
+    /// Edges which cross the ray in upward direction are counted as +1 and edges crossing in
+    /// downward direction as -1. A point is inside the polygon if the sum is not zero.
+    NonZero,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        Self::EvenOdd
+    }
+}
+
+impl<'a> Primitive for Polygon<'a> {}
+
+impl<'a> PointsIter for Polygon<'a> {
+    type Iter = Points<'a>;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl<'a> ContainsPoint for Polygon<'a> {
+    fn contains(&self, point: Point) -> bool {
+        let point = point - self.translate;
+
+        if self.vertices.len() < 2 || !untranslated_bounding_box(self.vertices).contains(point) {
+            return false;
+        }
+
+        // Points on the Bresenham representation of the edges are part of the polygon, even if
+        // they are slightly outside of the mathematical polygon.
+        is_inside(self.vertices, self.fill_rule, point)
+            || Polygon::new(self.vertices).edges().any(|edge| {
+                let mut scanline = Scanline::new_empty(point.y);
+                scanline.bresenham_intersection(&edge);
+
+                scanline.x.contains(&point.x)
+            })
+    }
+}
+
+impl<'a> Dimensions for Polygon<'a> {
+    fn bounding_box(&self) -> Rectangle {
+        untranslated_bounding_box(self.vertices).translate(self.translate)
+    }
+}
+
+impl<'a> Transform for Polygon<'a> {
+    /// Translate the polygon from its current position to a new position by (x, y) pixels,
+    /// returning a new `Polygon`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let polygon = Polygon::new(&points);
+    /// let moved = polygon.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(5, 7));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polygon from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let mut polygon = Polygon::new(&points);
+    ///
+    /// polygon.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+/// Returns the bounding box of the vertices.
+fn untranslated_bounding_box(vertices: &[Point]) -> Rectangle {
+    match vertices {
+        [] => Rectangle::zero(),
+        [v] => Rectangle::new(*v, Size::zero()),
+        vertices => Rectangle::from_points(vertices.iter().copied()),
+    }
+}
+
+/// Returns the signed area of the polygon, doubled.
+///
+/// The area is positive if the vertices are sorted clockwise.
+fn area_doubled(vertices: &[Point]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum()
+}
+
+/// Returns the intersections of the edges with the horizontal line at `y`.
+///
+/// Each intersection is returned as the first pixel which is on the right side of the edge and
+/// the winding direction of the edge, which is `1` for upward and `-1` for downward edges.
+/// Horizontal edges don't intersect the line and vertices are only counted for one of the
+/// adjacent edges, to make sure that each vertex is counted once.
+fn crossings(vertices: &[Point], y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .filter(move |(a, b)| (a.y <= y) != (b.y <= y))
+        .map(move |(a, b)| {
+            let dx = i64::from(b.x) - i64::from(a.x);
+            let dy = i64::from(b.y) - i64::from(a.y);
+            let numerator = (i64::from(y) - i64::from(a.y)) * dx;
+
+            // Round the offset towards positive infinity.
+            let (numerator, denominator, winding) = if dy < 0 {
+                (-numerator, -dy, 1)
+            } else {
+                (numerator, dy, -1)
+            };
+            let offset = -(-numerator).div_euclid(denominator);
+
+            // The intersection always lies between the start and end point of the edge and
+            // therefore fits into an `i32`.
+            ((i64::from(a.x) + offset) as i32, winding)
+        })
+}
+
+/// Checks if a point is inside the polygon.
+fn is_inside(vertices: &[Point], fill_rule: FillRule, point: Point) -> bool {
+    let crossings = crossings(vertices, point.y).filter(|(x, _)| *x > point.x);
+
+    match fill_rule {
+        FillRule::EvenOdd => crossings.count() % 2 == 1,
+        FillRule::NonZero => crossings.map(|(_, winding)| winding).sum::<i32>() != 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, PrimitiveStyle, Triangle},
+        Drawable,
+    };
+
+    pub(in crate::primitives::polygon) const ARROW: [Point; 7] = [
+        Point::new(1, 5),
+        Point::new(8, 5),
+        Point::new(8, 1),
+        Point::new(14, 7),
+        Point::new(8, 13),
+        Point::new(8, 9),
+        Point::new(1, 9),
+    ];
+
+    #[test]
+    fn special_case_dimensions() {
+        assert_eq!(Polygon::new(&[]).bounding_box(), Rectangle::zero());
+
+        assert_eq!(
+            Polygon::new(&[Point::new(15, 17)]).bounding_box(),
+            Rectangle::new(Point::new(15, 17), Size::zero())
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        let polygon = Polygon::new(&ARROW).translate(Point::new(-10, 20));
+
+        assert_eq!(
+            polygon.bounding_box(),
+            Rectangle::with_corners(Point::new(-9, 21), Point::new(4, 33))
+        );
+    }
+
+    #[test]
+    fn area_doubled_sign() {
+        let clockwise = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 10)];
+        let counterclockwise = [Point::new(0, 0), Point::new(10, 10), Point::new(10, 0)];
+
+        assert_eq!(area_doubled(&clockwise), 100);
+        assert_eq!(area_doubled(&counterclockwise), -100);
+        assert_eq!(
+            area_doubled(&clockwise),
+            Triangle::from_slice(&clockwise).area_doubled()
+        );
+    }
+
+    #[test]
+    fn edges() {
+        let polygon = Polygon::new(&ARROW).translate(Point::new(1, 2));
+
+        assert_eq!(polygon.edges().count(), ARROW.len());
+        assert_eq!(
+            polygon.edges().last(),
+            Some(Line::new(Point::new(2, 11), Point::new(2, 7)))
+        );
+
+        assert_eq!(Polygon::new(&[]).edges().next(), None);
+        assert_eq!(Polygon::new(&[Point::zero()]).edges().next(), None);
+        assert_eq!(
+            Polygon::new(&[Point::zero(), Point::new(1, 2)])
+                .edges()
+                .collect::<arrayvec::ArrayVec<[Line; 2]>>()
+                .as_slice(),
+            &[Line::new(Point::zero(), Point::new(1, 2))]
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let polygon = Polygon::new(&ARROW).translate(Point::new(3, 4));
+
+        let expected = MockDisplay::from_points(polygon.points(), BinaryColor::On);
+
+        for point in Rectangle::new(Point::zero(), Size::new(30, 30)).points() {
+            assert_eq!(
+                polygon.contains(point),
+                expected.get_pixel(point).is_some(),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn contains_non_zero() {
+        let star = [
+            Point::new(20, 0),
+            Point::new(32, 38),
+            Point::new(0, 14),
+            Point::new(40, 14),
+            Point::new(8, 38),
+        ];
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero].iter() {
+            let polygon = Polygon::new(&star)
+                .with_fill_rule(*fill_rule)
+                .translate(Point::new(5, 6));
+
+            let expected = MockDisplay::from_points(polygon.points(), BinaryColor::On);
+
+            for point in Rectangle::new(Point::zero(), Size::new(50, 50)).points() {
+                assert_eq!(
+                    polygon.contains(point),
+                    expected.get_pixel(point).is_some(),
+                    "{:?}, {:?}",
+                    fill_rule,
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn triangles_match_triangle_primitive() {
+        let triangles = [
+            [Point::new(2, 2), Point::new(30, 10), Point::new(12, 40)],
+            [Point::new(2, 2), Point::new(12, 40), Point::new(30, 10)],
+            [Point::new(40, 3), Point::new(5, 20), Point::new(50, 60)],
+            [Point::new(0, 0), Point::new(63, 0), Point::new(0, 63)],
+            [Point::new(10, 10), Point::new(20, 20), Point::new(30, 30)],
+        ];
+
+        for vertices in triangles.iter() {
+            let mut expected = MockDisplay::new();
+            Triangle::from_slice(vertices)
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Polygon::new(vertices)
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", vertices));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::{Scanline, StrokeOffset},
+        polygon::{scanline_iterator::ScanlineIterator, Polygon},
+        LineJoin,
+    },
+};
+
+/// Iterator over all points inside the polygon.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Points<'a> {
+    scanlines: ScanlineIterator<'a>,
+    current_line: Scanline,
+    translate: Point,
+}
+
+impl<'a> Points<'a> {
+    pub(in crate::primitives) fn new(polygon: &Polygon<'a>) -> Self {
+        let bounding_box = Polygon::new(polygon.vertices).bounding_box();
+
+        Self {
+            scanlines: ScanlineIterator::new(
+                polygon.vertices,
+                polygon.fill_rule,
+                0,
+                StrokeOffset::None,
+                LineJoin::default(),
+                true,
+                &bounding_box,
+            ),
+            current_line: Scanline::new_empty(0),
+            translate: polygon.translate,
+        }
+    }
+}
+
+impl<'a> Iterator for Points<'a> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.current_line.next() {
+                return Some(point + self.translate);
+            }
+
+            self.current_line = self.scanlines.next()?.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{polygon::FillRule, PointsIter, Rectangle},
+        transform::Transform,
+    };
+
+    #[test]
+    fn rectangle() {
+        let vertices = [
+            Point::new(2, 3),
+            Point::new(10, 3),
+            Point::new(10, 8),
+            Point::new(2, 8),
+        ];
+
+        let polygon = Polygon::new(&vertices);
+
+        assert!(polygon
+            .points()
+            .eq(Rectangle::with_corners(Point::new(2, 3), Point::new(10, 8)).points()));
+    }
+
+    #[test]
+    fn concave() {
+        let vertices = [
+            Point::new(1, 1),
+            Point::new(9, 1),
+            Point::new(9, 9),
+            Point::new(5, 5),
+            Point::new(1, 9),
+        ];
+
+        let display = MockDisplay::from_points(Polygon::new(&vertices).points(), BinaryColor::On);
+
+        display.assert_pattern(&[
+            "          ",
+            " #########",
+            " #########",
+            " #########",
+            " #########",
+            " #########",
+            " #### ####",
+            " ###   ###",
+            " ##     ##",
+            " #       #",
+        ]);
+    }
+
+    #[test]
+    fn self_intersecting() {
+        let vertices = [
+            Point::new(1, 1),
+            Point::new(9, 1),
+            Point::new(9, 9),
+            Point::new(3, 9),
+            Point::new(3, 5),
+            Point::new(12, 5),
+            Point::new(12, 7),
+            Point::new(1, 7),
+        ];
+
+        let display = MockDisplay::from_points(Polygon::new(&vertices).points(), BinaryColor::On);
+
+        display.assert_pattern(&[
+            "             ",
+            " #########   ",
+            " #########   ",
+            " #########   ",
+            " #########   ",
+            " ############",
+            " ###     ####",
+            " ############",
+            "   #######   ",
+            "   #######   ",
+        ]);
+    }
+
+    #[test]
+    fn non_zero_fill_rule() {
+        let vertices = [
+            Point::new(1, 1),
+            Point::new(9, 1),
+            Point::new(9, 9),
+            Point::new(3, 9),
+            Point::new(3, 5),
+            Point::new(12, 5),
+            Point::new(12, 7),
+            Point::new(1, 7),
+        ];
+
+        let polygon = Polygon::new(&vertices).with_fill_rule(FillRule::NonZero);
+        let display = MockDisplay::from_points(polygon.points(), BinaryColor::On);
+
+        display.assert_pattern(&[
+            "             ",
+            " #########   ",
+            " #########   ",
+            " #########   ",
+            " #########   ",
+            " ############",
+            " ############",
+            " ############",
+            "   #######   ",
+            "   #######   ",
+        ]);
+    }
+
+    #[test]
+    fn translated() {
+        let vertices = [Point::new(0, 0), Point::new(5, 1), Point::new(2, 6)];
+
+        let polygon = Polygon::new(&vertices);
+        let offset = Point::new(10, 20);
+
+        assert!(polygon
+            .translate(offset)
+            .points()
+            .eq(polygon.points().map(|p| p + offset)));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(Polygon::new(&[]).points().next(), None);
+        assert_eq!(Polygon::new(&[Point::new(1, 2)]).points().next(), None);
+
+        let line = [Point::new(1, 2), Point::new(5, 2)];
+        assert!(Polygon::new(&line)
+            .points()
+            .eq(Rectangle::new(Point::new(1, 2), Size::new(5, 1)).points()));
+    }
+}
@@ -0,0 +1,238 @@
+//! Scanline iterator.
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset},
+        polygon::{crossings, is_inside, FillRule, Polygon},
+        Circle, LineJoin, Rectangle,
+    },
+};
+use core::ops::Range;
+
+/// Iterate over every scanline in the polygon's bounding box.
+///
+/// Each row is split into stroke and fill spans, which are returned from left to right. The
+/// iterator works in the untranslated coordinate system of the polygon.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(in crate::primitives) struct ScanlineIterator<'a> {
+    vertices: &'a [Point],
+    state: ScanlineState,
+}
+
+impl<'a> ScanlineIterator<'a> {
+    /// New.
+    pub fn new(
+        vertices: &'a [Point],
+        fill_rule: FillRule,
+        stroke_width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        has_fill: bool,
+        bounding_box: &Rectangle,
+    ) -> Self {
+        Self {
+            vertices,
+            state: ScanlineState::new(
+                vertices.len(),
+                fill_rule,
+                stroke_width,
+                stroke_offset,
+                line_join,
+                has_fill,
+                bounding_box,
+            ),
+        }
+    }
+}
+
+impl Iterator for ScanlineIterator<'_> {
+    type Item = (Scanline, PointType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.vertices)
+    }
+}
+
+/// State of a scanline iterator.
+///
+/// The state doesn't borrow the vertices, which allows primitives that generate their vertices to
+/// store them alongside the state.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(in crate::primitives) struct ScanlineState {
+    fill_rule: FillRule,
+    stroke_width: u32,
+    stroke_offset: StrokeOffset,
+    line_join: LineJoin,
+    has_fill: bool,
+    rows: Range<i32>,
+    columns: Range<i32>,
+    scanline_y: i32,
+    x: i32,
+}
+
+impl ScanlineState {
+    /// New.
+    pub fn new(
+        vertex_count: usize,
+        fill_rule: FillRule,
+        stroke_width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        has_fill: bool,
+        bounding_box: &Rectangle,
+    ) -> Self {
+        // Polygons with less than 2 vertices aren't drawn.
+        let (rows, columns) = if vertex_count < 2 {
+            (0..0, 0..0)
+        } else {
+            (bounding_box.rows(), bounding_box.columns())
+        };
+
+        Self {
+            fill_rule,
+            stroke_width,
+            stroke_offset,
+            line_join,
+            has_fill,
+            x: columns.end,
+            scanline_y: rows.start,
+            rows,
+            columns,
+        }
+    }
+
+    /// Returns the next boundary span which ends to the right of `x`.
+    ///
+    /// The boundary consists of the stroke or, if the stroke width is zero, the Bresenham
+    /// representation of the edges. Round joins add a circle around each vertex to the stroke.
+    /// Overlapping and adjacent spans are merged.
+    fn next_boundary(&self, vertices: &[Point], x: i32) -> Option<Range<i32>> {
+        if self.stroke_width == 0 {
+            if !self.has_fill {
+                return None;
+            }
+
+            next_span(
+                || {
+                    Polygon::new(vertices).edges().map(move |edge| {
+                        let mut scanline = Scanline::new_empty(self.scanline_y);
+                        scanline.bresenham_intersection(&edge);
+                        scanline
+                    })
+                },
+                x,
+            )
+        } else {
+            let round_joints =
+                if self.line_join == LineJoin::Round && self.stroke_offset == StrokeOffset::None {
+                    vertices
+                } else {
+                    &[]
+                };
+
+            next_span(
+                || {
+                    ClosedThickSegmentIter::new(
+                        vertices,
+                        self.stroke_width,
+                        self.stroke_offset,
+                        self.line_join,
+                    )
+                    .map(move |segment| segment.intersection(self.scanline_y))
+                    .chain(round_joints.iter().map(move |vertex| {
+                        Circle::with_center(*vertex, self.stroke_width).scanline(self.scanline_y)
+                    }))
+                },
+                x,
+            )
+        }
+    }
+
+    /// Returns the next fill span between the current position and `end`.
+    fn next_fill(&mut self, vertices: &[Point], end: i32) -> Option<Scanline> {
+        while self.x < end {
+            let start = self.x;
+
+            // The inside state can only change at the intersections with the edges.
+            self.x = crossings(vertices, self.scanline_y)
+                .map(|(x, _)| x)
+                .filter(|x| *x > start)
+                .min()
+                .map_or(end, |x| x.min(end));
+
+            if is_inside(vertices, self.fill_rule, Point::new(start, self.scanline_y)) {
+                return Some(Scanline::new(self.scanline_y, start..self.x));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the next scanline.
+    ///
+    /// The same vertices must be passed to every call.
+    pub fn next(&mut self, vertices: &[Point]) -> Option<(Scanline, PointType)> {
+        loop {
+            if self.x >= self.columns.end {
+                self.scanline_y = self.rows.next()?;
+                self.x = self.columns.start;
+            }
+
+            let boundary = self.next_boundary(vertices, self.x);
+            let gap_end = boundary
+                .as_ref()
+                .map_or(self.columns.end, |boundary| boundary.start)
+                .min(self.columns.end);
+
+            if self.has_fill {
+                if let Some(fill) = self.next_fill(vertices, gap_end) {
+                    return Some((fill, PointType::Fill));
+                }
+            }
+
+            match boundary {
+                Some(boundary) if boundary.start < self.columns.end => {
+                    let boundary = boundary.start..boundary.end.min(self.columns.end);
+                    self.x = boundary.end;
+
+                    let point_type = if self.stroke_width > 0 {
+                        PointType::Stroke
+                    } else {
+                        PointType::Fill
+                    };
+
+                    return Some((Scanline::new(self.scanline_y, boundary), point_type));
+                }
+                _ => self.x = self.columns.end,
+            }
+        }
+    }
+}
+
+/// Returns the leftmost span that ends to the right of `x`.
+///
+/// The span is clamped to start at `x` and is extended by all spans which overlap or touch it.
+fn next_span<F, I>(spans: F, x: i32) -> Option<Range<i32>>
+where
+    F: Fn() -> I,
+    I: Iterator<Item = Scanline>,
+{
+    let mut span = spans()
+        .filter(|scanline| !scanline.is_empty() && scanline.x.end > x)
+        .map(|scanline| scanline.x.start.max(x)..scanline.x.end)
+        .min_by_key(|span| span.start)?;
+
+    loop {
+        let end = spans()
+            .filter(|scanline| !scanline.is_empty() && scanline.x.start <= span.end)
+            .map(|scanline| scanline.x.end)
+            .fold(span.end, i32::max);
+
+        if end == span.end {
+            return Some(span);
+        }
+
+        span.end = end;
+    }
+}
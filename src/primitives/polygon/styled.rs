@@ -0,0 +1,347 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{ClosedThickSegmentIter, PointType, Scanline},
+        polygon::{scanline_iterator::ScanlineIterator, Polygon},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        FillStyle, LineJoin, PrimitiveStyle, Rectangle, StrokeAlignment,
+    },
+    transform::Transform,
+    Pixel,
+};
+
+/// Compute the bounding box of the non-translated polygon.
+fn untranslated_bounding_box<C: PixelColor>(
+    primitive: &Polygon<'_>,
+    style: &PrimitiveStyle<C>,
+) -> Rectangle {
+    let polygon = Polygon::new(primitive.vertices);
+
+    // Short circuit special cases
+    if style.stroke_width < 2
+        || style.stroke_alignment == StrokeAlignment::Inside
+        || polygon.vertices.len() < 2
+    {
+        return polygon.bounding_box();
+    }
+
+    let (min, max) = ClosedThickSegmentIter::new(
+        polygon.vertices,
+        style.stroke_width,
+        polygon.stroke_offset(style.stroke_alignment),
+        LineJoin::default(),
+    )
+    .fold(
+        (
+            Point::new_equal(core::i32::MAX),
+            Point::new_equal(core::i32::MIN),
+        ),
+        |(min, max), segment| {
+            let bb = segment.edges_bounding_box();
+
+            (
+                min.component_min(bb.top_left),
+                max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
+            )
+        },
+    );
+
+    Rectangle::with_corners(min, max)
+}
+
+fn scanline_iterator<'a, C: PixelColor>(
+    primitive: &Polygon<'a>,
+    style: &PrimitiveStyle<C>,
+) -> ScanlineIterator<'a> {
+    ScanlineIterator::new(
+        primitive.vertices,
+        primitive.fill_rule,
+        style.stroke_width,
+        primitive.stroke_offset(style.stroke_alignment),
+        LineJoin::default(),
+        style.is_filled(),
+        &untranslated_bounding_box(primitive, style),
+    )
+}
+
+/// Pixel iterator for each pixel in the polygon
+///
+/// The polygon is drawn one scanline at a time and each pixel is returned exactly once, even if
+/// the polygon has both a stroke and a fill color.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StyledPixelsIterator<'a, C> {
+    lines_iter: ScanlineIterator<'a>,
+    current_line: Scanline,
+    current_color: Option<C>,
+    current_type: PointType,
+    fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+    stroke_color: Option<C>,
+    translate: Point,
+}
+
+impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
+    pub(in crate::primitives) fn new(primitive: &Polygon<'a>, style: &PrimitiveStyle<C>) -> Self {
+        Self {
+            lines_iter: scanline_iterator(primitive, style),
+            current_line: Scanline::new_empty(0),
+            current_color: None,
+            current_type: PointType::Stroke,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+            stroke_color: style.effective_stroke_color(),
+            translate: primitive.translate,
+        }
+    }
+}
+
+impl<'a, C: PixelColor> Iterator for StyledPixelsIterator<'a, C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(color) = self.current_color {
+                let translate = self.translate;
+
+                let pixel = match self.current_type {
+                    PointType::Stroke => self
+                        .current_line
+                        .next()
+                        .map(|p| Pixel(p + translate, color)),
+                    PointType::Fill => {
+                        let fill_style = self.fill_style;
+                        let bounding_box = self.bounding_box;
+
+                        // The fill pattern is aligned to the translated position.
+                        self.current_line.find_map(|p| {
+                            let p = p + translate;
+                            fill_style
+                                .color_at(p, color, &bounding_box)
+                                .map(|c| Pixel(p, c))
+                        })
+                    }
+                };
+
+                if pixel.is_some() {
+                    return pixel;
+                }
+            }
+
+            let (next_line, next_type) = self.lines_iter.next()?;
+
+            self.current_line = next_line;
+            self.current_type = next_type;
+
+            self.current_color = match next_type {
+                PointType::Stroke => self.stroke_color,
+                PointType::Fill => self.fill_color,
+            };
+        }
+    }
+}
+
+impl<'a, C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Polygon<'a> {
+    type Iter = StyledPixelsIterator<'a, C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<'a, C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Polygon<'a> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.is_transparent() {
+            return Ok(());
+        }
+
+        // Patterned fills can't be drawn using `fill_solid`
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        let target = &mut target.translated(self.translate);
+
+        for (line, kind) in scanline_iterator(self, style) {
+            let color = match kind {
+                PointType::Stroke => style.effective_stroke_color(),
+                PointType::Fill => style.effective_fill_color(),
+            };
+
+            if let Some(color) = color {
+                let rect = line.to_rectangle();
+
+                if !rect.is_zero_sized() {
+                    target.fill_solid(&rect, color)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Polygon<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        untranslated_bounding_box(self, style).translate(self.translate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{polygon::tests::ARROW, Primitive, PrimitiveStyleBuilder, Triangle},
+        Drawable,
+    };
+
+    #[test]
+    fn stroke_and_fill() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Polygon::new(&ARROW)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "               ",
+            "        #      ",
+            "        ##     ",
+            "        #.#    ",
+            "        #..#   ",
+            " ########...#  ",
+            " #...........# ",
+            " #............#",
+            " #...........# ",
+            " ########...#  ",
+            "        #..#   ",
+            "        #.#    ",
+            "        ##     ",
+            "        #      ",
+        ]);
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 0..6 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(*alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                // The vertices of the second polygon are in counter-clockwise order.
+                let reversed = [
+                    ARROW[6], ARROW[5], ARROW[4], ARROW[3], ARROW[2], ARROW[1], ARROW[0],
+                ];
+
+                let mut expected = MockDisplay::new();
+                Polygon::new(&ARROW)
+                    .translate(Point::new(20, 20))
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                for vertices in [ARROW, reversed].iter() {
+                    let styled = Polygon::new(vertices)
+                        .translate(Point::new(20, 20))
+                        .into_styled(style);
+
+                    let mut drawable = MockDisplay::new();
+                    styled.draw(&mut drawable).unwrap();
+
+                    let mut pixels = MockDisplay::new();
+                    styled.pixels().draw(&mut pixels).unwrap();
+
+                    pixels.assert_eq(&drawable);
+                    // Inside and outside strokes are on the same side of the edges, regardless
+                    // of the order of the vertices.
+                    if *alignment != StrokeAlignment::Center {
+                        assert_eq!(drawable.affected_area(), expected.affected_area());
+                    }
+                    assert_eq!(
+                        styled.bounding_box(),
+                        drawable.affected_area(),
+                        "{:?}, {}",
+                        alignment,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn triangle_stroke() {
+        let triangle = Triangle::new(Point::new(5, 5), Point::new(40, 15), Point::new(15, 40));
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        triangle.into_styled(style).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        Polygon::new(&triangle.vertices)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn transparent() {
+        let style = PrimitiveStyle::<BinaryColor>::with_stroke(BinaryColor::On, 0);
+
+        assert_eq!(
+            Polygon::new(&ARROW).into_styled(style).pixels().next(),
+            None
+        );
+    }
+
+    #[test]
+    fn long_thick_two_vertices() {
+        let mut display = MockDisplay::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        display.set_allow_overdraw(true);
+
+        Polygon::new(&[Point::new(-524, -483), Point::new(-138, -9)])
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 28))
+            .draw(&mut display)
+            .unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    geometry::Point,
+    primitives::{polygon::area_doubled, Triangle},
+};
+
+/// Iterator over the triangles of a triangulated polygon.
+///
+/// The triangles are generated using the ear clipping algorithm. See [`Polygon::triangulate`] for
+/// more information.
+///
+/// [`Polygon::triangulate`]: struct.Polygon.html#method.triangulate
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Triangulation<'a, 'b> {
+    vertices: &'a [Point],
+    translate: Point,
+    indices: &'b mut [usize],
+    len: usize,
+    orientation: i64,
+}
+
+impl<'a, 'b> Triangulation<'a, 'b> {
+    pub(in crate::primitives::polygon) fn new(
+        vertices: &'a [Point],
+        translate: Point,
+        indices: &'b mut [usize],
+    ) -> Self {
+        let len = vertices.len();
+
+        for (i, index) in indices[0..len].iter_mut().enumerate() {
+            *index = i;
+        }
+
+        Self {
+            vertices,
+            translate,
+            indices,
+            len,
+            orientation: if area_doubled(vertices) < 0 { -1 } else { 1 },
+        }
+    }
+
+    /// Returns the triangle formed by the remaining vertex at `index` and its neighbors.
+    fn corner(&self, index: usize) -> Triangle {
+        let prev = self.indices[(index + self.len - 1) % self.len];
+        let next = self.indices[(index + 1) % self.len];
+
+        Triangle::new(
+            self.vertices[prev],
+            self.vertices[self.indices[index]],
+            self.vertices[next],
+        )
+    }
+
+    /// Checks if the corner at `index` is an ear.
+    ///
+    /// A corner is an ear if it is convex and no other remaining vertex lies inside of it.
+    fn is_ear(&self, index: usize) -> bool {
+        let corner = self.corner(index);
+
+        if corner.area_doubled() * self.orientation <= 0 {
+            return false;
+        }
+
+        let [a, b, c] = corner.vertices;
+
+        !self.indices[0..self.len]
+            .iter()
+            .map(|i| self.vertices[*i])
+            .filter(|p| !corner.vertices.contains(p))
+            .any(|p| {
+                Triangle::new(a, b, p).area_doubled() * self.orientation >= 0
+                    && Triangle::new(b, c, p).area_doubled() * self.orientation >= 0
+                    && Triangle::new(c, a, p).area_doubled() * self.orientation >= 0
+            })
+    }
+
+    /// Removes the vertex at `index` from the remaining vertices.
+    fn remove(&mut self, index: usize) {
+        self.indices.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+    }
+}
+
+impl Iterator for Triangulation<'_, '_> {
+    type Item = Triangle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.len < 3 {
+                return None;
+            }
+
+            if let Some(index) = (0..self.len).find(|i| self.is_ear(*i)) {
+                let triangle = self.corner(index);
+                self.remove(index);
+
+                return Some(Triangle::new(
+                    triangle.vertices[0] + self.translate,
+                    triangle.vertices[1] + self.translate,
+                    triangle.vertices[2] + self.translate,
+                ));
+            }
+
+            // Vertices on a straight line don't contribute to the area of the polygon and can be
+            // removed without returning a triangle.
+            if let Some(index) = (0..self.len).find(|i| self.corner(*i).area_doubled() == 0) {
+                self.remove(index);
+
+                continue;
+            }
+
+            // There are no ears left if the polygon intersects itself. The remaining vertices are
+            // returned as triangles anyway, to make sure that the iterator terminates.
+            let triangle = self.corner(0);
+            self.remove(0);
+
+            return Some(Triangle::new(
+                triangle.vertices[0] + self.translate,
+                triangle.vertices[1] + self.translate,
+                triangle.vertices[2] + self.translate,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            polygon::{tests::ARROW, Polygon},
+            Primitive, PrimitiveStyle,
+        },
+        transform::Transform,
+        Drawable,
+    };
+
+    fn check_triangulation(vertices: &[Point]) {
+        let polygon = Polygon::new(vertices);
+
+        let mut indices = [0; 16];
+        let triangles = polygon.triangulate(&mut indices).unwrap();
+
+        let mut count = 0;
+        let mut area = 0;
+        for triangle in triangles {
+            assert!(
+                triangle.area_doubled() == 0
+                    || triangle.area_doubled().signum() == area_doubled(vertices).signum(),
+                "{:?}",
+                triangle
+            );
+
+            for vertex in triangle.vertices.iter() {
+                assert!(vertices.contains(vertex));
+            }
+
+            area += triangle.area_doubled();
+            count += 1;
+        }
+
+        assert_eq!(count, vertices.len() - 2, "{:?}", vertices);
+        assert_eq!(area, area_doubled(vertices), "{:?}", vertices);
+    }
+
+    #[test]
+    fn arrow() {
+        check_triangulation(&ARROW);
+    }
+
+    #[test]
+    fn counterclockwise() {
+        let mut reversed = ARROW;
+        reversed.reverse();
+
+        check_triangulation(&reversed);
+    }
+
+    #[test]
+    fn concave() {
+        check_triangulation(&[
+            Point::new(0, 0),
+            Point::new(20, 0),
+            Point::new(20, 20),
+            Point::new(10, 5),
+            Point::new(0, 20),
+        ]);
+
+        check_triangulation(&[
+            Point::new(0, 0),
+            Point::new(30, 0),
+            Point::new(30, 30),
+            Point::new(20, 30),
+            Point::new(20, 10),
+            Point::new(10, 10),
+            Point::new(10, 30),
+            Point::new(0, 30),
+        ]);
+    }
+
+    #[test]
+    fn colinear_vertices() {
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(5, 0),
+            Point::new(10, 0),
+            Point::new(10, 10),
+            Point::new(0, 10),
+        ];
+
+        let mut indices = [0; 5];
+        let triangles = Polygon::new(&vertices).triangulate(&mut indices).unwrap();
+
+        let mut area = 0;
+        for triangle in triangles {
+            assert_ne!(triangle.area_doubled(), 0, "{:?}", triangle);
+            area += triangle.area_doubled();
+        }
+
+        assert_eq!(area, area_doubled(&vertices));
+    }
+
+    #[test]
+    fn fill_area() {
+        let polygon = Polygon::new(&ARROW).translate(Point::new(5, 3));
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        let mut expected = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut indices = [0; 7];
+        for triangle in polygon.triangulate(&mut indices).unwrap() {
+            triangle.into_styled(style).draw(&mut display).unwrap();
+        }
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut indices = [0; 6];
+        assert!(Polygon::new(&ARROW).triangulate(&mut indices).is_none());
+    }
+
+    #[test]
+    fn degenerate() {
+        let mut indices = [0; 2];
+
+        assert_eq!(
+            Polygon::new(&[Point::zero(), Point::new(1, 1)])
+                .triangulate(&mut indices)
+                .unwrap()
+                .next(),
+            None
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        polyline::{points::PointsState, styled::StyledPixelsState, Polyline},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PointsIter, Primitive, PrimitiveStyle, Rectangle,
+    },
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
+    },
+    Pixel,
+};
+use core::hash::{Hash, Hasher};
+
+/// Polyline with owned vertex storage.
+///
+/// `PolylineBuf` stores its vertices in a buffer of type `B`, which makes it possible to build a
+/// polyline at runtime without keeping a separate vertex array around. Any buffer that implements
+/// `AsRef<[Point]>` and `AsMut<[Point]>` can be used, for example an array like `[Point; 16]` or
+/// a mutable slice. The capacity of the polyline is the length of the buffer.
+///
+/// A `PolylineBuf` can be styled and drawn just like a [`Polyline`]. Iterating over the points or
+/// pixels of a `PolylineBuf` requires a buffer that implements `Clone`, because the iterators
+/// store a copy of the vertices. Other buffers can be converted into a `Polyline` using
+/// [`as_polyline`] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PolylineBuf, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut polyline = PolylineBuf::new([Point::zero(); 16]);
+///
+/// for x in 0..6 {
+///     polyline.push(Point::new(5 + x * 10, 20 + (x % 2) * 20)).unwrap();
+/// }
+///
+/// polyline
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Polyline`]: struct.Polyline.html
+/// [`as_polyline`]: #method.as_polyline
+// MSRV: Use a const generic capacity when we bump to at least 1.51.0
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PolylineBuf<B> {
+    /// An offset to apply to the polyline as a whole
+    pub translate: Point,
+
+    /// Whether the last vertex is connected to the first vertex
+    pub closed: bool,
+
+    buffer: B,
+    len: usize,
+}
+
+impl<B> PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+{
+    /// Creates a new empty polyline which uses `buffer` to store its vertices.
+    ///
+    /// The previous content of the buffer is ignored.
+    pub fn new(buffer: B) -> Self {
+        Self {
+            translate: Point::zero(),
+            closed: false,
+            buffer,
+            len: 0,
+        }
+    }
+
+    /// Appends a vertex to the end of the polyline.
+    ///
+    /// Returns the vertex as an error if the buffer is full.
+    pub fn push(&mut self, vertex: Point) -> Result<(), Point> {
+        let slot = self.buffer.as_mut().get_mut(self.len).ok_or(vertex)?;
+        *slot = vertex;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the last vertex and returns it, or `None` if the polyline is empty.
+    pub fn pop(&mut self) -> Option<Point> {
+        self.len = self.len.checked_sub(1)?;
+
+        Some(self.buffer.as_ref()[self.len])
+    }
+
+    /// Removes all vertices.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the maximum number of vertices.
+    pub fn capacity(&self) -> usize {
+        self.buffer.as_ref().len()
+    }
+
+    /// Returns the vertices of the polyline.
+    pub fn vertices(&self) -> &[Point] {
+        &self.buffer.as_ref()[0..self.len]
+    }
+
+    /// Returns a `Polyline` which borrows the vertices of this polyline.
+    pub fn as_polyline(&self) -> Polyline<'_> {
+        Polyline {
+            translate: self.translate,
+            vertices: self.vertices(),
+            closed: self.closed,
+        }
+    }
+}
+
+// Vertices after `len` are ignored by the comparison and hash implementations, because they can
+// contain arbitrary data.
+impl<B, B2> PartialEq<PolylineBuf<B2>> for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+    B2: AsRef<[Point]> + AsMut<[Point]>,
+{
+    fn eq(&self, other: &PolylineBuf<B2>) -> bool {
+        self.as_polyline() == other.as_polyline()
+    }
+}
+
+impl<B> Eq for PolylineBuf<B> where B: AsRef<[Point]> + AsMut<[Point]> {}
+
+impl<B> Hash for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_polyline().hash(state)
+    }
+}
+
+impl<B> Primitive for PolylineBuf<B> where B: AsRef<[Point]> + AsMut<[Point]> {}
+
+impl<B> PointsIter for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    type Iter = BufPoints<B>;
+
+    fn points(&self) -> Self::Iter {
+        BufPoints {
+            state: PointsState::new(&self.as_polyline()),
+            polyline: self.clone(),
+        }
+    }
+}
+
+impl<B> Dimensions for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.as_polyline().bounding_box()
+    }
+}
+
+impl<B> Transform for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    /// Translate the polyline from its current position to a new position by (x, y) pixels, returning
+    /// a new `PolylineBuf`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::PolylineBuf;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut polyline = PolylineBuf::new([Point::zero(); 4]);
+    /// polyline.push(Point::new(5, 10)).unwrap();
+    /// polyline.push(Point::new(7, 7)).unwrap();
+    ///
+    /// let moved = polyline.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..self.clone()
+        }
+    }
+
+    /// Translate the polyline from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::PolylineBuf;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut polyline = PolylineBuf::new([Point::zero(); 4]);
+    /// polyline.push(Point::new(5, 10)).unwrap();
+    /// polyline.push(Point::new(7, 7)).unwrap();
+    ///
+    /// polyline.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(polyline.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+impl<B> PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    /// Returns a copy of the polyline with `f` applied to all translated vertices.
+    fn map_vertices<F>(&self, f: F) -> Self
+    where
+        F: Fn(Point) -> Point,
+    {
+        let mut polyline = self.clone();
+        let translate = polyline.translate;
+
+        for vertex in polyline.buffer.as_mut()[0..self.len].iter_mut() {
+            *vertex = f(*vertex + translate);
+        }
+        polyline.translate = Point::zero();
+
+        polyline
+    }
+}
+
+impl<B> Rotate for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    fn rotate_90(&self, center: Point) -> Self {
+        self.map_vertices(|vertex| rotate_point_90(vertex, center))
+    }
+}
+
+impl<B> Mirror for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    fn mirror_x(&self, axis: i32) -> Self {
+        self.map_vertices(|vertex| mirror_point_x(vertex, axis))
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        self.map_vertices(|vertex| mirror_point_y(vertex, axis))
+    }
+}
+
+impl<B> Scale for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+{
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        self.map_vertices(|vertex| scale_point(vertex, center, num, denom))
+    }
+}
+
+impl<B, C> StyledDrawable<PrimitiveStyle<C>> for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.as_polyline().draw_styled(style, target)
+    }
+}
+
+impl<B, C> StyledPixels<PrimitiveStyle<C>> for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]> + Clone,
+    C: PixelColor,
+{
+    type Iter = BufStyledPixelsIterator<B, C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        BufStyledPixelsIterator {
+            state: StyledPixelsState::new(&self.as_polyline(), style),
+            polyline: self.clone(),
+        }
+    }
+}
+
+impl<B, C> StyledDimensions<PrimitiveStyle<C>> for PolylineBuf<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+    C: PixelColor,
+{
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        self.as_polyline().styled_bounding_box(style)
+    }
+}
+
+/// Iterator over all points on a `PolylineBuf`.
+#[derive(Clone, Debug)]
+pub struct BufPoints<B> {
+    polyline: PolylineBuf<B>,
+    state: PointsState,
+}
+
+impl<B> Iterator for BufPoints<B>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+{
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.polyline.vertices())
+    }
+}
+
+/// Pixel iterator for each pixel in a `PolylineBuf`.
+#[derive(Clone, Debug)]
+pub struct BufStyledPixelsIterator<B, C> {
+    polyline: PolylineBuf<B>,
+    state: StyledPixelsState<C>,
+}
+
+impl<B, C> Iterator for BufStyledPixelsIterator<B, C>
+where
+    B: AsRef<[Point]> + AsMut<[Point]>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.polyline.vertices())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        iterator::PixelIteratorExt, mock_display::MockDisplay, pixelcolor::BinaryColor,
+        primitives::polyline::tests::SMALL, Drawable,
+    };
+
+    #[test]
+    fn push_and_pop() {
+        let mut polyline = PolylineBuf::new([Point::zero(); 3]);
+        assert_eq!(polyline.capacity(), 3);
+        assert_eq!(polyline.vertices(), &[]);
+
+        assert_eq!(polyline.push(Point::new(1, 2)), Ok(()));
+        assert_eq!(polyline.push(Point::new(3, 4)), Ok(()));
+        assert_eq!(polyline.push(Point::new(5, 6)), Ok(()));
+        assert_eq!(polyline.push(Point::new(7, 8)), Err(Point::new(7, 8)));
+
+        assert_eq!(
+            polyline.vertices(),
+            &[Point::new(1, 2), Point::new(3, 4), Point::new(5, 6)]
+        );
+
+        assert_eq!(polyline.pop(), Some(Point::new(5, 6)));
+        assert_eq!(polyline.vertices(), &[Point::new(1, 2), Point::new(3, 4)]);
+
+        polyline.clear();
+        assert_eq!(polyline.pop(), None);
+    }
+
+    #[test]
+    fn eq_ignores_unused_vertices() {
+        let mut a = PolylineBuf::new([Point::zero(); 3]);
+        a.push(Point::new(1, 2)).unwrap();
+
+        let mut b = PolylineBuf::new([Point::new(5, 5); 4]);
+        b.push(Point::new(1, 2)).unwrap();
+
+        assert_eq!(a, b);
+
+        b.closed = true;
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn slice_buffer() {
+        let mut buffer = [Point::zero(); 8];
+        let mut polyline = PolylineBuf::new(&mut buffer[..]);
+
+        for vertex in SMALL.iter() {
+            polyline.push(*vertex).unwrap();
+        }
+
+        assert_eq!(polyline.as_polyline(), Polyline::new(&SMALL));
+    }
+
+    #[test]
+    fn points() {
+        let mut polyline = PolylineBuf::new([Point::zero(); 8]);
+        for vertex in SMALL.iter() {
+            polyline.push(*vertex).unwrap();
+        }
+
+        assert!(polyline.points().eq(Polyline::new(&SMALL).points()));
+    }
+
+    #[test]
+    fn draw_equals_polyline() {
+        let mut polyline = PolylineBuf::new([Point::zero(); 8]);
+        for vertex in SMALL.iter() {
+            polyline.push(*vertex).unwrap();
+        }
+        polyline.translate_mut(Point::new(3, 4));
+
+        for stroke_width in 1..4 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let mut expected = MockDisplay::new();
+            Polyline::new(&SMALL)
+                .translate(Point::new(3, 4))
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            polyline.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+
+            let mut pixels = MockDisplay::new();
+            polyline
+                .into_styled(style)
+                .pixels()
+                .draw(&mut pixels)
+                .unwrap();
+            pixels.assert_eq(&expected);
+
+            assert_eq!(
+                polyline.into_styled(style).bounding_box(),
+                expected.affected_area()
+            );
+        }
+    }
+}
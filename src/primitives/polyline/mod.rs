@@ -2,15 +2,17 @@
 
 use crate::{
     geometry::{Dimensions, Point, Size},
-    primitives::{PointsIter, Primitive, Rectangle},
+    primitives::{common::simplify, PointsIter, Primitive, Rectangle},
     transform::Transform,
 };
 
+mod buf;
 mod points;
 pub(in crate::primitives) mod scanline_intersections;
 mod scanline_iterator;
 mod styled;
 
+pub use buf::{BufPoints, BufStyledPixelsIterator, PolylineBuf};
 pub use points::Points;
 pub use styled::StyledPixelsIterator;
 
@@ -53,6 +55,31 @@ pub use styled::StyledPixelsIterator;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// ## Draw a closed polyline
+///
+/// Closed polylines connect the last vertex back to the first vertex. Unlike repeating the first
+/// vertex at the end of the list, this draws a proper joint at the first vertex instead of two
+/// line ends.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565, prelude::*, primitives::{Polyline, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let points = [
+///     Point::new(10, 10),
+///     Point::new(50, 10),
+///     Point::new(30, 40),
+/// ];
+///
+/// Polyline::closed(&points)
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 5))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Polyline<'a> {
     /// An offset to apply to the polyline as a whole
@@ -60,6 +87,9 @@ pub struct Polyline<'a> {
 
     /// All vertices in the line
     pub vertices: &'a [Point],
+
+    /// Whether the last vertex is connected to the first vertex
+    pub closed: bool,
 }
 
 impl<'a> Polyline<'a> {
@@ -70,8 +100,76 @@ impl<'a> Polyline<'a> {
         Self {
             vertices,
             translate: Point::zero(),
+            closed: false,
         }
     }
+
+    /// Create a new closed polyline from a list of vertices
+    ///
+    /// The last vertex is connected back to the first vertex. If fewer than three vertices are
+    /// provided, the closing segment is omitted, because it would overlap the other segment.
+    pub const fn closed(vertices: &'a [Point]) -> Self {
+        Self {
+            vertices,
+            translate: Point::zero(),
+            closed: true,
+        }
+    }
+
+    /// Simplifies the polyline by removing vertices.
+    ///
+    /// The vertices are reduced using the Ramer–Douglas–Peucker algorithm. A vertex is removed if
+    /// its distance to the simplified line is less than or equal to `tolerance` pixels. The first
+    /// and last vertex are always kept. This can be used to reduce the number of segments that
+    /// need to be drawn for lines with many vertices, like plots of sampled data.
+    ///
+    /// The remaining vertices are written to `buffer`, which must contain at least as many
+    /// elements as the polyline has vertices, otherwise `None` is returned. The returned polyline
+    /// borrows its vertices from `buffer` and uses the same translation as this polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Polyline};
+    ///
+    /// let samples = [
+    ///     Point::new(0, 10),
+    ///     Point::new(1, 11),
+    ///     Point::new(2, 10),
+    ///     Point::new(3, 20),
+    ///     Point::new(4, 10),
+    ///     Point::new(5, 9),
+    ///     Point::new(6, 10),
+    /// ];
+    ///
+    /// let mut buffer = [Point::zero(); 7];
+    /// let simplified = Polyline::new(&samples).simplify(1, &mut buffer).unwrap();
+    ///
+    /// assert_eq!(
+    ///     simplified.vertices,
+    ///     &[
+    ///         Point::new(0, 10),
+    ///         Point::new(2, 10),
+    ///         Point::new(3, 20),
+    ///         Point::new(4, 10),
+    ///         Point::new(6, 10),
+    ///     ]
+    /// );
+    /// ```
+    pub fn simplify<'b>(&self, tolerance: u32, buffer: &'b mut [Point]) -> Option<Polyline<'b>> {
+        let len = simplify(self.vertices, self.closed, tolerance, buffer)?;
+
+        Some(Polyline {
+            translate: self.translate,
+            vertices: &buffer[0..len],
+            closed: self.closed,
+        })
+    }
+
+    /// Returns `true` if the closing segment between the last and first vertex is drawn.
+    fn has_closing_segment(&self) -> bool {
+        self.closed && self.vertices.len() > 2
+    }
 }
 
 impl<'a> Primitive for Polyline<'a> {}
@@ -89,23 +187,7 @@ impl<'a> Dimensions for Polyline<'a> {
         match self.vertices {
             [] => Rectangle::zero(),
             [v] => Rectangle::new(*v, Size::zero()),
-            vertices => {
-                let top_left = vertices
-                    .iter()
-                    .map(|v| *v + self.translate)
-                    .fold(Point::new(core::i32::MAX, core::i32::MAX), |accum, v| {
-                        Point::new(accum.x.min(v.x), accum.y.min(v.y))
-                    });
-
-                let bottom_right = vertices
-                    .iter()
-                    .map(|v| *v + self.translate)
-                    .fold(Point::new(core::i32::MIN, core::i32::MIN), |accum, v| {
-                        Point::new(accum.x.max(v.x), accum.y.max(v.y))
-                    });
-
-                Rectangle::with_corners(top_left, bottom_right)
-            }
+            vertices => Rectangle::from_points(vertices.iter().map(|v| *v + self.translate)),
         }
     }
 }
@@ -11,8 +11,7 @@ use crate::{
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Points<'a> {
     vertices: &'a [Point],
-    translate: Point,
-    segment_iter: line::Points,
+    state: PointsState,
 }
 
 impl<'a> Points<'a> {
@@ -20,26 +19,10 @@ impl<'a> Points<'a> {
     where
         'a: 'b,
     {
-        polyline
-            .vertices
-            .split_first()
-            .and_then(|(start, rest)| {
-                // Polyline is 2 or more vertices long, return an iterator for it
-                rest.get(0).map(|end| Points {
-                    vertices: rest,
-                    translate: polyline.translate,
-                    segment_iter: Line::new(*start + polyline.translate, *end + polyline.translate)
-                        .points(),
-                })
-            })
-            .unwrap_or_else(||
-                // Polyline is less than 2 vertices long. Return a dummy iterator that will short
-                // circuit
-                Points {
-                    vertices: &[],
-                    translate: Point::zero(),
-                    segment_iter: line::Points::empty(),
-                })
+        Self {
+            vertices: polyline.vertices,
+            state: PointsState::new(polyline),
+        }
     }
 }
 
@@ -47,19 +30,77 @@ impl<'a> Iterator for Points<'a> {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(p) = self.segment_iter.next() {
-            Some(p)
+        self.state.next(self.vertices)
+    }
+}
+
+/// State of a points iterator.
+///
+/// The state doesn't borrow the vertices, which allows primitives that generate their vertices to
+/// store them alongside the state.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(in crate::primitives) struct PointsState {
+    translate: Point,
+    segment_end: usize,
+    segment_iter: line::Points,
+    closing_segment: bool,
+}
+
+impl PointsState {
+    /// New.
+    pub fn new(polyline: &Polyline<'_>) -> Self {
+        // MSRV: Use subslice patterns when we bump to at least 1.42.0
+        if let Some([start, end]) = polyline.vertices.get(0..2) {
+            // Polyline is 2 or more vertices long, return an iterator for it
+            Self {
+                translate: polyline.translate,
+                segment_end: 1,
+                segment_iter: Line::new(*start + polyline.translate, *end + polyline.translate)
+                    .points(),
+                closing_segment: polyline.has_closing_segment(),
+            }
         } else {
-            let (start, rest) = self.vertices.split_first()?;
-            let end = rest.get(0)?;
+            // Polyline is less than 2 vertices long. Return a dummy iterator that will short
+            // circuit
+            Self {
+                translate: Point::zero(),
+                segment_end: 0,
+                segment_iter: line::Points::empty(),
+                closing_segment: false,
+            }
+        }
+    }
+
+    /// Returns the next point.
+    ///
+    /// The same vertices must be passed to every call.
+    pub fn next(&mut self, vertices: &[Point]) -> Option<Point> {
+        if let Some(p) = self.segment_iter.next() {
+            return Some(p);
+        }
+
+        let start = *vertices.get(self.segment_end)?;
 
-            self.vertices = rest;
+        if let Some(end) = vertices.get(self.segment_end + 1) {
+            self.segment_end += 1;
 
-            self.segment_iter = Line::new(*start + self.translate, *end + self.translate).points();
+            self.segment_iter = Line::new(start + self.translate, *end + self.translate).points();
+        } else if self.closing_segment {
+            self.closing_segment = false;
 
-            // Skip first point of next line, otherwise we overlap with the previous line
-            self.nth(1)
+            self.segment_iter =
+                Line::new(start + self.translate, vertices[0] + self.translate).points();
+
+            // Skip the last point of the closing line, which was already returned as the first
+            // point of the first line.
+            self.segment_iter.next_back();
+        } else {
+            return None;
         }
+
+        // Skip first point of next line, otherwise we overlap with the previous line
+        self.segment_iter.next();
+        self.next(vertices)
     }
 }
 
@@ -100,6 +141,30 @@ mod tests {
         assert!(polyline.points().eq(core::iter::empty()));
     }
 
+    #[test]
+    fn closed() {
+        let mut repeated_start = [Point::zero(); 5];
+        repeated_start[0..4].copy_from_slice(&SMALL);
+        repeated_start[4] = SMALL[0];
+
+        let open = Polyline::new(&repeated_start);
+        let closed = Polyline::closed(&SMALL);
+
+        // The open polyline returns the start point twice.
+        assert!(closed
+            .points()
+            .eq(open.points().take(open.points().count() - 1)));
+    }
+
+    #[test]
+    fn closed_two_points() {
+        let points = [Point::new(2, 5), Point::new(7, 3)];
+
+        assert!(Polyline::closed(&points)
+            .points()
+            .eq(Polyline::new(&points).points()));
+    }
+
     #[test]
     fn equal_points() {
         let points: [Point; 3] = [Point::new(2, 5), Point::new(2, 5), Point::new(2, 5)];
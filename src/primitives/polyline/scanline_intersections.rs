@@ -2,7 +2,10 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, Scanline, StrokeOffset, ThickSegment},
+    primitives::{
+        common::{LineJoint, Scanline, StrokeOffset, ThickSegment},
+        Circle, LineCap, LineJoin,
+    },
 };
 
 /// Scanline intersections iterator.
@@ -10,95 +13,155 @@ use crate::{
 /// This iterator returns multiple `Line`s corresponding to the filled in areas of a polyline
 /// defined by the `points` parameter.
 ///
-/// The result is one line of a filled polygon.
-#[derive(Clone, Debug)]
-pub struct ScanlineIntersections<'a> {
-    points: &'a [Point],
-    remaining_points: &'a [Point],
-    next_start_join: Option<LineJoin>,
+/// The result is one line of a filled polygon. The iterator doesn't borrow the points, which need
+/// to be passed to every call to `next` instead.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ScanlineIntersections {
     width: u32,
+    stroke_offset: StrokeOffset,
+    line_join: LineJoin,
+    line_cap: LineCap,
+    segment_index: usize,
+    next_start_join: Option<LineJoint>,
+    round_joint: Option<Point>,
     scanline: Scanline,
 }
 
-const EMPTY: &[Point; 3] = &[Point::zero(); 3];
-
-impl<'a> ScanlineIntersections<'a> {
+impl ScanlineIntersections {
     /// New
-    pub fn new(points: &'a [Point], width: u32, scanline_y: i32) -> Self {
-        // let next_start_join = if let Some([first, second]) = points.get(0..1) {
-        //     Some(LineJoin::start(*first, *second, width, StrokeOffset::None))
-        // } else {
-        //     None
-        // };
-
+    pub fn new(
+        points: &[Point],
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        line_cap: LineCap,
+        scanline_y: i32,
+    ) -> Self {
         // MSRV: Use subslice patterns when we bump to at least 1.42.0
         let next_start_join = match points.get(0..2) {
-            Some([first, second]) => {
-                Some(LineJoin::start(*first, *second, width, StrokeOffset::None))
-            }
+            Some([first, second]) => Some(LineJoint::start_with_cap(
+                *first,
+                *second,
+                width,
+                stroke_offset,
+                line_cap,
+            )),
             _ => None,
         };
 
+        // The round cap at the start point is returned before the first segment.
+        let round_joint = if next_start_join.is_some() && has_round_caps(line_cap, stroke_offset) {
+            points.first().copied()
+        } else {
+            None
+        };
+
         Self {
-            next_start_join,
             width,
-            points,
-            remaining_points: points,
+            stroke_offset,
+            line_join,
+            line_cap,
+            segment_index: 0,
+            next_start_join,
+            round_joint,
             scanline: Scanline::new_empty(scanline_y),
         }
     }
 
     /// Empty scanline iterator.
     pub(in crate::primitives) fn empty() -> Self {
-        Self {
-            next_start_join: None,
-            width: 0,
-            points: EMPTY,
-            remaining_points: EMPTY,
-            scanline: Scanline::new_empty(0),
-        }
+        Self::new(
+            &[],
+            0,
+            StrokeOffset::None,
+            LineJoin::default(),
+            LineCap::default(),
+            0,
+        )
     }
 
     /// Reset scanline iterator with a new scanline.
-    pub(in crate::primitives) fn reset_with_new_scanline(&mut self, scanline_y: i32) {
-        *self = Self::new(self.points, self.width, scanline_y);
+    pub(in crate::primitives) fn reset_with_new_scanline(
+        &mut self,
+        points: &[Point],
+        scanline_y: i32,
+    ) {
+        *self = Self::new(
+            points,
+            self.width,
+            self.stroke_offset,
+            self.line_join,
+            self.line_cap,
+            scanline_y,
+        );
     }
 
-    fn next_segment(&mut self) -> Option<ThickSegment> {
+    fn next_segment(&mut self, points: &[Point]) -> Option<ThickSegment> {
         let start_join = self.next_start_join?;
 
-        // let end_join = match self.remaining_points {
-        //     [start, mid, end, ..] => {
-        //         LineJoin::from_points(*start, *mid, *end, self.width, StrokeOffset::None)
-        //     }
-        //     [start, end] => LineJoin::end(*start, *end, self.width, StrokeOffset::None),
-        //     _ => return None,
-        // };
+        let remaining_points = points.get(self.segment_index..)?;
 
         // MSRV: Use subslice patterns when we bump to at least 1.42.0
-        let end_join = self
-            .remaining_points
+        let end_join = remaining_points
             .get(0..3)
-            .or_else(|| self.remaining_points.get(0..2))?;
+            .or_else(|| remaining_points.get(0..2))?;
 
         let end_join = match end_join {
-            [start, mid, end] => {
-                LineJoin::from_points(*start, *mid, *end, self.width, StrokeOffset::None)
+            [start, mid, end] => LineJoint::from_points_with_join(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            ),
+            [mid, end] => {
+                LineJoint::end_with_cap(*mid, *end, self.width, self.stroke_offset, self.line_cap)
             }
-            [mid, end] => LineJoin::end(*mid, *end, self.width, StrokeOffset::None),
             _ => return None,
         };
 
-        self.remaining_points = self.remaining_points.get(1..)?;
+        self.segment_index += 1;
+        self.next_start_join = Some(end_join);
 
-        let segment = ThickSegment::new(start_join, end_join);
+        Some(ThickSegment::new(start_join, end_join))
+    }
 
-        self.next_start_join = Some(end_join);
+    /// Returns the next segment, round joint or round cap intersection.
+    fn next_intersection(&mut self, points: &[Point]) -> Option<Scanline> {
+        if let Some(center) = self.round_joint.take() {
+            let scanline = Circle::with_center(center, self.width).scanline(self.scanline.y);
+
+            // Joints which don't intersect the scanline are skipped to make sure that the
+            // adjacent segments are still merged.
+            if !scanline.is_empty() {
+                return Some(scanline);
+            }
+        }
+
+        let segment = self.next_segment(points)?;
+
+        // Round joints are drawn as a bevel joint with an additional circle around the vertex.
+        // Only strokes which are centered on the polyline are supported, because the circle
+        // wouldn't line up with the edges of an offset stroke. Round caps are drawn in the same
+        // way at the end of the last segment.
+        if self.segment_index + 1 < points.len() {
+            if self.line_join == LineJoin::Round && self.stroke_offset == StrokeOffset::None {
+                self.round_joint = Some(points[self.segment_index]);
+            }
+        } else if has_round_caps(self.line_cap, self.stroke_offset) {
+            self.round_joint = Some(points[self.segment_index]);
+        }
 
-        Some(segment)
+        Some(segment.intersection(self.scanline.y))
     }
 }
 
+/// Returns `true` if circles need to be drawn around the start and end point.
+fn has_round_caps(line_cap: LineCap, stroke_offset: StrokeOffset) -> bool {
+    line_cap == LineCap::Round && stroke_offset == StrokeOffset::None
+}
+
 /// This iterator loops through all scanline intersections for all segments. If two intersections
 /// are adjacent or overlapping, an accumulator line is extended. This repeats until the next
 /// intersection does not touch the current accumulator. At this point, the accumulated line
@@ -123,13 +186,12 @@ impl<'a> ScanlineIntersections<'a> {
 ///      ⇓
 /// A---A B---B
 /// ```
-impl<'a> Iterator for ScanlineIntersections<'a> {
-    type Item = Scanline;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(segment) = self.next_segment() {
-            let next_scanline = segment.intersection(self.scanline.y);
-
+impl ScanlineIntersections {
+    /// Returns the next intersection.
+    ///
+    /// The same points must be passed to every call.
+    pub fn next(&mut self, points: &[Point]) -> Option<Scanline> {
+        while let Some(next_scanline) = self.next_intersection(points) {
             if !self.scanline.try_extend(&next_scanline) {
                 let ret = self.scanline.clone();
                 self.scanline = next_scanline;
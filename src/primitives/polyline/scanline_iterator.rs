@@ -3,13 +3,15 @@
 use core::ops::Range;
 
 use crate::{
+    geometry::Point,
     pixelcolor::PixelColor,
     primitives::{
-        common::Scanline,
+        common::{Scanline, StrokeOffset},
+        polygon::scanline_iterator::ScanlineState as PolygonScanlineState,
         polyline::{
             scanline_intersections::ScanlineIntersections, styled::untranslated_bounding_box,
         },
-        Polyline, PrimitiveStyle,
+        FillRule, Polyline, PrimitiveStyle,
     },
 };
 
@@ -18,26 +20,84 @@ use crate::{
 /// Each scanline produces multiple actual `Line`s for each intersection of the thick polyline.
 #[derive(Clone, Debug)]
 pub struct ScanlineIterator<'a> {
-    rows: Range<i32>,
-    scanline_y: i32,
-    intersections: ScanlineIntersections<'a>,
+    vertices: &'a [Point],
+    state: ScanlineState,
 }
 
 impl<'a> ScanlineIterator<'a> {
     /// New.
     pub fn new<C: PixelColor>(primitive: &Polyline<'a>, style: &PrimitiveStyle<C>) -> Self {
+        Self {
+            vertices: primitive.vertices,
+            state: ScanlineState::new(primitive, style),
+        }
+    }
+}
+
+impl<'a> Iterator for ScanlineIterator<'a> {
+    type Item = Scanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.vertices)
+    }
+}
+
+/// State of a scanline iterator.
+///
+/// The state doesn't borrow the vertices, which allows primitives that generate their vertices to
+/// store them alongside the state.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(in crate::primitives) enum ScanlineState {
+    /// Open polyline.
+    Open {
+        rows: Range<i32>,
+        scanline_y: i32,
+        intersections: ScanlineIntersections,
+    },
+
+    /// Closed polyline.
+    ///
+    /// The stroke of a closed polyline is identical to the stroke of an unfilled polygon, which
+    /// makes sure that the closing segment is merged with the first segment without overdraw.
+    Closed(PolygonScanlineState),
+}
+
+impl ScanlineState {
+    /// New.
+    pub fn new<C: PixelColor>(primitive: &Polyline<'_>, style: &PrimitiveStyle<C>) -> Self {
         debug_assert!(
             style.stroke_width > 1,
             "Polyline ScanlineIterator should only be used for stroke widths greater than 1"
         );
 
-        let mut rows = untranslated_bounding_box(primitive, style).rows();
+        let bounding_box = untranslated_bounding_box(primitive, style);
+        let stroke_offset = StrokeOffset::from(style.stroke_alignment);
+
+        if primitive.has_closing_segment() {
+            return Self::Closed(PolygonScanlineState::new(
+                primitive.vertices.len(),
+                FillRule::EvenOdd,
+                style.stroke_width,
+                stroke_offset,
+                style.line_join,
+                false,
+                &bounding_box,
+            ));
+        }
+
+        let mut rows = bounding_box.rows();
 
         if let Some(scanline_y) = rows.next() {
-            let intersections =
-                ScanlineIntersections::new(primitive.vertices, style.stroke_width, scanline_y);
+            let intersections = ScanlineIntersections::new(
+                primitive.vertices,
+                style.stroke_width,
+                stroke_offset,
+                style.line_join,
+                style.line_cap,
+                scanline_y,
+            );
 
-            Self {
+            Self::Open {
                 rows,
                 scanline_y,
                 intersections,
@@ -47,29 +107,36 @@ impl<'a> ScanlineIterator<'a> {
         }
     }
 
-    fn empty() -> Self {
-        Self {
+    /// Empty.
+    pub fn empty() -> Self {
+        Self::Open {
             rows: 0i32..0,
             scanline_y: 0,
             intersections: ScanlineIntersections::empty(),
         }
     }
-}
 
-impl<'a> Iterator for ScanlineIterator<'a> {
-    type Item = Scanline;
+    /// Returns the next scanline.
+    ///
+    /// The same vertices must be passed to every call.
+    pub fn next(&mut self, vertices: &[Point]) -> Option<Scanline> {
+        match self {
+            Self::Open {
+                rows,
+                scanline_y,
+                intersections,
+            } => loop {
+                if let Some(next) = intersections.next(vertices) {
+                    if !next.is_empty() {
+                        break Some(next);
+                    }
+                } else {
+                    *scanline_y = rows.next()?;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if let Some(next) = self.intersections.next() {
-                if !next.is_empty() {
-                    break Some(next);
+                    intersections.reset_with_new_scanline(vertices, *scanline_y);
                 }
-            } else {
-                self.scanline_y = self.rows.next()?;
-
-                self.intersections.reset_with_new_scanline(self.scanline_y);
-            }
+            },
+            Self::Closed(state) => state.next(vertices).map(|(scanline, _)| scanline),
         }
     }
 }
@@ -3,10 +3,15 @@ use crate::{
     geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{
-        common::{Scanline, StrokeOffset, ThickSegmentIter},
-        polyline::{self, scanline_iterator::ScanlineIterator, Polyline},
+        common::{ClosedThickSegmentIter, Scanline, StrokeOffset, ThickSegmentIter},
+        line::Line,
+        polyline::{
+            points::PointsState,
+            scanline_iterator::{ScanlineIterator, ScanlineState},
+            Polyline,
+        },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PointsIter, PrimitiveStyle, Rectangle,
+        Circle, LineCap, LineJoin, PointsIter, PrimitiveStyle, Rectangle, StrokePattern,
     },
     transform::Transform,
     Pixel,
@@ -17,22 +22,70 @@ pub(in crate::primitives::polyline) fn untranslated_bounding_box<C: PixelColor>(
     primitive: &Polyline,
     style: &PrimitiveStyle<C>,
 ) -> Rectangle {
-    if style.effective_stroke_color().is_some() && primitive.vertices.len() > 1 {
-        let (min, max) =
-            ThickSegmentIter::new(primitive.vertices, style.stroke_width, StrokeOffset::None).fold(
-                (
-                    Point::new_equal(core::i32::MAX),
-                    Point::new_equal(core::i32::MIN),
-                ),
-                |(min, max), segment| {
-                    let bb = segment.edges_bounding_box();
+    let vertices = primitive.vertices;
+
+    if style.effective_stroke_color().is_some() && vertices.len() > 1 {
+        let stroke_offset = StrokeOffset::from(style.stroke_alignment);
+        let closed = primitive.has_closing_segment();
+
+        // Round joints add a circle around each vertex that isn't the start or end of the line.
+        let round_joints =
+            if style.line_join != LineJoin::Round || stroke_offset != StrokeOffset::None {
+                &[]
+            } else if closed {
+                vertices
+            } else {
+                &vertices[1..vertices.len() - 1]
+            };
+
+        // Round caps add a circle around the start and end of an open line.
+        let round_caps =
+            if style.line_cap != LineCap::Round || stroke_offset != StrokeOffset::None || closed {
+                &[]
+            } else {
+                vertices
+            };
+        let round_caps = round_caps.first().into_iter().chain(round_caps.last());
+
+        let joint_bounding_boxes = round_joints
+            .iter()
+            .chain(round_caps)
+            .map(|vertex| Circle::with_center(*vertex, style.stroke_width).bounding_box());
+
+        let initial = (
+            Point::new_equal(core::i32::MAX),
+            Point::new_equal(core::i32::MIN),
+        );
 
-                    (
-                        min.component_min(bb.top_left),
-                        max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
-                    )
-                },
-            );
+        let extend = |(min, max): (Point, Point), bb: Rectangle| {
+            (
+                min.component_min(bb.top_left),
+                max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
+            )
+        };
+
+        let (min, max) = if closed {
+            ClosedThickSegmentIter::new(
+                vertices,
+                style.stroke_width,
+                stroke_offset,
+                style.line_join,
+            )
+            .map(|segment| segment.edges_bounding_box())
+            .chain(joint_bounding_boxes)
+            .fold(initial, extend)
+        } else {
+            ThickSegmentIter::new(
+                vertices,
+                style.stroke_width,
+                stroke_offset,
+                style.line_join,
+                style.line_cap,
+            )
+            .map(|segment| segment.edges_bounding_box())
+            .chain(joint_bounding_boxes)
+            .fold(initial, extend)
+        };
 
         Rectangle::with_corners(min, max)
     } else {
@@ -60,31 +113,101 @@ where
     Ok(())
 }
 
-#[derive(Clone, Debug)]
-enum StyledIter<'a> {
-    Thin(polyline::Points<'a>),
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum StyledIter {
+    Thin(PointsState),
     Thick {
-        scanline_iter: ScanlineIterator<'a>,
+        scanline_iter: ScanlineState,
         line_iter: Scanline,
         translate: Point,
     },
 }
 
+/// Returns the position of a point along a thick polyline.
+///
+/// The point is assigned to the closest segment of the polyline and the position is measured in
+/// pixels along the major axis of each segment. If `closed` is `true` the segment between the last
+/// and first vertex is included.
+fn dash_position(vertices: &[Point], closed: bool, point: Point) -> i32 {
+    let mut closest_distance = core::u32::MAX;
+    let mut closest_position = 0;
+    let mut segment_start = 0;
+
+    let closing_segment = match (vertices.last(), vertices.first()) {
+        (Some(last), Some(first)) if closed => Some((*last, *first)),
+        _ => None,
+    };
+
+    let segments = vertices
+        .windows(2)
+        .map(|segment| (segment[0], segment[1]))
+        .chain(closing_segment);
+
+    for (start, end) in segments {
+        let line = Line::new(start, end);
+        let delta = line.delta();
+        let major = delta.x.abs().max(delta.y.abs());
+
+        let position = line.dash_position(point).max(0).min(major);
+
+        let distance = line.distance_squared_to_point(point);
+        if distance < closest_distance {
+            closest_distance = distance;
+            closest_position = segment_start + position;
+        }
+
+        segment_start += major;
+    }
+
+    closest_position
+}
+
 /// Pixel iterator for each pixel in the line
 #[derive(Clone, Debug)]
 pub struct StyledPixelsIterator<'a, C> {
-    stroke_color: Option<C>,
-    line_iter: StyledIter<'a>,
+    vertices: &'a [Point],
+    state: StyledPixelsState<C>,
 }
 
 impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
     pub(in crate::primitives) fn new(primitive: &Polyline<'a>, style: &PrimitiveStyle<C>) -> Self {
+        Self {
+            vertices: primitive.vertices,
+            state: StyledPixelsState::new(primitive, style),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<'_, C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.vertices)
+    }
+}
+
+/// State of a styled pixels iterator.
+///
+/// The state doesn't borrow the vertices, which allows primitives that generate their vertices to
+/// store them alongside the state.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(in crate::primitives) struct StyledPixelsState<C> {
+    stroke_color: Option<C>,
+    stroke_pattern: StrokePattern,
+    closed: bool,
+    position: i32,
+    line_iter: StyledIter,
+}
+
+impl<C: PixelColor> StyledPixelsState<C> {
+    /// New.
+    pub fn new(primitive: &Polyline<'_>, style: &PrimitiveStyle<C>) -> Self {
         let line_iter = if style.stroke_width <= 1 {
-            StyledIter::Thin(primitive.points())
+            StyledIter::Thin(PointsState::new(primitive))
         } else {
-            let mut scanline_iter = ScanlineIterator::new(primitive, style);
+            let mut scanline_iter = ScanlineState::new(primitive, style);
             let line_iter = scanline_iter
-                .next()
+                .next(primitive.vertices)
                 .unwrap_or_else(|| Scanline::new_empty(0));
 
             StyledIter::Thick {
@@ -94,41 +217,67 @@ impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
             }
         };
 
-        StyledPixelsIterator {
+        Self {
             stroke_color: style.effective_stroke_color(),
+            stroke_pattern: style.stroke_pattern,
+            closed: primitive.has_closing_segment(),
+            position: 0,
             line_iter,
         }
     }
-}
 
-impl<C: PixelColor> Iterator for StyledPixelsIterator<'_, C> {
-    type Item = Pixel<C>;
+    /// Returns the next point and its position along the polyline.
+    fn next_point(&mut self, vertices: &[Point]) -> Option<(Point, i32)> {
+        match self.line_iter {
+            StyledIter::Thin(ref mut it) => {
+                let point = it.next(vertices)?;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // Return none if stroke color is none
-        let stroke_color = self.stroke_color?;
+                let position = self.position;
+                self.position += 1;
 
-        match self.line_iter {
-            StyledIter::Thin(ref mut it) => it.next(),
+                Some((point, position))
+            }
             StyledIter::Thick {
                 ref mut scanline_iter,
                 ref mut line_iter,
                 translate,
             } => {
                 // We've got a line to iterate over, so get it's next pixel.
-                if let Some(p) = line_iter.next() {
-                    Some(p)
+                let point = if let Some(p) = line_iter.next() {
+                    p
                 }
                 // Finished this line. Get the next one from the scanline iterator.
                 else {
-                    *line_iter = scanline_iter.next()?;
+                    *line_iter = scanline_iter.next(vertices)?;
 
-                    line_iter.next()
-                }
-                .map(|p| p + translate)
+                    line_iter.next()?
+                };
+
+                let position = if self.stroke_pattern.is_solid() {
+                    0
+                } else {
+                    dash_position(vertices, self.closed, point)
+                };
+
+                Some((point + translate, position))
+            }
+        }
+    }
+
+    /// Returns the next pixel.
+    ///
+    /// The same vertices must be passed to every call.
+    pub fn next(&mut self, vertices: &[Point]) -> Option<Pixel<C>> {
+        // Return none if stroke color is none
+        let stroke_color = self.stroke_color?;
+
+        loop {
+            let (point, position) = self.next_point(vertices)?;
+
+            if self.stroke_pattern.is_dash(position) {
+                return Some(Pixel(point, stroke_color));
             }
         }
-        .map(|point| Pixel(point, stroke_color))
     }
 }
 
@@ -152,6 +301,11 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Polyline<'_> {
     where
         D: DrawTarget<Color = C>,
     {
+        // Dashed strokes can't be drawn using scanlines
+        if !style.stroke_pattern.is_solid() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
         if let Some(stroke_color) = style.stroke_color {
             match style.stroke_width {
                 0 => Ok(()),
@@ -189,7 +343,10 @@ mod tests {
         iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, RgbColor},
-        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            LineJoin, Polygon, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+            StrokePattern,
+        },
         Drawable,
     };
 
@@ -416,27 +573,295 @@ mod tests {
     }
 
     #[test]
-    fn alignment_has_no_effect() {
+    fn stroke_alignment() {
         let base_style = PrimitiveStyleBuilder::new()
             .stroke_color(BinaryColor::On)
             .stroke_width(3);
 
-        let mut expected_display = MockDisplay::new();
+        let mut display = MockDisplay::new();
 
-        Polyline::new(&PATTERN)
-            .into_styled(base_style.build())
-            .draw(&mut expected_display)
+        Polyline::new(&[Point::new(1, 4), Point::new(11, 4), Point::new(11, 10)])
+            .into_styled(base_style.stroke_alignment(StrokeAlignment::Inside).build())
+            .draw(&mut display)
             .unwrap();
 
-        for alignment in [StrokeAlignment::Inside, StrokeAlignment::Outside].iter() {
+        display.assert_pattern(&[
+            "            ",
+            "            ",
+            "            ",
+            "            ",
+            " ###########",
+            " ###########",
+            " ###########",
+            "         ###",
+            "         ###",
+            "         ###",
+            "         ###",
+        ]);
+    }
+
+    #[test]
+    fn line_joins() {
+        let vertices = [Point::new(2, 14), Point::new(12, 4), Point::new(22, 14)];
+
+        let cases: [(LineJoin, &[&str]); 4] = [
+            (
+                LineJoin::Miter { limit: 4 },
+                &[
+                    "                        ",
+                    "            #           ",
+                    "           ###          ",
+                    "          #####         ",
+                    "         #######        ",
+                    "        #########       ",
+                    "       ###########      ",
+                    "      #############     ",
+                    "     ####### #######    ",
+                    "    #######   #######   ",
+                    "   #######     #######  ",
+                    "  #######       ####### ",
+                    " #######         #######",
+                    "  #####           ##### ",
+                    "   ###             ###  ",
+                    "    #               #   ",
+                ],
+            ),
+            (
+                // The miter limit is exceeded, which results in a bevel joint.
+                LineJoin::Miter { limit: 1 },
+                &[
+                    "                        ",
+                    "                        ",
+                    "                        ",
+                    "          #####         ",
+                    "         #######        ",
+                    "        #########       ",
+                    "       ###########      ",
+                    "      #############     ",
+                    "     ####### #######    ",
+                    "    #######   #######   ",
+                    "   #######     #######  ",
+                    "  #######       ####### ",
+                    " #######         #######",
+                    "  #####           ##### ",
+                    "   ###             ###  ",
+                    "    #               #   ",
+                ],
+            ),
+            (
+                LineJoin::Bevel,
+                &[
+                    "                        ",
+                    "                        ",
+                    "                        ",
+                    "          #####         ",
+                    "         #######        ",
+                    "        #########       ",
+                    "       ###########      ",
+                    "      #############     ",
+                    "     ####### #######    ",
+                    "    #######   #######   ",
+                    "   #######     #######  ",
+                    "  #######       ####### ",
+                    " #######         #######",
+                    "  #####           ##### ",
+                    "   ###             ###  ",
+                    "    #               #   ",
+                ],
+            ),
+            (
+                LineJoin::Round,
+                &[
+                    "                        ",
+                    "                        ",
+                    "           ###          ",
+                    "          #####         ",
+                    "         #######        ",
+                    "        #########       ",
+                    "       ###########      ",
+                    "      #############     ",
+                    "     ####### #######    ",
+                    "    #######   #######   ",
+                    "   #######     #######  ",
+                    "  #######       ####### ",
+                    " #######         #######",
+                    "  #####           ##### ",
+                    "   ###             ###  ",
+                    "    #               #   ",
+                ],
+            ),
+        ];
+
+        for (line_join, expected) in cases.iter() {
             let mut display = MockDisplay::new();
 
-            Polyline::new(&PATTERN)
-                .into_styled(base_style.stroke_alignment(*alignment).build())
+            Polyline::new(&vertices)
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(5)
+                        .line_join(*line_join)
+                        .build(),
+                )
                 .draw(&mut display)
                 .unwrap();
 
-            display.assert_eq_with_message(&expected_display, |f| write!(f, "{:?}", alignment));
+            display.assert_pattern_with_message(expected, |f| write!(f, "{:?}", line_join));
+        }
+    }
+
+    #[test]
+    fn joins_and_alignments() {
+        for line_join in [
+            LineJoin::Miter { limit: 4 },
+            LineJoin::Bevel,
+            LineJoin::Round,
+        ]
+        .iter()
+        {
+            for alignment in [
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ]
+            .iter()
+            {
+                for stroke_width in 2..8 {
+                    let styled = Polyline::new(&PATTERN)
+                        .translate(Point::new(5, 5))
+                        .into_styled(
+                            PrimitiveStyleBuilder::new()
+                                .stroke_color(BinaryColor::On)
+                                .stroke_width(stroke_width)
+                                .stroke_alignment(*alignment)
+                                .line_join(*line_join)
+                                .build(),
+                        );
+
+                    let mut drawable = MockDisplay::new();
+                    styled.draw(&mut drawable).unwrap();
+
+                    let mut pixels = MockDisplay::new();
+                    styled.pixels().draw(&mut pixels).unwrap();
+
+                    pixels.assert_eq(&drawable);
+                    assert_eq!(
+                        styled.bounding_box(),
+                        drawable.affected_area(),
+                        "{:?}, {:?}, {}",
+                        line_join,
+                        alignment,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn closed() {
+        let mut display = MockDisplay::new();
+
+        Polyline::closed(&[Point::new(2, 2), Point::new(12, 2), Point::new(2, 12)])
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "                ",
+            " ###############",
+            " ############## ",
+            " #############  ",
+            " ###     ####   ",
+            " ###    ####    ",
+            " ###   ####     ",
+            " ###  ####      ",
+            " ### ####       ",
+            " #######        ",
+            " ######         ",
+            " #####          ",
+            " ####           ",
+            " ###            ",
+            " ##             ",
+            " #              ",
+        ]);
+    }
+
+    const QUAD: [Point; 4] = [
+        Point::new(5, 5),
+        Point::new(30, 8),
+        Point::new(25, 30),
+        Point::new(8, 20),
+    ];
+
+    #[test]
+    fn closed_equals_polygon_stroke() {
+        let vertices = QUAD;
+
+        for stroke_width in 2..8 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let mut expected = MockDisplay::new();
+            Polygon::new(&vertices)
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Polyline::closed(&vertices)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{}", stroke_width));
+        }
+    }
+
+    #[test]
+    fn closed_joins_and_alignments() {
+        for line_join in [
+            LineJoin::Miter { limit: 4 },
+            LineJoin::Bevel,
+            LineJoin::Round,
+        ]
+        .iter()
+        {
+            for alignment in [
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ]
+            .iter()
+            {
+                for stroke_width in 1..8 {
+                    let styled = Polyline::closed(&QUAD)
+                        .translate(Point::new(10, 10))
+                        .into_styled(
+                            PrimitiveStyleBuilder::new()
+                                .stroke_color(BinaryColor::On)
+                                .stroke_width(stroke_width)
+                                .stroke_alignment(*alignment)
+                                .line_join(*line_join)
+                                .build(),
+                        );
+
+                    let mut drawable = MockDisplay::new();
+                    styled.draw(&mut drawable).unwrap();
+
+                    let mut pixels = MockDisplay::new();
+                    styled.pixels().draw(&mut pixels).unwrap();
+
+                    pixels.assert_eq(&drawable);
+                    assert_eq!(
+                        styled.bounding_box(),
+                        drawable.affected_area(),
+                        "{:?}, {:?}, {}",
+                        line_join,
+                        alignment,
+                        stroke_width
+                    );
+                }
+            }
         }
     }
 
@@ -486,11 +911,30 @@ mod tests {
     fn bounding_box() {
         let pl = Polyline::new(&PATTERN);
 
-        let styled = pl.into_styled(PrimitiveStyle::with_stroke(Rgb565::BLUE, 5));
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            let styled = pl.into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb565::BLUE)
+                    .stroke_width(5)
+                    .stroke_alignment(*alignment)
+                    .build(),
+            );
 
-        let mut display = MockDisplay::new();
-        styled.draw(&mut display).unwrap();
-        assert_eq!(display.affected_area(), styled.bounding_box());
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(
+                display.affected_area(),
+                styled.bounding_box(),
+                "{:?}",
+                alignment
+            );
+        }
 
         assert_eq!(
             pl.into_styled(
@@ -527,6 +971,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn large_bounding_box() {
+        let vertices = [
+            Point::new(533, -109),
+            Point::new(-433, -30),
+            Point::new(-171, 595),
+        ];
+
+        for line_join in [
+            LineJoin::Miter { limit: 4 },
+            LineJoin::Bevel,
+            LineJoin::Round,
+        ]
+        .iter()
+        {
+            for alignment in [
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ]
+            .iter()
+            {
+                let styled = Polyline::new(&vertices).into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(28)
+                        .stroke_alignment(*alignment)
+                        .line_join(*line_join)
+                        .build(),
+                );
+
+                // The polyline is too large for a mock display.
+                let (min, max) = styled.pixels().fold(
+                    (
+                        Point::new_equal(core::i32::MAX),
+                        Point::new_equal(core::i32::MIN),
+                    ),
+                    |(min, max), Pixel(point, _)| {
+                        (min.component_min(point), max.component_max(point))
+                    },
+                );
+
+                assert_eq!(
+                    styled.bounding_box(),
+                    Rectangle::with_corners(min, max),
+                    "{:?}, {:?}",
+                    line_join,
+                    alignment
+                );
+            }
+        }
+    }
+
     #[test]
     fn translated_bounding_box() {
         let by = Point::new(10, 12);
@@ -631,4 +1128,221 @@ mod tests {
         // Check no pixels are drawn outside bounding box
         assert_eq!(display.affected_area(), bb);
     }
+
+    #[test]
+    fn dashed_one_px_stroke() {
+        let mut display = MockDisplay::new();
+
+        Polyline::new(&PATTERN)
+            .translate(Point::new(-5, -5))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(1)
+                    .stroke_pattern(StrokePattern::dashed(4, 2))
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "        #                #",
+            "      ## #              # ",
+            "                     #    ",
+            "   #        #      ##     ",
+            " ##          ##   #       ",
+            "#              #          ",
+        ]);
+    }
+
+    #[test]
+    fn dashed_thick_stroke() {
+        let mut display = MockDisplay::new();
+
+        Polyline::new(&[Point::new(0, 2), Point::new(12, 2), Point::new(12, 10)])
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(3)
+                    .stroke_pattern(StrokePattern::dashed(3, 2))
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "              ",
+            "###  ###  ####",
+            "###  ###  ####",
+            "###  ###  ##  ",
+            "              ",
+            "           ###",
+            "           ###",
+            "           ###",
+            "              ",
+            "              ",
+            "           ###",
+        ]);
+    }
+
+    #[test]
+    fn dashed_thick_stroke_points() {
+        let mut d1 = MockDisplay::new();
+        let mut d2 = MockDisplay::new();
+
+        let pl = Polyline::new(&PATTERN)
+            .translate(Point::new(2, 3))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(4)
+                    .stroke_pattern(StrokePattern::dashed(5, 3))
+                    .build(),
+            );
+
+        pl.draw(&mut d1).unwrap();
+
+        pl.pixels().draw(&mut d2).unwrap();
+
+        d1.assert_eq(&d2);
+    }
+
+    #[test]
+    fn line_caps() {
+        let vertices = [Point::new(2, 1), Point::new(10, 1), Point::new(10, 7)];
+
+        for &(line_cap, expected) in &[
+            (
+                LineCap::Butt,
+                &[
+                    "            ",
+                    "            ",
+                    "  ##########",
+                    "  ##########",
+                    "  ##########",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                ][..],
+            ),
+            (
+                LineCap::Square,
+                &[
+                    "            ",
+                    "            ",
+                    " ###########",
+                    " ###########",
+                    " ###########",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                ][..],
+            ),
+            (
+                LineCap::Round,
+                &[
+                    "            ",
+                    "            ",
+                    "  ##########",
+                    " ###########",
+                    "  ##########",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "         ###",
+                    "          # ",
+                ][..],
+            ),
+        ] {
+            let mut display = MockDisplay::new();
+
+            Polyline::new(&vertices)
+                .translate(Point::new(0, 2))
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(3)
+                        .line_cap(line_cap)
+                        .build(),
+                )
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_pattern_with_message(expected, |f| write!(f, "{:?}", line_cap));
+        }
+    }
+
+    #[test]
+    fn line_caps_bounding_box() {
+        let vertices = [Point::new(4, 4), Point::new(12, 4), Point::new(12, 10)];
+
+        for &line_cap in &[LineCap::Butt, LineCap::Square, LineCap::Round] {
+            for &stroke_width in &[1, 3, 4] {
+                let styled = Polyline::new(&vertices).into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .line_cap(line_cap)
+                        .build(),
+                );
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let mut pixels_display = MockDisplay::new();
+                styled.pixels().draw(&mut pixels_display).unwrap();
+
+                assert_eq!(
+                    display.affected_area(),
+                    styled.bounding_box(),
+                    "{:?}, {}",
+                    line_cap,
+                    stroke_width
+                );
+                display.assert_eq_with_message(&pixels_display, |f| {
+                    write!(f, "{:?}, {}", line_cap, stroke_width)
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn line_caps_are_ignored_for_thin_and_closed_polylines() {
+        let vertices = [Point::new(4, 4), Point::new(12, 4), Point::new(12, 10)];
+
+        for &(closed, stroke_width) in &[(false, 1), (true, 1), (true, 3)] {
+            let polyline = if closed {
+                Polyline::closed(&vertices)
+            } else {
+                Polyline::new(&vertices)
+            };
+
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let mut expected = MockDisplay::new();
+            polyline.into_styled(style).draw(&mut expected).unwrap();
+
+            for &line_cap in &[LineCap::Square, LineCap::Round] {
+                let styled = polyline.into_styled(
+                    PrimitiveStyleBuilder::from(&style)
+                        .line_cap(line_cap)
+                        .build(),
+                );
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                display.assert_eq(&expected);
+                assert_eq!(
+                    styled.bounding_box(),
+                    polyline.into_styled(style).bounding_box()
+                );
+            }
+        }
+    }
 }
@@ -1,4 +1,18 @@
-use crate::{pixelcolor::PixelColor, primitives::OffsetOutline, SaturatingCast};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    draw_target::BlendMode,
+    geometry::{div_round, isqrt, OriginDimensions, Point, Size},
+    image::{BitOrder, ImageRaw},
+    iterator::raw::RawDataSlice,
+    pixelcolor::{raw::ByteOrder, BinaryColor, PixelColor, Rgb888, RgbColor},
+    primitives::{OffsetOutline, Rectangle},
+    SaturatingCast,
+};
 
 /// Style properties for primitives.
 ///
@@ -15,15 +29,20 @@ use crate::{pixelcolor::PixelColor, primitives::OffsetOutline, SaturatingCast};
 /// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[non_exhaustive]
-pub struct PrimitiveStyle<C>
-where
-    C: PixelColor,
-{
+pub struct PrimitiveStyle<C> {
     /// Fill color of the primitive.
     ///
-    /// If `fill_color` is set to `None` no fill will be drawn.
+    /// The fill color is used by solid and patterned fills. If `fill_color` is set to `None` these
+    /// fills aren't drawn. Gradient and texture fills don't use the fill color and are drawn
+    /// regardless of this setting.
     pub fill_color: Option<C>,
 
+    /// Fill style.
+    ///
+    /// The fill style sets if the fill is drawn using a solid color, a repeating pattern, a
+    /// gradient or a texture.
+    pub fill_style: FillStyle<C>,
+
     /// Stroke color of the primitive.
     ///
     /// If `stroke_color` is set to `None` or the `stroke_width` is set to `0` no stroke will be
@@ -38,39 +57,148 @@ where
     /// The stroke alignment sets if the stroke is drawn inside, outside or centered
     /// on the outline of a shape.
     ///
-    /// This property only applies to closed shapes (rectangle, circle, ...) and is
-    /// ignored for open shapes (line, ...).
+    /// This property only applies to closed shapes (rectangle, circle, ...) and thick
+    /// polylines. It is ignored for other open shapes (line, ...).
     pub stroke_alignment: StrokeAlignment,
+
+    /// Stroke pattern.
+    ///
+    /// The stroke pattern sets if the stroke is drawn solid or dashed.
+    ///
+    /// Dashed strokes are currently supported by [`Line`], [`Polyline`], [`Rectangle`] and
+    /// [`Circle`]. Other primitives ignore this property and always draw a solid stroke.
+    ///
+    /// [`Line`]: line/struct.Line.html
+    /// [`Polyline`]: polyline/struct.Polyline.html
+    /// [`Rectangle`]: rectangle/struct.Rectangle.html
+    /// [`Circle`]: circle/struct.Circle.html
+    pub stroke_pattern: StrokePattern,
+
+    /// Line cap.
+    ///
+    /// The line cap determines the shape of the ends of a thick [`Line`], [`Arc`] or open
+    /// [`Polyline`]. For polylines the cap is applied to the start of the first segment and the end
+    /// of the last segment, which also applies to curves that are drawn as polylines, like
+    /// [`QuadraticBezier`], [`CubicBezier`] and [`CatmullRomSpline`]. Round caps are only drawn for
+    /// polylines if the stroke is centered. The line cap is ignored for thin lines, closed
+    /// polylines and all other primitives.
+    ///
+    /// [`Line`]: line/struct.Line.html
+    /// [`Arc`]: arc/struct.Arc.html
+    /// [`Polyline`]: polyline/struct.Polyline.html
+    /// [`QuadraticBezier`]: bezier/struct.QuadraticBezier.html
+    /// [`CubicBezier`]: bezier/struct.CubicBezier.html
+    /// [`CatmullRomSpline`]: catmull_rom_spline/struct.CatmullRomSpline.html
+    pub line_cap: LineCap,
+
+    /// Line join.
+    ///
+    /// The line join determines the shape of the corners between the segments of a thick
+    /// [`Polyline`]. It is ignored for thin polylines and for all other primitives.
+    ///
+    /// [`Polyline`]: polyline/struct.Polyline.html
+    pub line_join: LineJoin,
+
+    /// Opacity.
+    ///
+    /// The opacity ranges from `0` for a fully transparent to `255` for a fully opaque primitive.
+    ///
+    /// The opacity and the blend mode are only applied if the primitive is drawn using
+    /// [`Styled::draw_blended`], which requires a draw target that can read back its pixels.
+    /// Drawing the primitive using [`Drawable::draw`] ignores both properties and draws opaque
+    /// pixels.
+    ///
+    /// [`Styled::draw_blended`]: struct.Styled.html#method.draw_blended
+    /// [`Drawable::draw`]: ../trait.Drawable.html#tymethod.draw
+    pub opacity: u8,
+
+    /// Blend mode.
+    ///
+    /// The blend mode determines how the primitive is combined with the existing content of the
+    /// draw target. See [`opacity`](#structfield.opacity) for more information.
+    pub blend_mode: BlendMode,
 }
 
-impl<C> PrimitiveStyle<C>
-where
-    C: PixelColor,
-{
+// MSRV: Add a `C: PixelColor` bound to the struct and move these methods into the bounded impl
+//       block when trait bounds in const functions are supported (rust >= 1.61.0)
+impl<C> PrimitiveStyle<C> {
     /// Creates a primitive style without fill and stroke.
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Self::with_colors(None, None, 0)
     }
 
     /// Creates a stroke primitive style.
     ///
     /// If the `stroke_width` is `0` the resulting style won't draw a stroke.
-    pub fn with_stroke(stroke_color: C, stroke_width: u32) -> Self {
-        Self {
-            stroke_color: Some(stroke_color),
-            stroke_width,
-            ..PrimitiveStyle::default()
-        }
+    pub const fn with_stroke(stroke_color: C, stroke_width: u32) -> Self {
+        Self::with_colors(None, Some(stroke_color), stroke_width)
     }
 
     /// Creates a fill primitive style.
-    pub fn with_fill(fill_color: C) -> Self {
+    pub const fn with_fill(fill_color: C) -> Self {
+        Self::with_colors(Some(fill_color), None, 0)
+    }
+
+    /// Creates a primitive style with a fill and a stroke.
+    ///
+    /// Due to `const fn` limitations [`PrimitiveStyleBuilder`] cannot be used in `const`
+    /// contexts. This method provides a workaround to create styles with a fill and a stroke,
+    /// which can be stored in a `const` or `static` item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// static BUTTON_STYLE: PrimitiveStyle<Rgb565> =
+    ///     PrimitiveStyle::with_fill_and_stroke(Rgb565::BLUE, Rgb565::WHITE, 2);
+    ///
+    /// Rectangle::new(Point::new(10, 10), Size::new(40, 20))
+    ///     .into_styled(BUTTON_STYLE)
+    ///     .draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`PrimitiveStyleBuilder`]: struct.PrimitiveStyleBuilder.html
+    pub const fn with_fill_and_stroke(fill_color: C, stroke_color: C, stroke_width: u32) -> Self {
+        Self::with_colors(Some(fill_color), Some(stroke_color), stroke_width)
+    }
+
+    /// Creates a primitive style with the given colors and default values for all other
+    /// properties.
+    ///
+    /// All fields are initialized at once, because overwriting or dropping a field with a generic
+    /// type isn't supported in `const` functions.
+    const fn with_colors(
+        fill_color: Option<C>,
+        stroke_color: Option<C>,
+        stroke_width: u32,
+    ) -> Self {
         Self {
-            fill_color: Some(fill_color),
-            ..PrimitiveStyle::default()
+            fill_color,
+            fill_style: FillStyle::Solid,
+            stroke_color,
+            stroke_width,
+            stroke_alignment: StrokeAlignment::Center,
+            stroke_pattern: StrokePattern::Solid,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter { limit: 4 },
+            opacity: 255,
+            blend_mode: BlendMode::SourceOver,
         }
     }
+}
 
+impl<C> PrimitiveStyle<C>
+where
+    C: PixelColor,
+{
     /// Returns the stroke width on the outside of the shape.
     ///
     /// The outside stroke width is determined by `stroke_width` and `stroke_alignment`.
@@ -95,7 +223,7 @@ where
 
     /// Returns if a primitive drawn with this style is completely transparent.
     pub fn is_transparent(&self) -> bool {
-        (self.stroke_color.is_none() || self.stroke_width == 0) && self.fill_color.is_none()
+        self.effective_stroke_color().is_none() && !self.is_filled()
     }
 
     /// Returns the effective stroke color of the style.
@@ -106,6 +234,40 @@ where
         self.stroke_color.filter(|_| self.stroke_width > 0)
     }
 
+    /// Returns the effective fill color of the style.
+    ///
+    /// Gradient and texture fills don't use the fill color. For these fill styles a color of the
+    /// gradient or texture is returned instead, which is passed to [`FillStyle::color_at`] by
+    /// the renderers. `None` is returned if no fill is drawn.
+    ///
+    /// [`FillStyle::color_at`]: enum.FillStyle.html#method.color_at
+    pub(crate) fn effective_fill_color(&self) -> Option<C> {
+        match self.fill_style {
+            FillStyle::Solid | FillStyle::Pattern { .. } => self.fill_color,
+            FillStyle::LinearGradient(gradient) => Some(gradient.start),
+            FillStyle::RadialGradient(gradient) => Some(gradient.center),
+            FillStyle::Texture(texture) => texture.color_at(Point::zero()),
+        }
+    }
+
+    /// Returns `true` if the fill of the primitive is drawn.
+    pub(crate) fn is_filled(&self) -> bool {
+        self.effective_fill_color().is_some()
+    }
+
+    /// Returns the fill color of the pixel at the given point.
+    ///
+    /// The bounding box of the primitive is used to position gradients. `None` is returned if
+    /// the pixel is transparent.
+    pub(in crate::primitives) fn fill_color_at(
+        &self,
+        point: Point,
+        bounding_box: &Rectangle,
+    ) -> Option<C> {
+        self.effective_fill_color()
+            .and_then(|fill_color| self.fill_style.color_at(point, fill_color, bounding_box))
+    }
+
     /// Returns the stroke area.
     pub(in crate::primitives) fn stroke_area<P: OffsetOutline>(&self, primitive: &P) -> P {
         // saturate offset at i32::max_value() if stroke width is to large
@@ -121,6 +283,26 @@ where
 
         primitive.offset(offset)
     }
+
+    /// Returns a style which covers the same area as this style, independent of its colors.
+    ///
+    /// The returned style uses the same stroke geometry as this style, but with an opaque solid
+    /// stroke and fill. It can be used to determine the area that is affected by a styled
+    /// primitive by iterating over its pixels.
+    pub(in crate::primitives) fn coverage_style(&self) -> PrimitiveStyle<BinaryColor> {
+        PrimitiveStyle {
+            fill_color: Some(BinaryColor::On),
+            fill_style: FillStyle::Solid,
+            stroke_color: Some(BinaryColor::On),
+            stroke_width: self.stroke_width,
+            stroke_alignment: self.stroke_alignment,
+            stroke_pattern: StrokePattern::Solid,
+            line_cap: self.line_cap,
+            line_join: self.line_join,
+            opacity: 255,
+            blend_mode: BlendMode::SourceOver,
+        }
+    }
 }
 
 impl<C> Default for PrimitiveStyle<C>
@@ -128,12 +310,7 @@ where
     C: PixelColor,
 {
     fn default() -> Self {
-        Self {
-            fill_color: None,
-            stroke_color: None,
-            stroke_width: 0,
-            stroke_alignment: StrokeAlignment::Center,
-        }
+        Self::new()
     }
 }
 
@@ -186,6 +363,8 @@ where
 /// ```
 ///
 /// [`PrimitiveStyle`]: ./struct.PrimitiveStyle.html
+// MSRV: Make the builder methods `const` when trait bounds in const functions are supported
+//       (rust >= 1.61.0)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct PrimitiveStyleBuilder<C>
 where
@@ -219,6 +398,30 @@ where
         self
     }
 
+    /// Sets the fill style.
+    pub fn fill_style(mut self, fill_style: FillStyle<C>) -> Self {
+        self.style.fill_style = fill_style;
+
+        self
+    }
+
+    /// Sets a texture fill.
+    ///
+    /// The fill of the primitive is drawn by repeating the image. See [`Texture`] for more
+    /// information.
+    ///
+    /// [`Texture`]: struct.Texture.html
+    pub fn fill_image<BO>(mut self, image: &ImageRaw<'static, C, BO>) -> Self
+    where
+        C: From<<C as PixelColor>::Raw>,
+        BO: ByteOrder,
+        RawDataSlice<'static, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+    {
+        self.style.fill_style = FillStyle::Texture(Texture::new(image));
+
+        self
+    }
+
     /// Sets the stroke color.
     pub fn stroke_color(mut self, stroke_color: C) -> Self {
         self.style.stroke_color = Some(stroke_color);
@@ -247,12 +450,75 @@ where
         self
     }
 
+    /// Sets the stroke pattern.
+    pub fn stroke_pattern(mut self, stroke_pattern: StrokePattern) -> Self {
+        self.style.stroke_pattern = stroke_pattern;
+
+        self
+    }
+
+    /// Sets the line cap.
+    pub fn line_cap(mut self, line_cap: LineCap) -> Self {
+        self.style.line_cap = line_cap;
+
+        self
+    }
+
+    /// Sets the line join.
+    pub fn line_join(mut self, line_join: LineJoin) -> Self {
+        self.style.line_join = line_join;
+
+        self
+    }
+
+    /// Sets the opacity.
+    pub fn opacity(mut self, opacity: u8) -> Self {
+        self.style.opacity = opacity;
+
+        self
+    }
+
+    /// Sets the blend mode.
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.style.blend_mode = blend_mode;
+
+        self
+    }
+
     /// Builds the primitive style.
     pub fn build(self) -> PrimitiveStyle<C> {
         self.style
     }
 }
 
+impl<C> PrimitiveStyleBuilder<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    /// Sets a linear gradient fill.
+    ///
+    /// The fill style is set to a [`LinearGradient`].
+    ///
+    /// [`LinearGradient`]: struct.LinearGradient.html
+    pub fn fill_gradient(mut self, start: C, end: C, direction: GradientDirection) -> Self {
+        self.style.fill_style =
+            FillStyle::LinearGradient(LinearGradient::new(start, end, direction));
+
+        self
+    }
+
+    /// Sets a radial gradient fill.
+    ///
+    /// The fill style is set to a [`RadialGradient`].
+    ///
+    /// [`RadialGradient`]: struct.RadialGradient.html
+    pub fn fill_radial_gradient(mut self, center: C, edge: C) -> Self {
+        self.style.fill_style = FillStyle::RadialGradient(RadialGradient::new(center, edge));
+
+        self
+    }
+}
+
 impl<C> From<&PrimitiveStyle<C>> for PrimitiveStyleBuilder<C>
 where
     C: PixelColor,
@@ -263,13 +529,47 @@ where
 }
 
 /// Stroke alignment.
+///
+/// The stroke alignment determines where the stroke of a closed shape is drawn relative to the
+/// outline of the shape. It is ignored for open shapes like [`Line`] and [`Arc`].
+///
+/// Thick [`Polyline`]s are an exception to this rule. The inside of a polyline is the right side
+/// when walking along the polyline from the first to the last vertex, which matches the inside of
+/// a closed shape with vertices in clockwise order.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyleBuilder, StrokeAlignment},
+/// };
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(BinaryColor::On)
+///     .stroke_width(3)
+///     .stroke_alignment(StrokeAlignment::Inside)
+///     .build();
+///
+/// let circle = Circle::new(Point::new(5, 5), 20);
+///
+/// // The stroke doesn't extend beyond the outline of the circle.
+/// assert_eq!(circle.into_styled(style).bounding_box(), circle.bounding_box());
+/// ```
+///
+/// [`Line`]: line/struct.Line.html
+/// [`Polyline`]: polyline/struct.Polyline.html
+/// [`Arc`]: arc/struct.Arc.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum StrokeAlignment {
-    /// Inside.
+    /// The stroke is drawn inside the shape.
     Inside,
-    /// Center.
+    /// The stroke is centered on the outline of the shape.
+    ///
+    /// If the stroke width is odd the additional pixel is drawn inside the shape.
     Center,
-    /// Outside.
+    /// The stroke is drawn outside the shape.
     Outside,
 }
 
@@ -279,97 +579,953 @@ impl Default for StrokeAlignment {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::pixelcolor::{BinaryColor, Rgb888, RgbColor};
+/// Stroke pattern.
+///
+/// The stroke pattern determines if a stroke is drawn solid or dashed. The length of the dashes
+/// and gaps is measured in pixels along the outline of the primitive.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{PrimitiveStyleBuilder, Rectangle, StrokePattern},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// // Selection box with 2px long dashes and 2px long gaps.
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(BinaryColor::On)
+///     .stroke_width(1)
+///     .stroke_pattern(StrokePattern::dashed(2, 2))
+///     .build();
+///
+/// Rectangle::new(Point::new(1, 1), Size::new(9, 5))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "           ",
+///     " ##  ##  # ",
+///     "         # ",
+///     "           ",
+///     " #         ",
+///     " #  ##  ## ",
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum StrokePattern {
+    /// Solid stroke.
+    Solid,
 
-    #[test]
-    fn default_style() {
-        assert_eq!(
-            PrimitiveStyle::<BinaryColor>::default(),
-            PrimitiveStyle {
-                fill_color: None,
-                stroke_color: None,
-                stroke_width: 0,
-                stroke_alignment: StrokeAlignment::Center,
-            }
-        );
+    /// Dashed stroke.
+    Dashed {
+        /// Length of the dashes in pixels.
+        dash_length: u32,
 
-        assert_eq!(
-            PrimitiveStyle::<BinaryColor>::default(),
-            PrimitiveStyle::new()
-        );
-    }
+        /// Length of the gaps between the dashes in pixels.
+        gap_length: u32,
 
-    #[test]
-    fn constructors() {
-        let style = PrimitiveStyle::with_fill(Rgb888::RED);
-        assert_eq!(style.fill_color, Some(Rgb888::RED));
-        assert_eq!(style.stroke_color, None);
+        /// Offset of the pattern in pixels.
+        ///
+        /// The offset shifts the start of the pattern along the stroke. It can be used to
+        /// animate dashed strokes, e.g. to create a "marching ants" effect.
+        offset: u32,
+    },
+}
 
-        let style = PrimitiveStyle::with_stroke(Rgb888::GREEN, 123);
-        assert_eq!(style.fill_color, None);
-        assert_eq!(style.stroke_color, Some(Rgb888::GREEN));
-        assert_eq!(style.stroke_width, 123);
+impl StrokePattern {
+    /// Creates a dashed stroke pattern without an offset.
+    pub const fn dashed(dash_length: u32, gap_length: u32) -> Self {
+        Self::Dashed {
+            dash_length,
+            gap_length,
+            offset: 0,
+        }
     }
 
-    #[test]
-    fn stroke_alignment_1px() {
-        let mut style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+    /// Returns `true` if this is a solid pattern.
+    pub fn is_solid(&self) -> bool {
+        // MSRV: replace with matches! for rust >= 1.42.0
+        match self {
+            Self::Solid => true,
+            _ => false,
+        }
+    }
 
-        style.stroke_alignment = StrokeAlignment::Inside;
-        assert_eq!(style.inside_stroke_width(), 1);
-        assert_eq!(style.outside_stroke_width(), 0);
+    /// Rounds the length of a closed stroke down to a whole number of pattern periods.
+    ///
+    /// This prevents a partial dash or gap at the point where a closed stroke starts and ends,
+    /// which would otherwise merge with the neighboring dash or gap. Rounding down ensures that
+    /// scaling the positions along the stroke to the new length never skips a dash or gap, unless
+    /// the stroke is shorter than a single period.
+    pub(in crate::primitives) fn closed_length(&self, length: u32) -> u32 {
+        match *self {
+            Self::Dashed {
+                dash_length,
+                gap_length,
+                ..
+            } => {
+                let period = u64::from(dash_length) + u64::from(gap_length);
+                if period == 0 {
+                    return length;
+                }
 
-        style.stroke_alignment = StrokeAlignment::Center;
-        assert_eq!(style.inside_stroke_width(), 1);
-        assert_eq!(style.outside_stroke_width(), 0);
+                let periods = (u64::from(length) / period).max(1);
 
-        style.stroke_alignment = StrokeAlignment::Outside;
-        assert_eq!(style.inside_stroke_width(), 0);
-        assert_eq!(style.outside_stroke_width(), 1);
+                (periods * period).min(u64::from(u32::max_value())) as u32
+            }
+            Self::Solid => length,
+        }
     }
 
-    #[test]
-    fn stroke_alignment_2px() {
-        let mut style = PrimitiveStyle::with_stroke(BinaryColor::On, 2);
+    /// Returns the pattern shifted by `length` pixels.
+    ///
+    /// This is used to continue the pattern on a stroke that is drawn in multiple parts.
+    pub(in crate::primitives) fn advanced_by(&self, length: u64) -> Self {
+        match *self {
+            Self::Dashed {
+                dash_length,
+                gap_length,
+                offset,
+            } => {
+                let period = u64::from(dash_length) + u64::from(gap_length);
+                if period == 0 {
+                    return *self;
+                }
 
-        style.stroke_alignment = StrokeAlignment::Inside;
-        assert_eq!(style.inside_stroke_width(), 2);
-        assert_eq!(style.outside_stroke_width(), 0);
+                Self::Dashed {
+                    dash_length,
+                    gap_length,
+                    offset: ((u64::from(offset) + length % period) % period)
+                        .min(u64::from(u32::max_value())) as u32,
+                }
+            }
+            Self::Solid => *self,
+        }
+    }
 
-        style.stroke_alignment = StrokeAlignment::Center;
-        assert_eq!(style.inside_stroke_width(), 1);
-        assert_eq!(style.outside_stroke_width(), 1);
+    /// Returns `true` if the pixel at the given position along the stroke is part of a dash.
+    pub(in crate::primitives) fn is_dash(&self, position: i32) -> bool {
+        match *self {
+            Self::Solid => true,
+            Self::Dashed {
+                dash_length,
+                gap_length,
+                offset,
+            } => {
+                let period = i64::from(dash_length) + i64::from(gap_length);
+                if period == 0 {
+                    return false;
+                }
 
-        style.stroke_alignment = StrokeAlignment::Outside;
-        assert_eq!(style.inside_stroke_width(), 0);
-        assert_eq!(style.outside_stroke_width(), 2);
+                (i64::from(position) + i64::from(offset)).rem_euclid(period)
+                    < i64::from(dash_length)
+            }
+        }
     }
+}
 
-    #[test]
-    fn builder_default() {
-        assert_eq!(
-            PrimitiveStyleBuilder::<BinaryColor>::new().build(),
-            PrimitiveStyle::<BinaryColor>::default()
-        );
+impl Default for StrokePattern {
+    fn default() -> Self {
+        Self::Solid
     }
+}
 
-    #[test]
-    fn builder_stroke() {
-        assert_eq!(
-            PrimitiveStyleBuilder::new()
-                .stroke_color(BinaryColor::On)
-                .stroke_width(10)
-                .build(),
-            PrimitiveStyle::with_stroke(BinaryColor::On, 10)
-        );
+/// Fill style.
+///
+/// The fill style determines if the fill of a closed shape is drawn using a solid color, a
+/// repeating [`FillPattern`], a [`LinearGradient`], a [`RadialGradient`] or a [`Texture`].
+/// Patterned fills are useful on monochrome displays, which can't use different colors to
+/// distinguish regions.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{FillPattern, FillStyle, PrimitiveStyleBuilder, Rectangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .fill_color(BinaryColor::On)
+///     .fill_style(FillStyle::Pattern {
+///         pattern: FillPattern::CHECKERBOARD,
+///         background: Some(BinaryColor::Off),
+///     })
+///     .build();
+///
+/// Rectangle::new(Point::zero(), Size::new(6, 3))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "#.#.#.", //
+///     ".#.#.#", //
+///     "#.#.#.", //
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`FillPattern`]: struct.FillPattern.html
+/// [`LinearGradient`]: struct.LinearGradient.html
+/// [`RadialGradient`]: struct.RadialGradient.html
+/// [`Texture`]: struct.Texture.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FillStyle<C> {
+    /// Solid fill.
+    Solid,
+
+    /// Patterned fill.
+    ///
+    /// Pixels which correspond to a set bit in the pattern are drawn using the fill color of the
+    /// style and all other pixels are drawn using the background color.
+    Pattern {
+        /// Fill pattern.
+        pattern: FillPattern,
+
+        /// Background color.
+        ///
+        /// If the background color is set to `None` the pixels which correspond to a cleared bit
+        /// in the pattern aren't drawn.
+        background: Option<C>,
+    },
+
+    /// Linear gradient fill.
+    ///
+    /// The fill color of the style is ignored.
+    LinearGradient(LinearGradient<C>),
+
+    /// Radial gradient fill.
+    ///
+    /// The fill color of the style is ignored.
+    RadialGradient(RadialGradient<C>),
+
+    /// Texture fill.
+    ///
+    /// The fill color of the style is ignored. Nothing is drawn if the texture image is empty.
+    Texture(Texture<C>),
+}
+
+impl<C: PixelColor> FillStyle<C> {
+    /// Returns `true` if this is a solid fill style.
+    pub fn is_solid(&self) -> bool {
+        // MSRV: replace with matches! for rust >= 1.42.0
+        match self {
+            Self::Solid => true,
+            _ => false,
+        }
     }
 
-    #[test]
-    fn builder_reset_stroke_color() {
-        assert_eq!(
+    /// Returns the color of the pixel at the given point.
+    pub(in crate::primitives) fn color_at(
+        &self,
+        point: Point,
+        fill_color: C,
+        bounding_box: &Rectangle,
+    ) -> Option<C> {
+        match *self {
+            Self::Solid => Some(fill_color),
+            Self::LinearGradient(gradient) => Some(gradient.color_at(point, bounding_box)),
+            Self::RadialGradient(gradient) => Some(gradient.color_at(point, bounding_box)),
+            Self::Texture(texture) => texture.color_at(point),
+            Self::Pattern {
+                pattern,
+                background,
+            } => {
+                if pattern.is_set(point) {
+                    Some(fill_color)
+                } else {
+                    background
+                }
+            }
+        }
+    }
+}
+
+impl<C> Default for FillStyle<C> {
+    fn default() -> Self {
+        Self::Solid
+    }
+}
+
+/// Linear gradient.
+///
+/// The color of a linear gradient changes from the start color to the end color along the
+/// bounding box of the primitive. The colors are interpolated in the RGB888 color space, which is
+/// supported by all RGB and grayscale color types in embedded-graphics.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Circle, GradientDirection, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .fill_gradient(Rgb565::YELLOW, Rgb565::RED, GradientDirection::Vertical)
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(1)
+///     .build();
+///
+/// Circle::new(Point::new(10, 10), 40)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LinearGradient<C> {
+    start: C,
+    end: C,
+    direction: GradientDirection,
+
+    // The interpolation function is stored in the gradient, because the conversion to and from
+    // RGB888 isn't available for all color types.
+    interpolate: InterpolateFn<C>,
+}
+
+impl<C> LinearGradient<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    /// Creates a new linear gradient.
+    pub fn new(start: C, end: C, direction: GradientDirection) -> Self {
+        Self {
+            start,
+            end,
+            direction,
+            interpolate: ColorFn(interpolate::<C>),
+        }
+    }
+}
+
+impl<C: PixelColor> LinearGradient<C> {
+    /// Returns the start color.
+    pub fn start(&self) -> C {
+        self.start
+    }
+
+    /// Returns the end color.
+    pub fn end(&self) -> C {
+        self.end
+    }
+
+    /// Returns the direction.
+    pub fn direction(&self) -> GradientDirection {
+        self.direction
+    }
+
+    /// Returns the color of the pixel at the given point.
+    fn color_at(&self, point: Point, bounding_box: &Rectangle) -> C {
+        let (position, length) = match self.direction {
+            GradientDirection::Horizontal => {
+                (point.x - bounding_box.top_left.x, bounding_box.size.width)
+            }
+            GradientDirection::Vertical => {
+                (point.y - bounding_box.top_left.y, bounding_box.size.height)
+            }
+        };
+
+        let length = length.saturating_sub(1);
+        if length == 0 {
+            return self.start;
+        }
+
+        // Strokes can extend beyond the bounding box of the primitive.
+        let position = position.max(0).min(length.saturating_cast()) as u32;
+
+        (self.interpolate.0)(self.start, self.end, position, length)
+    }
+}
+
+/// Radial gradient.
+///
+/// The color of a radial gradient changes from the center color in the center of the primitive's
+/// bounding box to the edge color at the edge of the largest circle that fits inside the bounding
+/// box. Pixels outside this circle, like the corners of a rounded rectangle, are drawn using the
+/// edge color. The colors are interpolated in the RGB888 color space.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Draw a glowing button.
+/// let style = PrimitiveStyleBuilder::new()
+///     .fill_radial_gradient(Rgb565::WHITE, Rgb565::BLUE)
+///     .build();
+///
+/// Circle::new(Point::new(10, 10), 40)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RadialGradient<C> {
+    center: C,
+    edge: C,
+    interpolate: InterpolateFn<C>,
+}
+
+impl<C> RadialGradient<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    /// Creates a new radial gradient.
+    pub fn new(center: C, edge: C) -> Self {
+        Self {
+            center,
+            edge,
+            interpolate: ColorFn(interpolate::<C>),
+        }
+    }
+}
+
+impl<C: PixelColor> RadialGradient<C> {
+    /// Returns the center color.
+    pub fn center(&self) -> C {
+        self.center
+    }
+
+    /// Returns the edge color.
+    pub fn edge(&self) -> C {
+        self.edge
+    }
+
+    /// Returns the color of the pixel at the given point.
+    fn color_at(&self, point: Point, bounding_box: &Rectangle) -> C {
+        // The radius and the distance are calculated in half pixels to support bounding boxes
+        // with an even size, and are additionally scaled to increase the number of steps in the
+        // gradient.
+        let radius = bounding_box
+            .size
+            .width
+            .min(bounding_box.size.height)
+            .saturating_sub(1);
+        if radius == 0 {
+            return self.center;
+        }
+
+        let center_2x = bounding_box.top_left * 2 + bounding_box.size - Size::new(1, 1);
+        let delta = point * 2 - center_2x;
+        let distance_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+
+        let radius = u64::from(radius) * RADIAL_SCALE;
+        let distance = isqrt(distance_squared as u64 * RADIAL_SCALE * RADIAL_SCALE).min(radius);
+
+        (self.interpolate.0)(self.center, self.edge, distance as u32, radius as u32)
+    }
+}
+
+/// Scaling factor for the radius and distance of radial gradients.
+const RADIAL_SCALE: u64 = 16;
+
+/// Function pointer stored in a fill style.
+///
+/// Fill styles store function pointers to use conversions which aren't available for all color
+/// types. The functions only depend on the type parameters of the fill style, which is why all
+/// instances are considered equal.
+#[derive(Copy, Clone)]
+struct ColorFn<F>(F);
+
+/// Color interpolation function of a gradient.
+type InterpolateFn<C> = ColorFn<fn(C, C, u32, u32) -> C>;
+
+/// Sampling function of a texture.
+type SampleFn<C> = ColorFn<fn(&Texture<C>, Point) -> Option<C>>;
+
+impl<F> PartialEq for ColorFn<F> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<F> Eq for ColorFn<F> {}
+
+impl<F> PartialOrd for ColorFn<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F> Ord for ColorFn<F> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<F> Hash for ColorFn<F> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<F> fmt::Debug for ColorFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ColorFn")
+    }
+}
+
+/// Interpolates between two colors.
+///
+/// `position` must be in the range from 0 to `length`.
+fn interpolate<C>(start: C, end: C, position: u32, length: u32) -> C
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    let (start, end) = (start.into(), end.into());
+
+    let channel = |start: u8, end: u8| {
+        let delta = i64::from(end) - i64::from(start);
+
+        (i64::from(start) + div_round(delta * i64::from(position), i64::from(length))) as u8
+    };
+
+    C::from(Rgb888::new(
+        channel(start.r(), end.r()),
+        channel(start.g(), end.g()),
+        channel(start.b(), end.b()),
+    ))
+}
+
+/// Texture.
+///
+/// A texture fills a primitive with a repeated image. The image is tiled starting at the origin of
+/// the draw target, which makes the texture line up seamlessly between adjacent primitives.
+///
+/// The image data must have a `'static` lifetime, because primitive styles don't have a lifetime
+/// parameter. This is usually the case for images that are included in the program binary.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{PrimitiveStyleBuilder, Rectangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// // 3x2 bricks pattern.
+/// const BRICKS: &[u8] = &[
+///     0b110_00000, //
+///     0b011_00000, //
+/// ];
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .fill_image(&ImageRaw::<BinaryColor>::new(BRICKS, 3))
+///     .build();
+///
+/// Rectangle::new(Point::zero(), Size::new(7, 4))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "##.##.#", //
+///     ".##.##.", //
+///     "##.##.#", //
+///     ".##.##.", //
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Texture<C> {
+    data: &'static [u8],
+    size: Size,
+    stride: usize,
+    bit_order: BitOrder,
+
+    // The sampling function is stored in the texture to erase the byte order type parameter of
+    // the image.
+    sample: SampleFn<C>,
+}
+
+impl<C: PixelColor> Texture<C> {
+    /// Creates a new texture from an image.
+    pub fn new<BO>(image: &ImageRaw<'static, C, BO>) -> Self
+    where
+        C: From<<C as PixelColor>::Raw>,
+        BO: ByteOrder,
+        RawDataSlice<'static, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+    {
+        Self {
+            data: image.data(),
+            size: image.size(),
+            stride: image.stride(),
+            bit_order: image.bit_order(),
+            sample: ColorFn(sample::<C, BO>),
+        }
+    }
+
+    /// Returns the size of the texture image.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the color of the pixel at the given point.
+    ///
+    /// `None` is returned if the texture is empty.
+    fn color_at(&self, point: Point) -> Option<C> {
+        if self.size.width == 0 || self.size.height == 0 {
+            return None;
+        }
+
+        let point = Point::new(
+            point.x.rem_euclid(self.size.width.saturating_cast()),
+            point.y.rem_euclid(self.size.height.saturating_cast()),
+        );
+
+        (self.sample.0)(self, point)
+    }
+}
+
+/// Returns the color of a pixel in a raw image.
+fn sample<C, BO>(texture: &Texture<C>, point: Point) -> Option<C>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    RawDataSlice<'static, C::Raw, BO>: IntoIterator<Item = C::Raw>,
+{
+    ImageRaw::<C, BO>::from_layout(
+        texture.data,
+        texture.size,
+        texture.stride,
+        texture.bit_order,
+    )
+    .pixel(point)
+}
+
+/// Gradient direction.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum GradientDirection {
+    /// Gradient from the left to the right edge.
+    Horizontal,
+
+    /// Gradient from the top to the bottom edge.
+    Vertical,
+}
+
+/// Fill pattern.
+///
+/// A fill pattern is a repeating 8x8 pixel bit pattern. Each byte represents a row of the pattern
+/// and the most significant bit of a row corresponds to the leftmost pixel.
+///
+/// The pattern is aligned to the origin of the draw target and not to the primitive, which makes
+/// the patterns of adjacent shapes line up seamlessly.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FillPattern {
+    /// Rows of the pattern.
+    pub rows: [u8; 8],
+}
+
+impl FillPattern {
+    /// Checkerboard pattern with alternating pixels.
+    pub const CHECKERBOARD: Self = Self::new([0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]);
+
+    /// Horizontal lines.
+    pub const HORIZONTAL_HATCH: Self = Self::new([0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00]);
+
+    /// Vertical lines.
+    pub const VERTICAL_HATCH: Self = Self::new([0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88]);
+
+    /// Diagonal lines from the bottom left to the top right.
+    pub const DIAGONAL_HATCH: Self = Self::new([0x11, 0x22, 0x44, 0x88, 0x11, 0x22, 0x44, 0x88]);
+
+    /// Horizontal and vertical lines.
+    pub const CROSS_HATCH: Self = Self::new([0xFF, 0x88, 0x88, 0x88, 0xFF, 0x88, 0x88, 0x88]);
+
+    /// Sparse grid of dots.
+    pub const STIPPLE: Self = Self::new([0x88, 0x00, 0x22, 0x00, 0x88, 0x00, 0x22, 0x00]);
+
+    /// Creates a new fill pattern.
+    pub const fn new(rows: [u8; 8]) -> Self {
+        Self { rows }
+    }
+
+    /// Returns `true` if the bit for the given point is set.
+    pub fn is_set(&self, point: Point) -> bool {
+        let row = self.rows[point.y.rem_euclid(8) as usize];
+
+        row & (0x80 >> point.x.rem_euclid(8)) != 0
+    }
+}
+
+/// Line cap.
+///
+/// The line cap determines how the ends of a thick line, arc or open polyline are drawn.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Line, LineCap, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(BinaryColor::On)
+///     .stroke_width(5)
+///     .line_cap(LineCap::Round)
+///     .build();
+///
+/// Line::new(Point::new(3, 3), Point::new(10, 3))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "              ",
+///     "  ##########  ",
+///     " ############ ",
+///     " ############ ",
+///     " ############ ",
+///     "  ##########  ",
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum LineCap {
+    /// Butt cap.
+    ///
+    /// The line ends exactly at the start and end points with a perpendicular cut.
+    Butt,
+
+    /// Square cap.
+    ///
+    /// The line is extended by half the stroke width beyond the start and end points.
+    Square,
+
+    /// Round cap.
+    ///
+    /// A semicircle with a diameter equal to the stroke width is added to both ends of the line.
+    Round,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        Self::Butt
+    }
+}
+
+/// Line join.
+///
+/// The line join determines how the corners between the segments of a thick polyline are drawn.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{LineJoin, Polyline, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(BinaryColor::On)
+///     .stroke_width(5)
+///     .line_join(LineJoin::Round)
+///     .build();
+///
+/// let points = [Point::new(5, 30), Point::new(20, 5), Point::new(35, 30)];
+///
+/// Polyline::new(&points)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum LineJoin {
+    /// Miter join.
+    ///
+    /// The outer edges of the segments are extended until they meet in a sharp point. If the
+    /// ratio between the length of the miter and the stroke width exceeds `limit`, a bevel join
+    /// is drawn instead. The length of the miter is measured from the inner to the outer corner of
+    /// the joint.
+    Miter {
+        /// Miter limit.
+        limit: u32,
+    },
+
+    /// Bevel join.
+    ///
+    /// The outer corners of the segments are connected by a straight line.
+    Bevel,
+
+    /// Round join.
+    ///
+    /// The corners are rounded by a circle with a diameter equal to the stroke width. Round joins
+    /// are only supported for center aligned strokes. Bevel joins are used for other stroke
+    /// alignments.
+    Round,
+}
+
+impl Default for LineJoin {
+    /// Returns a miter join with a limit of 4.
+    fn default() -> Self {
+        Self::Miter { limit: 4 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTarget,
+        geometry::{AngleUnit, Dimensions, Size},
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{
+            styled::StyledPixels, Chord, Circle, Ellipse, PointsIter, Polygon, Primitive,
+            Rectangle, RegularPolygon, RoundedRectangle, RoundedTriangle, Sector, Star,
+            StyledDrawable, Superellipse, Triangle,
+        },
+        transform::Transform,
+        Drawable, Pixel,
+    };
+
+    #[test]
+    fn default_style() {
+        assert_eq!(
+            PrimitiveStyle::<BinaryColor>::default(),
+            PrimitiveStyle {
+                fill_color: None,
+                fill_style: FillStyle::Solid,
+                stroke_color: None,
+                stroke_width: 0,
+                stroke_alignment: StrokeAlignment::Center,
+                stroke_pattern: StrokePattern::Solid,
+                line_cap: LineCap::Butt,
+                line_join: LineJoin::Miter { limit: 4 },
+                opacity: 255,
+                blend_mode: BlendMode::SourceOver,
+            }
+        );
+
+        assert_eq!(
+            PrimitiveStyle::<BinaryColor>::default(),
+            PrimitiveStyle::new()
+        );
+    }
+
+    #[test]
+    fn constructors() {
+        let style = PrimitiveStyle::with_fill(Rgb888::RED);
+        assert_eq!(style.fill_color, Some(Rgb888::RED));
+        assert_eq!(style.stroke_color, None);
+
+        let style = PrimitiveStyle::with_stroke(Rgb888::GREEN, 123);
+        assert_eq!(style.fill_color, None);
+        assert_eq!(style.stroke_color, Some(Rgb888::GREEN));
+        assert_eq!(style.stroke_width, 123);
+    }
+
+    #[test]
+    fn stroke_alignment_1px() {
+        let mut style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        style.stroke_alignment = StrokeAlignment::Inside;
+        assert_eq!(style.inside_stroke_width(), 1);
+        assert_eq!(style.outside_stroke_width(), 0);
+
+        style.stroke_alignment = StrokeAlignment::Center;
+        assert_eq!(style.inside_stroke_width(), 1);
+        assert_eq!(style.outside_stroke_width(), 0);
+
+        style.stroke_alignment = StrokeAlignment::Outside;
+        assert_eq!(style.inside_stroke_width(), 0);
+        assert_eq!(style.outside_stroke_width(), 1);
+    }
+
+    #[test]
+    fn stroke_alignment_2px() {
+        let mut style = PrimitiveStyle::with_stroke(BinaryColor::On, 2);
+
+        style.stroke_alignment = StrokeAlignment::Inside;
+        assert_eq!(style.inside_stroke_width(), 2);
+        assert_eq!(style.outside_stroke_width(), 0);
+
+        style.stroke_alignment = StrokeAlignment::Center;
+        assert_eq!(style.inside_stroke_width(), 1);
+        assert_eq!(style.outside_stroke_width(), 1);
+
+        style.stroke_alignment = StrokeAlignment::Outside;
+        assert_eq!(style.inside_stroke_width(), 0);
+        assert_eq!(style.outside_stroke_width(), 2);
+    }
+
+    #[test]
+    fn const_constructors() {
+        const STROKE: PrimitiveStyle<BinaryColor> = PrimitiveStyle::with_stroke(BinaryColor::On, 2);
+        const FILL: PrimitiveStyle<BinaryColor> = PrimitiveStyle::with_fill(BinaryColor::Off);
+        const FILL_AND_STROKE: PrimitiveStyle<BinaryColor> =
+            PrimitiveStyle::with_fill_and_stroke(BinaryColor::Off, BinaryColor::On, 3);
+
+        assert_eq!(
+            STROKE,
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(2)
+                .build()
+        );
+        assert_eq!(
+            FILL,
+            PrimitiveStyleBuilder::new()
+                .fill_color(BinaryColor::Off)
+                .build()
+        );
+        assert_eq!(
+            FILL_AND_STROKE,
+            PrimitiveStyleBuilder::new()
+                .fill_color(BinaryColor::Off)
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .build()
+        );
+    }
+
+    #[test]
+    fn builder_default() {
+        assert_eq!(
+            PrimitiveStyleBuilder::<BinaryColor>::new().build(),
+            PrimitiveStyle::<BinaryColor>::default()
+        );
+    }
+
+    #[test]
+    fn builder_stroke() {
+        assert_eq!(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(10)
+                .build(),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 10)
+        );
+    }
+
+    #[test]
+    fn builder_reset_stroke_color() {
+        assert_eq!(
             PrimitiveStyleBuilder::new()
                 .stroke_color(BinaryColor::On)
                 .stroke_width(10)
@@ -433,4 +1589,535 @@ mod tests {
             core::u32::MAX / 2
         );
     }
+
+    #[test]
+    fn builder_stroke_pattern() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_pattern(StrokePattern::dashed(3, 2))
+            .build();
+
+        assert_eq!(
+            style.stroke_pattern,
+            StrokePattern::Dashed {
+                dash_length: 3,
+                gap_length: 2,
+                offset: 0
+            }
+        );
+    }
+
+    #[test]
+    fn builder_line_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .line_cap(LineCap::Round)
+            .build();
+
+        assert_eq!(style.line_cap, LineCap::Round);
+        assert_eq!(PrimitiveStyle::<BinaryColor>::new().line_cap, LineCap::Butt);
+    }
+
+    #[test]
+    fn builder_line_join() {
+        let style = PrimitiveStyleBuilder::<BinaryColor>::new()
+            .line_join(LineJoin::Bevel)
+            .build();
+
+        assert_eq!(style.line_join, LineJoin::Bevel);
+        assert_eq!(
+            PrimitiveStyle::<BinaryColor>::new().line_join,
+            LineJoin::Miter { limit: 4 }
+        );
+    }
+
+    #[test]
+    fn builder_opacity_and_blend_mode() {
+        let style = PrimitiveStyleBuilder::<BinaryColor>::new()
+            .opacity(100)
+            .blend_mode(BlendMode::Xor)
+            .build();
+
+        assert_eq!(style.opacity, 100);
+        assert_eq!(style.blend_mode, BlendMode::Xor);
+    }
+
+    #[test]
+    fn stroke_pattern_is_dash() {
+        assert!((-10..10).all(|i| StrokePattern::Solid.is_dash(i)));
+
+        let pattern = StrokePattern::dashed(3, 2);
+        let dashes = [
+            true, true, true, false, false, true, true, true, false, false,
+        ];
+        assert!((0..10)
+            .map(|i| pattern.is_dash(i))
+            .eq(dashes.iter().copied()));
+        assert!((-5..5)
+            .map(|i| pattern.is_dash(i))
+            .eq(dashes.iter().copied()));
+
+        let pattern = StrokePattern::Dashed {
+            dash_length: 3,
+            gap_length: 2,
+            offset: 1,
+        };
+        let dashes = [
+            true, true, false, false, true, true, true, false, false, true,
+        ];
+        assert!((0..10)
+            .map(|i| pattern.is_dash(i))
+            .eq(dashes.iter().copied()));
+    }
+
+    #[test]
+    fn stroke_pattern_zero_length() {
+        assert!((0..10).all(|i| !StrokePattern::dashed(0, 0).is_dash(i)));
+        assert!((0..10).all(|i| !StrokePattern::dashed(0, 2).is_dash(i)));
+        assert!((0..10).all(|i| StrokePattern::dashed(2, 0).is_dash(i)));
+    }
+
+    #[test]
+    fn stroke_pattern_closed_length() {
+        assert_eq!(StrokePattern::Solid.closed_length(34), 34);
+        assert_eq!(StrokePattern::dashed(3, 3).closed_length(34), 30);
+        assert_eq!(StrokePattern::dashed(3, 3).closed_length(36), 36);
+        assert_eq!(StrokePattern::dashed(3, 3).closed_length(41), 36);
+        assert_eq!(StrokePattern::dashed(4, 6).closed_length(2), 10);
+        assert_eq!(StrokePattern::dashed(0, 0).closed_length(7), 7);
+        assert_eq!(
+            StrokePattern::dashed(u32::max_value(), 1).closed_length(7),
+            u32::max_value()
+        );
+    }
+
+    #[test]
+    fn stroke_pattern_advanced_by() {
+        assert_eq!(StrokePattern::Solid.advanced_by(5), StrokePattern::Solid);
+        assert_eq!(
+            StrokePattern::dashed(0, 0).advanced_by(5),
+            StrokePattern::dashed(0, 0)
+        );
+
+        let pattern = StrokePattern::dashed(3, 2);
+        for &length in &[0, 1, 4, 5, 13, 1 << 40] {
+            let advanced = pattern.advanced_by(length);
+
+            assert!(
+                (0..20).all(|i| advanced.is_dash(i) == pattern.is_dash(i + (length % 5) as i32)),
+                "{}",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn fill_pattern_is_set() {
+        let pattern = FillPattern::new([0x80, 0x01, 0, 0, 0, 0, 0, 0xFF]);
+
+        assert!(pattern.is_set(Point::new(0, 0)));
+        assert!(!pattern.is_set(Point::new(1, 0)));
+        assert!(pattern.is_set(Point::new(7, 1)));
+        assert!(!pattern.is_set(Point::new(6, 1)));
+        assert!((0..8).all(|x| pattern.is_set(Point::new(x, 7))));
+
+        // The pattern repeats in both directions.
+        assert!(pattern.is_set(Point::new(8, 8)));
+        assert!(pattern.is_set(Point::new(-8, 0)));
+        assert!(pattern.is_set(Point::new(-1, -7)));
+        assert!(!pattern.is_set(Point::new(-1, -8)));
+    }
+
+    #[test]
+    fn fill_color_at() {
+        let bounding_box = Rectangle::new(Point::zero(), Size::new(8, 8));
+
+        let mut style = PrimitiveStyle::with_fill(BinaryColor::On);
+        assert_eq!(
+            style.fill_color_at(Point::new(1, 0), &bounding_box),
+            Some(BinaryColor::On)
+        );
+
+        style.fill_style = FillStyle::Pattern {
+            pattern: FillPattern::CHECKERBOARD,
+            background: None,
+        };
+        assert_eq!(
+            style.fill_color_at(Point::new(0, 0), &bounding_box),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(style.fill_color_at(Point::new(1, 0), &bounding_box), None);
+
+        style.fill_style = FillStyle::Pattern {
+            pattern: FillPattern::CHECKERBOARD,
+            background: Some(BinaryColor::Off),
+        };
+        assert_eq!(
+            style.fill_color_at(Point::new(1, 0), &bounding_box),
+            Some(BinaryColor::Off)
+        );
+
+        style.fill_color = None;
+        assert_eq!(style.fill_color_at(Point::new(0, 0), &bounding_box), None);
+    }
+
+    #[test]
+    fn builder_fill_style() {
+        let fill_style = FillStyle::Pattern {
+            pattern: FillPattern::STIPPLE,
+            background: None,
+        };
+
+        let style = PrimitiveStyleBuilder::<BinaryColor>::new()
+            .fill_style(fill_style)
+            .build();
+
+        assert_eq!(style.fill_style, fill_style);
+    }
+
+    /// Checks that a patterned fill draws the same pixels as a solid fill, with the exception of
+    /// the pixels that are cleared in the pattern.
+    fn test_fill_pattern<T>(primitive: T)
+    where
+        T: Primitive
+            + StyledDrawable<PrimitiveStyle<BinaryColor>, Color = BinaryColor>
+            + StyledPixels<PrimitiveStyle<BinaryColor>>
+            + Copy
+            + fmt::Debug,
+        T::Iter: Iterator<Item = Pixel<BinaryColor>>,
+    {
+        for stroke_width in 0..3 {
+            let solid_style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::Off)
+                .stroke_width(stroke_width)
+                .fill_color(BinaryColor::On)
+                .build();
+
+            let pattern_style = PrimitiveStyleBuilder::from(&solid_style)
+                .fill_style(FillStyle::Pattern {
+                    pattern: FillPattern::DIAGONAL_HATCH,
+                    background: None,
+                })
+                .build();
+
+            let mut solid = MockDisplay::new();
+            primitive.into_styled(solid_style).draw(&mut solid).unwrap();
+
+            let mut expected = MockDisplay::new();
+            expected
+                .draw_iter(solid.bounding_box().points().filter_map(|p| {
+                    solid
+                        .get_pixel(p)
+                        .filter(|c| *c == BinaryColor::Off || FillPattern::DIAGONAL_HATCH.is_set(p))
+                        .map(|c| Pixel(p, c))
+                }))
+                .unwrap();
+
+            let styled = primitive.into_styled(pattern_style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            display.assert_eq_with_message(&expected, |f| {
+                write!(f, "{:?}, {}", primitive, stroke_width)
+            });
+
+            let mut pixels = MockDisplay::new();
+            pixels.draw_iter(styled.pixels()).unwrap();
+            pixels.assert_eq_with_message(&expected, |f| {
+                write!(f, "{:?}, {}", primitive, stroke_width)
+            });
+        }
+    }
+
+    #[test]
+    fn fill_pattern_primitives() {
+        let triangle = Triangle::new(Point::new(3, 2), Point::new(30, 10), Point::new(8, 28));
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(25, 19));
+
+        test_fill_pattern(rectangle);
+        test_fill_pattern(Circle::new(Point::new(3, 2), 25));
+        test_fill_pattern(Ellipse::new(Point::new(3, 2), Size::new(30, 17)));
+        test_fill_pattern(RoundedRectangle::with_equal_corners(
+            rectangle,
+            Size::new(5, 6),
+        ));
+        test_fill_pattern(triangle);
+        test_fill_pattern(RoundedTriangle::new(triangle, 4));
+        test_fill_pattern(Polygon::new(&triangle.vertices).translate(Point::new(5, 3)));
+        test_fill_pattern(Sector::new(Point::new(3, 2), 25, 10.0.deg(), 120.0.deg()));
+        test_fill_pattern(Chord::new(Point::new(3, 2), 25, 10.0.deg(), 200.0.deg()));
+        test_fill_pattern(RegularPolygon::new(Point::new(15, 15), 12, 6, 0.0.deg()));
+        test_fill_pattern(Star::new(Point::new(15, 15), 14, 6, 5, 0.0.deg()));
+        test_fill_pattern(Superellipse::new(Point::new(3, 2), Size::new(25, 20), 4));
+    }
+
+    #[test]
+    fn gradient_color_at() {
+        let gradient = LinearGradient::new(
+            Rgb888::BLACK,
+            Rgb888::new(200, 100, 50),
+            GradientDirection::Horizontal,
+        );
+        let bounding_box = Rectangle::new(Point::new(10, 3), Size::new(5, 1));
+
+        let color_at = |x| gradient.color_at(Point::new(x, 3), &bounding_box);
+        assert_eq!(color_at(10), Rgb888::BLACK);
+        assert_eq!(color_at(11), Rgb888::new(50, 25, 13));
+        assert_eq!(color_at(12), Rgb888::new(100, 50, 25));
+        assert_eq!(color_at(14), Rgb888::new(200, 100, 50));
+
+        // Points outside the bounding box use the color at the nearest edge.
+        assert_eq!(color_at(5), Rgb888::BLACK);
+        assert_eq!(color_at(20), Rgb888::new(200, 100, 50));
+    }
+
+    #[test]
+    fn gradient_direction() {
+        let bounding_box = Rectangle::new(Point::zero(), Size::new(3, 3));
+        let point = Point::new(2, 0);
+
+        let horizontal =
+            LinearGradient::new(Rgb888::BLACK, Rgb888::WHITE, GradientDirection::Horizontal);
+        assert_eq!(horizontal.color_at(point, &bounding_box), Rgb888::WHITE);
+
+        let vertical =
+            LinearGradient::new(Rgb888::BLACK, Rgb888::WHITE, GradientDirection::Vertical);
+        assert_eq!(vertical.color_at(point, &bounding_box), Rgb888::BLACK);
+    }
+
+    #[test]
+    fn gradient_single_pixel() {
+        let gradient =
+            LinearGradient::new(Rgb888::RED, Rgb888::BLUE, GradientDirection::Horizontal);
+
+        for &size in &[Size::zero(), Size::new(1, 1)] {
+            let bounding_box = Rectangle::new(Point::zero(), size);
+            assert_eq!(gradient.color_at(Point::zero(), &bounding_box), Rgb888::RED);
+        }
+    }
+
+    #[test]
+    fn radial_gradient_color_at() {
+        let gradient = RadialGradient::new(Rgb888::BLACK, Rgb888::new(200, 100, 50));
+        let bounding_box = Rectangle::new(Point::new(10, 20), Size::new(5, 7));
+
+        let color_at = |x, y| gradient.color_at(Point::new(x, y), &bounding_box);
+        assert_eq!(color_at(12, 23), Rgb888::BLACK);
+        assert_eq!(color_at(13, 23), Rgb888::new(100, 50, 25));
+        assert_eq!(color_at(12, 24), Rgb888::new(100, 50, 25));
+        assert_eq!(color_at(14, 23), Rgb888::new(200, 100, 50));
+        assert_eq!(color_at(12, 21), Rgb888::new(200, 100, 50));
+
+        // Points outside the largest circle in the bounding box use the edge color.
+        assert_eq!(color_at(10, 20), Rgb888::new(200, 100, 50));
+        assert_eq!(color_at(12, 20), Rgb888::new(200, 100, 50));
+    }
+
+    #[test]
+    fn radial_gradient_even_size() {
+        let gradient = RadialGradient::new(Rgb888::BLACK, Rgb888::WHITE);
+        let bounding_box = Rectangle::new(Point::zero(), Size::new(4, 4));
+
+        // The four center pixels are equally distant from the center.
+        let center_color = gradient.color_at(Point::new(1, 1), &bounding_box);
+        assert_ne!(center_color, Rgb888::BLACK);
+        for &point in &[Point::new(2, 1), Point::new(1, 2), Point::new(2, 2)] {
+            assert_eq!(gradient.color_at(point, &bounding_box), center_color);
+        }
+    }
+
+    #[test]
+    fn builder_fill_radial_gradient() {
+        let style = PrimitiveStyleBuilder::new()
+            .fill_radial_gradient(Rgb888::RED, Rgb888::BLUE)
+            .build();
+
+        assert_eq!(style.fill_color, None);
+        assert!(style.is_filled());
+        assert_eq!(
+            style.fill_style,
+            FillStyle::RadialGradient(RadialGradient::new(Rgb888::RED, Rgb888::BLUE))
+        );
+    }
+
+    #[test]
+    fn builder_fill_gradient() {
+        let style = PrimitiveStyleBuilder::new()
+            .fill_gradient(Rgb888::RED, Rgb888::BLUE, GradientDirection::Vertical)
+            .build();
+
+        assert_eq!(style.fill_color, None);
+        assert!(style.is_filled());
+        assert_eq!(
+            style.fill_style,
+            FillStyle::LinearGradient(LinearGradient::new(
+                Rgb888::RED,
+                Rgb888::BLUE,
+                GradientDirection::Vertical
+            ))
+        );
+    }
+
+    /// 3x2 Rgb888 texture.
+    const RGB_TEXTURE: &[u8] = &[
+        0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xFF, //
+        0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0x10, 0x20, 0x30, //
+    ];
+
+    #[test]
+    fn texture_color_at() {
+        let texture = Texture::new(&ImageRaw::<Rgb888>::new(RGB_TEXTURE, 3));
+        assert_eq!(texture.size(), Size::new(3, 2));
+
+        assert_eq!(texture.color_at(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(
+            texture.color_at(Point::new(2, 1)),
+            Some(Rgb888::new(0x10, 0x20, 0x30))
+        );
+
+        // The texture is repeated in all directions.
+        assert_eq!(texture.color_at(Point::new(4, 0)), Some(Rgb888::GREEN));
+        assert_eq!(
+            texture.color_at(Point::new(3, 3)),
+            Some(Rgb888::new(0x80, 0x80, 0x80))
+        );
+        assert_eq!(
+            texture.color_at(Point::new(-1, -1)),
+            Some(Rgb888::new(0x10, 0x20, 0x30))
+        );
+        assert_eq!(texture.color_at(Point::new(-3, -2)), Some(Rgb888::RED));
+    }
+
+    #[test]
+    fn texture_layout() {
+        const DATA: &[u8] = &[
+            0b0000_0110,
+            0xFF,        //
+            0b0000_0001, //
+        ];
+
+        let image = ImageRaw::<BinaryColor>::new(DATA, 3)
+            .with_stride(2)
+            .with_bit_order(BitOrder::LsbFirst);
+        let texture = Texture::new(&image);
+        assert_eq!(texture.size(), Size::new(3, 2));
+
+        assert_eq!(texture.color_at(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(texture.color_at(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(texture.color_at(Point::new(0, 1)), Some(BinaryColor::On));
+        assert_eq!(texture.color_at(Point::new(2, 1)), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn empty_texture() {
+        let texture = Texture::new(&ImageRaw::<Rgb888>::new(&[], 3));
+
+        assert_eq!(texture.color_at(Point::zero()), None);
+        let style = PrimitiveStyleBuilder::new()
+            .fill_image(&ImageRaw::<Rgb888>::new(&[], 3))
+            .build();
+        assert!(!style.is_filled());
+        assert!(style.is_transparent());
+    }
+
+    #[test]
+    fn builder_fill_image() {
+        let image = ImageRaw::<Rgb888>::new(RGB_TEXTURE, 3);
+        let style = PrimitiveStyleBuilder::new().fill_image(&image).build();
+
+        assert_eq!(style.fill_color, None);
+        assert!(style.is_filled());
+        assert_eq!(style.fill_style, FillStyle::Texture(Texture::new(&image)));
+    }
+
+    /// Checks that a gradient or texture fill without a fill color draws the same pixels as a solid
+    /// fill, with the fill color replaced by the color of the fill style.
+    fn test_fill_colors<T>(primitive: T)
+    where
+        T: Primitive
+            + StyledDrawable<PrimitiveStyle<Rgb888>, Color = Rgb888>
+            + StyledPixels<PrimitiveStyle<Rgb888>>
+            + Copy
+            + fmt::Debug,
+        T::Iter: Iterator<Item = Pixel<Rgb888>>,
+    {
+        let fill_styles = [
+            FillStyle::LinearGradient(LinearGradient::new(
+                Rgb888::RED,
+                Rgb888::BLUE,
+                GradientDirection::Horizontal,
+            )),
+            FillStyle::LinearGradient(LinearGradient::new(
+                Rgb888::RED,
+                Rgb888::BLUE,
+                GradientDirection::Vertical,
+            )),
+            FillStyle::RadialGradient(RadialGradient::new(Rgb888::RED, Rgb888::BLUE)),
+            FillStyle::Texture(Texture::new(&ImageRaw::<Rgb888>::new(RGB_TEXTURE, 3))),
+        ];
+
+        for &fill_style in fill_styles.iter() {
+            for stroke_width in 0..3 {
+                let solid_style = PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb888::WHITE)
+                    .stroke_width(stroke_width)
+                    .fill_color(Rgb888::GREEN)
+                    .build();
+
+                let gradient_style = PrimitiveStyleBuilder::from(&solid_style)
+                    .reset_fill_color()
+                    .fill_style(fill_style)
+                    .build();
+
+                let mut solid = MockDisplay::new();
+                primitive.into_styled(solid_style).draw(&mut solid).unwrap();
+
+                let bounding_box = primitive.bounding_box();
+                let mut expected = MockDisplay::new();
+                expected
+                    .draw_iter(solid.bounding_box().points().filter_map(|p| {
+                        solid.get_pixel(p).and_then(|c| {
+                            if c == Rgb888::GREEN {
+                                fill_style.color_at(p, c, &bounding_box)
+                            } else {
+                                Some(c)
+                            }
+                            .map(|c| Pixel(p, c))
+                        })
+                    }))
+                    .unwrap();
+
+                let styled = primitive.into_styled(gradient_style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{:?}, {:?}, {}", primitive, fill_style, stroke_width)
+                });
+
+                let mut pixels = MockDisplay::new();
+                pixels.draw_iter(styled.pixels()).unwrap();
+                pixels.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{:?}, {:?}, {}", primitive, fill_style, stroke_width)
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn fill_colors_primitives() {
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(25, 19));
+
+        test_fill_colors(rectangle);
+        test_fill_colors(RoundedRectangle::with_equal_corners(
+            rectangle,
+            Size::new(5, 6),
+        ));
+        test_fill_colors(Circle::new(Point::new(3, 2), 25));
+        test_fill_colors(Triangle::new(
+            Point::new(3, 2),
+            Point::new(30, 10),
+            Point::new(8, 28),
+        ));
+    }
 }
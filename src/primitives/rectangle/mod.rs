@@ -3,10 +3,16 @@
 use crate::{
     geometry::{Point, Size},
     primitives::{ContainsPoint, OffsetOutline, Primitive},
-    transform::Transform,
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
+    },
 };
 
-pub use embedded_graphics_core::primitives::{rectangle::Points, Rectangle};
+pub use embedded_graphics_core::primitives::{
+    rectangle::{ColumnMajorPoints, Points},
+    Rectangle,
+};
 
 mod styled;
 
@@ -77,6 +83,66 @@ impl Transform for Rectangle {
     }
 }
 
+impl Rotate for Rectangle {
+    /// Rotate the rectangle by 90° clockwise around `center`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::prelude::*;
+    /// let rect = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let rotated = rect.rotate_90(Point::new(5, 10));
+    ///
+    /// assert_eq!(rotated.top_left, Point::new(2, 10));
+    /// assert_eq!(rotated.size, Size::new(4, 10));
+    /// ```
+    fn rotate_90(&self, center: Point) -> Self {
+        let bottom_left = self.top_left + Point::new(0, self.size.height as i32 - 1);
+
+        Self::new(
+            rotate_point_90(bottom_left, center),
+            Size::new(self.size.height, self.size.width),
+        )
+    }
+}
+
+impl Mirror for Rectangle {
+    fn mirror_x(&self, axis: i32) -> Self {
+        let top_right = self.top_left + Point::new(self.size.width as i32 - 1, 0);
+
+        Self::new(mirror_point_x(top_right, axis), self.size)
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        let bottom_left = self.top_left + Point::new(0, self.size.height as i32 - 1);
+
+        Self::new(mirror_point_y(bottom_left, axis), self.size)
+    }
+}
+
+impl Scale for Rectangle {
+    /// Scale the rectangle by `num / denom` relative to `center`.
+    ///
+    /// The top left corner and the corner after the bottom right pixel are scaled, which makes
+    /// sure that adjacent rectangles stay adjacent after scaling.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Rectangle;
+    /// # use embedded_graphics::prelude::*;
+    /// let rect = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let scaled = rect.scale_around(Point::new(5, 10), 3, 2);
+    ///
+    /// assert_eq!(scaled.top_left, Point::new(5, 10));
+    /// assert_eq!(scaled.size, Size::new(15, 6));
+    /// ```
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let top_left = scale_point(self.top_left, center, num, denom);
+        let bottom_right = scale_point(self.top_left + self.size, center, num, denom);
+        let size = bottom_right - top_left;
+
+        Self::new(top_left, Size::new(size.x as u32, size.y as u32))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,14 +1,15 @@
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{DrawTarget, IterationOrder},
     geometry::{Dimensions, Point, Size},
+    iterator::contiguous::Runs,
     pixelcolor::PixelColor,
     primitives::{
         rectangle::{Points, Rectangle},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PointsIter, PrimitiveStyle,
+        FillStyle, PointsIter, PrimitiveStyle, StrokePattern,
     },
     transform::Transform,
-    Pixel, SaturatingCast,
+    Pixel,
 };
 
 /// Pixel iterator for each pixel in the rect border
@@ -17,24 +18,36 @@ pub struct StyledPixelsIterator<C> {
     iter: Points,
 
     stroke_color: Option<C>,
+    stroke_area: Rectangle,
+    stroke_pattern: StrokePattern,
+    perimeter: u32,
 
     fill_area: Rectangle,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
     pub(in crate::primitives) fn new(primitive: &Rectangle, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = stroke_area(primitive, style);
+
         let iter = if !style.is_transparent() {
-            style.stroke_area(primitive).points()
+            stroke_area.points()
         } else {
             Points::empty()
         };
 
         Self {
             iter,
+            stroke_area,
+            stroke_pattern: style.stroke_pattern,
+            perimeter: style.stroke_pattern.closed_length(perimeter(&stroke_area)),
             fill_area: style.fill_area(primitive),
             stroke_color: style.stroke_color,
-            fill_color: style.fill_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
         }
     }
 }
@@ -43,11 +56,21 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let fill_style = self.fill_style;
+        let bounding_box = self.bounding_box;
+
         for point in &mut self.iter {
             let color = if self.fill_area.contains(point) {
                 self.fill_color
-            } else {
+                    .and_then(|fill_color| fill_style.color_at(point, fill_color, &bounding_box))
+            } else if self.stroke_pattern.is_dash(dash_position(
+                &self.stroke_area,
+                self.perimeter,
+                point,
+            )) {
                 self.stroke_color
+            } else {
+                None
             };
 
             if let Some(color) = color {
@@ -59,6 +82,62 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the stroke area.
+///
+/// Rectangles with a zero width or height are stroked like a 1px wide line, by treating the zero
+/// sized dimensions as 1px. The fill area of these rectangles remains empty.
+fn stroke_area<C: PixelColor>(rectangle: &Rectangle, style: &PrimitiveStyle<C>) -> Rectangle {
+    if style.stroke_width > 0 {
+        let size = rectangle.size.component_max(Size::new_equal(1));
+
+        style.stroke_area(&Rectangle::new(rectangle.top_left, size))
+    } else {
+        style.stroke_area(rectangle)
+    }
+}
+
+/// Returns the length of the outline of the stroke area.
+fn perimeter(stroke_area: &Rectangle) -> u32 {
+    let width = u64::from(stroke_area.size.width.saturating_sub(1));
+    let height = u64::from(stroke_area.size.height.saturating_sub(1));
+
+    (2 * (width + height)).min(u64::from(u32::max_value())) as u32
+}
+
+/// Returns the position of a stroke pixel along the outline of the stroke area.
+///
+/// The position increases clockwise, starting at the top left corner. Each corner pixel is
+/// assigned to exactly one edge, which keeps the position continuous for thin strokes. The
+/// position is scaled to make the outline `perimeter` pixels long.
+fn dash_position(stroke_area: &Rectangle, perimeter: u32, point: Point) -> i32 {
+    let width = stroke_area.size.width.saturating_sub(1) as i32;
+    let height = stroke_area.size.height.saturating_sub(1) as i32;
+
+    let Point { x, y } = point - stroke_area.top_left;
+
+    let top = y;
+    let right = width - x;
+    let bottom = height - y;
+    let left = x;
+
+    let position = if top <= right && top <= bottom && top <= left {
+        x
+    } else if right <= bottom && right <= left {
+        width + y
+    } else if bottom <= left {
+        width + height + (width - x)
+    } else {
+        2 * width + height + (height - y)
+    };
+
+    let length = self::perimeter(stroke_area);
+    if length == 0 {
+        return position;
+    }
+
+    (i64::from(position) * i64::from(perimeter) / i64::from(length)) as i32
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Rectangle {
     type Iter = StyledPixelsIterator<C>;
 
@@ -82,15 +161,43 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Rectangle {
         let fill_area = style.fill_area(self);
 
         // Fill rectangle
-        if let Some(fill_color) = style.fill_color {
-            target.fill_solid(&fill_area, fill_color)?;
+        if let Some(fill_color) = style.effective_fill_color() {
+            if style.fill_style.is_solid() {
+                target.fill_solid(&fill_area, fill_color)?;
+            } else if D::CAPABILITIES.iteration_order == IterationOrder::ColumnMajor {
+                target.draw_iter(fill_area.points_column_major().filter_map(|point| {
+                    style
+                        .fill_color_at(point, self)
+                        .map(|color| Pixel(point, color))
+                }))?;
+            } else {
+                target.draw_runs(Runs::new(
+                    fill_area.points().map(|p| style.fill_color_at(p, self)),
+                    &fill_area,
+                ))?;
+            }
+        }
+
+        // Dashed strokes can't be drawn using `fill_solid`
+        if !style.stroke_pattern.is_solid() {
+            let mut iter = StyledPixelsIterator::new(self, style);
+            iter.fill_color = None;
+
+            return target.draw_iter(iter);
         }
 
         // Draw stroke
         if let Some(stroke_color) = style.effective_stroke_color() {
             let stroke_width = style.stroke_width;
 
-            let stroke_area = style.stroke_area(self);
+            let stroke_area = stroke_area(self, style);
+
+            // Rectangles without a fill area are completely covered by the stroke. Handling this
+            // case separately also prevents the left and right border from overlapping in thin
+            // rectangles.
+            if fill_area.is_zero_sized() {
+                return target.fill_solid(&stroke_area, stroke_color);
+            }
 
             let top_border = Rectangle::new(
                 stroke_area.top_left,
@@ -115,26 +222,24 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Rectangle {
             target.fill_solid(&top_border, stroke_color)?;
             target.fill_solid(&bottom_border, stroke_color)?;
 
-            if fill_area.size.height > 0 {
-                let left_border = Rectangle::new(
-                    stroke_area.top_left + top_border.size.y_axis(),
-                    Size::new(
-                        (stroke_width * 2).min(stroke_area.size.width + 1) / 2,
-                        fill_area.size.height,
-                    ),
-                );
+            let left_border = Rectangle::new(
+                stroke_area.top_left + top_border.size.y_axis(),
+                Size::new(
+                    (stroke_width * 2).min(stroke_area.size.width + 1) / 2,
+                    fill_area.size.height,
+                ),
+            );
 
-                let right_border = left_border.translate(Point::new(
-                    stroke_area
-                        .size
-                        .width
-                        .saturating_sub(left_border.size.width) as i32,
-                    0,
-                ));
+            let right_border = left_border.translate(Point::new(
+                stroke_area
+                    .size
+                    .width
+                    .saturating_sub(left_border.size.width) as i32,
+                0,
+            ));
 
-                target.fill_solid(&left_border, stroke_color)?;
-                target.fill_solid(&right_border, stroke_color)?;
-            }
+            target.fill_solid(&left_border, stroke_color)?;
+            target.fill_solid(&right_border, stroke_color)?;
         }
 
         Ok(())
@@ -143,9 +248,7 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Rectangle {
 
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Rectangle {
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let offset = style.outside_stroke_width().saturating_cast();
-
-        self.bounding_box().offset(offset)
+        stroke_area(self, style)
     }
 }
 
@@ -153,11 +256,15 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Rectangle {
 mod tests {
     use super::*;
     use crate::{
-        geometry::{Point, Size},
+        draw_target::Capabilities,
+        geometry::{Dimensions, Point, Size},
         iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, RgbColor},
-        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            FillPattern, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+            StrokePattern,
+        },
         Drawable,
     };
 
@@ -184,7 +291,6 @@ mod tests {
         let rectangle = Rectangle::new(Point::new(10, 10), Size::new(20, 30));
 
         let styled_points = rectangle
-            .clone()
             .into_styled(PrimitiveStyle::with_fill(Rgb565::WHITE))
             .pixels()
             .map(|Pixel(p, _)| p);
@@ -341,6 +447,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn drawable_vs_iterator_thin() {
+        for &size in &[
+            Size::new(1, 10),
+            Size::new(10, 1),
+            Size::new(3, 10),
+            Size::new(10, 3),
+            Size::new(1, 1),
+        ] {
+            compare_drawable_iter(Rectangle::new(Point::new(10, 20), size))
+        }
+    }
+
+    #[test]
+    fn drawable_vs_iterator_zero_sized() {
+        for &size in &[Size::new(0, 10), Size::new(10, 0), Size::zero()] {
+            compare_drawable_iter(Rectangle::new(Point::new(10, 20), size))
+        }
+    }
+
     #[test]
     fn drawable_vs_iterator() {
         compare_drawable_iter(Rectangle::new(Point::new(10, 20), Size::new(20, 30)))
@@ -390,6 +516,59 @@ mod tests {
         assert_eq!(display.affected_area(), outside.bounding_box());
     }
 
+    #[test]
+    fn zero_sized_stroke() {
+        let rect = Rectangle::new(Point::new(1, 1), Size::new(5, 0));
+
+        let mut display = MockDisplay::new();
+        rect.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["      ", " #####"]);
+
+        let mut display = MockDisplay::new();
+        rect.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["#######", "#######", "#######"]);
+    }
+
+    #[test]
+    fn zero_sized_bounding_box() {
+        for &size in &[Size::new(0, 10), Size::new(10, 0), Size::zero()] {
+            let rect = Rectangle::new(Point::new(10, 20), size);
+
+            for stroke_width in 1..5 {
+                for &alignment in &[
+                    StrokeAlignment::Center,
+                    StrokeAlignment::Inside,
+                    StrokeAlignment::Outside,
+                ] {
+                    let styled = rect.into_styled(
+                        PrimitiveStyleBuilder::new()
+                            .stroke_color(BinaryColor::On)
+                            .stroke_width(stroke_width)
+                            .stroke_alignment(alignment)
+                            .fill_color(BinaryColor::Off)
+                            .build(),
+                    );
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{:?}, {}, {:?}",
+                        size,
+                        stroke_width,
+                        alignment
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn bounding_box_is_independent_of_colors() {
         let rect = Rectangle::new(Point::new(5, 5), Size::new(11, 14));
@@ -399,4 +578,187 @@ mod tests {
 
         assert_eq!(transparent_rect.bounding_box(), filled_rect.bounding_box(),);
     }
+
+    #[test]
+    fn dashed_stroke() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(3, 1))
+            .build();
+
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(8, 5))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        // The outline is scaled to a whole number of periods, which prevents the last dash on the
+        // left edge from merging with the first dash in the top left corner.
+        display.assert_pattern(&[
+            "#### ###", //
+            "        ", //
+            "#      #", //
+            "#      #", //
+            "# ### ##", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_stroke_ends_with_gap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(3, 1))
+            .build();
+
+        for width in 3..20 {
+            for height in 3..20 {
+                let mut display = MockDisplay::new();
+                Rectangle::new(Point::zero(), Size::new(width, height))
+                    .into_styled(style)
+                    .draw(&mut display)
+                    .unwrap();
+
+                // The outline starts with a dash in the top left corner and ends with a gap
+                // directly below it.
+                assert_eq!(
+                    display.get_pixel(Point::new(0, 0)),
+                    Some(BinaryColor::On),
+                    "{}x{}",
+                    width,
+                    height
+                );
+                assert_eq!(
+                    display.get_pixel(Point::new(0, 1)),
+                    None,
+                    "{}x{}",
+                    width,
+                    height
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dashed_stroke_with_fill() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb565::RED)
+            .stroke_width(1)
+            .stroke_pattern(StrokePattern::dashed(2, 2))
+            .fill_color(Rgb565::GREEN)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(6, 4))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "RR  RR", //
+            " GGGG ", //
+            " GGGG ", //
+            "RR  RR", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_drawable_vs_iterator() {
+        let rect = Rectangle::new(Point::new(8, 9), Size::new(15, 11));
+
+        for width in 0..6 {
+            for alignment in [
+                StrokeAlignment::Center,
+                StrokeAlignment::Inside,
+                StrokeAlignment::Outside,
+            ]
+            .iter()
+            {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb565::RED)
+                    .stroke_width(width)
+                    .stroke_alignment(*alignment)
+                    .stroke_pattern(StrokePattern::dashed(4, 3))
+                    .fill_color(Rgb565::GREEN)
+                    .build();
+
+                let mut display_drawable = MockDisplay::new();
+                let mut display_iter = MockDisplay::new();
+
+                rect.into_styled(style).draw(&mut display_drawable).unwrap();
+                rect.into_styled(style)
+                    .pixels()
+                    .draw(&mut display_iter)
+                    .unwrap();
+
+                display_drawable.assert_eq(&display_iter);
+            }
+        }
+    }
+
+    /// Draw target which prefers column major order and records the drawn points.
+    struct ColumnMajor {
+        points: [Point; 6],
+        len: usize,
+    }
+
+    impl DrawTarget for ColumnMajor {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        const CAPABILITIES: Capabilities =
+            Capabilities::new().with_iteration_order(IterationOrder::ColumnMajor);
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(point, _) in pixels {
+                self.points[self.len] = point;
+                self.len += 1;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Dimensions for ColumnMajor {
+        fn bounding_box(&self) -> Rectangle {
+            Rectangle::new(Point::zero(), Size::new(64, 64))
+        }
+    }
+
+    #[test]
+    fn pattern_fill_column_major() {
+        let style = PrimitiveStyleBuilder::new()
+            .fill_color(BinaryColor::On)
+            .fill_style(FillStyle::Pattern {
+                pattern: FillPattern::new([0xFF; 8]),
+                background: None,
+            })
+            .build();
+
+        let mut target = ColumnMajor {
+            points: [Point::zero(); 6],
+            len: 0,
+        };
+        Rectangle::new(Point::new(1, 2), Size::new(2, 3))
+            .into_styled(style)
+            .draw(&mut target)
+            .unwrap();
+
+        assert_eq!(target.len, 6);
+        assert_eq!(
+            target.points,
+            [
+                Point::new(1, 2),
+                Point::new(1, 3),
+                Point::new(1, 4),
+                Point::new(2, 2),
+                Point::new(2, 3),
+                Point::new(2, 4),
+            ]
+        );
+    }
 }
@@ -0,0 +1,347 @@
+//! The regular polygon primitive.
+
+use crate::{
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_90DEG},
+        Angle, Dimensions, Point, Real, Trigonometry,
+    },
+    primitives::{ContainsPoint, PointsIter, Polygon, Primitive, Rectangle},
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_length, scale_point, Mirror, Rotate,
+        Scale, Transform,
+    },
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Regular polygon primitive
+///
+/// A regular polygon has sides of equal length and all vertices lie on a circle around the center
+/// point. The vertices are placed clockwise, starting at the angle given by `rotation`. An angle
+/// of zero degrees points to the right, which is the same convention that is used by [`Arc`] and
+/// [`Sector`].
+///
+/// The number of sides is limited to the range from 3 to [`MAX_SIDES`]. Values outside of this
+/// range are clamped.
+///
+/// # Examples
+///
+/// ## Draw a hexagon and a pentagon
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, PrimitiveStyleBuilder, RegularPolygon},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Hexagon with a flat top and bottom side.
+/// RegularPolygon::new(Point::new(20, 20), 15, 6, 0.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Pentagon which points upwards.
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(2)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// RegularPolygon::new(Point::new(45, 45), 12, 5, (-90.0).deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Arc`]: ../arc/struct.Arc.html
+/// [`Sector`]: ../sector/struct.Sector.html
+/// [`MAX_SIDES`]: #associatedconstant.MAX_SIDES
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct RegularPolygon {
+    /// Center point
+    pub center: Point,
+
+    /// Radius of the circle through all vertices
+    pub circumradius: u32,
+
+    /// Number of sides
+    pub sides: u32,
+
+    /// Angle of the first vertex
+    pub rotation: Angle,
+}
+
+impl RegularPolygon {
+    /// The maximum number of sides.
+    pub const MAX_SIDES: u32 = 32;
+
+    /// Creates a new regular polygon.
+    pub const fn new(center: Point, circumradius: u32, sides: u32, rotation: Angle) -> Self {
+        Self {
+            center,
+            circumradius,
+            sides,
+            rotation,
+        }
+    }
+
+    /// Returns an iterator over the vertices of the polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::RegularPolygon};
+    ///
+    /// let square = RegularPolygon::new(Point::new(10, 10), 5, 4, 0.0.deg());
+    ///
+    /// let mut vertices = square.vertices();
+    /// assert_eq!(vertices.next(), Some(Point::new(15, 10)));
+    /// assert_eq!(vertices.next(), Some(Point::new(10, 15)));
+    /// assert_eq!(vertices.next(), Some(Point::new(5, 10)));
+    /// assert_eq!(vertices.next(), Some(Point::new(10, 5)));
+    /// assert_eq!(vertices.next(), None);
+    /// ```
+    pub fn vertices(&self) -> Vertices {
+        Vertices {
+            regular_polygon: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of sides clamped to the supported range.
+    fn clamped_sides(&self) -> u32 {
+        self.sides.max(3).min(Self::MAX_SIDES)
+    }
+
+    /// Returns the vertex with the given index.
+    fn vertex(&self, index: u32) -> Point {
+        let angle =
+            self.rotation + Angle::from_degrees(360.0 * index as f32 / self.clamped_sides() as f32);
+        let radius = Real::from(self.circumradius);
+
+        self.center
+            + Point::new(
+                i32::from((radius * angle.cos()).round()),
+                i32::from((radius * angle.sin()).round()),
+            )
+    }
+}
+
+impl Primitive for RegularPolygon {}
+
+impl PointsIter for RegularPolygon {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for RegularPolygon {
+    fn contains(&self, point: Point) -> bool {
+        VertexBuffer::new(self).polygon().contains(point)
+    }
+}
+
+impl Dimensions for RegularPolygon {
+    fn bounding_box(&self) -> Rectangle {
+        VertexBuffer::new(self).polygon().bounding_box()
+    }
+}
+
+impl Transform for RegularPolygon {
+    /// Translate the regular polygon from its current position to a new position by (x, y)
+    /// pixels, returning a new `RegularPolygon`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RegularPolygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let hexagon = RegularPolygon::new(Point::new(10, 10), 5, 6, 0.0.deg());
+    /// let moved = hexagon.translate(Point::new(10, 20));
+    ///
+    /// assert_eq!(moved.center, Point::new(20, 30));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the regular polygon from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RegularPolygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut hexagon = RegularPolygon::new(Point::new(10, 10), 5, 6, 0.0.deg());
+    /// hexagon.translate_mut(Point::new(10, 20));
+    ///
+    /// assert_eq!(hexagon.center, Point::new(20, 30));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+impl Rotate for RegularPolygon {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self {
+            center: rotate_point_90(self.center, center),
+            rotation: (self.rotation + ANGLE_90DEG).normalize(),
+            ..*self
+        }
+    }
+}
+
+impl Mirror for RegularPolygon {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_x(self.center, axis),
+            rotation: (ANGLE_180DEG - self.rotation).normalize(),
+            ..*self
+        }
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_y(self.center, axis),
+            rotation: (-self.rotation).normalize(),
+            ..*self
+        }
+    }
+}
+
+impl Scale for RegularPolygon {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self {
+            center: scale_point(self.center, center, num, denom),
+            circumradius: scale_length(self.circumradius, num, denom),
+            ..*self
+        }
+    }
+}
+
+/// Iterator over the vertices of a regular polygon.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Vertices {
+    regular_polygon: RegularPolygon,
+    index: u32,
+}
+
+impl Iterator for Vertices {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.regular_polygon.clamped_sides() {
+            return None;
+        }
+
+        let vertex = self.regular_polygon.vertex(self.index);
+        self.index += 1;
+
+        Some(vertex)
+    }
+}
+
+/// Vertex storage, which is used to draw regular polygons using the polygon implementation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct VertexBuffer {
+    vertices: [Point; RegularPolygon::MAX_SIDES as usize],
+    len: usize,
+}
+
+impl VertexBuffer {
+    fn new(regular_polygon: &RegularPolygon) -> Self {
+        let mut vertices = [Point::zero(); RegularPolygon::MAX_SIDES as usize];
+
+        let mut len = 0;
+        for (vertex, target) in regular_polygon.vertices().zip(vertices.iter_mut()) {
+            *target = vertex;
+            len += 1;
+        }
+
+        Self { vertices, len }
+    }
+
+    fn as_slice(&self) -> &[Point] {
+        &self.vertices[0..self.len]
+    }
+
+    fn polygon(&self) -> Polygon<'_> {
+        Polygon::new(self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    #[test]
+    fn clamped_sides() {
+        let center = Point::new(10, 10);
+
+        assert_eq!(
+            RegularPolygon::new(center, 5, 0, 0.0.deg())
+                .vertices()
+                .count(),
+            3
+        );
+        assert_eq!(
+            RegularPolygon::new(center, 5, 100, 0.0.deg())
+                .vertices()
+                .count(),
+            RegularPolygon::MAX_SIDES as usize
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let triangle = RegularPolygon::new(Point::new(10, 10), 8, 3, (-90.0).deg());
+
+        let mut vertices = triangle.vertices();
+        assert_eq!(vertices.next(), Some(Point::new(10, 2)));
+        assert_eq!(vertices.next(), Some(Point::new(17, 14)));
+        assert_eq!(vertices.next(), Some(Point::new(3, 14)));
+        assert_eq!(vertices.next(), None);
+    }
+
+    #[test]
+    fn dimensions() {
+        let square = RegularPolygon::new(Point::new(10, 20), 5, 4, 0.0.deg());
+
+        assert_eq!(
+            square.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(11, 11))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let hexagon = RegularPolygon::new(Point::new(15, 15), 12, 6, 10.0.deg());
+
+        let expected = MockDisplay::from_points(hexagon.points(), BinaryColor::On);
+
+        for point in Rectangle::new(Point::zero(), Size::new(32, 32)).points() {
+            assert_eq!(
+                hexagon.contains(point),
+                expected.get_pixel(point).is_some(),
+                "{:?}",
+                point
+            );
+        }
+    }
+}
@@ -5,7 +5,7 @@ use core::ops::Range;
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{rectangle::Rectangle, ContainsPoint, OffsetOutline, PointsIter, Primitive},
-    transform::Transform,
+    transform::{scale_length, Mirror, Rotate, Scale, Transform},
 };
 
 mod corner_radii;
@@ -336,6 +336,87 @@ impl Transform for RoundedRectangle {
     }
 }
 
+impl Rotate for RoundedRectangle {
+    /// Rotate the rounded rectangle by 90° clockwise around `center`.
+    ///
+    /// The corner radii are moved to the rotated corners and their width and height are swapped.
+    ///
+    /// ```
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::{CornerRadiiBuilder, Rectangle, RoundedRectangle};
+    ///
+    /// let original = RoundedRectangle::new(
+    ///     Rectangle::new(Point::new(0, 0), Size::new(20, 10)),
+    ///     CornerRadiiBuilder::new().top_left(Size::new(6, 4)).build(),
+    /// );
+    /// let rotated = original.rotate_90(Point::new(0, 0));
+    ///
+    /// assert_eq!(rotated.rectangle, Rectangle::new(Point::new(-9, 0), Size::new(10, 20)));
+    /// assert_eq!(rotated.corners.top_right, Size::new(4, 6));
+    /// assert_eq!(rotated.corners.top_left, Size::zero());
+    /// ```
+    fn rotate_90(&self, center: Point) -> Self {
+        let transpose = |size: Size| Size::new(size.height, size.width);
+
+        Self::new(
+            self.rectangle.rotate_90(center),
+            CornerRadii {
+                top_left: transpose(self.corners.bottom_left),
+                top_right: transpose(self.corners.top_left),
+                bottom_right: transpose(self.corners.top_right),
+                bottom_left: transpose(self.corners.bottom_right),
+            },
+        )
+    }
+}
+
+impl Mirror for RoundedRectangle {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(
+            self.rectangle.mirror_x(axis),
+            CornerRadii {
+                top_left: self.corners.top_right,
+                top_right: self.corners.top_left,
+                bottom_right: self.corners.bottom_left,
+                bottom_left: self.corners.bottom_right,
+            },
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(
+            self.rectangle.mirror_y(axis),
+            CornerRadii {
+                top_left: self.corners.bottom_left,
+                top_right: self.corners.bottom_right,
+                bottom_right: self.corners.top_right,
+                bottom_left: self.corners.top_left,
+            },
+        )
+    }
+}
+
+impl Scale for RoundedRectangle {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let scale = |size: Size| {
+            Size::new(
+                scale_length(size.width, num, denom),
+                scale_length(size.height, num, denom),
+            )
+        };
+
+        Self::new(
+            self.rectangle.scale_around(center, num, denom),
+            CornerRadii {
+                top_left: scale(self.corners.top_left),
+                top_right: scale(self.corners.top_right),
+                bottom_right: scale(self.corners.bottom_right),
+                bottom_left: scale(self.corners.bottom_left),
+            },
+        )
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub(in crate::primitives) struct RoundedRectangleContains {
     /// Bounding box rows.
@@ -6,7 +6,7 @@ use crate::{
         common::{Scanline, StyledScanline},
         rounded_rectangle::{points::Scanlines, RoundedRectangle},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle, Rectangle,
+        FillStyle, PrimitiveStyle, Rectangle,
     },
     Pixel, SaturatingCast,
 };
@@ -24,6 +24,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -40,7 +42,9 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             fill: Scanline::new_empty(0),
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
-            fill_color: style.fill_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
         }
     }
 }
@@ -69,7 +73,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                     .stroke_left
                     .next()
                     .map(|p| Pixel(p, stroke_color))
-                    .or_else(|| self.fill.next().map(|p| Pixel(p, fill_color)))
+                    .or_else(|| {
+                        self.fill
+                            .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                    })
                     .or_else(|| self.stroke_right.next().map(|p| Pixel(p, stroke_color)))
                 {
                     return Some(pixel);
@@ -81,7 +88,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                 self.stroke_right = scanline.stroke_right();
             },
             (None, Some(fill_color)) => loop {
-                if let Some(pixel) = self.fill.next().map(|p| Pixel(p, fill_color)) {
+                if let Some(pixel) =
+                    self.fill
+                        .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                {
                     return Some(pixel);
                 }
 
@@ -113,7 +123,12 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RoundedRectangle {
     where
         D: DrawTarget<Color = C>,
     {
-        match (style.effective_stroke_color(), style.fill_color) {
+        // Patterned fills can't be drawn using scanlines
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        match (style.effective_stroke_color(), style.effective_fill_color()) {
             (Some(stroke_color), None) => {
                 for scanline in
                     StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
@@ -199,7 +214,8 @@ mod tests {
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
         primitives::{
-            rectangle::Rectangle, CornerRadii, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            rectangle::Rectangle, CornerRadii, OffsetOutline, Primitive, PrimitiveStyleBuilder,
+            StrokeAlignment,
         },
         Drawable,
     };
@@ -394,6 +410,47 @@ mod tests {
         pixels.assert_pattern(expected_pattern);
     }
 
+    #[test]
+    fn stroke_alignment() {
+        let rounded_rect = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(5, 5), Size::new(20, 15)),
+            Size::new(8, 6),
+        );
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+
+        let mut display_center = MockDisplay::new();
+        rounded_rect
+            .into_styled(style)
+            .draw(&mut display_center)
+            .unwrap();
+
+        let mut display_inside = MockDisplay::new();
+        rounded_rect
+            .offset(1)
+            .into_styled(
+                PrimitiveStyleBuilder::from(&style)
+                    .stroke_alignment(StrokeAlignment::Inside)
+                    .build(),
+            )
+            .draw(&mut display_inside)
+            .unwrap();
+
+        let mut display_outside = MockDisplay::new();
+        rounded_rect
+            .offset(-2)
+            .into_styled(
+                PrimitiveStyleBuilder::from(&style)
+                    .stroke_alignment(StrokeAlignment::Outside)
+                    .build(),
+            )
+            .draw(&mut display_outside)
+            .unwrap();
+
+        display_inside.assert_eq(&display_center);
+        display_outside.assert_eq(&display_center);
+    }
+
     #[test]
     fn styled_dimensions() {
         let base = PrimitiveStyleBuilder::new()
@@ -0,0 +1,521 @@
+//! The rounded triangle primitive.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::{div_round, isqrt, Dimensions, Point, Size},
+    primitives::{
+        common::Scanline, ContainsPoint, Line, PointsIter, Primitive, Rectangle, Triangle,
+    },
+    transform::{scale_length, Mirror, Rotate, Scale, Transform},
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Rounded triangle primitive.
+///
+/// Creates a triangle with rounded vertices. All vertices are rounded with the same circular
+/// radius, which is tangent to the two edges that meet at each vertex. The straight parts of the
+/// edges lie on the edges of the base triangle.
+///
+/// # Overlapping corners
+///
+/// The corner radius can't be larger than the radius of the largest circle that fits inside the
+/// base triangle. Larger radii are confined to this value before use by other parts of
+/// embedded-graphics, which turns the rounded triangle into a circle.
+///
+/// # Examples
+///
+/// ## Create a play button
+///
+/// This example draws a play button with a 3px corner radius, using a solid green fill with a 2px
+/// white stroke.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyleBuilder, RoundedTriangle, Triangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_width(2)
+///     .stroke_color(Rgb565::WHITE)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// RoundedTriangle::new(
+///     Triangle::new(Point::new(10, 5), Point::new(50, 30), Point::new(10, 55)),
+///     3,
+/// )
+/// .into_styled(style)
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RoundedTriangle {
+    /// The base triangle
+    pub triangle: Triangle,
+
+    /// The radius of each corner
+    pub radius: u32,
+}
+
+impl RoundedTriangle {
+    /// Creates a new rounded triangle with the given corner radius.
+    ///
+    /// The position of the rounded triangle is determined by the given base triangle.
+    pub const fn new(triangle: Triangle, radius: u32) -> Self {
+        Self { triangle, radius }
+    }
+
+    /// Returns the rounded triangle with a confined corner radius.
+    ///
+    /// This method will return a rounded triangle with the same base triangle, but with the corner
+    /// radius confined to fit within the base triangle.
+    ///
+    /// Calling this method is not necessary when using operations provided by embedded-graphics
+    /// (`.into_styled()`, `.contains()`, etc) as these confine the corner radius internally.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     geometry::Point,
+    ///     primitives::{RoundedTriangle, Triangle},
+    /// };
+    ///
+    /// let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 40));
+    ///
+    /// let rounded_triangle = RoundedTriangle::new(triangle, 20);
+    ///
+    /// assert_eq!(rounded_triangle.confine_radius().radius, 10);
+    /// ```
+    pub fn confine_radius(&self) -> Self {
+        let [p1, p2, p3] = self.triangle.vertices;
+
+        let perimeter_scaled =
+            scaled_length(p2 - p1) + scaled_length(p3 - p2) + scaled_length(p1 - p3);
+
+        // The radius of the incircle is equal to the area divided by half the perimeter.
+        let inradius = if perimeter_scaled > 0 {
+            self.triangle.area_doubled().abs() * i64::from(SCALE) / perimeter_scaled
+        } else {
+            0
+        };
+
+        Self::new(self.triangle, self.radius.min(inradius as u32))
+    }
+}
+
+impl Primitive for RoundedTriangle {}
+
+impl PointsIter for RoundedTriangle {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for RoundedTriangle {
+    fn contains(&self, point: Point) -> bool {
+        RoundedTriangleContains::new(self, 0).contains(point)
+    }
+}
+
+impl Dimensions for RoundedTriangle {
+    fn bounding_box(&self) -> Rectangle {
+        RoundedTriangleContains::new(self, 0).bounding_box()
+    }
+}
+
+impl Transform for RoundedTriangle {
+    /// Translate the rounded triangle from its current position to a new position by (x, y)
+    /// pixels, returning a new `RoundedTriangle`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{RoundedTriangle, Triangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let triangle = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(8, 15));
+    /// let rounded_triangle = RoundedTriangle::new(triangle, 2);
+    /// let moved = rounded_triangle.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.triangle, triangle.translate(Point::new(10, 10)));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            triangle: self.triangle.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the rounded triangle from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{RoundedTriangle, Triangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let triangle = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(8, 15));
+    /// let mut rounded_triangle = RoundedTriangle::new(triangle, 2);
+    /// rounded_triangle.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(rounded_triangle.triangle, triangle.translate(Point::new(10, 10)));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.triangle.translate_mut(by);
+
+        self
+    }
+}
+
+impl Rotate for RoundedTriangle {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::new(self.triangle.rotate_90(center), self.radius)
+    }
+}
+
+impl Mirror for RoundedTriangle {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(self.triangle.mirror_x(axis), self.radius)
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(self.triangle.mirror_y(axis), self.radius)
+    }
+}
+
+impl Scale for RoundedTriangle {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::new(
+            self.triangle.scale_around(center, num, denom),
+            scale_length(self.radius, num, denom),
+        )
+    }
+}
+
+/// Scaling factor for the subpixel accurate corner centers.
+const SCALE: i32 = 16;
+
+/// Returns the length of a vector, rounded down.
+fn length(vector: Point) -> i64 {
+    let length_squared = i64::from(vector.x).pow(2) + i64::from(vector.y).pow(2);
+
+    isqrt(length_squared as u64) as i64
+}
+
+/// Returns the length of a vector, scaled by `SCALE`.
+fn scaled_length(vector: Point) -> i64 {
+    length(vector * SCALE)
+}
+
+/// Point containment test for rounded triangles.
+///
+/// A rounded triangle contains all points which are at most `radius` away from the inner triangle,
+/// which is formed by the centers of the corner circles. To support thick strokes the outline can
+/// be offset by a positive or negative value. Offsets that are smaller than `-radius` result in a
+/// triangle with sharp corners, which is offset from the inner triangle.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(in crate::primitives) struct RoundedTriangleContains {
+    /// Vertices of the inner triangle, scaled by `SCALE`.
+    inner: [Point; 3],
+
+    /// Lengths of the inner triangle edges, scaled by `SCALE`.
+    edge_lengths: [i64; 3],
+
+    /// Sign of the inner triangle area.
+    ///
+    /// The inner triangle collapses into a single point if the radius is equal to the incircle
+    /// radius of the base triangle.
+    orientation: i64,
+
+    /// Signed distance between the outline and the inner triangle, scaled by `SCALE`.
+    distance: i64,
+
+    /// Rows which can contain points.
+    rows: Range<i32>,
+
+    /// Columns which can contain points.
+    columns: Range<i32>,
+}
+
+impl RoundedTriangleContains {
+    pub fn new(rounded_triangle: &RoundedTriangle, offset: i32) -> Self {
+        let RoundedTriangle { triangle, radius } = rounded_triangle.confine_radius();
+
+        let inner = inner_triangle(&triangle, radius);
+
+        let [p1, p2, p3] = inner;
+        let edge_lengths = [length(p2 - p1), length(p3 - p2), length(p1 - p3)];
+
+        let bounding_box = triangle.bounding_box().offset(offset.max(0));
+
+        Self {
+            inner,
+            edge_lengths,
+            orientation: Triangle::new(p1, p2, p3).area_doubled().signum(),
+            // Points are sampled at the pixel centers. Extending the outline by half a pixel makes
+            // the corners match the shape of a `Circle` with the same radius.
+            distance: (i64::from(radius) + i64::from(offset)) * i64::from(SCALE)
+                + i64::from(SCALE / 2),
+            rows: bounding_box.rows(),
+            columns: bounding_box.columns(),
+        }
+    }
+
+    /// Returns the signed distance between the point and the edge line, scaled by the edge length.
+    ///
+    /// Positive values are returned for points on the inside of the edge.
+    fn edge_distance(&self, index: usize, point: Point) -> i64 {
+        let start = self.inner[index];
+        let end = self.inner[(index + 1) % 3];
+
+        let delta = end - start;
+        let offset = point - start;
+
+        (i64::from(delta.x) * i64::from(offset.y) - i64::from(delta.y) * i64::from(offset.x))
+            * self.orientation
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        if !(self.rows.contains(&point.y) && self.columns.contains(&point.x)) {
+            return false;
+        }
+
+        let point = point * SCALE;
+
+        if self.distance < 0 {
+            // The outline is offset past the corner circle centers, which results in sharp corners.
+            return self.orientation != 0
+                && (0..3).all(|i| {
+                    self.edge_distance(i, point) >= -self.distance * self.edge_lengths[i]
+                });
+        }
+
+        if self.orientation != 0 && (0..3).all(|i| self.edge_distance(i, point) >= 0) {
+            return true;
+        }
+
+        (0..3).any(|i| {
+            let edge = Line::new(self.inner[i], self.inner[(i + 1) % 3]);
+            let delta = point - edge.closest_point(point);
+
+            i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2) <= self.distance.pow(2)
+        })
+    }
+
+    /// Returns the scanline at the given y coordinate.
+    pub fn scanline(&self, y: i32) -> Scanline {
+        let start = self
+            .columns
+            .clone()
+            .find(|x| self.contains(Point::new(*x, y)));
+        let end = self
+            .columns
+            .clone()
+            .rfind(|x| self.contains(Point::new(*x, y)));
+
+        match (start, end) {
+            (Some(start), Some(end)) => Scanline::new(y, start..end + 1),
+            _ => Scanline::new_empty(y),
+        }
+    }
+
+    pub fn bounding_box(&self) -> Rectangle {
+        // PERF: The bounding box could be calculated from the corner circles instead.
+        let (min, max) = self.rows.clone().map(|y| self.scanline(y)).fold(
+            (
+                Point::new_equal(core::i32::MAX),
+                Point::new_equal(core::i32::MIN),
+            ),
+            |(min, max), scanline| {
+                if scanline.is_empty() {
+                    (min, max)
+                } else {
+                    (
+                        min.component_min(Point::new(scanline.x.start, scanline.y)),
+                        max.component_max(Point::new(scanline.x.end - 1, scanline.y)),
+                    )
+                }
+            },
+        );
+
+        if min.x > max.x {
+            Rectangle::new(
+                Point::new(self.columns.start, self.rows.start),
+                Size::zero(),
+            )
+        } else {
+            Rectangle::with_corners(min, max)
+        }
+    }
+}
+
+/// Returns the triangle formed by the centers of the corner circles, scaled by `SCALE`.
+///
+/// The radius must be confined to the incircle radius of the triangle.
+fn inner_triangle(triangle: &Triangle, radius: u32) -> [Point; 3] {
+    let [p1, p2, p3] = triangle.vertices;
+
+    if radius == 0 || triangle.area_doubled() == 0 {
+        return [p1 * SCALE, p2 * SCALE, p3 * SCALE];
+    }
+
+    [
+        corner_center(p1, p2, p3, radius),
+        corner_center(p2, p3, p1, radius),
+        corner_center(p3, p1, p2, radius),
+    ]
+}
+
+/// Returns the center of the circle that touches both edges that meet at `vertex`.
+fn corner_center(vertex: Point, a: Point, b: Point, radius: u32) -> Point {
+    // Normal vectors of both edges, pointing to the inside of the triangle.
+    let normal = |edge: Point, other: Point| {
+        let normal = Point::new(-edge.y, edge.x);
+
+        if i64::from(normal.x) * i64::from(other.x) + i64::from(normal.y) * i64::from(other.y) < 0 {
+            -normal
+        } else {
+            normal
+        }
+    };
+
+    let n1 = normal(a - vertex, b - vertex);
+    let n2 = normal(b - vertex, a - vertex);
+
+    // The center is offset by `radius` from both edges: `n.dot(center - vertex) = radius * |n|`
+    let d1 = i64::from(radius) * scaled_length(n1);
+    let d2 = i64::from(radius) * scaled_length(n2);
+
+    let (n1x, n1y) = (i64::from(n1.x), i64::from(n1.y));
+    let (n2x, n2y) = (i64::from(n2.x), i64::from(n2.y));
+
+    let determinant = n1x * n2y - n1y * n2x;
+
+    let x = div_round(d1 * n2y - d2 * n1y, determinant);
+    let y = div_round(n1x * d2 - n2x * d1, determinant);
+
+    vertex * SCALE + Point::new(x as i32, y as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn confine_radius() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 40));
+
+        assert_eq!(RoundedTriangle::new(triangle, 5).confine_radius().radius, 5);
+        assert_eq!(
+            RoundedTriangle::new(triangle, 10).confine_radius().radius,
+            10
+        );
+        assert_eq!(
+            RoundedTriangle::new(triangle, 11).confine_radius().radius,
+            10
+        );
+
+        let colinear = Triangle::new(Point::new(0, 0), Point::new(10, 10), Point::new(20, 20));
+        assert_eq!(RoundedTriangle::new(colinear, 5).confine_radius().radius, 0);
+    }
+
+    #[test]
+    fn zero_radius() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 30));
+
+        let mut expected = MockDisplay::new();
+        triangle
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        RoundedTriangle::new(triangle, 0)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn corners_are_rounded() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 30));
+        let rounded_triangle = RoundedTriangle::new(triangle, 5);
+
+        assert!(triangle.contains(Point::new(0, 0)));
+        assert!(!rounded_triangle.contains(Point::new(0, 0)));
+        assert!(!rounded_triangle.contains(Point::new(1, 1)));
+        assert!(rounded_triangle.contains(Point::new(2, 2)));
+
+        // The straight parts of the edges lie on the base triangle.
+        assert!(rounded_triangle.contains(Point::new(15, 0)));
+        assert!(rounded_triangle.contains(Point::new(0, 15)));
+    }
+
+    #[test]
+    fn large_radius_is_incircle() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 40));
+
+        let mut expected = MockDisplay::new();
+        Circle::with_center(Point::new(10, 10), 21)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        RoundedTriangle::new(triangle, 100)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(30, 0), Point::new(0, 30));
+
+        assert_eq!(
+            RoundedTriangle::new(triangle, 0).bounding_box(),
+            triangle.bounding_box()
+        );
+
+        let rounded_triangle = RoundedTriangle::new(triangle, 5);
+        let display = MockDisplay::from_points(rounded_triangle.points(), BinaryColor::On);
+        assert_eq!(rounded_triangle.bounding_box(), display.affected_area());
+    }
+
+    #[test]
+    fn points_equals_contains() {
+        let rounded_triangle = RoundedTriangle::new(
+            Triangle::new(Point::new(5, 3), Point::new(50, 20), Point::new(20, 60)),
+            6,
+        );
+
+        let display = MockDisplay::from_points(rounded_triangle.points(), BinaryColor::On);
+
+        for point in Rectangle::new(Point::zero(), Size::new(64, 64)).points() {
+            assert_eq!(
+                rounded_triangle.contains(point),
+                display.get_pixel(point).is_some(),
+                "{:?}",
+                point
+            );
+        }
+    }
+}
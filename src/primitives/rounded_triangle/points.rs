@@ -0,0 +1,97 @@
+use core::ops::Range;
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::Scanline,
+        rounded_triangle::{RoundedTriangle, RoundedTriangleContains},
+    },
+};
+
+/// Iterator over all points inside the rounded triangle.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Points {
+    scanlines: Scanlines,
+    current_scanline: Scanline,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(rounded_triangle: &RoundedTriangle) -> Self {
+        Self {
+            scanlines: Scanlines::new(RoundedTriangleContains::new(rounded_triangle, 0)),
+            current_scanline: Scanline::new_empty(0),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.current_scanline.next() {
+                return Some(point);
+            }
+
+            // Scanlines can be empty in rows close to the sharp vertices of the base triangle.
+            self.current_scanline = self.scanlines.next()?;
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Scanlines {
+    rows: Range<i32>,
+    rounded_triangle: RoundedTriangleContains,
+}
+
+impl Scanlines {
+    pub fn new(rounded_triangle: RoundedTriangleContains) -> Self {
+        Self {
+            rows: rounded_triangle.rows.clone(),
+            rounded_triangle,
+        }
+    }
+}
+
+impl Iterator for Scanlines {
+    type Item = Scanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.rows.next()?;
+
+        Some(self.rounded_triangle.scanline(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Dimensions,
+        primitives::{ContainsPoint, PointsIter, Triangle},
+    };
+
+    #[test]
+    fn points_are_inside() {
+        let rounded_triangle = RoundedTriangle::new(
+            Triangle::new(Point::new(10, 2), Point::new(40, 30), Point::new(3, 25)),
+            4,
+        );
+
+        let mut count = 0;
+        for point in rounded_triangle.points() {
+            assert!(rounded_triangle.contains(point), "{:?}", point);
+            count += 1;
+        }
+
+        assert_eq!(
+            count,
+            rounded_triangle
+                .bounding_box()
+                .points()
+                .filter(|p| rounded_triangle.contains(*p))
+                .count()
+        );
+    }
+}
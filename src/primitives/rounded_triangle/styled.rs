@@ -0,0 +1,326 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{Scanline, StyledScanline},
+        rounded_triangle::{points::Scanlines, RoundedTriangle, RoundedTriangleContains},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        FillStyle, PrimitiveStyle, Rectangle,
+    },
+    Pixel, SaturatingCast,
+};
+
+/// Pixel iterator for each pixel in the rounded triangle
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StyledPixelsIterator<C> {
+    styled_scanlines: StyledScanlines,
+
+    stroke_left: Scanline,
+    fill: Scanline,
+    stroke_right: Scanline,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    pub(in crate::primitives) fn new(
+        primitive: &RoundedTriangle,
+        style: &PrimitiveStyle<C>,
+    ) -> Self {
+        Self {
+            styled_scanlines: StyledScanlines::new(primitive, style),
+            stroke_left: Scanline::new_empty(0),
+            fill: Scanline::new_empty(0),
+            stroke_right: Scanline::new_empty(0),
+            stroke_color: style.effective_stroke_color(),
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.stroke_color, self.fill_color) {
+            (Some(stroke_color), None) => loop {
+                if let Some(pixel) = self
+                    .stroke_left
+                    .next()
+                    .or_else(|| self.stroke_right.next())
+                    .map(|p| Pixel(p, stroke_color))
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.stroke_left = scanline.stroke_left();
+                self.stroke_right = scanline.stroke_right();
+            },
+            (Some(stroke_color), Some(fill_color)) => loop {
+                if let Some(pixel) = self
+                    .stroke_left
+                    .next()
+                    .map(|p| Pixel(p, stroke_color))
+                    .or_else(|| {
+                        self.fill
+                            .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                    })
+                    .or_else(|| self.stroke_right.next().map(|p| Pixel(p, stroke_color)))
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.stroke_left = scanline.stroke_left();
+                self.fill = scanline.fill();
+                self.stroke_right = scanline.stroke_right();
+            },
+            (None, Some(fill_color)) => loop {
+                if let Some(pixel) =
+                    self.fill
+                        .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.fill = scanline.fill();
+            },
+            (None, None) => None,
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for RoundedTriangle {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RoundedTriangle {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Patterned fills can't be drawn using scanlines
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        match (style.effective_stroke_color(), style.effective_fill_color()) {
+            (Some(stroke_color), None) => {
+                for scanline in StyledScanlines::new(self, style) {
+                    scanline.draw_stroke(target, stroke_color)?;
+                }
+            }
+            (Some(stroke_color), Some(fill_color)) => {
+                for scanline in StyledScanlines::new(self, style) {
+                    scanline.draw_stroke_and_fill(target, stroke_color, fill_color)?;
+                }
+            }
+            (None, Some(fill_color)) => {
+                let offset = style.inside_stroke_width().saturating_cast_neg();
+
+                for scanline in Scanlines::new(RoundedTriangleContains::new(self, offset)) {
+                    scanline.draw(target, fill_color)?;
+                }
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for RoundedTriangle {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset = style.outside_stroke_width().saturating_cast();
+
+        RoundedTriangleContains::new(self, offset).bounding_box()
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct StyledScanlines {
+    scanlines: Scanlines,
+    fill_area: RoundedTriangleContains,
+}
+
+impl StyledScanlines {
+    pub fn new<C: PixelColor>(primitive: &RoundedTriangle, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_offset = style.outside_stroke_width().saturating_cast();
+        let fill_offset = style.inside_stroke_width().saturating_cast_neg();
+
+        Self {
+            scanlines: Scanlines::new(RoundedTriangleContains::new(primitive, stroke_offset)),
+            fill_area: RoundedTriangleContains::new(primitive, fill_offset),
+        }
+    }
+}
+
+impl Iterator for StyledScanlines {
+    type Item = StyledScanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanlines.next().map(|scanline| {
+            let fill = self.fill_area.scanline(scanline.y);
+
+            if fill.is_empty() {
+                StyledScanline::new(scanline.y, scanline.x, None)
+            } else {
+                StyledScanline::new(scanline.y, scanline.x, Some(fill.x))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{Dimensions, Point},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyleBuilder, StrokeAlignment, Triangle},
+        Drawable,
+    };
+
+    fn test_rounded_triangle(
+        rounded_triangle: RoundedTriangle,
+        style: PrimitiveStyle<BinaryColor>,
+        pattern: &[&str],
+    ) {
+        let styled = rounded_triangle.into_styled(style);
+
+        let mut drawable = MockDisplay::new();
+        styled.draw(&mut drawable).unwrap();
+        drawable.assert_pattern(pattern);
+
+        let mut pixels = MockDisplay::new();
+        styled.pixels().draw(&mut pixels).unwrap();
+        pixels.assert_pattern(pattern);
+
+        assert_eq!(styled.bounding_box(), drawable.affected_area());
+    }
+
+    #[test]
+    fn filled() {
+        test_rounded_triangle(
+            RoundedTriangle::new(
+                Triangle::new(Point::new(0, 0), Point::new(12, 6), Point::new(0, 12)),
+                2,
+            ),
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            &[
+                "            ",
+                " ###        ",
+                "######      ",
+                "########    ",
+                "##########  ",
+                "##########  ",
+                "########### ",
+                "##########  ",
+                "##########  ",
+                "########    ",
+                "######      ",
+                " ###        ",
+            ],
+        );
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        test_rounded_triangle(
+            RoundedTriangle::new(
+                Triangle::new(Point::new(1, 1), Point::new(13, 7), Point::new(1, 13)),
+                3,
+            ),
+            style,
+            &[
+                "           ",
+                "           ",
+                "  #####    ",
+                " ########  ",
+                "###...#### ",
+                "##......###",
+                "##.......##",
+                "##.......##",
+                "##.......##",
+                "##......###",
+                "###...#### ",
+                " ########  ",
+                "  #####    ",
+            ],
+        );
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        let rounded_triangle = RoundedTriangle::new(
+            Triangle::new(Point::new(15, 10), Point::new(50, 30), Point::new(12, 50)),
+            4,
+        );
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 0..10 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(*alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                let styled = rounded_triangle.into_styled(style);
+
+                let mut drawable = MockDisplay::new();
+                styled.draw(&mut drawable).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&drawable);
+                assert_eq!(styled.bounding_box(), drawable.affected_area());
+            }
+        }
+    }
+
+    #[test]
+    fn transparent() {
+        let rounded_triangle = RoundedTriangle::new(
+            Triangle::new(Point::new(1, 1), Point::new(13, 7), Point::new(1, 13)),
+            3,
+        );
+        let style = PrimitiveStyle::<BinaryColor>::with_stroke(BinaryColor::On, 0);
+
+        assert_eq!(rounded_triangle.into_styled(style).pixels().next(), None);
+    }
+}
@@ -1,11 +1,14 @@
 //! The sector primitive
 
 use crate::{
-    geometry::{Angle, Dimensions, Point, Size},
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_90DEG},
+        Angle, Dimensions, Point, Size,
+    },
     primitives::{
         common::PlaneSector, Circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{Mirror, Rotate, Scale, Transform},
 };
 
 mod points;
@@ -53,6 +56,37 @@ pub use styled::StyledPixelsIterator;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// ## Draw a pie chart
+///
+/// Neighboring sectors share the pixels along their common edge. The color of these pixels is
+/// determined by the sector that is drawn last.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Sector},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+///
+/// let values = [(50.0, Rgb565::RED), (30.0, Rgb565::GREEN), (20.0, Rgb565::BLUE)];
+/// let total: f32 = values.iter().map(|(value, _)| value).sum();
+///
+/// let mut angle_start = 90.0;
+/// for (value, color) in values.iter() {
+///     let angle_sweep = -360.0 * value / total;
+///
+///     Sector::with_center(Point::new(32, 32), 50, angle_start.deg(), angle_sweep.deg())
+///         .into_styled(PrimitiveStyle::with_fill(*color))
+///         .draw(&mut display)?;
+///
+///     angle_start += angle_sweep;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 pub struct Sector {
     /// Top-left point of the bounding-box of the circle supporting the sector
@@ -206,6 +240,44 @@ impl Transform for Sector {
     }
 }
 
+impl Rotate for Sector {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self::from_circle(
+            self.to_circle().rotate_90(center),
+            (self.angle_start - ANGLE_90DEG).normalize(),
+            self.angle_sweep,
+        )
+    }
+}
+
+impl Mirror for Sector {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_x(axis),
+            (ANGLE_180DEG - self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::from_circle(
+            self.to_circle().mirror_y(axis),
+            (-self.angle_start).normalize(),
+            -self.angle_sweep,
+        )
+    }
+}
+
+impl Scale for Sector {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self::from_circle(
+            self.to_circle().scale_around(center, num, denom),
+            self.angle_start,
+            self.angle_sweep,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
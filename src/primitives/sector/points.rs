@@ -59,7 +59,6 @@ mod tests {
         let sector = Sector::with_center(Point::new(10, 10), 5, 0.0.deg(), 90.0.deg());
 
         let styled_points = sector
-            .clone()
             .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
             .pixels()
             .map(|Pixel(p, _)| p);
@@ -1,14 +1,15 @@
 use crate::{
     draw_target::DrawTarget,
     geometry::angle_consts::ANGLE_90DEG,
-    geometry::{Angle, Dimensions},
+    geometry::{Angle, Dimensions, Size},
+    iterator::PixelIteratorExt,
     pixelcolor::PixelColor,
     primitives::{
         common::{
             DistanceIterator, LineSide, LinearEquation, PlaneSector, PointType, NORMAL_VECTOR_SCALE,
         },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle, Rectangle, Sector,
+        FillStyle, PrimitiveStyle, Rectangle, Sector,
     },
     Pixel, SaturatingCast,
 };
@@ -30,6 +31,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -94,7 +97,9 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             stroke_threshold_outside,
             bevel,
             stroke_color: style.stroke_color,
-            fill_color: style.fill_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
         }
     }
 }
@@ -139,7 +144,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
 
             let color = match point_type {
                 PointType::Stroke => self.stroke_color,
-                PointType::Fill => self.fill_color,
+                PointType::Fill => self.fill_color.and_then(|fill_color| {
+                    self.fill_style
+                        .color_at(point, fill_color, &self.bounding_box)
+                }),
             };
 
             if let Some(color) = color {
@@ -174,11 +182,16 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Sector {
 }
 
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Sector {
-    // FIXME: This doesn't take into account start/end angles. This should be fixed to close #405.
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let offset = style.outside_stroke_width().saturating_cast();
+        // The bounding box is determined by the drawn pixels, because the area covered by the
+        // start and end angles can't be calculated exactly without rasterizing the shape.
+        let bounding_box = StyledPixelsIterator::new(self, &style.coverage_style()).bounding_box();
 
-        self.bounding_box().offset(offset)
+        if bounding_box.is_zero_sized() {
+            Rectangle::new(self.center(), Size::zero())
+        } else {
+            bounding_box
+        }
     }
 }
 
@@ -362,14 +375,13 @@ mod tests {
                 .build(),
         );
 
-        // TODO: Uncomment when arc bounding box is fixed in #405
-        // let mut display = MockDisplay::new();
-        // center.draw(&mut display).unwrap();
-        // assert_eq!(display.affected_area(), center.bounding_box());
+        for styled in [center, inside, outside].iter() {
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
 
-        assert_eq!(center.bounding_box(), inside.bounding_box());
-        assert_eq!(outside.bounding_box(), inside.bounding_box());
-        assert_eq!(transparent.bounding_box(), inside.bounding_box());
+        assert_eq!(transparent.bounding_box(), center.bounding_box());
     }
 
     /// The radial lines should be connected using a line join.
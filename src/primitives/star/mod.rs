@@ -0,0 +1,364 @@
+//! The star primitive.
+
+use crate::{
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_90DEG},
+        Angle, Dimensions, Point, Real, Trigonometry,
+    },
+    primitives::{ContainsPoint, PointsIter, Polygon, Primitive, Rectangle},
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_length, scale_point, Mirror, Rotate,
+        Scale, Transform,
+    },
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Star primitive
+///
+/// A star is a polygon with vertices that alternate between an outer and an inner circle around
+/// the center point. The tips of the star lie on the outer circle and are placed clockwise,
+/// starting at the angle given by `rotation`. An angle of zero degrees points to the right, which
+/// is the same convention that is used by [`RegularPolygon`].
+///
+/// The number of points is limited to the range from 3 to [`MAX_POINTS`]. Values outside of this
+/// range are clamped.
+///
+/// # Examples
+///
+/// ## Draw a rating widget
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Star},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let rating = 3;
+///
+/// for i in 0..5 {
+///     let style = if i < rating {
+///         PrimitiveStyle::with_fill(Rgb565::YELLOW)
+///     } else {
+///         PrimitiveStyle::with_stroke(Rgb565::YELLOW, 1)
+///     };
+///
+///     Star::new(Point::new(6 + i * 12, 6), 5, 2, 5, (-90.0).deg())
+///         .into_styled(style)
+///         .draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`RegularPolygon`]: ../regular_polygon/struct.RegularPolygon.html
+/// [`MAX_POINTS`]: #associatedconstant.MAX_POINTS
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Star {
+    /// Center point
+    pub center: Point,
+
+    /// Radius of the circle through the tips of the star
+    pub outer_radius: u32,
+
+    /// Radius of the circle through the inner vertices
+    pub inner_radius: u32,
+
+    /// Number of points
+    pub points: u32,
+
+    /// Angle of the first tip
+    pub rotation: Angle,
+}
+
+impl Star {
+    /// The maximum number of points.
+    pub const MAX_POINTS: u32 = 16;
+
+    /// Creates a new star.
+    pub const fn new(
+        center: Point,
+        outer_radius: u32,
+        inner_radius: u32,
+        points: u32,
+        rotation: Angle,
+    ) -> Self {
+        Self {
+            center,
+            outer_radius,
+            inner_radius,
+            points,
+            rotation,
+        }
+    }
+
+    /// Returns an iterator over the vertices of the star.
+    ///
+    /// The vertices alternate between the tips on the outer circle and the vertices on the inner
+    /// circle, starting with the first tip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Star};
+    ///
+    /// let star = Star::new(Point::new(10, 10), 8, 4, 4, 0.0.deg());
+    ///
+    /// let mut vertices = star.vertices();
+    /// assert_eq!(vertices.next(), Some(Point::new(18, 10)));
+    /// assert_eq!(vertices.next(), Some(Point::new(13, 13)));
+    /// assert_eq!(vertices.next(), Some(Point::new(10, 18)));
+    /// assert_eq!(vertices.count(), 5);
+    /// ```
+    pub fn vertices(&self) -> Vertices {
+        Vertices {
+            star: *self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of points clamped to the supported range.
+    fn clamped_points(&self) -> u32 {
+        self.points.max(3).min(Self::MAX_POINTS)
+    }
+
+    /// Returns the vertex with the given index.
+    ///
+    /// Even indices are tips of the star and odd indices are inner vertices.
+    fn vertex(&self, index: u32) -> Point {
+        let angle = self.rotation
+            + Angle::from_degrees(180.0 * index as f32 / self.clamped_points() as f32);
+        let radius = Real::from(if index % 2 == 0 {
+            self.outer_radius
+        } else {
+            self.inner_radius
+        });
+
+        self.center
+            + Point::new(
+                i32::from((radius * angle.cos()).round()),
+                i32::from((radius * angle.sin()).round()),
+            )
+    }
+}
+
+impl Primitive for Star {}
+
+impl PointsIter for Star {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Star {
+    fn contains(&self, point: Point) -> bool {
+        VertexBuffer::new(self).polygon().contains(point)
+    }
+}
+
+impl Dimensions for Star {
+    fn bounding_box(&self) -> Rectangle {
+        VertexBuffer::new(self).polygon().bounding_box()
+    }
+}
+
+impl Transform for Star {
+    /// Translate the star from its current position to a new position by (x, y) pixels,
+    /// returning a new `Star`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Star;
+    /// # use embedded_graphics::prelude::*;
+    /// let star = Star::new(Point::new(10, 10), 8, 3, 5, 0.0.deg());
+    /// let moved = star.translate(Point::new(10, 20));
+    ///
+    /// assert_eq!(moved.center, Point::new(20, 30));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the star from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Star;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut star = Star::new(Point::new(10, 10), 8, 3, 5, 0.0.deg());
+    /// star.translate_mut(Point::new(10, 20));
+    ///
+    /// assert_eq!(star.center, Point::new(20, 30));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+impl Rotate for Star {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self {
+            center: rotate_point_90(self.center, center),
+            rotation: (self.rotation + ANGLE_90DEG).normalize(),
+            ..*self
+        }
+    }
+}
+
+impl Mirror for Star {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_x(self.center, axis),
+            rotation: (ANGLE_180DEG - self.rotation).normalize(),
+            ..*self
+        }
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self {
+            center: mirror_point_y(self.center, axis),
+            rotation: (-self.rotation).normalize(),
+            ..*self
+        }
+    }
+}
+
+impl Scale for Star {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self {
+            center: scale_point(self.center, center, num, denom),
+            outer_radius: scale_length(self.outer_radius, num, denom),
+            inner_radius: scale_length(self.inner_radius, num, denom),
+            ..*self
+        }
+    }
+}
+
+/// Iterator over the vertices of a star.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Vertices {
+    star: Star,
+    index: u32,
+}
+
+impl Iterator for Vertices {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= 2 * self.star.clamped_points() {
+            return None;
+        }
+
+        let vertex = self.star.vertex(self.index);
+        self.index += 1;
+
+        Some(vertex)
+    }
+}
+
+/// Vertex storage, which is used to draw stars using the polygon implementation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct VertexBuffer {
+    vertices: [Point; 2 * Star::MAX_POINTS as usize],
+    len: usize,
+}
+
+impl VertexBuffer {
+    fn new(star: &Star) -> Self {
+        let mut vertices = [Point::zero(); 2 * Star::MAX_POINTS as usize];
+
+        let mut len = 0;
+        for (vertex, target) in star.vertices().zip(vertices.iter_mut()) {
+            *target = vertex;
+            len += 1;
+        }
+
+        Self { vertices, len }
+    }
+
+    fn as_slice(&self) -> &[Point] {
+        &self.vertices[0..self.len]
+    }
+
+    fn polygon(&self) -> Polygon<'_> {
+        Polygon::new(self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    #[test]
+    fn clamped_points() {
+        let center = Point::new(10, 10);
+
+        assert_eq!(Star::new(center, 8, 4, 0, 0.0.deg()).vertices().count(), 6);
+        assert_eq!(
+            Star::new(center, 8, 4, 100, 0.0.deg()).vertices().count(),
+            2 * Star::MAX_POINTS as usize
+        );
+    }
+
+    #[test]
+    fn alternating_radii() {
+        let star = Star::new(Point::new(20, 20), 10, 4, 5, (-90.0).deg());
+
+        for (index, vertex) in star.vertices().enumerate() {
+            let expected = if index % 2 == 0 { 10 } else { 4 };
+            let delta = vertex - star.center;
+            let distance_squared = delta.x.pow(2) + delta.y.pow(2);
+
+            assert!(
+                (distance_squared - expected * expected).abs() <= expected,
+                "{}: {:?}",
+                index,
+                vertex
+            );
+        }
+
+        assert_eq!(star.vertices().next(), Some(Point::new(20, 10)));
+    }
+
+    #[test]
+    fn dimensions() {
+        let star = Star::new(Point::new(10, 20), 5, 2, 4, 0.0.deg());
+
+        assert_eq!(
+            star.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(11, 11))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let star = Star::new(Point::new(15, 15), 14, 6, 5, 10.0.deg());
+
+        let expected = MockDisplay::from_points(star.points(), BinaryColor::On);
+
+        for point in Rectangle::new(Point::zero(), Size::new(32, 32)).points() {
+            assert_eq!(
+                star.contains(point),
+                expected.get_pixel(point).is_some(),
+                "{:?}",
+                point
+            );
+        }
+    }
+}
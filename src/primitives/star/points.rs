@@ -0,0 +1,72 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::{Scanline, StrokeOffset},
+        polygon::scanline_iterator::ScanlineState,
+        star::{Star, VertexBuffer},
+        FillRule, LineJoin,
+    },
+};
+
+/// Iterator over all points inside the star.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Points {
+    vertices: VertexBuffer,
+    scanlines: ScanlineState,
+    current_line: Scanline,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(star: &Star) -> Self {
+        let vertices = VertexBuffer::new(star);
+
+        Self {
+            scanlines: ScanlineState::new(
+                vertices.len,
+                FillRule::EvenOdd,
+                0,
+                StrokeOffset::None,
+                LineJoin::default(),
+                true,
+                &vertices.polygon().bounding_box(),
+            ),
+            vertices,
+            current_line: Scanline::new_empty(0),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.current_line.next() {
+                return Some(point);
+            }
+
+            self.current_line = self.scanlines.next(self.vertices.as_slice())?.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        primitives::{PointsIter, Polygon},
+    };
+
+    #[test]
+    fn points_equals_polygon_points() {
+        let star = Star::new(Point::new(20, 20), 15, 6, 5, 12.0.deg());
+
+        let mut vertices = [Point::zero(); 10];
+        for (target, vertex) in vertices.iter_mut().zip(star.vertices()) {
+            *target = vertex;
+        }
+
+        assert!(star.points().eq(Polygon::new(&vertices).points()));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{PointType, Scanline, StrokeOffset},
+        polygon::scanline_iterator::ScanlineState,
+        star::{Star, VertexBuffer},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        FillRule, FillStyle, LineJoin, PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+/// Pixel iterator for each pixel in the star
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StyledPixelsIterator<C> {
+    vertices: VertexBuffer,
+    lines_iter: ScanlineState,
+    current_line: Scanline,
+    current_color: Option<C>,
+    current_type: PointType,
+    fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+    stroke_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    pub(in crate::primitives) fn new(primitive: &Star, style: &PrimitiveStyle<C>) -> Self {
+        let vertices = VertexBuffer::new(primitive);
+
+        // The vertices of a star are always ordered clockwise, which means that the
+        // stroke offset doesn't need to be adjusted.
+        let lines_iter = ScanlineState::new(
+            vertices.len,
+            FillRule::EvenOdd,
+            style.stroke_width,
+            StrokeOffset::from(style.stroke_alignment),
+            LineJoin::default(),
+            style.is_filled(),
+            &vertices.polygon().styled_bounding_box(style),
+        );
+
+        Self {
+            vertices,
+            lines_iter,
+            current_line: Scanline::new_empty(0),
+            current_color: None,
+            current_type: PointType::Stroke,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+            stroke_color: style.effective_stroke_color(),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(color) = self.current_color {
+                let pixel = match self.current_type {
+                    PointType::Stroke => self.current_line.next().map(|p| Pixel(p, color)),
+                    PointType::Fill => self.current_line.next_fill_pixel(
+                        color,
+                        self.fill_style,
+                        &self.bounding_box,
+                    ),
+                };
+
+                if pixel.is_some() {
+                    return pixel;
+                }
+            }
+
+            let (next_line, next_type) = self.lines_iter.next(self.vertices.as_slice())?;
+
+            self.current_line = next_line;
+            self.current_type = next_type;
+
+            self.current_color = match next_type {
+                PointType::Stroke => self.stroke_color,
+                PointType::Fill => self.fill_color,
+            };
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Star {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Star {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        VertexBuffer::new(self).polygon().draw_styled(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Star {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        VertexBuffer::new(self).polygon().styled_bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions, Point},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn five_pointed_star() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Star::new(Point::new(7, 7), 7, 3, 5, (-90.0).deg())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "       #       ",
+            "       #       ",
+            "      #.#      ",
+            "      #.#      ",
+            "     #...#     ",
+            "######...######",
+            " #..........## ",
+            "  ##.......#   ",
+            "    #.....#    ",
+            "    #.....#    ",
+            "    #..#..#    ",
+            "   #.## #..#   ",
+            "   ##    ###   ",
+            "   #       #   ",
+        ]);
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        let star = Star::new(Point::new(30, 30), 20, 8, 5, (-90.0).deg());
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            for stroke_width in 0..6 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(*alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                let styled = star.into_styled(style);
+
+                let mut drawable = MockDisplay::new();
+                styled.draw(&mut drawable).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&drawable);
+                assert_eq!(
+                    styled.bounding_box(),
+                    drawable.affected_area(),
+                    "{:?}, {}",
+                    alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+}
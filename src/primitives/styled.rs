@@ -1,10 +1,10 @@
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{DrawTarget, DrawTargetExt, GetPixel},
     geometry::{Dimensions, Point},
-    pixelcolor::PixelColor,
+    pixelcolor::{PixelColor, Rgb888},
     primitives::OffsetOutline,
-    primitives::{PrimitiveStyle, Rectangle},
-    transform::Transform,
+    primitives::{ContainsPoint, PrimitiveStyle, Rectangle},
+    transform::{Mirror, Rotate, Scale, Transform},
     Drawable,
 };
 
@@ -22,6 +22,41 @@ impl<T, S> Styled<T, S> {
     pub fn new(primitive: T, style: S) -> Self {
         Self { primitive, style }
     }
+
+    /// Returns a mutable reference to the style.
+    ///
+    /// This can be used to change the style of an existing styled primitive, for example to
+    /// highlight a pressed button, without creating a new `Styled`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    /// # display.set_allow_overdraw(true);
+    ///
+    /// let mut button = Circle::new(Point::new(5, 5), 20)
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+    /// button.draw(&mut display)?;
+    ///
+    /// // Fill the button while it is pressed.
+    /// button.style_mut().fill_color = Some(BinaryColor::On);
+    /// button.draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn style_mut(&mut self) -> &mut S {
+        &mut self.style
+    }
+
+    /// Returns the primitive and the style.
+    pub fn into_inner(self) -> (T, S) {
+        (self.primitive, self.style)
+    }
 }
 
 impl<T: OffsetOutline, C: PixelColor> Styled<T, PrimitiveStyle<C>> {
@@ -96,6 +131,63 @@ impl<T: OffsetOutline, C: PixelColor> Styled<T, PrimitiveStyle<C>> {
     }
 }
 
+/// Hit testing for styled primitives.
+///
+/// Unlike the [`ContainsPoint`] implementation of the unstyled primitive, this implementation
+/// checks if a point is covered by the pixels that would be drawn by calling [`draw`]. The stroke
+/// width and alignment are taken into account and transparent parts, like the inside of a
+/// primitive without a fill color or the unset pixels of a patterned fill without a background
+/// color, are excluded. The stroke pattern is ignored, which means that the gaps between the
+/// dashes of a dashed stroke are treated as part of the stroke.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb888,
+///     prelude::*,
+///     primitives::{Circle, ContainsPoint, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+/// };
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb888::RED)
+///     .stroke_width(4)
+///     .stroke_alignment(StrokeAlignment::Outside)
+///     .build();
+///
+/// let button = Circle::with_center(Point::new(20, 20), 21).into_styled(style);
+///
+/// // The outside stroke is part of the hit area, even though it is outside the circle.
+/// assert!(button.contains(Point::new(20, 32)));
+/// assert!(!button.primitive.contains(Point::new(20, 32)));
+///
+/// // The circle isn't filled, which is why the center isn't part of the hit area.
+/// assert!(!button.contains(Point::new(20, 20)));
+///
+/// let filled_button = button.primitive.into_styled(PrimitiveStyle::with_fill(Rgb888::RED));
+/// assert!(filled_button.contains(Point::new(20, 20)));
+/// ```
+///
+/// [`ContainsPoint`]: trait.ContainsPoint.html
+/// [`draw`]: ../trait.Drawable.html#tymethod.draw
+impl<T, C> ContainsPoint for Styled<T, PrimitiveStyle<C>>
+where
+    T: OffsetOutline + ContainsPoint + Dimensions,
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        let fill_area = self.fill_area();
+
+        if fill_area.contains(point) {
+            self.style
+                .fill_color_at(point, &self.primitive.bounding_box())
+                .is_some()
+        } else {
+            self.style.effective_stroke_color().is_some() && self.stroke_area().contains(point)
+        }
+    }
+}
+
 impl<T: StyledPixels<S>, S> Styled<T, S> {
     /// Returns an iterator over the pixels in this styled primitive.
     pub fn pixels(&self) -> T::Iter {
@@ -121,6 +213,93 @@ impl<T: StyledDrawable<S>, S> Drawable for Styled<T, S> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<T: StyledDrawable<S, Output = ()> + StyledPixels<S>, S> crate::AsyncDrawable for Styled<T, S>
+where
+    T::Iter: IntoIterator<Item = crate::Pixel<T::Color>>,
+{
+    async fn draw_async<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: crate::draw_target::AsyncDrawTarget<Color = Self::Color>,
+    {
+        let mut runs =
+            crate::primitives::common::ColorRuns::new(self.pixels().into_iter()).peekable();
+
+        // Horizontal runs of pixels, like the scanlines of filled areas, are drawn by using
+        // `fill_solid` to make use of hardware accelerated fills. Consecutive single pixels, which
+        // are common in outlines, are combined into a single `draw_iter` call instead, because
+        // awaiting one `fill_solid` call per pixel would be much slower.
+        while let Some(&(area, color)) = runs.peek() {
+            if area.size.width > 1 {
+                runs.next();
+                target.fill_solid(&area, color).await?;
+            } else {
+                let pixels = core::iter::from_fn(|| match runs.peek() {
+                    Some((area, _)) if area.size.width == 1 => runs
+                        .next()
+                        .map(|(area, color)| crate::Pixel(area.top_left, color)),
+                    _ => None,
+                });
+
+                target.draw_iter(pixels).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, C> Styled<T, PrimitiveStyle<C>>
+where
+    T: StyledDrawable<PrimitiveStyle<C>, Color = C>,
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    /// Draws the styled primitive using the opacity and blend mode of the style.
+    ///
+    /// Each drawn pixel is combined with the current color of the pixel in the draw target, which
+    /// is why the draw target needs to implement [`GetPixel`]. Use [`draw`] to draw to targets
+    /// that can't read back their pixels, which ignores the opacity and blend mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb888,
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyleBuilder, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// display.fill_solid(&Rectangle::new(Point::zero(), Size::new(20, 20)), Rgb888::BLACK)?;
+    ///
+    /// // Draw a semi transparent highlight over the existing content.
+    /// let style = PrimitiveStyleBuilder::new()
+    ///     .fill_color(Rgb888::YELLOW)
+    ///     .opacity(64)
+    ///     .build();
+    ///
+    /// Circle::new(Point::new(2, 2), 16)
+    ///     .into_styled(style)
+    ///     .draw_blended(&mut display)?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(10, 10)), Some(Rgb888::new(64, 64, 0)));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`GetPixel`]: ../draw_target/trait.GetPixel.html
+    /// [`draw`]: ../trait.Drawable.html#tymethod.draw
+    pub fn draw_blended<D>(&self, target: &mut D) -> Result<T::Output, D::Error>
+    where
+        D: GetPixel<Color = C>,
+    {
+        let mut target = target.blended(self.style.opacity, self.style.blend_mode);
+
+        self.primitive.draw_styled(&self.style, &mut target)
+    }
+}
+
 impl<T: Transform, S: Clone> Transform for Styled<T, S> {
     fn translate(&self, by: Point) -> Self {
         Self {
@@ -136,6 +315,40 @@ impl<T: Transform, S: Clone> Transform for Styled<T, S> {
     }
 }
 
+impl<T: Rotate, S: Clone> Rotate for Styled<T, S> {
+    fn rotate_90(&self, center: Point) -> Self {
+        Self {
+            primitive: self.primitive.rotate_90(center),
+            style: self.style.clone(),
+        }
+    }
+}
+
+impl<T: Mirror, S: Clone> Mirror for Styled<T, S> {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self {
+            primitive: self.primitive.mirror_x(axis),
+            style: self.style.clone(),
+        }
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self {
+            primitive: self.primitive.mirror_y(axis),
+            style: self.style.clone(),
+        }
+    }
+}
+
+impl<T: Scale, S: Clone> Scale for Styled<T, S> {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        Self {
+            primitive: self.primitive.scale_around(center, num, denom),
+            style: self.style.clone(),
+        }
+    }
+}
+
 /// Styled drawable.
 pub trait StyledDrawable<S> {
     /// Color type.
@@ -163,3 +376,324 @@ pub trait StyledPixels<S> {
     /// Returns an iterator over all pixels in this styled primitive.
     fn pixels(&self, style: &S) -> Self::Iter;
 }
+
+// The following implementations make it possible to draw a `Styled` that only contains references
+// to the primitive and style, which is returned by `Primitive::into_styled_ref`.
+
+impl<T: StyledDrawable<S> + ?Sized, S> StyledDrawable<&S> for &T {
+    type Color = T::Color;
+    type Output = T::Output;
+
+    fn draw_styled<D>(&self, style: &&S, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        (**self).draw_styled(*style, target)
+    }
+}
+
+impl<T: StyledDimensions<S> + ?Sized, S> StyledDimensions<&S> for &T {
+    fn styled_bounding_box(&self, style: &&S) -> Rectangle {
+        (**self).styled_bounding_box(*style)
+    }
+}
+
+impl<T: StyledPixels<S> + ?Sized, S> StyledPixels<&S> for &T {
+    type Iter = T::Iter;
+
+    fn pixels(&self, style: &&S) -> Self::Iter {
+        (**self).pixels(*style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, RgbColor},
+        primitives::{
+            Circle, CornerRadii, Ellipse, FillPattern, FillStyle, PointsIter, Primitive,
+            PrimitiveStyleBuilder, RoundedRectangle, StrokeAlignment,
+        },
+    };
+
+    fn test_contains<T>(primitive: T)
+    where
+        T: Primitive
+            + OffsetOutline
+            + ContainsPoint
+            + StyledDrawable<PrimitiveStyle<BinaryColor>, Color = BinaryColor>
+            + Copy
+            + core::fmt::Debug,
+    {
+        let fill_styles = [
+            FillStyle::Solid,
+            FillStyle::Pattern {
+                pattern: FillPattern::CHECKERBOARD,
+                background: None,
+            },
+        ];
+
+        for &alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for &(stroke_color, fill_color) in &[
+                (Some(BinaryColor::On), None),
+                (None, Some(BinaryColor::On)),
+                (Some(BinaryColor::On), Some(BinaryColor::On)),
+            ] {
+                for (fill_style, stroke_width) in fill_styles
+                    .iter()
+                    .flat_map(|fill_style| (0..5).map(move |width| (fill_style, width)))
+                {
+                    let mut builder = PrimitiveStyleBuilder::new()
+                        .stroke_width(stroke_width)
+                        .stroke_alignment(alignment)
+                        .fill_style(*fill_style);
+                    if let Some(color) = stroke_color {
+                        builder = builder.stroke_color(color);
+                    }
+                    if let Some(color) = fill_color {
+                        builder = builder.fill_color(color);
+                    }
+
+                    let styled = primitive.into_styled(builder.build());
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    for point in display.bounding_box().points() {
+                        assert_eq!(
+                            styled.contains(point),
+                            display.get_pixel(point).is_some(),
+                            "{:?}, {:?}",
+                            styled,
+                            point
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn contains_circle() {
+        test_contains(Circle::new(Point::new(5, 6), 21));
+    }
+
+    #[test]
+    fn contains_ellipse() {
+        test_contains(Ellipse::new(Point::new(5, 6), Size::new(25, 14)));
+    }
+
+    #[test]
+    fn contains_rectangle() {
+        test_contains(Rectangle::new(Point::new(5, 6), Size::new(20, 15)));
+    }
+
+    #[test]
+    fn contains_rounded_rectangle() {
+        test_contains(RoundedRectangle::new(
+            Rectangle::new(Point::new(5, 6), Size::new(20, 15)),
+            CornerRadii::new(Size::new(5, 4)),
+        ));
+    }
+
+    #[test]
+    fn draw_blended() {
+        let background = Rectangle::new(Point::zero(), Size::new(5, 5));
+
+        let style = PrimitiveStyleBuilder::new()
+            .fill_color(Rgb888::BLUE)
+            .stroke_color(Rgb888::RED)
+            .stroke_width(1)
+            .opacity(128)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.fill_solid(&background, Rgb888::WHITE).unwrap();
+
+        background
+            .into_styled(style)
+            .draw_blended(&mut display)
+            .unwrap();
+
+        let stroke = Rgb888::new(255, 127, 127);
+        let fill = Rgb888::new(127, 127, 255);
+        for point in background.points() {
+            let expected = if background.offset(-1).contains(point) {
+                fill
+            } else {
+                stroke
+            };
+
+            assert_eq!(display.get_pixel(point), Some(expected), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn draw_ignores_opacity() {
+        let rectangle = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+        let style = PrimitiveStyleBuilder::new()
+            .fill_color(BinaryColor::On)
+            .opacity(10)
+            .build();
+
+        let mut display = MockDisplay::new();
+        rectangle.into_styled(style).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "###", //
+            "###", //
+        ]);
+    }
+
+    #[test]
+    fn style_mut() {
+        let mut styled = Rectangle::new(Point::zero(), Size::new(3, 2))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        styled.style_mut().fill_color = Some(BinaryColor::Off);
+        styled.style_mut().stroke_width = 0;
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "...", //
+            "...", //
+        ]);
+    }
+
+    #[test]
+    fn into_inner() {
+        let rectangle = Rectangle::new(Point::new(1, 2), Size::new(3, 4));
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        assert_eq!(
+            rectangle.into_styled(style).into_inner(),
+            (rectangle, style)
+        );
+    }
+
+    #[test]
+    fn into_styled_ref() {
+        let circle = Circle::new(Point::new(1, 2), 9);
+
+        for &style in &[
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 3),
+            PrimitiveStyle::with_fill_and_stroke(BinaryColor::Off, BinaryColor::On, 1),
+        ] {
+            let styled = circle.into_styled(style);
+            let styled_ref = circle.into_styled_ref(&style);
+
+            let mut expected = MockDisplay::new();
+            styled.draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            styled_ref.draw(&mut display).unwrap();
+            display.assert_eq(&expected);
+
+            assert_eq!(styled_ref.bounding_box(), styled.bounding_box());
+            assert!(styled_ref.pixels().eq(styled.pixels()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn draw_async() {
+        use crate::{mock_display::block_on, AsyncDrawable};
+
+        let circle = Circle::new(Point::new(1, 2), 9).into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(2)
+                .fill_color(BinaryColor::Off)
+                .build(),
+        );
+
+        let mut display = MockDisplay::new();
+        block_on(circle.draw_async(&mut display)).unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn draw_async_uses_fill_solid() {
+        use crate::{
+            draw_target::AsyncDrawTarget, geometry::OriginDimensions, mock_display::block_on,
+            AsyncDrawable, Pixel,
+        };
+
+        /// Draw target which counts the number of drawn pixels, `draw_iter` and `fill_solid` calls.
+        #[derive(Default)]
+        struct Counter {
+            pixels: usize,
+            draw_iter_calls: usize,
+            fills: usize,
+        }
+
+        impl OriginDimensions for Counter {
+            fn size(&self) -> Size {
+                Size::new(64, 64)
+            }
+        }
+
+        impl AsyncDrawTarget for Counter {
+            type Color = BinaryColor;
+            type Error = core::convert::Infallible;
+
+            async fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+            where
+                I: IntoIterator<Item = Pixel<Self::Color>>,
+            {
+                self.pixels += pixels.into_iter().count();
+                self.draw_iter_calls += 1;
+
+                Ok(())
+            }
+
+            async fn fill_solid(
+                &mut self,
+                _area: &Rectangle,
+                _color: Self::Color,
+            ) -> Result<(), Self::Error> {
+                self.fills += 1;
+
+                Ok(())
+            }
+        }
+
+        let rectangle = Rectangle::new(Point::new(1, 2), Size::new(10, 5))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let mut counter = Counter::default();
+        block_on(rectangle.draw_async(&mut counter)).unwrap();
+
+        assert_eq!(counter.pixels, 0);
+        assert_eq!(counter.draw_iter_calls, 0);
+        assert_eq!(counter.fills, 5);
+
+        // The single pixels on the left and right side of the outline are drawn in one call.
+        let outline = Rectangle::new(Point::new(1, 2), Size::new(10, 5))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        let mut counter = Counter::default();
+        block_on(outline.draw_async(&mut counter)).unwrap();
+
+        assert_eq!(counter.pixels, 6);
+        assert_eq!(counter.draw_iter_calls, 1);
+        assert_eq!(counter.fills, 2);
+    }
+}
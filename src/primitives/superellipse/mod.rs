@@ -0,0 +1,349 @@
+//! The superellipse primitive
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle},
+    transform::{Mirror, Rotate, Scale, Transform},
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Superellipse primitive
+///
+/// A superellipse is the set of points for which `|x / a|^n + |y / b|^n <= 1`, where `a` and `b`
+/// are the semi-axes and `n` is the exponent. An exponent of 2 results in an ellipse, larger
+/// exponents make the shape more rectangular and an exponent of 1 results in a diamond. The
+/// popular squircle shape, which is often used for app icons, is a superellipse with an exponent
+/// of 4.
+///
+/// Unlike a [`RoundedRectangle`], the edges of a superellipse don't have any straight sections
+/// and the curvature changes continuously.
+///
+/// The shape is calculated using fixed point integer math, which is why the outline can differ
+/// slightly from an [`Ellipse`] of the same size if an exponent of 2 is used.
+///
+/// # Examples
+///
+/// ## Draw a squircle icon background
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyleBuilder, Superellipse},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(2)
+///     .fill_color(Rgb565::BLUE)
+///     .build();
+///
+/// Superellipse::new(Point::new(10, 10), Size::new(40, 40), Superellipse::SQUIRCLE)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`RoundedRectangle`]: ../rounded_rectangle/struct.RoundedRectangle.html
+/// [`Ellipse`]: ../ellipse/struct.Ellipse.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Superellipse {
+    /// Top-left point of the superellipse's bounding box
+    pub top_left: Point,
+
+    /// Size of the superellipse
+    pub size: Size,
+
+    /// Exponent
+    ///
+    /// Exponents less than 1 are treated as 1.
+    pub exponent: u32,
+}
+
+impl Superellipse {
+    /// Exponent of a squircle.
+    pub const SQUIRCLE: u32 = 4;
+
+    /// Creates a new superellipse delimited with a top-left point with a specific size.
+    pub const fn new(top_left: Point, size: Size, exponent: u32) -> Self {
+        Self {
+            top_left,
+            size,
+            exponent,
+        }
+    }
+
+    /// Creates a new superellipse centered around a given point with a specific size.
+    pub fn with_center(center: Point, size: Size, exponent: u32) -> Self {
+        let top_left = Rectangle::with_center(center, size).top_left;
+
+        Self {
+            top_left,
+            size,
+            exponent,
+        }
+    }
+
+    /// Returns the center point of the superellipse.
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns the center point of the superellipse scaled by a factor of 2.
+    fn center_2x(&self) -> Point {
+        let radius = self.size.saturating_sub(Size::new(1, 1));
+
+        self.top_left * 2 + radius
+    }
+}
+
+impl OffsetOutline for Superellipse {
+    fn offset(&self, offset: i32) -> Self {
+        let size = if offset >= 0 {
+            self.size.saturating_add(Size::new_equal(2 * offset as u32))
+        } else {
+            self.size
+                .saturating_sub(Size::new_equal(2 * (-offset) as u32))
+        };
+
+        Self::with_center(self.center(), size, self.exponent)
+    }
+}
+
+impl Primitive for Superellipse {}
+
+impl PointsIter for Superellipse {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Superellipse {
+    fn contains(&self, point: Point) -> bool {
+        SuperellipseContains::new(self.size, self.exponent).contains(point * 2 - self.center_2x())
+    }
+}
+
+impl Dimensions for Superellipse {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, self.size)
+    }
+}
+
+impl Transform for Superellipse {
+    /// Translate the superellipse from its current position to a new position by (x, y) pixels,
+    /// returning a new `Superellipse`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Superellipse;
+    /// # use embedded_graphics::prelude::*;
+    /// let superellipse = Superellipse::new(Point::new(5, 10), Size::new(10, 15), 4);
+    /// let moved = superellipse.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the superellipse from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Superellipse;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut superellipse = Superellipse::new(Point::new(5, 10), Size::new(10, 15), 4);
+    /// superellipse.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(superellipse.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+impl Rotate for Superellipse {
+    fn rotate_90(&self, center: Point) -> Self {
+        let rectangle = self.bounding_box().rotate_90(center);
+
+        Self::new(rectangle.top_left, rectangle.size, self.exponent)
+    }
+}
+
+impl Mirror for Superellipse {
+    fn mirror_x(&self, axis: i32) -> Self {
+        Self::new(
+            self.bounding_box().mirror_x(axis).top_left,
+            self.size,
+            self.exponent,
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        Self::new(
+            self.bounding_box().mirror_y(axis).top_left,
+            self.size,
+            self.exponent,
+        )
+    }
+}
+
+impl Scale for Superellipse {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let rectangle = self.bounding_box().scale_around(center, num, denom);
+
+        Self::new(rectangle.top_left, rectangle.size, self.exponent)
+    }
+}
+
+/// Fixed point scaling factor, which is used to calculate the shape of the superellipse.
+const SCALE: u64 = 1 << 24;
+
+/// Determines if a point is inside a superellipse.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Debug)]
+struct SuperellipseContains {
+    width: u64,
+    height: u64,
+    exponent: u32,
+}
+
+impl SuperellipseContains {
+    /// Creates an object to determine if a point is inside a superellipse.
+    ///
+    /// The superellipse is always located in the origin.
+    fn new(size: Size, exponent: u32) -> Self {
+        Self {
+            width: u64::from(size.width),
+            height: u64::from(size.height),
+            exponent: exponent.max(1),
+        }
+    }
+
+    /// Returns `|value / semi_axis|^exponent` as a fixed point number.
+    ///
+    /// Returns `None` if the result is larger than or equal to 1.
+    fn normalized_pow(&self, value: i32, semi_axis: u64) -> Option<u64> {
+        let value = u64::from(value.abs() as u32);
+        if value >= semi_axis {
+            return None;
+        }
+
+        let normalized = value * SCALE / semi_axis;
+
+        let mut result = SCALE;
+        for _ in 0..self.exponent {
+            result = result * normalized / SCALE;
+
+            if result == 0 {
+                break;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns `true` if the point is inside the superellipse.
+    ///
+    /// The coordinates of the point need to be scaled by a factor of 2.
+    fn contains(&self, point: Point) -> bool {
+        // The semi-axes in scaled coordinates are equal to the width and height.
+        match (
+            self.normalized_pow(point.x, self.width),
+            self.normalized_pow(point.y, self.height),
+        ) {
+            (Some(x), Some(y)) => x + y < SCALE,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Ellipse, PointsIter},
+    };
+
+    #[test]
+    fn contains() {
+        for &exponent in &[1, 2, 3, 4, 10] {
+            let superellipse = Superellipse::new(Point::new(1, 2), Size::new(31, 20), exponent);
+
+            let expected = MockDisplay::from_points(superellipse.points(), BinaryColor::On);
+
+            for point in Rectangle::new(Point::zero(), Size::new(40, 30)).points() {
+                assert_eq!(
+                    superellipse.contains(point),
+                    expected.get_pixel(point).is_some(),
+                    "{}, {:?}",
+                    exponent,
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exponent_2_approximates_ellipse() {
+        for &size in &[Size::new(10, 10), Size::new(21, 14), Size::new(40, 7)] {
+            let superellipse = Superellipse::new(Point::zero(), size, 2);
+            let ellipse = Ellipse::new(Point::zero(), size);
+
+            let differences = superellipse
+                .bounding_box()
+                .points()
+                .filter(|p| superellipse.contains(*p) != ellipse.contains(*p))
+                .count();
+
+            assert_eq!(differences, 0, "{:?}", size);
+        }
+    }
+
+    #[test]
+    fn zero_exponent() {
+        let size = Size::new(15, 11);
+
+        assert!(Superellipse::new(Point::zero(), size, 0)
+            .points()
+            .eq(Superellipse::new(Point::zero(), size, 1).points()));
+    }
+
+    #[test]
+    fn zero_size() {
+        for &size in &[Size::zero(), Size::new(10, 0), Size::new(0, 10)] {
+            let superellipse = Superellipse::new(Point::new(5, 5), size, 4);
+
+            assert_eq!(superellipse.points().next(), None);
+            assert!(!superellipse.contains(Point::new(5, 5)));
+        }
+    }
+
+    #[test]
+    fn offset() {
+        let superellipse = Superellipse::new(Point::new(5, 6), Size::new(10, 11), 4);
+
+        assert_eq!(
+            superellipse.offset(2),
+            Superellipse::new(Point::new(3, 4), Size::new(14, 15), 4)
+        );
+        assert_eq!(
+            superellipse.offset(-2),
+            Superellipse::new(Point::new(7, 8), Size::new(6, 7), 4)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use core::ops::Range;
+
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::Scanline,
+        superellipse::{Superellipse, SuperellipseContains},
+    },
+};
+
+/// Iterator over all points inside the superellipse.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Points {
+    scanlines: Scanlines,
+    current_scanline: Scanline,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(superellipse: &Superellipse) -> Self {
+        Self {
+            scanlines: Scanlines::new(superellipse),
+            current_scanline: Scanline::new_empty(0),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current_scanline.next().or_else(|| {
+            self.current_scanline = self.scanlines.next()?;
+            self.current_scanline.next()
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Scanlines {
+    rows: Range<i32>,
+    columns: Range<i32>,
+    pub(super) center_2x: Point,
+    contains: SuperellipseContains,
+}
+
+impl Scanlines {
+    pub fn new(superellipse: &Superellipse) -> Self {
+        let bounding_box = superellipse.bounding_box();
+
+        Self {
+            rows: bounding_box.rows(),
+            columns: bounding_box.columns(),
+            center_2x: superellipse.center_2x(),
+            contains: SuperellipseContains::new(superellipse.size, superellipse.exponent),
+        }
+    }
+}
+
+impl Iterator for Scanlines {
+    type Item = Scanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let y = self.rows.next()?;
+
+            let scaled_y = y * 2 - self.center_2x.y;
+
+            // Rows can be empty for small exponents, because the top and bottom edges of the
+            // superellipse are pointed.
+            if let Some(x) = self.columns.clone().find(|x| {
+                self.contains
+                    .contains(Point::new(*x * 2 - self.center_2x.x, scaled_y))
+            }) {
+                // The superellipse is symmetric, which is why the right side of the scanline is
+                // shortened by the same amount as the left side.
+                return Some(Scanline::new(
+                    y,
+                    x..self.columns.end - (x - self.columns.start),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        primitives::{Ellipse, PointsIter},
+    };
+
+    #[test]
+    fn diamond() {
+        let diamond = Superellipse::new(Point::zero(), Size::new(5, 5), 1);
+
+        assert!(diamond.points().eq([
+            Point::new(2, 0),
+            Point::new(1, 1),
+            Point::new(2, 1),
+            Point::new(3, 1),
+            Point::new(0, 2),
+            Point::new(1, 2),
+            Point::new(2, 2),
+            Point::new(3, 2),
+            Point::new(4, 2),
+            Point::new(1, 3),
+            Point::new(2, 3),
+            Point::new(3, 3),
+            Point::new(2, 4),
+        ]
+        .iter()
+        .copied()));
+    }
+
+    #[test]
+    fn exponent_2_matches_ellipse_points() {
+        for diameter in 0..30 {
+            let size = Size::new(diameter, diameter / 2 + 3);
+
+            assert!(
+                Superellipse::new(Point::zero(), size, 2)
+                    .points()
+                    .eq(Ellipse::new(Point::zero(), size).points()),
+                "{:?}",
+                size
+            );
+        }
+    }
+}
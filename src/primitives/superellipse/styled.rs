@@ -0,0 +1,320 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{Scanline, StyledScanline},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        superellipse::{points::Scanlines, Superellipse, SuperellipseContains},
+        FillStyle, PrimitiveStyle, Rectangle,
+    },
+    Pixel, SaturatingCast,
+};
+
+/// Pixel iterator for each pixel in the superellipse
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StyledPixelsIterator<C> {
+    styled_scanlines: StyledScanlines,
+
+    stroke_left: Scanline,
+    fill: Scanline,
+    stroke_right: Scanline,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    pub(in crate::primitives) fn new(primitive: &Superellipse, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = style.stroke_area(primitive);
+        let fill_area = style.fill_area(primitive);
+
+        Self {
+            styled_scanlines: StyledScanlines::new(&stroke_area, &fill_area),
+            stroke_left: Scanline::new_empty(0),
+            fill: Scanline::new_empty(0),
+            stroke_right: Scanline::new_empty(0),
+            stroke_color: style.stroke_color,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.stroke_color, self.fill_color) {
+            (Some(stroke_color), None) => loop {
+                if let Some(pixel) = self
+                    .stroke_left
+                    .next()
+                    .or_else(|| self.stroke_right.next())
+                    .map(|p| Pixel(p, stroke_color))
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.stroke_left = scanline.stroke_left();
+                self.stroke_right = scanline.stroke_right();
+            },
+            (Some(stroke_color), Some(fill_color)) => loop {
+                if let Some(pixel) = self
+                    .stroke_left
+                    .next()
+                    .map(|p| Pixel(p, stroke_color))
+                    .or_else(|| {
+                        self.fill
+                            .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                    })
+                    .or_else(|| self.stroke_right.next().map(|p| Pixel(p, stroke_color)))
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.stroke_left = scanline.stroke_left();
+                self.fill = scanline.fill();
+                self.stroke_right = scanline.stroke_right();
+            },
+            (None, Some(fill_color)) => loop {
+                if let Some(pixel) =
+                    self.fill
+                        .next_fill_pixel(fill_color, self.fill_style, &self.bounding_box)
+                {
+                    return Some(pixel);
+                }
+
+                let scanline = self.styled_scanlines.next()?;
+                self.fill = scanline.fill();
+            },
+            (None, None) => None,
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Superellipse {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Superellipse {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        // Patterned fills can't be drawn using scanlines
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        match (style.effective_stroke_color(), style.effective_fill_color()) {
+            (Some(stroke_color), None) => {
+                for scanline in
+                    StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
+                {
+                    scanline.draw_stroke(target, stroke_color)?;
+                }
+            }
+            (Some(stroke_color), Some(fill_color)) => {
+                for scanline in
+                    StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
+                {
+                    scanline.draw_stroke_and_fill(target, stroke_color, fill_color)?;
+                }
+            }
+            (None, Some(fill_color)) => {
+                for scanline in Scanlines::new(&style.fill_area(self)) {
+                    scanline.draw(target, fill_color)?;
+                }
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Superellipse {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset = style.outside_stroke_width().saturating_cast();
+
+        self.bounding_box().offset(offset)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct StyledScanlines {
+    scanlines: Scanlines,
+    fill_area: SuperellipseContains,
+}
+
+impl StyledScanlines {
+    pub fn new(stroke_area: &Superellipse, fill_area: &Superellipse) -> Self {
+        Self {
+            scanlines: Scanlines::new(stroke_area),
+            fill_area: SuperellipseContains::new(fill_area.size, fill_area.exponent),
+        }
+    }
+}
+
+impl Iterator for StyledScanlines {
+    type Item = StyledScanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanlines.next().map(|scanline| {
+            let scaled_y = scanline.y * 2 - self.scanlines.center_2x.y;
+
+            let fill_range = scanline
+                .x
+                .clone()
+                .find(|x| {
+                    self.fill_area
+                        .contains(Point::new(*x * 2 - self.scanlines.center_2x.x, scaled_y))
+                })
+                .map(|x| x..scanline.x.end - (x - scanline.x.start));
+
+            StyledScanline::new(scanline.y, scanline.x, fill_range)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    fn test_superellipse(
+        size: Size,
+        exponent: u32,
+        style: PrimitiveStyle<BinaryColor>,
+        pattern: &[&str],
+    ) {
+        let superellipse = Superellipse::new(Point::zero(), size, exponent).into_styled(style);
+
+        let mut drawable = MockDisplay::new();
+        superellipse.draw(&mut drawable).unwrap();
+        drawable.assert_pattern(pattern);
+
+        let mut pixels = MockDisplay::new();
+        superellipse.pixels().draw(&mut pixels).unwrap();
+        pixels.assert_pattern(pattern);
+    }
+
+    #[test]
+    fn filled_squircle() {
+        test_superellipse(
+            Size::new(15, 15),
+            Superellipse::SQUIRCLE,
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            &[
+                "  ###########  ",
+                " ############# ",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                "###############",
+                " ############# ",
+                "  ###########  ",
+            ],
+        );
+    }
+
+    #[test]
+    fn stroked_squircle() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Inside)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        test_superellipse(
+            Size::new(15, 11),
+            Superellipse::SQUIRCLE,
+            style,
+            &[
+                "  ###########  ",
+                " ############# ",
+                "###.........###",
+                "##...........##",
+                "##...........##",
+                "##...........##",
+                "##...........##",
+                "##...........##",
+                "###.........###",
+                " ############# ",
+                "  ###########  ",
+            ],
+        );
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        for &exponent in &[1, 2, 4, 8] {
+            let superellipse = Superellipse::new(Point::new(10, 10), Size::new(30, 21), exponent);
+
+            for &alignment in &[
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ] {
+                for stroke_width in 0..6 {
+                    let style = PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .stroke_alignment(alignment)
+                        .fill_color(BinaryColor::Off)
+                        .build();
+
+                    let styled = superellipse.into_styled(style);
+
+                    let mut drawable = MockDisplay::new();
+                    styled.draw(&mut drawable).unwrap();
+
+                    let mut pixels = MockDisplay::new();
+                    styled.pixels().draw(&mut pixels).unwrap();
+
+                    pixels.assert_eq(&drawable);
+                    assert_eq!(
+                        styled.bounding_box(),
+                        drawable.affected_area(),
+                        "{}, {:?}, {}",
+                        exponent,
+                        alignment,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::{
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        triangle::{Points, Triangle},
+        PointsIter, Rectangle,
+    },
+    Pixel,
+};
+
+/// Per-vertex color gradient style for triangles.
+///
+/// Each vertex of the triangle is assigned a color and the colors are interpolated across the
+/// triangle using integer barycentric coordinates. The first color is used for the first vertex in
+/// [`Triangle::vertices`], the second color for the second vertex and so on.
+///
+/// The interpolation is done in the RGB888 color space, which is supported by all RGB color types
+/// in embedded-graphics.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{triangle::TriangleGradient, Triangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// Triangle::new(Point::new(0, 0), Point::new(40, 10), Point::new(10, 40))
+///     .into_styled(TriangleGradient::new([Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE]))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Triangle::vertices`]: struct.Triangle.html#structfield.vertices
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TriangleGradient<C> {
+    /// The colors of the triangle vertices.
+    pub colors: [C; 3],
+}
+
+impl<C> TriangleGradient<C> {
+    /// Creates a new gradient style with the given vertex colors.
+    pub const fn new(colors: [C; 3]) -> Self {
+        Self { colors }
+    }
+}
+
+/// Pixel iterator for a triangle with a per-vertex color gradient.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GradientPixels<C> {
+    points: Points,
+    triangle: Triangle,
+    area_sign: i64,
+    colors: [Rgb888; 3],
+    color_type: core::marker::PhantomData<C>,
+}
+
+impl<C> GradientPixels<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    fn new(triangle: &Triangle, style: &TriangleGradient<C>) -> Self {
+        let [c1, c2, c3] = style.colors;
+
+        Self {
+            points: triangle.points(),
+            triangle: *triangle,
+            area_sign: triangle.area_doubled().signum(),
+            colors: [c1.into(), c2.into(), c3.into()],
+            color_type: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the barycentric weights of a point.
+    ///
+    /// Points on the edges of the triangle are drawn using Bresenham lines and can lie slightly
+    /// outside the mathematical triangle. Negative weights are therefore clamped to zero.
+    fn weights(&self, point: Point) -> [i64; 3] {
+        let [p1, p2, p3] = self.triangle.vertices;
+
+        let weight = |a: Point, b: Point| {
+            let area = Triangle::new(point, a, b).area_doubled() * self.area_sign;
+
+            area.max(0)
+        };
+
+        [weight(p2, p3), weight(p3, p1), weight(p1, p2)]
+    }
+
+    fn color(&self, point: Point) -> C {
+        let weights = self.weights(point);
+        let total: i64 = weights.iter().sum();
+
+        // Colinear triangles have no area to interpolate over. The color of the closest vertex
+        // is used instead.
+        if total == 0 {
+            let closest = self
+                .triangle
+                .vertices
+                .iter()
+                .zip(self.colors.iter())
+                .min_by_key(|(vertex, _)| (**vertex - point).length_squared())
+                .map(|(_, color)| *color)
+                .unwrap();
+
+            return C::from(closest);
+        }
+
+        let channel = |f: fn(&Rgb888) -> u8| {
+            let sum: i64 = weights
+                .iter()
+                .zip(self.colors.iter())
+                .map(|(weight, color)| weight * i64::from(f(color)))
+                .sum();
+
+            ((sum + total / 2) / total) as u8
+        };
+
+        C::from(Rgb888::new(
+            channel(Rgb888::r),
+            channel(Rgb888::g),
+            channel(Rgb888::b),
+        ))
+    }
+}
+
+impl<C> Iterator for GradientPixels<C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.next()?;
+
+        Some(Pixel(point, self.color(point)))
+    }
+}
+
+impl<C> StyledPixels<TriangleGradient<C>> for Triangle
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Iter = GradientPixels<C>;
+
+    fn pixels(&self, style: &TriangleGradient<C>) -> Self::Iter {
+        GradientPixels::new(self, style)
+    }
+}
+
+impl<C> StyledDrawable<TriangleGradient<C>> for Triangle
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &TriangleGradient<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(GradientPixels::new(self, style))
+    }
+}
+
+impl<C> StyledDimensions<TriangleGradient<C>> for Triangle {
+    fn styled_bounding_box(&self, _style: &TriangleGradient<C>) -> Rectangle {
+        self.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb565},
+        primitives::{Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn vertex_colors() {
+        let triangle = Triangle::new(Point::new(1, 1), Point::new(30, 5), Point::new(8, 25));
+        let colors = [Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE];
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_gradient_styled(colors)
+            .draw(&mut display)
+            .unwrap();
+
+        for (vertex, color) in triangle.vertices.iter().zip(colors.iter()) {
+            assert_eq!(display.get_pixel(*vertex), Some(*color));
+        }
+    }
+
+    #[test]
+    fn same_area_as_fill() {
+        let triangles = [
+            Triangle::new(Point::new(1, 1), Point::new(30, 5), Point::new(8, 25)),
+            Triangle::new(Point::new(1, 1), Point::new(8, 25), Point::new(30, 5)),
+            Triangle::new(Point::new(2, 3), Point::new(20, 21), Point::new(11, 12)),
+            Triangle::new(Point::new(5, 5), Point::new(5, 5), Point::new(5, 5)),
+        ];
+
+        for triangle in triangles.iter() {
+            let mut expected = MockDisplay::new();
+            triangle
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            triangle
+                .into_gradient_styled([Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE])
+                .draw(&mut display)
+                .unwrap();
+
+            display
+                .map(|_| BinaryColor::On)
+                .assert_eq_with_message(&expected, |f| write!(f, "{:?}", triangle));
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(20, 0), Point::new(0, 20));
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_gradient_styled([Rgb888::BLACK, Rgb888::new(200, 0, 0), Rgb888::BLACK])
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display.get_pixel(Point::new(10, 0)),
+            Some(Rgb888::new(100, 0, 0))
+        );
+        assert_eq!(
+            display.get_pixel(Point::new(5, 5)),
+            Some(Rgb888::new(50, 0, 0))
+        );
+        assert_eq!(display.get_pixel(Point::new(0, 10)), Some(Rgb888::BLACK));
+    }
+
+    #[test]
+    fn colinear() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(5, 0));
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_gradient_styled([Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE])
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Rgb888::RED));
+        assert_eq!(display.get_pixel(Point::new(5, 0)), Some(Rgb888::BLUE));
+        assert_eq!(display.get_pixel(Point::new(9, 0)), Some(Rgb888::GREEN));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let triangle = Triangle::new(Point::new(1, 1), Point::new(30, 5), Point::new(8, 25));
+
+        assert_eq!(
+            triangle
+                .into_gradient_styled([Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE])
+                .bounding_box(),
+            triangle.bounding_box()
+        );
+    }
+}
@@ -3,19 +3,25 @@
 use core::cmp::{max, min, Ordering};
 
 use crate::{
-    geometry::{Dimensions, Point},
+    geometry::{div_round, Dimensions, Point},
+    pixelcolor::{PixelColor, Rgb888},
     primitives::{
-        common::{LineJoin, LineSide, LinearEquation, Scanline, StrokeOffset},
-        ContainsPoint, Line, PointsIter, Primitive, Rectangle,
+        common::{LineJoint, LineSide, LinearEquation, Scanline, StrokeOffset},
+        ContainsPoint, Line, PointsIter, Primitive, Rectangle, Styled,
+    },
+    transform::{
+        mirror_point_x, mirror_point_y, rotate_point_90, scale_point, Mirror, Rotate, Scale,
+        Transform,
     },
-    transform::Transform,
 };
 
+mod gradient;
 mod points;
 mod scanline_intersections;
 mod scanline_iterator;
 mod styled;
 
+pub use gradient::{GradientPixels, TriangleGradient};
 pub use points::Points;
 pub use styled::StyledPixelsIterator;
 
@@ -63,7 +69,25 @@ pub use styled::StyledPixelsIterator;
 /// # assert_eq!(tri, Triangle::new(p1, p2, p3));
 /// ```
 ///
+/// ## Hit testing
+///
+/// The [`ContainsPoint`] trait can be used to check if a point, e.g. the position of a touch
+/// event, is inside a triangle. The test uses integer math only and works for any vertex order.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::Point,
+///     primitives::{ContainsPoint, Triangle},
+/// };
+///
+/// let button = Triangle::new(Point::new(10, 10), Point::new(30, 20), Point::new(10, 30));
+///
+/// assert!(button.contains(Point::new(15, 20)));
+/// assert!(!button.contains(Point::new(25, 27)));
+/// ```
+///
 /// [`from_slice`]: #method.from_slice
+/// [`ContainsPoint`]: ../trait.ContainsPoint.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Triangle {
     /// The vertices of the triangle.
@@ -93,8 +117,8 @@ impl ContainsPoint for Triangle {
         // Check if point is inside triangle using https://stackoverflow.com/a/20861130/383609.
         // Works for any point ordering.
         let is_inside = {
-            let s = p1.y * p3.x - p1.x * p3.y + (p3.y - p1.y) * p.x + (p1.x - p3.x) * p.y;
-            let t = p1.x * p2.y - p1.y * p2.x + (p1.y - p2.y) * p.x + (p2.x - p1.x) * p.y;
+            let s = Triangle::new(p1, p, p3).area_doubled();
+            let t = Triangle::new(p1, p2, p).area_doubled();
 
             if (s < 0) != (t < 0) {
                 false
@@ -110,9 +134,9 @@ impl ContainsPoint for Triangle {
                 // This check allows this algorithm to work with clockwise or counterclockwise
                 // triangles.
                 if a < 0 {
-                    s <= 0 && s + t >= a
+                    s <= 0 && s.saturating_add(t) >= a
                 } else {
-                    s >= 0 && s + t <= a
+                    s >= 0 && s.saturating_add(t) <= a
                 }
             }
         };
@@ -173,18 +197,123 @@ impl Triangle {
         }
     }
 
-    /// Return the area of the triangle, doubled.
+    /// Converts this triangle into a `Styled` with a per-vertex color gradient.
+    ///
+    /// The colors of the vertices are interpolated across the triangle. See [`TriangleGradient`]
+    /// for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Triangle};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// Triangle::new(Point::new(0, 0), Point::new(40, 10), Point::new(10, 40))
+    ///     .into_gradient_styled([Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE])
+    ///     .draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`TriangleGradient`]: struct.TriangleGradient.html
+    pub fn into_gradient_styled<C>(self, colors: [C; 3]) -> Styled<Self, TriangleGradient<C>>
+    where
+        C: PixelColor + Into<Rgb888> + From<Rgb888>,
+    {
+        self.into_styled(TriangleGradient::new(colors))
+    }
+
+    /// Returns the area of the triangle, doubled.
     ///
-    /// This method can be used to determine if the triangle is colinear by checking if the returned
-    /// value is equal to zero.
-    pub(in crate::primitives) fn area_doubled(&self) -> i32 {
+    /// The returned value is signed and depends on the winding order of the vertices. It is
+    /// positive if the vertices are ordered clockwise and negative if they are ordered
+    /// counterclockwise, with the Y axis pointing down like on a display. A value of zero means
+    /// that the triangle is colinear.
+    ///
+    /// The area is doubled to keep the result exact for all integer vertex coordinates. The result
+    /// saturates at the limits of `i64`, which can only happen if the vertex coordinates are close
+    /// to the limits of `i32`. The sign of the result is always correct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Triangle};
+    ///
+    /// let clockwise = Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 10));
+    /// assert_eq!(clockwise.area_doubled(), 100);
+    ///
+    /// let counterclockwise = Triangle::new(Point::new(0, 0), Point::new(0, 10), Point::new(10, 0));
+    /// assert_eq!(counterclockwise.area_doubled(), -100);
+    ///
+    /// let colinear = Triangle::new(Point::new(0, 0), Point::new(5, 5), Point::new(10, 10));
+    /// assert_eq!(colinear.area_doubled(), 0);
+    /// ```
+    pub fn area_doubled(&self) -> i64 {
         let [p1, p2, p3] = self.vertices;
 
-        -p2.y * p3.x + p1.y * (p3.x - p2.x) + p1.x * (p2.y - p3.y) + p2.x * p3.y
+        let (x1, y1) = (i128::from(p1.x), i128::from(p1.y));
+        let (x2, y2) = (i128::from(p2.x), i128::from(p2.y));
+        let (x3, y3) = (i128::from(p3.x), i128::from(p3.y));
+
+        let area = -y2 * x3 + y1 * (x3 - x2) + x1 * (y2 - y3) + x2 * y3;
+
+        area.max(i64::min_value().into())
+            .min(i64::max_value().into()) as i64
     }
 
-    /// Create a new triangle with points sorted in a clockwise direction.
-    pub(in crate::primitives::triangle) fn sorted_clockwise(&self) -> Self {
+    /// Returns the centroid of the triangle.
+    ///
+    /// The centroid is the average of the three vertices, rounded to the nearest integer
+    /// coordinate. Rounding is symmetric around zero, which means that mirroring a triangle also
+    /// mirrors its centroid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Triangle};
+    ///
+    /// let triangle = Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 10));
+    ///
+    /// assert_eq!(triangle.centroid(), Point::new(3, 3));
+    /// ```
+    pub fn centroid(&self) -> Point {
+        let [p1, p2, p3] = self.vertices;
+        let sum = p1 + p2 + p3;
+
+        Point::new(
+            div_round(i64::from(sum.x), 3) as i32,
+            div_round(i64::from(sum.y), 3) as i32,
+        )
+    }
+
+    /// Returns a new triangle with the vertices sorted in clockwise order.
+    ///
+    /// The returned triangle is guaranteed to have a non-negative [`area_doubled`], with the Y axis
+    /// pointing down like on a display. Triangles that are already wound clockwise are returned
+    /// unchanged and counterclockwise triangles have their first two vertices swapped.
+    ///
+    /// Colinear triangles don't have a winding order. Their vertices are sorted by increasing Y
+    /// coordinate, and by increasing X coordinate for vertices with the same Y coordinate, which
+    /// places them sequentially along the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::Point, primitives::Triangle};
+    ///
+    /// let triangle = Triangle::new(Point::new(0, 0), Point::new(0, 10), Point::new(10, 0));
+    /// assert!(triangle.area_doubled() < 0);
+    ///
+    /// let sorted = triangle.sorted_clockwise();
+    /// assert!(sorted.area_doubled() > 0);
+    /// assert_eq!(
+    ///     sorted,
+    ///     Triangle::new(Point::new(0, 10), Point::new(0, 0), Point::new(10, 0))
+    /// );
+    /// ```
+    ///
+    /// [`area_doubled`]: #method.area_doubled
+    pub fn sorted_clockwise(&self) -> Self {
         match self.area_doubled().cmp(&0) {
             // Triangle is wound CCW. Swap two points to make it CW.
             Ordering::Less => Self::new(self.vertices[1], self.vertices[0], self.vertices[2]),
@@ -231,16 +360,42 @@ impl Triangle {
     }
 
     /// Generate a line join for each corner of the triangle.
-    fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [LineJoin; 3] {
+    fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [LineJoint; 3] {
         let [p1, p2, p3] = self.vertices;
 
         [
-            LineJoin::from_points(p3, p1, p2, stroke_width, stroke_offset),
-            LineJoin::from_points(p1, p2, p3, stroke_width, stroke_offset),
-            LineJoin::from_points(p2, p3, p1, stroke_width, stroke_offset),
+            LineJoint::from_points(p3, p1, p2, stroke_width, stroke_offset),
+            LineJoint::from_points(p1, p2, p3, stroke_width, stroke_offset),
+            LineJoint::from_points(p2, p3, p1, stroke_width, stroke_offset),
         ]
     }
 
+    /// Returns the stroke width and offset used to draw the outline of a thick stroked triangle.
+    ///
+    /// The inner edges of a thick center aligned stroke cross each other if the stroke is wide
+    /// compared to the size of the triangle, which results in degenerate joints. The stroke covers
+    /// the entire interior of the triangle in this case and is instead drawn as an outside stroke
+    /// with the outside part of the stroke width. The third value in the returned tuple is `true`
+    /// if the interior of the triangle needs to be filled with the stroke color.
+    ///
+    /// The triangle must be sorted clockwise.
+    pub(in crate::primitives::triangle) fn stroke_parameters(
+        &self,
+        stroke_width: u32,
+        stroke_offset: StrokeOffset,
+    ) -> (u32, StrokeOffset, bool) {
+        if stroke_offset == StrokeOffset::None
+            && self
+                .joins(stroke_width, stroke_offset)
+                .iter()
+                .any(|join| join.is_degenerate())
+        {
+            (stroke_width / 2, StrokeOffset::Left, true)
+        } else {
+            (stroke_width, stroke_offset, false)
+        }
+    }
+
     /// Compute whether a triangle with thick stroke has a hole in its center or is completely
     /// filled by stroke.
     // PERF: This doesn't need to compute the entire join, much like how `thick_stroke_inset`
@@ -321,6 +476,52 @@ impl Transform for Triangle {
     }
 }
 
+impl Rotate for Triangle {
+    fn rotate_90(&self, center: Point) -> Self {
+        let [v1, v2, v3] = self.vertices;
+
+        Self::new(
+            rotate_point_90(v1, center),
+            rotate_point_90(v2, center),
+            rotate_point_90(v3, center),
+        )
+    }
+}
+
+impl Mirror for Triangle {
+    fn mirror_x(&self, axis: i32) -> Self {
+        let [v1, v2, v3] = self.vertices;
+
+        Self::new(
+            mirror_point_x(v1, axis),
+            mirror_point_x(v2, axis),
+            mirror_point_x(v3, axis),
+        )
+    }
+
+    fn mirror_y(&self, axis: i32) -> Self {
+        let [v1, v2, v3] = self.vertices;
+
+        Self::new(
+            mirror_point_y(v1, axis),
+            mirror_point_y(v2, axis),
+            mirror_point_y(v3, axis),
+        )
+    }
+}
+
+impl Scale for Triangle {
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self {
+        let [v1, v2, v3] = self.vertices;
+
+        Self::new(
+            scale_point(v1, center, num, denom),
+            scale_point(v2, center, num, denom),
+            scale_point(v3, center, num, denom),
+        )
+    }
+}
+
 fn sort_two_yx(p1: Point, p2: Point) -> (Point, Point) {
     // If p1.y is less than p2.y, return it first. Otherwise, if they have the same Y coordinate,
     // the first point becomes the one with the lesser X coordinate.
@@ -399,7 +600,7 @@ mod tests {
 
         for triangle in triangles.iter() {
             for point in Rectangle::new(Point::new(-5, -5), Size::new(70, 70)).points() {
-                assert_eq!(triangle.contains(point), false);
+                assert!(!triangle.contains(point));
             }
         }
     }
@@ -442,6 +643,106 @@ mod tests {
     fn check_collapsed() {
         let triangle = Triangle::new(Point::new(10, 10), Point::new(30, 20), Point::new(20, 25));
 
-        assert_eq!(triangle.is_collapsed(20, StrokeOffset::None), true);
+        assert!(triangle.is_collapsed(20, StrokeOffset::None));
+    }
+
+    #[test]
+    fn area_doubled_large_coordinates() {
+        let triangle = Triangle::new(
+            Point::new(0, 0),
+            Point::new(100_000, 0),
+            Point::new(0, 100_000),
+        );
+        assert_eq!(triangle.area_doubled(), 10_000_000_000);
+
+        let triangle = Triangle::new(
+            Point::new(i32::min_value(), i32::min_value()),
+            Point::new(i32::max_value(), i32::min_value()),
+            Point::new(i32::min_value(), i32::max_value()),
+        );
+        assert_eq!(triangle.area_doubled(), i64::max_value());
+        assert_eq!(
+            triangle.sorted_clockwise(),
+            Triangle::new(
+                triangle.vertices[0],
+                triangle.vertices[1],
+                triangle.vertices[2]
+            )
+        );
+
+        let triangle = Triangle::new(
+            Point::new(i32::min_value(), i32::min_value()),
+            Point::new(i32::min_value(), i32::max_value()),
+            Point::new(i32::max_value(), i32::min_value()),
+        );
+        assert_eq!(triangle.area_doubled(), i64::min_value());
+    }
+
+    #[test]
+    fn contains_large_coordinates() {
+        let triangle = Triangle::new(
+            Point::new(-100_000, -100_000),
+            Point::new(100_000, -100_000),
+            Point::new(0, 100_000),
+        );
+
+        assert!(triangle.contains(Point::zero()));
+        assert!(triangle.contains(Point::new(0, 99_999)));
+        assert!(!triangle.contains(Point::new(-99_999, 99_999)));
+    }
+
+    #[test]
+    fn centroid() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 10));
+        assert_eq!(triangle.centroid(), Point::new(3, 3));
+
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(-10, 0), Point::new(0, -10));
+        assert_eq!(triangle.centroid(), Point::new(-3, -3));
+
+        let triangle = Triangle::new(Point::new(1, 2), Point::new(1, 2), Point::new(2, 3));
+        assert_eq!(triangle.centroid(), Point::new(1, 2));
+
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(1, 1), Point::new(1, 1));
+        assert_eq!(triangle.centroid(), Point::new(1, 1));
+    }
+
+    #[test]
+    fn sorted_clockwise() {
+        let triangles = [
+            Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 10)),
+            Triangle::new(Point::new(0, 0), Point::new(0, 10), Point::new(10, 0)),
+            Triangle::new(Point::new(5, 3), Point::new(-4, 8), Point::new(12, -7)),
+            Triangle::new(Point::new(5, 3), Point::new(12, -7), Point::new(-4, 8)),
+        ];
+
+        for triangle in triangles.iter() {
+            let sorted = triangle.sorted_clockwise();
+
+            assert!(sorted.area_doubled() > 0, "{:?}", triangle);
+            assert_eq!(sorted.area_doubled(), triangle.area_doubled().abs());
+
+            let mut expected = triangle.vertices;
+            let mut actual = sorted.vertices;
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn sorted_clockwise_colinear() {
+        let triangle = Triangle::new(Point::new(10, 15), Point::new(5, 10), Point::new(15, 20));
+
+        assert_eq!(
+            triangle.sorted_clockwise(),
+            Triangle::new(Point::new(5, 10), Point::new(10, 15), Point::new(15, 20))
+        );
+
+        let triangle = Triangle::new(Point::new(8, 3), Point::new(2, 3), Point::new(5, 3));
+
+        assert_eq!(
+            triangle.sorted_clockwise(),
+            Triangle::new(Point::new(2, 3), Point::new(5, 3), Point::new(8, 3))
+        );
     }
 }
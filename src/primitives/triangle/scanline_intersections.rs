@@ -3,7 +3,7 @@
 use crate::{
     geometry::Point,
     primitives::{
-        common::{LineJoin, PointType, Scanline, StrokeOffset, ThickSegment},
+        common::{LineJoint, PointType, Scanline, StrokeOffset, ThickSegment},
         Triangle,
     },
 };
@@ -25,6 +25,7 @@ pub struct ScanlineIntersections {
     stroke_offset: StrokeOffset,
     has_fill: bool,
     is_collapsed: bool,
+    interior_type: PointType,
 }
 
 impl ScanlineIntersections {
@@ -42,12 +43,22 @@ impl ScanlineIntersections {
         let is_collapsed = triangle.is_collapsed(stroke_width, stroke_offset)
             && stroke_offset == StrokeOffset::Right;
 
+        // Special case: If a center aligned stroke has degenerate joints, only the outside part of
+        // the stroke is drawn as edges and the interior is filled with the stroke color.
+        let (stroke_width, stroke_offset, interior_is_stroke) =
+            triangle.stroke_parameters(stroke_width, stroke_offset);
+
         let mut self_ = Self {
-            has_fill,
+            has_fill: has_fill || interior_is_stroke,
             triangle: *triangle,
             stroke_offset,
             stroke_width,
             is_collapsed,
+            interior_type: if interior_is_stroke {
+                PointType::Stroke
+            } else {
+                PointType::Fill
+            },
             ..Self::empty()
         };
 
@@ -70,6 +81,7 @@ impl ScanlineIntersections {
             stroke_width: 0,
             stroke_offset: StrokeOffset::None,
             is_collapsed: false,
+            interior_type: PointType::Fill,
         }
     }
 
@@ -91,14 +103,14 @@ impl ScanlineIntersections {
             }
 
             while idx < 3 {
-                let start = LineJoin::from_points(
+                let start = LineJoint::from_points(
                     self.triangle.vertices[idx % 3],
                     self.triangle.vertices[(idx + 1) % 3],
                     self.triangle.vertices[(idx + 2) % 3],
                     self.stroke_width,
                     self.stroke_offset,
                 );
-                let end = LineJoin::from_points(
+                let end = LineJoint::from_points(
                     self.triangle.vertices[(idx + 1) % 3],
                     self.triangle.vertices[(idx + 2) % 3],
                     self.triangle.vertices[(idx + 3) % 3],
@@ -182,7 +194,7 @@ impl ScanlineIntersections {
                 first: first.unwrap_or(Scanline::new_empty(scanline_y)),
                 second: second.unwrap_or(Scanline::new_empty(scanline_y)),
                 internal,
-                internal_type: PointType::Fill,
+                internal_type: self.interior_type,
             })
         }
     }
@@ -196,10 +208,11 @@ impl Iterator for ScanlineIntersections {
             Some((internal, self.lines.internal_type))
         } else if let Some(first) = self.lines.first.try_take() {
             Some((first, PointType::Stroke))
-        } else if let Some(second) = self.lines.second.try_take() {
-            Some((second, PointType::Stroke))
         } else {
-            None
+            self.lines
+                .second
+                .try_take()
+                .map(|second| (second, PointType::Stroke))
         }
     }
 }
@@ -6,28 +6,34 @@ use crate::{
         common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         triangle::{scanline_iterator::ScanlineIterator, Triangle},
-        PrimitiveStyle, Rectangle, StrokeAlignment,
+        FillStyle, LineJoin, PrimitiveStyle, Rectangle, StrokeAlignment,
     },
     Pixel,
 };
 
 /// Pixel iterator for each pixel in the triangle border
+///
+/// The triangle is drawn one scanline at a time and each pixel is returned exactly once, even if
+/// the triangle has both a stroke and a fill color.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct StyledPixelsIterator<C> {
     lines_iter: ScanlineIterator,
     current_line: Scanline,
     current_color: Option<C>,
+    current_type: PointType,
     fill_color: Option<C>,
+    fill_style: FillStyle<C>,
+    bounding_box: Rectangle,
     stroke_color: Option<C>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
     pub(in crate::primitives) fn new(primitive: &Triangle, style: &PrimitiveStyle<C>) -> Self {
         let mut lines_iter = ScanlineIterator::new(
-            &primitive,
+            primitive,
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
-            style.fill_color.is_some(),
+            style.is_filled(),
             &primitive.styled_bounding_box(style),
         );
 
@@ -37,14 +43,17 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
 
         let current_color = match point_type {
             PointType::Stroke => style.effective_stroke_color(),
-            PointType::Fill => style.fill_color,
+            PointType::Fill => style.effective_fill_color(),
         };
 
         Self {
             lines_iter,
             current_line,
             current_color,
-            fill_color: style.fill_color,
+            current_type: point_type,
+            fill_color: style.effective_fill_color(),
+            fill_style: style.fill_style,
+            bounding_box: primitive.bounding_box(),
             stroke_color: style.effective_stroke_color(),
         }
     }
@@ -55,12 +64,23 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if let Some(p) = self.current_line.next() {
-                return Some(Pixel(p, self.current_color?));
+            let color = self.current_color?;
+
+            let pixel = match self.current_type {
+                PointType::Stroke => self.current_line.next().map(|p| Pixel(p, color)),
+                PointType::Fill => {
+                    self.current_line
+                        .next_fill_pixel(color, self.fill_style, &self.bounding_box)
+                }
+            };
+
+            if pixel.is_some() {
+                return pixel;
             } else {
                 let (next_line, next_type) = self.lines_iter.next()?;
 
                 self.current_line = next_line;
+                self.current_type = next_type;
 
                 self.current_color = match next_type {
                     PointType::Stroke => self.stroke_color,
@@ -95,28 +115,34 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Triangle {
             return Ok(());
         }
 
-        for (line, kind) in ScanlineIterator::new(
-            &self,
+        // Patterned fills can't be drawn using `fill_solid`
+        if !style.fill_style.is_solid() && style.is_filled() {
+            return target.draw_iter(StyledPixelsIterator::new(self, style));
+        }
+
+        let runs = ScanlineIterator::new(
+            self,
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
-            style.fill_color.is_some(),
+            style.is_filled(),
             &self.styled_bounding_box(style),
-        ) {
+        )
+        .filter_map(|(line, kind)| {
             let color = match kind {
                 PointType::Stroke => style.effective_stroke_color(),
-                PointType::Fill => style.fill_color,
-            };
+                PointType::Fill => style.effective_fill_color(),
+            }?;
 
-            if let Some(color) = color {
-                let rect = line.to_rectangle();
+            let rect = line.to_rectangle();
 
-                if !rect.is_zero_sized() {
-                    target.fill_solid(&rect, color)?;
-                }
+            if rect.is_zero_sized() {
+                None
+            } else {
+                Some((rect.top_left, rect.size.width, color))
             }
-        }
+        });
 
-        Ok(())
+        target.draw_runs(runs)
     }
 }
 
@@ -129,10 +155,16 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Triangle {
 
         let t = self.sorted_clockwise();
 
-        let (min, max) = ClosedThickSegmentIter::new(
-            &t.vertices,
+        let (stroke_width, stroke_offset, _) = t.stroke_parameters(
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
+        );
+
+        let (min, max) = ClosedThickSegmentIter::new(
+            &t.vertices,
+            stroke_width,
+            stroke_offset,
+            LineJoin::default(),
         )
         .fold(
             (
@@ -158,6 +190,7 @@ mod tests {
     use super::*;
     use crate::{
         geometry::Point,
+        iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, Rgb888, RgbColor},
         primitives::{Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
@@ -165,6 +198,75 @@ mod tests {
         Drawable,
     };
 
+    /// Draw target which only accepts runs.
+    struct RunsOnly {
+        display: MockDisplay<BinaryColor>,
+        runs: usize,
+    }
+
+    impl DrawTarget for RunsOnly {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            panic!("unexpected call to draw_iter")
+        }
+
+        fn fill_solid(
+            &mut self,
+            _area: &Rectangle,
+            _color: Self::Color,
+        ) -> Result<(), Self::Error> {
+            panic!("unexpected call to fill_solid")
+        }
+
+        fn draw_runs<I>(&mut self, runs: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = (Point, u32, Self::Color)>,
+        {
+            let count = &mut self.runs;
+            let runs = runs.into_iter().inspect(|(_, length, _)| {
+                assert!(*length > 0);
+                *count += 1;
+            });
+
+            self.display.draw_runs(runs)
+        }
+    }
+
+    impl Dimensions for RunsOnly {
+        fn bounding_box(&self) -> Rectangle {
+            self.display.bounding_box()
+        }
+    }
+
+    #[test]
+    fn draw_runs() {
+        let styled = Triangle::new(Point::new(2, 1), Point::new(12, 4), Point::new(4, 9))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(2)
+                    .fill_color(BinaryColor::Off)
+                    .build(),
+            );
+
+        let mut target = RunsOnly {
+            display: MockDisplay::new(),
+            runs: 0,
+        };
+        styled.draw(&mut target).unwrap();
+
+        let mut expected = MockDisplay::new();
+        styled.pixels().draw(&mut expected).unwrap();
+
+        target.display.assert_eq(&expected);
+        assert!(target.runs > 0);
+    }
+
     #[test]
     fn unfilled_no_stroke_width_no_triangle() {
         let mut tri = Triangle::new(Point::new(2, 2), Point::new(4, 2), Point::new(2, 4))
@@ -552,4 +654,127 @@ mod tests {
             "R            ",
         ]);
     }
+
+    #[test]
+    fn thick_stroke_degenerate_joints() {
+        let mut display = MockDisplay::new();
+
+        Triangle::new(Point::new(5, 4), Point::new(10, 4), Point::new(7, 8))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 8))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "                  ",
+            "##################",
+            " ################ ",
+            " ###############  ",
+            "  ##############  ",
+            "  #############   ",
+            "   ###########    ",
+            "   ##########     ",
+            "    #########     ",
+            "    ########      ",
+            "     ######       ",
+            "     #####        ",
+            "      ####        ",
+            "      ###         ",
+            "       #          ",
+        ]);
+    }
+
+    #[test]
+    fn thick_stroke_bounding_box() {
+        let triangles = [
+            Triangle::new(Point::new(28, 28), Point::new(38, 28), Point::new(32, 36)),
+            Triangle::new(Point::new(25, 36), Point::new(40, 32), Point::new(28, 26)),
+            Triangle::new(Point::new(31, 25), Point::new(34, 40), Point::new(28, 36)),
+        ];
+
+        for triangle in triangles.iter() {
+            for stroke_width in 1..12 {
+                for alignment in [
+                    StrokeAlignment::Inside,
+                    StrokeAlignment::Center,
+                    StrokeAlignment::Outside,
+                ]
+                .iter()
+                {
+                    let styled = triangle.into_styled(
+                        PrimitiveStyleBuilder::new()
+                            .stroke_color(BinaryColor::On)
+                            .stroke_width(stroke_width)
+                            .stroke_alignment(*alignment)
+                            .build(),
+                    );
+
+                    // Drawing to the mock display also checks for overdraw.
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{:?}, {} px, {:?}",
+                        triangle,
+                        stroke_width,
+                        alignment
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fill_no_overdraw() {
+        let points = [
+            Point::new(20, 20),
+            Point::new(40, 22),
+            Point::new(25, 41),
+            Point::new(21, 35),
+            Point::new(38, 38),
+            Point::new(30, 20),
+        ];
+
+        for (i, p1) in points.iter().enumerate() {
+            for (j, p2) in points.iter().enumerate().skip(i + 1) {
+                for p3 in points.iter().skip(j + 1) {
+                    let triangle = Triangle::new(*p1, *p2, *p3);
+
+                    for stroke_width in 0..4 {
+                        let style = PrimitiveStyleBuilder::new()
+                            .stroke_color(Rgb565::RED)
+                            .stroke_width(stroke_width)
+                            .fill_color(Rgb565::GREEN)
+                            .build();
+
+                        // Drawing to the mock display will panic on overdraw.
+                        let mut drawn = MockDisplay::new();
+                        triangle.into_styled(style).draw(&mut drawn).unwrap();
+
+                        let mut pixels = MockDisplay::new();
+                        triangle
+                            .into_styled(style)
+                            .pixels()
+                            .draw(&mut pixels)
+                            .unwrap();
+
+                        drawn.assert_eq(&pixels);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_colinear_thick_stroke() {
+        let mut display = MockDisplay::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        display.set_allow_overdraw(true);
+
+        Triangle::new(Point::new(0, 0), Point::new(300, 0), Point::new(600, 0))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+            .draw(&mut display)
+            .unwrap();
+    }
 }
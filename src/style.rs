@@ -0,0 +1,223 @@
+//! Color themes.
+//!
+//! A [`Theme`] groups the colors that are used by the user interface of an application. Styles
+//! for primitives and text are derived from the theme, which makes it possible to switch between
+//! different themes, like a light and a dark theme, in one place.
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_graphics::{
+//!     mono_font::ascii::FONT_6X10,
+//!     pixelcolor::Rgb565,
+//!     prelude::*,
+//!     primitives::Rectangle,
+//!     style::Theme,
+//!     text::{Baseline, Text},
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::new();
+//! # display.set_allow_overdraw(true);
+//!
+//! let dark_mode = true;
+//! let theme = if dark_mode {
+//!     Theme::<Rgb565>::dark()
+//! } else {
+//!     Theme::light()
+//! };
+//!
+//! display.clear(theme.background)?;
+//!
+//! Rectangle::new(Point::new(2, 2), Size::new(50, 16))
+//!     .into_styled(theme.primary_style())
+//!     .draw(&mut display)?;
+//!
+//! Text::with_baseline(
+//!     "OK",
+//!     Point::new(5, 5),
+//!     theme.mono_text_style(&FONT_6X10),
+//!     Baseline::Top,
+//! )
+//! .draw(&mut display)?;
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
+//! [`Theme`]: struct.Theme.html
+
+use crate::{
+    mono_font::{MonoFont, MonoTextStyle},
+    pixelcolor::{PixelColor, RgbColor},
+    primitives::PrimitiveStyle,
+};
+
+/// Color theme.
+///
+/// See the [module-level documentation] for more information.
+///
+/// [module-level documentation]: index.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Theme<C> {
+    /// Background color.
+    pub background: C,
+
+    /// Primary color.
+    ///
+    /// The primary color is used to fill prominent elements, like buttons.
+    pub primary: C,
+
+    /// Secondary color.
+    ///
+    /// The secondary color is used to fill less prominent elements.
+    pub secondary: C,
+
+    /// Outline color.
+    pub outline: C,
+
+    /// Text color.
+    pub text: C,
+}
+
+impl<C: PixelColor> Theme<C> {
+    /// Returns a style which fills a shape with the background color.
+    pub fn background_style(&self) -> PrimitiveStyle<C> {
+        PrimitiveStyle::with_fill(self.background)
+    }
+
+    /// Returns a style which fills a shape with the primary color and draws a 1px outline.
+    pub fn primary_style(&self) -> PrimitiveStyle<C> {
+        PrimitiveStyle::with_fill_and_stroke(self.primary, self.outline, 1)
+    }
+
+    /// Returns a style which fills a shape with the secondary color and draws a 1px outline.
+    pub fn secondary_style(&self) -> PrimitiveStyle<C> {
+        PrimitiveStyle::with_fill_and_stroke(self.secondary, self.outline, 1)
+    }
+
+    /// Returns a style which only draws the outline of a shape.
+    pub fn outline_style(&self, stroke_width: u32) -> PrimitiveStyle<C> {
+        PrimitiveStyle::with_stroke(self.outline, stroke_width)
+    }
+
+    /// Returns a character style which draws text in the text color with a transparent background.
+    pub fn mono_text_style<'a>(&self, font: &'a MonoFont<'a>) -> MonoTextStyle<'a, C> {
+        MonoTextStyle::new(font, self.text)
+    }
+}
+
+impl<C: RgbColor> Theme<C> {
+    /// Returns a theme with dark text on a white background.
+    pub fn light() -> Self {
+        Self {
+            background: C::WHITE,
+            primary: C::BLUE,
+            secondary: C::CYAN,
+            outline: C::BLACK,
+            text: C::BLACK,
+        }
+    }
+
+    /// Returns a theme with light text on a black background.
+    pub fn dark() -> Self {
+        Self {
+            background: C::BLACK,
+            primary: C::BLUE,
+            secondary: C::MAGENTA,
+            outline: C::WHITE,
+            text: C::WHITE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{Point, Size},
+        mock_display::MockDisplay,
+        mono_font::ascii::FONT_6X9,
+        pixelcolor::{BinaryColor, Rgb888},
+        primitives::{Primitive, Rectangle},
+        text::{Baseline, Text},
+        Drawable,
+    };
+
+    const THEME: Theme<BinaryColor> = Theme {
+        background: BinaryColor::Off,
+        primary: BinaryColor::On,
+        secondary: BinaryColor::Off,
+        outline: BinaryColor::On,
+        text: BinaryColor::On,
+    };
+
+    #[test]
+    fn primitive_styles() {
+        assert_eq!(
+            THEME.background_style(),
+            PrimitiveStyle::with_fill(BinaryColor::Off)
+        );
+        assert_eq!(
+            THEME.primary_style(),
+            PrimitiveStyle::with_fill_and_stroke(BinaryColor::On, BinaryColor::On, 1)
+        );
+        assert_eq!(
+            THEME.secondary_style(),
+            PrimitiveStyle::with_fill_and_stroke(BinaryColor::Off, BinaryColor::On, 1)
+        );
+        assert_eq!(
+            THEME.outline_style(3),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 3)
+        );
+    }
+
+    #[test]
+    fn draw_secondary() {
+        let mut display = MockDisplay::new();
+
+        Rectangle::new(Point::zero(), Size::new(4, 3))
+            .into_styled(THEME.secondary_style())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "####", //
+            "#..#", //
+            "####", //
+        ]);
+    }
+
+    #[test]
+    fn mono_text_style() {
+        let mut expected = MockDisplay::new();
+        Text::with_baseline(
+            "A",
+            Point::zero(),
+            MonoTextStyle::new(&FONT_6X9, Rgb888::WHITE),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline(
+            "A",
+            Point::zero(),
+            Theme::dark().mono_text_style(&FONT_6X9),
+            Baseline::Top,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn light_and_dark() {
+        let light = Theme::<Rgb888>::light();
+        let dark = Theme::<Rgb888>::dark();
+
+        assert_eq!(light.background, Rgb888::WHITE);
+        assert_eq!(light.text, Rgb888::BLACK);
+        assert_eq!(dark.background, Rgb888::BLACK);
+        assert_eq!(dark.text, Rgb888::WHITE);
+    }
+}
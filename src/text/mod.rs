@@ -37,6 +37,9 @@
 //! If the text contains multiple lines only the first line will be vertically aligned based on the
 //! baseline setting. All following lines will be spaced relative to the first line, according to the [`line_height`] setting.
 //!
+//! The [`scale`] and [`rotation`] settings can be used to draw enlarged text, or text which is rotated
+//! in 90° steps around the text position, like vertical axis labels in a chart.
+//!
 //! # Examples
 //!
 //! ## Draw basic text
@@ -162,18 +165,28 @@
 //! [`alignment`]: struct.TextStyle.html#structfield.alignment
 //! [`baseline`]: struct.TextStyle.html#structfield.baseline
 //! [`line_height`]: struct.TextStyle.html#structfield.line_height
+//! [`scale`]: struct.TextStyle.html#structfield.scale
+//! [`rotation`]: struct.TextStyle.html#structfield.rotation
 //! [`TextStyleBuilder`]: struct.TextStyleBuilder.html
 //! [`mono_font`]: ../mono_font/index.html
 //! [`MonoTextStyle`]: ../mono_font/struct.MonoTextStyle.html
 //! [`renderer` module]: renderer/index.html
 //! [external crates list]: ../index.html#additional-functions-provided-by-external-crates
 
+mod path_text;
 pub mod renderer;
+#[allow(clippy::module_inception)]
 mod text;
+mod text_box;
+mod text_spans;
 mod text_style;
+mod transformed;
 
 use embedded_graphics_core::prelude::PixelColor;
+pub use path_text::{PathText, TextPath};
 pub use text::Text;
+pub use text_box::TextBox;
+pub use text_spans::TextSpans;
 pub use text_style::{TextStyle, TextStyleBuilder};
 
 /// Text baseline.
@@ -199,6 +212,27 @@ pub enum Alignment {
     /// Right.
     Right,
 }
+
+/// Handling of lines which are wider than a text box.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Overflow {
+    /// Wrap long lines.
+    Wrap,
+    /// Clip long lines at the edge of the text box.
+    Clip,
+    /// Truncate long lines and append an ellipsis.
+    ///
+    /// The ellipsis is drawn as three periods (`...`), because the built-in fonts don't include
+    /// the `…` character.
+    Ellipsis,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Self::Wrap
+    }
+}
+
 /// Text decoration color.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum DecorationColor<C> {
@@ -238,10 +272,10 @@ impl<C: PixelColor> DecorationColor<C> {
         }
     }
 
-    pub(crate) fn to_color(&self, text_color: Option<C>) -> Option<C> {
+    pub(crate) fn to_color(self, text_color: Option<C>) -> Option<C> {
         match self {
             DecorationColor::TextColor => text_color,
-            DecorationColor::Custom(custom_color) => Some(*custom_color),
+            DecorationColor::Custom(custom_color) => Some(custom_color),
             DecorationColor::None => None,
         }
     }
@@ -0,0 +1,475 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{isqrt, Angle, Dimensions, Point, Real, Rotation, Size, Trigonometry},
+    primitives::{Arc, Line, Polyline, Rectangle},
+    text::{
+        renderer::TextRenderer, text::measure_line, Alignment, Baseline, Text, TextStyle,
+        TextStyleBuilder,
+    },
+    Drawable, SaturatingCast,
+};
+
+/// Path which text can be drawn along.
+///
+/// This trait is implemented for [`Line`], [`Arc`] and [`Polyline`] and can be implemented for
+/// custom paths.
+///
+/// [`Line`]: ../primitives/line/struct.Line.html
+/// [`Arc`]: ../primitives/arc/struct.Arc.html
+/// [`Polyline`]: ../primitives/polyline/struct.Polyline.html
+pub trait TextPath {
+    /// Returns the length of the path.
+    fn length(&self) -> u32;
+
+    /// Returns the point at the given distance from the start of the path and the rotation of the
+    /// text at this point.
+    ///
+    /// The distance is guaranteed to be less than or equal to the length of the path.
+    fn position_at(&self, distance: u32) -> (Point, Rotation);
+}
+
+/// Returns the text rotation which is closest to the given direction.
+fn direction_to_rotation(direction: Point) -> Rotation {
+    if direction.x.abs() >= direction.y.abs() {
+        if direction.x >= 0 {
+            Rotation::Deg0
+        } else {
+            Rotation::Deg180
+        }
+    } else if direction.y > 0 {
+        Rotation::Deg90
+    } else {
+        Rotation::Deg270
+    }
+}
+
+impl TextPath for Line {
+    fn length(&self) -> u32 {
+        isqrt(self.delta().length_squared() as u64) as u32
+    }
+
+    fn position_at(&self, distance: u32) -> (Point, Rotation) {
+        let point = self.point_at(distance.saturating_cast(), self.length().saturating_cast());
+
+        (point, direction_to_rotation(self.delta()))
+    }
+}
+
+impl TextPath for Polyline<'_> {
+    fn length(&self) -> u32 {
+        polyline_segments(self).map(|line| line.length()).sum()
+    }
+
+    fn position_at(&self, mut distance: u32) -> (Point, Rotation) {
+        let mut last = None;
+
+        for line in polyline_segments(self) {
+            let length = line.length();
+            if distance <= length {
+                return line.position_at(distance);
+            }
+
+            distance -= length;
+            last = Some(line);
+        }
+
+        last.map(|line| line.position_at(line.length()))
+            .unwrap_or((self.translate, Rotation::Deg0))
+    }
+}
+
+/// Returns an iterator over the line segments of a polyline, including the closing segment.
+fn polyline_segments<'a>(polyline: &'a Polyline<'_>) -> impl Iterator<Item = Line> + 'a {
+    let vertices = polyline.vertices;
+    let translate = polyline.translate;
+
+    let closing = if polyline.closed && vertices.len() > 2 {
+        Some(Line::new(vertices[vertices.len() - 1], vertices[0]))
+    } else {
+        None
+    };
+
+    vertices
+        .windows(2)
+        .map(|vertices| Line::new(vertices[0], vertices[1]))
+        .chain(closing)
+        .map(move |line| Line::new(line.start + translate, line.end + translate))
+}
+
+impl TextPath for Arc {
+    fn length(&self) -> u32 {
+        ((self.diameter / 2) as f32 * self.angle_sweep.abs().to_radians()) as u32
+    }
+
+    fn position_at(&self, distance: u32) -> (Point, Rotation) {
+        let length = self.length();
+        let fraction = if length > 0 {
+            distance as f32 / length as f32
+        } else {
+            0.0
+        };
+
+        let angle = Angle::from_radians(
+            self.angle_start.to_radians() + self.angle_sweep.to_radians() * fraction,
+        );
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        let radius = Real::from(self.diameter / 2);
+        let point = self.center()
+            + Point::new(
+                i32::from((radius * cos).round()),
+                -i32::from((radius * sin).round()),
+            );
+
+        // The direction of increasing angles, scaled to make the rounding more precise.
+        let scale = Real::from(1000);
+        let mut direction = -Point::new(
+            i32::from((scale * sin).round()),
+            i32::from((scale * cos).round()),
+        );
+        if self.angle_sweep < Angle::zero() {
+            direction = -direction;
+        }
+
+        (point, direction_to_rotation(direction))
+    }
+}
+
+/// Text drawn along a path.
+///
+/// Each glyph is centered on a point of the path and is rotated in 90° steps to follow the
+/// direction of the path at this point, which can be used to draw curved labels for gauges and
+/// dials. Glyphs which don't fit onto the path aren't drawn.
+///
+/// The text is drawn as a single line. The alignment of the text style defines whether the text
+/// starts at the beginning of the path, is centered on the path or ends at the end of the path.
+/// The baseline defines the vertical position of the glyphs relative to the path. The rotation
+/// setting of the text style is ignored, because the rotation of each glyph is determined by the
+/// path.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Arc,
+///     text::{Alignment, Baseline, PathText, TextStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+/// let text_style = TextStyleBuilder::new()
+///     .alignment(Alignment::Center)
+///     .baseline(Baseline::Bottom)
+///     .build();
+///
+/// // Label on the upper half of a gauge, drawn clockwise from left to right.
+/// let arc = Arc::with_center(Point::new(32, 40), 50, 180.0.deg(), -180.0.deg());
+///
+/// PathText::with_text_style("SPEED", arc, style, text_style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PathText<'a, P, S> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The path.
+    pub path: P,
+
+    /// The character style.
+    pub character_style: S,
+
+    /// The text style.
+    pub text_style: TextStyle,
+}
+
+impl<'a, P, S> PathText<'a, P, S> {
+    /// Creates a path text drawable with the default text style.
+    pub const fn new(text: &'a str, path: P, character_style: S) -> Self {
+        Self {
+            text,
+            path,
+            character_style,
+            text_style: TextStyleBuilder::new().build(),
+        }
+    }
+
+    /// Creates a path text drawable with the given text style.
+    pub const fn with_text_style(
+        text: &'a str,
+        path: P,
+        character_style: S,
+        text_style: TextStyle,
+    ) -> Self {
+        Self {
+            text,
+            path,
+            character_style,
+            text_style,
+        }
+    }
+}
+
+impl<'a, P: TextPath, S: TextRenderer + Clone> PathText<'a, P, S> {
+    /// Returns the unscaled width of a string.
+    fn width(&self, text: &str) -> u32 {
+        measure_line(
+            &self.character_style,
+            text,
+            Point::zero(),
+            Baseline::Top,
+            self.text_style.character_spacing,
+        )
+        .bounding_box
+        .size
+        .width
+    }
+
+    /// Returns a text drawable for each glyph which fits onto the path.
+    fn glyphs(&self) -> impl Iterator<Item = Text<'a, S>> + '_ {
+        let text = self.text;
+        let scale = self.text_style.scale;
+
+        let length = self.path.length();
+        let width = self.width(text) * scale;
+        let start = match self.text_style.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => length.saturating_sub(width) / 2,
+            Alignment::Right => length.saturating_sub(width),
+        };
+
+        text.char_indices().filter_map(move |(index, c)| {
+            let end = index + c.len_utf8();
+            let glyph = &text[index..end];
+
+            // Distance of the glyph center from the start of the path. The center is calculated in
+            // the same way as for centered text to place the glyph at the same position as it
+            // would be in a straight line of text.
+            let glyph_width = self.width(glyph);
+            let offset = self.width(&text[..end]) - glyph_width;
+            let distance = start + (offset + glyph_width.saturating_sub(1) / 2) * scale;
+            if distance > length {
+                return None;
+            }
+
+            let (position, rotation) = self.path.position_at(distance);
+
+            Some(Text::with_text_style(
+                glyph,
+                position,
+                self.character_style.clone(),
+                TextStyle {
+                    alignment: Alignment::Center,
+                    rotation,
+                    ..self.text_style
+                },
+            ))
+        })
+    }
+}
+
+impl<P: TextPath, S: TextRenderer + Clone> Drawable for PathText<'_, P, S> {
+    type Color = S::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for glyph in self.glyphs() {
+            glyph.draw(target)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<P: TextPath, S: TextRenderer + Clone> Dimensions for PathText<'_, P, S> {
+    fn bounding_box(&self) -> Rectangle {
+        let mut min_max: Option<(Point, Point)> = None;
+
+        for glyph in self.glyphs() {
+            let bounding_box = glyph.bounding_box();
+
+            if let Some(bottom_right) = bounding_box.bottom_right() {
+                if let Some((min, max)) = &mut min_max {
+                    *min = min.component_min(bounding_box.top_left);
+                    *max = max.component_max(bottom_right);
+                } else {
+                    min_max = Some((bounding_box.top_left, bottom_right));
+                }
+            }
+        }
+
+        if let Some((min, max)) = min_max {
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::new(self.path.position_at(0).0, Size::zero())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        pixelcolor::BinaryColor,
+        text::DecorationColor,
+        transform::Transform,
+    };
+
+    const STYLE: MonoTextStyle<'static, BinaryColor> = MonoTextStyle {
+        text_color: Some(BinaryColor::On),
+        background_color: None,
+        underline_color: DecorationColor::None,
+        strikethrough_color: DecorationColor::None,
+        font: &FONT_6X9,
+    };
+
+    const TOP: TextStyle = TextStyleBuilder::new().baseline(Baseline::Top).build();
+
+    #[test]
+    fn line_position_at() {
+        let line = Line::new(Point::new(10, 20), Point::new(10, 0));
+
+        assert_eq!(line.length(), 20);
+        assert_eq!(line.position_at(0), (Point::new(10, 20), Rotation::Deg270));
+        assert_eq!(line.position_at(5), (Point::new(10, 15), Rotation::Deg270));
+        assert_eq!(line.position_at(20), (Point::new(10, 0), Rotation::Deg270));
+    }
+
+    #[test]
+    fn polyline_position_at() {
+        let vertices = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 10)];
+        let polyline = Polyline::new(&vertices).translate(Point::new(1, 2));
+
+        assert_eq!(polyline.length(), 20);
+        assert_eq!(polyline.position_at(5), (Point::new(6, 2), Rotation::Deg0));
+        assert_eq!(
+            polyline.position_at(10),
+            (Point::new(11, 2), Rotation::Deg0)
+        );
+        assert_eq!(
+            polyline.position_at(15),
+            (Point::new(11, 7), Rotation::Deg90)
+        );
+        assert_eq!(
+            polyline.position_at(20),
+            (Point::new(11, 12), Rotation::Deg90)
+        );
+    }
+
+    #[test]
+    fn arc_position_at() {
+        let arc = Arc::with_center(Point::new(20, 20), 41, 180.0.deg(), -180.0.deg());
+
+        assert_eq!(arc.length(), 62);
+        assert_eq!(arc.position_at(0), (Point::new(0, 20), Rotation::Deg270));
+        assert_eq!(arc.position_at(31), (Point::new(20, 0), Rotation::Deg0));
+        assert_eq!(arc.position_at(62), (Point::new(40, 20), Rotation::Deg90));
+
+        let arc = Arc::with_center(Point::new(20, 20), 41, 180.0.deg(), 180.0.deg());
+        assert_eq!(arc.position_at(31), (Point::new(20, 40), Rotation::Deg0));
+    }
+
+    #[test]
+    fn horizontal_line() {
+        let mut expected = MockDisplay::new();
+        Text::with_text_style("abc", Point::new(1, 2), STYLE, TOP)
+            .draw(&mut expected)
+            .unwrap();
+
+        let line = Line::new(Point::new(1, 2), Point::new(40, 2));
+
+        let mut display = MockDisplay::new();
+        PathText::with_text_style("abc", line, STYLE, TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn vertical_line() {
+        let text_style = TextStyleBuilder::from(&TOP)
+            .rotation(Rotation::Deg90)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        Text::with_text_style("abc", Point::new(20, 2), STYLE, text_style)
+            .draw(&mut expected)
+            .unwrap();
+
+        let line = Line::new(Point::new(20, 2), Point::new(20, 40));
+
+        let mut display = MockDisplay::new();
+        PathText::with_text_style("abc", line, STYLE, TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn alignment() {
+        let line = Line::new(Point::new(0, 2), Point::new(40, 2));
+
+        for &(alignment, x) in &[
+            (Alignment::Left, 0),
+            (Alignment::Center, 14),
+            (Alignment::Right, 28),
+        ] {
+            let mut expected = MockDisplay::new();
+            Text::with_text_style("ab", Point::new(x, 2), STYLE, TOP)
+                .draw(&mut expected)
+                .unwrap();
+
+            let text_style = TextStyleBuilder::from(&TOP).alignment(alignment).build();
+
+            let mut display = MockDisplay::new();
+            PathText::with_text_style("ab", line, STYLE, text_style)
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", alignment));
+        }
+    }
+
+    #[test]
+    fn glyphs_beyond_path_end() {
+        let mut expected = MockDisplay::new();
+        Text::with_text_style("ab", Point::zero(), STYLE, TOP)
+            .draw(&mut expected)
+            .unwrap();
+
+        let line = Line::new(Point::zero(), Point::new(10, 0));
+
+        let mut display = MockDisplay::new();
+        PathText::with_text_style("abcd", line, STYLE, TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let line = Line::new(Point::new(1, 2), Point::new(40, 2));
+
+        assert_eq!(
+            PathText::with_text_style("abc", line, STYLE, TOP).bounding_box(),
+            Text::with_text_style("abc", Point::new(1, 2), STYLE, TOP).bounding_box(),
+        );
+        assert_eq!(
+            PathText::with_text_style("", line, STYLE, TOP).bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero()),
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{DrawTarget, DrawTargetExt, GetPixel, Rotated},
     geometry::{Dimensions, Point, Size},
+    pixelcolor::Rgb888,
     primitives::Rectangle,
     text::{
         renderer::{TextMetrics, TextRenderer},
+        transformed::Transformation,
         Alignment, Baseline, TextStyle,
     },
     transform::Transform,
@@ -113,19 +115,11 @@ impl<S: TextRenderer> Text<'_, S> {
             let p = match self.text_style.alignment {
                 Alignment::Left => position,
                 Alignment::Right => {
-                    let metrics = self.character_style.measure_string(
-                        line,
-                        Point::zero(),
-                        self.text_style.baseline,
-                    );
+                    let metrics = self.measure_line(line, Point::zero());
                     position - (metrics.next_position - Point::new(1, 0))
                 }
                 Alignment::Center => {
-                    let metrics = self.character_style.measure_string(
-                        line,
-                        Point::zero(),
-                        self.text_style.baseline,
-                    );
+                    let metrics = self.measure_line(line, Point::zero());
                     position - (metrics.next_position - Point::new(1, 0)) / 2
                 }
             };
@@ -139,6 +133,35 @@ impl<S: TextRenderer> Text<'_, S> {
             (line, p)
         })
     }
+
+    fn measure_line(&self, line: &str, position: Point) -> TextMetrics {
+        measure_line(
+            &self.character_style,
+            line,
+            position,
+            self.text_style.baseline,
+            self.text_style.character_spacing,
+        )
+    }
+}
+
+/// Measures a line of text with additional character spacing.
+pub(super) fn measure_line<S: TextRenderer>(
+    character_style: &S,
+    line: &str,
+    position: Point,
+    baseline: Baseline,
+    character_spacing: u32,
+) -> TextMetrics {
+    let mut metrics = character_style.measure_string(line, position, baseline);
+
+    let spacing = line.chars().count().saturating_sub(1) as u32 * character_spacing;
+    if spacing > 0 {
+        metrics.bounding_box.size.width += spacing;
+        metrics.next_position.x += spacing.saturating_cast();
+    }
+
+    metrics
 }
 
 impl<S: TextRenderer> Drawable for Text<'_, S> {
@@ -148,22 +171,103 @@ impl<S: TextRenderer> Drawable for Text<'_, S> {
     fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
     where
         D: DrawTarget<Color = Self::Color>,
+    {
+        let transformation = self.transformation();
+        if transformation.is_identity() {
+            return self.draw_untransformed(target);
+        }
+
+        // Text with a scaling factor of zero doesn't cover any pixels.
+        if transformation.scale > 0 {
+            let origin = transformation.origin;
+
+            let mut rotated = Rotated::with_offset(target, transformation.rotation, origin);
+            let next_position = self.draw_untransformed(
+                &mut rotated
+                    .scaled(transformation.scale)
+                    .translated(Point::zero() - origin),
+            )?;
+
+            Ok(transformation.point(next_position))
+        } else {
+            Ok(transformation.origin)
+        }
+    }
+}
+
+impl<S: TextRenderer> Text<'_, S> {
+    fn transformation(&self) -> Transformation {
+        Transformation {
+            origin: self.position,
+            scale: self.text_style.scale,
+            rotation: self.text_style.rotation,
+        }
+    }
+
+    fn draw_untransformed<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = S::Color>,
     {
         let mut next_position = self.position;
 
+        let baseline = self.text_style.baseline;
+        let character_spacing = self.text_style.character_spacing;
+
         for (line, position) in self.lines() {
-            next_position = self.character_style.draw_string(
-                line,
-                position,
-                self.text_style.baseline,
-                target,
-            )?;
+            if character_spacing == 0 {
+                next_position = self
+                    .character_style
+                    .draw_string(line, position, baseline, target)?;
+                continue;
+            }
+
+            // Draw the characters one by one and fill the additional spacing by drawing
+            // whitespace, to make the background and decorations continuous.
+            next_position = position;
+            for (index, c) in line.char_indices() {
+                if index > 0 {
+                    next_position = self.character_style.draw_whitespace(
+                        character_spacing,
+                        next_position,
+                        baseline,
+                        target,
+                    )?;
+                }
+
+                next_position = self.character_style.draw_string(
+                    &line[index..index + c.len_utf8()],
+                    next_position,
+                    baseline,
+                    target,
+                )?;
+            }
         }
 
         Ok(next_position)
     }
 }
 
+impl<S> Text<'_, S>
+where
+    S: TextRenderer,
+    S::Color: Into<Rgb888> + From<Rgb888>,
+{
+    /// Draws the text using the opacity and blend mode of the text style.
+    ///
+    /// Each drawn pixel is combined with the current color of the pixel in the draw target, which
+    /// is why the draw target needs to implement [`GetPixel`]. Use [`draw`] to draw to targets
+    /// that can't read back their pixels, which ignores the opacity and blend mode.
+    ///
+    /// [`GetPixel`]: ../draw_target/trait.GetPixel.html
+    /// [`draw`]: ../trait.Drawable.html#tymethod.draw
+    pub fn draw_blended<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: GetPixel<Color = S::Color>,
+    {
+        self.draw(&mut target.blended(self.text_style.opacity, self.text_style.blend_mode))
+    }
+}
+
 fn update_min_max(min_max: &mut Option<(Point, Point)>, metrics: &TextMetrics) {
     if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
         if let Some((min, max)) = min_max {
@@ -182,14 +286,13 @@ impl<S: TextRenderer> Dimensions for Text<'_, S> {
         let mut min_max: Option<(Point, Point)> = None;
 
         for (line, position) in self.lines() {
-            let metrics =
-                self.character_style
-                    .measure_string(line, position, self.text_style.baseline);
+            let metrics = self.measure_line(line, position);
             update_min_max(&mut min_max, &metrics);
         }
 
         if let Some((min, max)) = min_max {
-            Rectangle::with_corners(min, max)
+            self.transformation()
+                .rectangle(&Rectangle::with_corners(min, max))
         } else {
             Rectangle::new(self.position, Size::zero())
         }
@@ -200,7 +303,8 @@ impl<S: TextRenderer> Dimensions for Text<'_, S> {
 mod tests {
     use super::*;
     use crate::{
-        geometry::Size,
+        draw_target::BlendMode,
+        geometry::{Rotation, Size},
         mock_display::MockDisplay,
         mono_font::{
             ascii::{FONT_6X13, FONT_6X9},
@@ -208,11 +312,11 @@ mod tests {
             MonoTextStyle, MonoTextStyleBuilder,
         },
         pixelcolor::BinaryColor,
-        primitives::{Primitive, PrimitiveStyle},
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
         text::{Alignment, Baseline, LineHeight, TextStyleBuilder},
     };
 
-    const HELLO_WORLD: &'static str = "Hello World!";
+    const HELLO_WORLD: &str = "Hello World!";
 
     #[test]
     fn constructor() {
@@ -727,4 +831,244 @@ mod tests {
             "####    ##  ",
         ]);
     }
+
+    #[test]
+    fn draw_blended() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .blend_mode(BlendMode::Xor)
+            .build();
+        let text = Text::with_text_style("AB", Point::zero(), character_style, text_style);
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+            .fill_solid(&text.bounding_box(), BinaryColor::On)
+            .unwrap();
+
+        text.draw_blended(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        expected
+            .fill_solid(&text.bounding_box(), BinaryColor::On)
+            .unwrap();
+        Text::with_text_style(
+            text.text,
+            text.position,
+            MonoTextStyle::new(&FONT_6X9, BinaryColor::Off),
+            text_style,
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    /// Draws the expected result of a scaled or rotated text by transforming the pixels of the
+    /// untransformed text.
+    fn transformed_pattern(text: &Text<MonoTextStyle<BinaryColor>>) -> MockDisplay<BinaryColor> {
+        let mut untransformed = MockDisplay::new();
+        untransformed.set_allow_out_of_bounds_drawing(true);
+        Text {
+            text_style: TextStyle {
+                scale: 1,
+                rotation: Rotation::Deg0,
+                ..text.text_style
+            },
+            ..*text
+        }
+        .draw(&mut untransformed)
+        .unwrap();
+
+        let mut expected = MockDisplay::new();
+        for point in untransformed.affected_area().points() {
+            if let Some(color) = untransformed.get_pixel(point) {
+                let area = text
+                    .transformation()
+                    .rectangle(&Rectangle::new(point, Size::new_equal(1)));
+                expected.fill_solid(&area, color).unwrap();
+            }
+        }
+
+        expected
+    }
+
+    #[test]
+    fn scale() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .scale(3)
+            .build();
+        let text = Text::with_text_style("Ag", Point::new(3, 4), character_style, text_style);
+
+        let mut display = MockDisplay::new();
+        let next = text.draw(&mut display).unwrap();
+
+        display.assert_eq(&transformed_pattern(&text));
+        assert_eq!(next, Point::new(3 + 12 * 3, 4));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(3, 4), Size::new(12 * 3, 9 * 3))
+        );
+    }
+
+    #[test]
+    fn scale_multiline() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .alignment(Alignment::Center)
+            .scale(2)
+            .build();
+        let text = Text::with_text_style("AB\nC", Point::new(12, 0), character_style, text_style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        display.assert_eq(&transformed_pattern(&text));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(2, 0), Size::new(12 * 2, 18 * 2))
+        );
+    }
+
+    #[test]
+    fn scale_zero() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text_style = TextStyleBuilder::new().scale(0).build();
+        let text = Text::with_text_style("A", Point::new(5, 10), character_style, text_style);
+
+        let mut display = MockDisplay::new();
+        assert_eq!(text.draw(&mut display).unwrap(), Point::new(5, 10));
+
+        display.assert_pattern(&[]);
+        assert_eq!(text.bounding_box().size, Size::zero());
+    }
+
+    #[test]
+    fn character_spacing() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .underline()
+            .build();
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .character_spacing(2)
+            .build();
+        let text = Text::with_text_style("AB\nC", Point::new(1, 2), character_style, text_style);
+
+        // The underline of the font overlaps the glyph cell.
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let next = text.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        for &(c, position) in &[
+            ("A", Point::new(1, 2)),
+            ("B", Point::new(9, 2)),
+            ("C", Point::new(1, 11)),
+        ] {
+            Text::with_baseline(c, position, character_style, Baseline::Top)
+                .draw(&mut expected)
+                .unwrap();
+        }
+        // Spacing between `A` and `B`, including the underline.
+        expected
+            .fill_solid(
+                &Rectangle::new(Point::new(7, 2), Size::new(2, 9)),
+                BinaryColor::Off,
+            )
+            .unwrap();
+        expected
+            .fill_solid(
+                &Rectangle::new(Point::new(7, 2 + 8), Size::new(2, 1)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, Point::new(7, 11));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::new(14, 18))
+        );
+    }
+
+    #[test]
+    fn character_spacing_right_aligned() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Right)
+            .baseline(Baseline::Top)
+            .character_spacing(3)
+            .build();
+        let text = Text::with_text_style("ABC", Point::new(30, 0), character_style, text_style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(30 - 24 + 1, 0), Size::new(24, 9))
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        for &(rotation, next) in &[
+            (Rotation::Deg90, Point::new(30 - 9, 32 + 6)),
+            (Rotation::Deg180, Point::new(30 - 6, 32 - 9)),
+            (Rotation::Deg270, Point::new(30 + 9, 32 - 6)),
+        ] {
+            let text_style = TextStyleBuilder::new().rotation(rotation).build();
+            let text =
+                Text::with_text_style("Ag\nB", Point::new(30, 32), character_style, text_style);
+
+            let mut display = MockDisplay::new();
+            assert_eq!(text.draw(&mut display).unwrap(), next, "{:?}", rotation);
+
+            display.assert_eq_with_message(&transformed_pattern(&text), |f| {
+                write!(f, "{:?}", rotation)
+            });
+            assert_eq!(
+                text.bounding_box(),
+                display.affected_area(),
+                "{:?}",
+                rotation
+            );
+        }
+    }
+
+    #[test]
+    fn rotation_and_scale() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .rotation(Rotation::Deg270)
+            .scale(2)
+            .build();
+        let text = Text::with_text_style("AB", Point::new(0, 40), character_style, text_style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        display.assert_eq(&transformed_pattern(&text));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(0, 40 - 23), Size::new(18, 24))
+        );
+    }
 }
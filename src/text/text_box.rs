@@ -0,0 +1,644 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{AnchorPoint, Dimensions, Point, Rotation},
+    primitives::Rectangle,
+    text::{
+        renderer::TextRenderer, text::measure_line, Alignment, Baseline, Overflow, Text, TextStyle,
+        TextStyleBuilder,
+    },
+    transform::Transform,
+    Drawable, SaturatingCast,
+};
+
+/// Text box drawable.
+///
+/// A text box draws a string inside a rectangular area. Lines which are wider than the text box
+/// are wrapped at the last space that fits inside the box. Words which don't fit into a single line
+/// are broken at the last character that fits.
+///
+/// Line breaks in the string start a new paragraph. The lines are positioned according to the
+/// alignment, line height and scale of the text style. The baseline setting of the text style is
+/// ignored, because the first line is always placed at the top edge of the text box. Text boxes
+/// can't be rotated, which is why the rotation setting is also ignored.
+///
+/// All drawing is clipped to the bounds of the text box, which means that text which doesn't fit
+/// into the box is cut off at the bottom edge.
+///
+/// Wrapping can be disabled by setting the [`overflow`] field. Each paragraph is then drawn as a
+/// single line, which is either clipped at the edge of the text box or truncated with an ellipsis.
+/// This is useful to draw strings of variable length, like file names, into a fixed width column.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     text::TextBox,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+/// let bounds = Rectangle::new(Point::zero(), Size::new(64, 40));
+///
+/// TextBox::new("Connecting to the network, please wait...", bounds, style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// Truncating a line which doesn't fit into the text box:
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     text::{Overflow, TextBox},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+/// let bounds = Rectangle::new(Point::zero(), Size::new(60, 10));
+///
+/// let mut text_box = TextBox::new("Network name: embedded-graphics", bounds, style);
+/// text_box.overflow = Overflow::Ellipsis;
+/// text_box.draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`overflow`]: #structfield.overflow
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TextBox<'a, S> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The bounds of the text box.
+    pub bounds: Rectangle,
+
+    /// The character style.
+    pub character_style: S,
+
+    /// The text style.
+    pub text_style: TextStyle,
+
+    /// The handling of lines which are wider than the text box.
+    pub overflow: Overflow,
+}
+
+impl<'a, S> TextBox<'a, S> {
+    /// Creates a text box with the default text style.
+    pub const fn new(text: &'a str, bounds: Rectangle, character_style: S) -> Self {
+        Self {
+            text,
+            bounds,
+            character_style,
+            text_style: TextStyleBuilder::new().build(),
+            overflow: Overflow::Wrap,
+        }
+    }
+
+    /// Creates a text box with the given text style.
+    pub const fn with_text_style(
+        text: &'a str,
+        bounds: Rectangle,
+        character_style: S,
+        text_style: TextStyle,
+    ) -> Self {
+        Self {
+            text,
+            bounds,
+            character_style,
+            text_style,
+            overflow: Overflow::Wrap,
+        }
+    }
+}
+
+impl<S: Clone> Transform for TextBox<'_, S> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            bounds: self.bounds.translate(by),
+            ..self.clone()
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.bounds.translate_mut(by);
+
+        self
+    }
+}
+
+impl<S: TextRenderer + Clone> TextBox<'_, S> {
+    fn draw_lines<'b, I, D>(&self, lines: I, target: &mut D) -> Result<(), D::Error>
+    where
+        I: Iterator<Item = &'b str>,
+        D: DrawTarget<Color = S::Color>,
+    {
+        let anchor = match self.text_style.alignment {
+            Alignment::Left => AnchorPoint::TopLeft,
+            Alignment::Center => AnchorPoint::TopCenter,
+            Alignment::Right => AnchorPoint::TopRight,
+        };
+        let mut position = self.bounds.anchor_point(anchor);
+
+        let line_height: i32 = (self
+            .text_style
+            .line_height
+            .to_absolute(self.character_style.line_height())
+            * self.text_style.scale)
+            .saturating_cast();
+        let bottom = self.bounds.top_left.y + self.bounds.size.height.saturating_cast();
+
+        for line in lines {
+            if position.y >= bottom {
+                break;
+            }
+
+            if self.overflow == Overflow::Ellipsis
+                && line_width(
+                    &self.character_style,
+                    line,
+                    self.text_style.character_spacing,
+                ) > self.unscaled_width()
+            {
+                self.draw_truncated(line, position, target)?;
+            } else {
+                Text::with_text_style(
+                    line,
+                    position,
+                    self.character_style.clone(),
+                    self.line_text_style(),
+                )
+                .draw(target)?;
+            }
+
+            position.y += line_height;
+        }
+
+        Ok(())
+    }
+
+    /// Draws the longest prefix of a line which fits into the text box followed by an ellipsis.
+    fn draw_truncated<D>(&self, line: &str, position: Point, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = S::Color>,
+    {
+        let spacing = self.text_style.character_spacing;
+        let width = self.unscaled_width();
+        let ellipsis_width = line_width(&self.character_style, ELLIPSIS, spacing);
+
+        // Width of the prefix and the ellipsis, including the spacing between them.
+        let total_width = |prefix: &str| {
+            if prefix.is_empty() {
+                ellipsis_width
+            } else {
+                line_width(&self.character_style, prefix, spacing) + spacing + ellipsis_width
+            }
+        };
+
+        let end = line
+            .char_indices()
+            .map(|(index, c)| index + c.len_utf8())
+            .take_while(|index| total_width(&line[..*index]) <= width)
+            .last()
+            .unwrap_or(0);
+        let prefix = line[..end].trim_end_matches(' ');
+
+        // The prefix and the ellipsis are drawn separately, which is why the alignment needs to be
+        // applied to the combined width.
+        let offset = total_width(prefix).saturating_sub(1);
+        let offset = match self.text_style.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => offset / 2,
+            Alignment::Right => offset,
+        };
+        let scale = self.text_style.scale;
+        let position = position - Point::new((offset * scale).saturating_cast(), 0);
+
+        let text_style = TextStyle {
+            alignment: Alignment::Left,
+            ..self.line_text_style()
+        };
+
+        let mut next =
+            Text::with_text_style(prefix, position, self.character_style.clone(), text_style)
+                .draw(target)?;
+        if !prefix.is_empty() {
+            next.x += (spacing * scale).saturating_cast();
+        }
+
+        Text::with_text_style(ELLIPSIS, next, self.character_style.clone(), text_style)
+            .draw(target)?;
+
+        Ok(())
+    }
+
+    /// Returns the text style which is used to draw a single line.
+    fn line_text_style(&self) -> TextStyle {
+        TextStyle {
+            baseline: Baseline::Top,
+            rotation: Rotation::Deg0,
+            ..self.text_style
+        }
+    }
+
+    /// Returns the width of the text box in unscaled coordinates.
+    ///
+    /// Lines are measured in unscaled coordinates, because the text is scaled when it's drawn.
+    fn unscaled_width(&self) -> u32 {
+        self.bounds.size.width / self.text_style.scale.max(1)
+    }
+}
+
+impl<S: TextRenderer + Clone> Drawable for TextBox<'_, S> {
+    type Color = S::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut target = target.clipped(&self.bounds);
+
+        match self.overflow {
+            Overflow::Wrap => self.draw_lines(
+                WrappedLines::new(
+                    self.text,
+                    &self.character_style,
+                    self.unscaled_width(),
+                    self.text_style.character_spacing,
+                ),
+                &mut target,
+            ),
+            Overflow::Clip | Overflow::Ellipsis => self.draw_lines(self.text.lines(), &mut target),
+        }
+    }
+}
+
+impl<S> Dimensions for TextBox<'_, S> {
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+/// String which is appended to truncated lines.
+const ELLIPSIS: &str = "...";
+
+/// Returns the width of a single line of text.
+fn line_width<S: TextRenderer>(character_style: &S, text: &str, character_spacing: u32) -> u32 {
+    measure_line(
+        character_style,
+        text,
+        Point::zero(),
+        Baseline::Top,
+        character_spacing,
+    )
+    .bounding_box
+    .size
+    .width
+}
+
+/// Iterator over the wrapped lines in a string.
+struct WrappedLines<'a, 'b, S> {
+    paragraphs: core::str::Lines<'a>,
+    paragraph: Option<&'a str>,
+    character_style: &'b S,
+    width: u32,
+    character_spacing: u32,
+}
+
+impl<'a, 'b, S: TextRenderer> WrappedLines<'a, 'b, S> {
+    fn new(text: &'a str, character_style: &'b S, width: u32, character_spacing: u32) -> Self {
+        Self {
+            paragraphs: text.lines(),
+            paragraph: None,
+            character_style,
+            width,
+            character_spacing,
+        }
+    }
+
+    /// Returns `true` if the text fits into a single line.
+    fn fits(&self, text: &str) -> bool {
+        line_width(self.character_style, text, self.character_spacing) <= self.width
+    }
+
+    /// Splits the text after the longest prefix that fits into a single line.
+    fn split(&self, text: &'a str) -> (&'a str, &'a str) {
+        let space_indices = text
+            .char_indices()
+            .filter(|(_, c)| *c == ' ')
+            .map(|(index, _)| index)
+            .chain(core::iter::once(text.len()));
+
+        let mut end = None;
+        for index in space_indices {
+            if !self.fits(&text[..index]) {
+                break;
+            }
+            end = Some(index);
+        }
+
+        // Break words which are wider than a single line. At least one character is returned to
+        // make sure that the iterator makes progress.
+        let end = end.filter(|end| *end > 0).unwrap_or_else(|| {
+            text.char_indices()
+                .map(|(index, c)| index + c.len_utf8())
+                .take_while(|index| self.fits(&text[..*index]))
+                .last()
+                .or_else(|| text.chars().next().map(char::len_utf8))
+                .unwrap_or(0)
+        });
+
+        (&text[..end], &text[end..])
+    }
+}
+
+impl<'a, S: TextRenderer> Iterator for WrappedLines<'a, '_, S> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let paragraph = match self.paragraph.take() {
+            Some(paragraph) => paragraph,
+            None => {
+                let paragraph = self.paragraphs.next()?;
+
+                // Empty paragraphs are returned as empty lines.
+                if paragraph.is_empty() {
+                    return Some(paragraph);
+                }
+
+                paragraph
+            }
+        };
+
+        let (line, rest) = self.split(paragraph);
+
+        let rest = rest.trim_start_matches(' ');
+        if !rest.is_empty() {
+            self.paragraph = Some(rest);
+        }
+
+        Some(line.trim_end_matches(' '))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        pixelcolor::BinaryColor,
+        text::{DecorationColor, LineHeight},
+    };
+
+    const STYLE: MonoTextStyle<'static, BinaryColor> = MonoTextStyle {
+        text_color: Some(BinaryColor::On),
+        background_color: None,
+        underline_color: DecorationColor::None,
+        strikethrough_color: DecorationColor::None,
+        font: &FONT_6X9,
+    };
+
+    fn wrap(text: &str, width: u32) -> impl Iterator<Item = &str> {
+        WrappedLines::new(text, &STYLE, width, 0)
+    }
+
+    #[test]
+    fn wrap_words() {
+        assert!(wrap("Hello world foo", 6 * 6).eq(["Hello", "world", "foo"].iter().copied()));
+        assert!(wrap("Hello world foo", 6 * 11).eq(["Hello world", "foo"].iter().copied()));
+        assert!(wrap("Hello world foo", 6 * 15).eq(["Hello world foo"].iter().copied()));
+    }
+
+    #[test]
+    fn wrap_multiple_spaces() {
+        assert!(wrap("ab   cd  ", 6 * 3).eq(["ab", "cd"].iter().copied()));
+    }
+
+    #[test]
+    fn wrap_long_word() {
+        assert!(wrap("abcdefgh ij", 6 * 3).eq(["abc", "def", "gh", "ij"].iter().copied()));
+    }
+
+    #[test]
+    fn wrap_zero_width() {
+        assert!(wrap("ab", 0).eq(["a", "b"].iter().copied()));
+    }
+
+    #[test]
+    fn wrap_character_spacing() {
+        let wrap = |width| WrappedLines::new("ab cd", &STYLE, width, 2);
+
+        assert!(wrap(6 * 5 + 2 * 4).eq(["ab cd"].iter().copied()));
+        assert!(wrap(6 * 5 + 2 * 4 - 1).eq(["ab", "cd"].iter().copied()));
+    }
+
+    #[test]
+    fn wrap_paragraphs() {
+        assert!(wrap("ab cd\n\nef", 6 * 3).eq(["ab", "cd", "", "ef"].iter().copied()));
+    }
+
+    #[test]
+    fn draw() {
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("Hello\nworld", Point::new(1, 2), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        TextBox::new(
+            "Hello world",
+            Rectangle::new(Point::new(1, 2), Size::new(6 * 8, 30)),
+            STYLE,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_clipped_at_bottom() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 3, 13));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("abc\ndef", Point::zero(), STYLE, Baseline::Top)
+            .draw(&mut expected.clipped(&bounds))
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        TextBox::new("abc def ghi", bounds, STYLE)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_aligned() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 5, 30));
+
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Right)
+            .line_height(LineHeight::Pixels(10))
+            .build();
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("abcd", Point::new(6, 0), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+        Text::with_baseline("ef", Point::new(18, 10), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        TextBox::with_text_style("abcd ef", bounds, STYLE, text_style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_scaled() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 3 * 2, 40));
+        let text_style = TextStyleBuilder::new().scale(2).build();
+
+        let mut expected = MockDisplay::new();
+        Text::with_text_style(
+            "abc\ndef",
+            Point::zero(),
+            STYLE,
+            TextStyleBuilder::from(&text_style)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        let mut display = MockDisplay::new();
+        TextBox::with_text_style("abc def", bounds, STYLE, text_style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    fn draw_with_overflow(
+        text: &str,
+        bounds: Rectangle,
+        text_style: TextStyle,
+        overflow: Overflow,
+    ) -> MockDisplay<BinaryColor> {
+        let mut text_box = TextBox::with_text_style(text, bounds, STYLE, text_style);
+        text_box.overflow = overflow;
+
+        let mut display = MockDisplay::new();
+        text_box.draw(&mut display).unwrap();
+
+        display
+    }
+
+    #[test]
+    fn draw_clip() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 3, 20));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("abc def\ngh", Point::zero(), STYLE, Baseline::Top)
+            .draw(&mut expected.clipped(&bounds))
+            .unwrap();
+
+        let text_style = TextStyleBuilder::new().build();
+        draw_with_overflow("abc def\ngh", bounds, text_style, Overflow::Clip).assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_ellipsis() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 6 + 5, 20));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("abc...\ngh", Point::zero(), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        let text_style = TextStyleBuilder::new().build();
+        draw_with_overflow("abcdefgh\ngh", bounds, text_style, Overflow::Ellipsis)
+            .assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_ellipsis_trims_spaces() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 6, 10));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("ab...", Point::zero(), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        let text_style = TextStyleBuilder::new().build();
+        draw_with_overflow("ab cdefgh", bounds, text_style, Overflow::Ellipsis)
+            .assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_ellipsis_right_aligned() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 7 + 2, 10));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("abcd...", Point::new(2, 0), STYLE, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        let text_style = TextStyleBuilder::new().alignment(Alignment::Right).build();
+        draw_with_overflow("abcdefgh", bounds, text_style, Overflow::Ellipsis).assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_ellipsis_character_spacing() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 5 + 4 * 2, 10));
+        let text_style = TextStyleBuilder::new().character_spacing(2).build();
+
+        let mut expected = MockDisplay::new();
+        Text::with_text_style(
+            "ab...",
+            Point::zero(),
+            STYLE,
+            TextStyleBuilder::from(&text_style)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        draw_with_overflow("abcdefgh", bounds, text_style, Overflow::Ellipsis).assert_eq(&expected);
+    }
+
+    #[test]
+    fn draw_ellipsis_too_narrow() {
+        let bounds = Rectangle::new(Point::zero(), Size::new(6 * 2, 10));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("...", Point::zero(), STYLE, Baseline::Top)
+            .draw(&mut expected.clipped(&bounds))
+            .unwrap();
+
+        let text_style = TextStyleBuilder::new().build();
+        draw_with_overflow("abcdefgh", bounds, text_style, Overflow::Ellipsis).assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bounds = Rectangle::new(Point::new(1, 2), Size::new(3, 4));
+
+        assert_eq!(TextBox::new("abc", bounds, STYLE).bounding_box(), bounds);
+    }
+}
@@ -0,0 +1,487 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Rotation, Size},
+    primitives::Rectangle,
+    text::{
+        renderer::TextRenderer, text::measure_line, Alignment, Text, TextStyle, TextStyleBuilder,
+    },
+    transform::Transform,
+    Drawable, SaturatingCast,
+};
+
+/// Text spans drawable.
+///
+/// Text spans draw a sequence of strings with different character styles, which makes it possible
+/// to combine different colors or fonts on a single line of text. Each span continues at the
+/// position where the previous span ended. Line breaks inside a span start a new line, which
+/// continues to use the character style of the span.
+///
+/// The spans share a single text style. The alignment is applied to the combined width of all
+/// spans on a line and the line height is based on the tallest character style on a line. Text
+/// spans can't be rotated, which is why the rotation setting of the text style is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::TextSpans,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let label = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+/// let warning = MonoTextStyle::new(&FONT_6X10, Rgb565::RED);
+///
+/// let spans = [("CPU: ", label), ("98%", warning)];
+///
+/// TextSpans::new(&spans, Point::new(5, 20)).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TextSpans<'a, S> {
+    /// The spans.
+    ///
+    /// Each span consists of a string and the character style which is used to draw it.
+    pub spans: &'a [(&'a str, S)],
+
+    /// The position.
+    pub position: Point,
+
+    /// The text style.
+    pub text_style: TextStyle,
+}
+
+impl<'a, S> TextSpans<'a, S> {
+    /// Creates a text spans drawable with the default text style.
+    pub const fn new(spans: &'a [(&'a str, S)], position: Point) -> Self {
+        Self {
+            spans,
+            position,
+            text_style: TextStyleBuilder::new().build(),
+        }
+    }
+
+    /// Creates a text spans drawable with the given text style.
+    pub const fn with_text_style(
+        spans: &'a [(&'a str, S)],
+        position: Point,
+        text_style: TextStyle,
+    ) -> Self {
+        Self {
+            spans,
+            position,
+            text_style,
+        }
+    }
+}
+
+impl<S> Transform for TextSpans<'_, S> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+impl<'a, S: TextRenderer + Clone> TextSpans<'a, S> {
+    /// Returns a text drawable for each line of each span.
+    fn layout(&self) -> Layout<'a, S> {
+        Layout {
+            segments: Segments {
+                spans: self.spans.iter(),
+                lines: None,
+                new_line: false,
+            },
+            text_style: TextStyle {
+                alignment: Alignment::Left,
+                rotation: Rotation::Deg0,
+                ..self.text_style
+            },
+            alignment: self.text_style.alignment,
+            line_position: self.position,
+            position: None,
+            previous_is_empty: true,
+            line_height: 0,
+        }
+    }
+}
+
+impl<S: TextRenderer + Clone> Drawable for TextSpans<'_, S> {
+    type Color = S::Color;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut next_position = self.position;
+
+        for text in self.layout() {
+            next_position = text.draw(target)?;
+        }
+
+        Ok(next_position)
+    }
+}
+
+impl<S: TextRenderer + Clone> Dimensions for TextSpans<'_, S> {
+    fn bounding_box(&self) -> Rectangle {
+        let mut min_max: Option<(Point, Point)> = None;
+
+        for text in self.layout() {
+            let bounding_box = text.bounding_box();
+
+            if let Some(bottom_right) = bounding_box.bottom_right() {
+                if let Some((min, max)) = &mut min_max {
+                    *min = min.component_min(bounding_box.top_left);
+                    *max = max.component_max(bottom_right);
+                } else {
+                    min_max = Some((bounding_box.top_left, bottom_right));
+                }
+            }
+        }
+
+        if let Some((min, max)) = min_max {
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::new(self.position, Size::zero())
+        }
+    }
+}
+
+/// Part of a span which doesn't contain line breaks.
+struct Segment<'a, S> {
+    text: &'a str,
+    character_style: &'a S,
+    new_line: bool,
+}
+
+/// Iterator over the segments in a list of spans.
+#[derive(Clone)]
+struct Segments<'a, S> {
+    spans: core::slice::Iter<'a, (&'a str, S)>,
+    lines: Option<(core::str::Split<'a, char>, &'a S)>,
+    new_line: bool,
+}
+
+impl<'a, S> Iterator for Segments<'a, S> {
+    type Item = Segment<'a, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((lines, character_style)) = &mut self.lines {
+                if let Some(text) = lines.next() {
+                    let new_line = self.new_line;
+                    self.new_line = true;
+
+                    return Some(Segment {
+                        text: text.trim_end_matches('\r'),
+                        character_style,
+                        new_line,
+                    });
+                }
+            }
+
+            let (text, character_style) = self.spans.next()?;
+            self.lines = Some((text.split('\n'), character_style));
+            self.new_line = false;
+        }
+    }
+}
+
+/// Iterator which positions the segments of a list of spans.
+struct Layout<'a, S> {
+    segments: Segments<'a, S>,
+    text_style: TextStyle,
+    alignment: Alignment,
+    /// Unaligned position of the current line.
+    line_position: Point,
+    /// Position of the next segment, or `None` if no segment was returned yet.
+    position: Option<Point>,
+    previous_is_empty: bool,
+    /// Maximum line height of all character styles on the current line.
+    line_height: u32,
+}
+
+impl<'a, S: TextRenderer + Clone> Layout<'a, S> {
+    /// Returns the unscaled width of a segment.
+    fn width(&self, segment: &Segment<'_, S>) -> u32 {
+        measure_line(
+            segment.character_style,
+            segment.text,
+            Point::zero(),
+            self.text_style.baseline,
+            self.text_style.character_spacing,
+        )
+        .bounding_box
+        .size
+        .width
+    }
+
+    /// Returns the unscaled width of the line which starts with the given segment.
+    fn line_width(&self, first: &Segment<'_, S>) -> u32 {
+        let spacing = self.text_style.character_spacing;
+
+        let mut width = self.width(first);
+        let mut previous_is_empty = first.text.is_empty();
+
+        for segment in self
+            .segments
+            .clone()
+            .take_while(|segment| !segment.new_line)
+        {
+            if segment.text.is_empty() {
+                continue;
+            }
+
+            if !previous_is_empty {
+                width += spacing;
+            }
+            width += self.width(&segment);
+            previous_is_empty = false;
+        }
+
+        width
+    }
+}
+
+impl<'a, S: TextRenderer + Clone> Iterator for Layout<'a, S> {
+    type Item = Text<'a, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let segment = self.segments.next()?;
+        let scale = self.text_style.scale;
+
+        let mut position = match self.position {
+            Some(position) if !segment.new_line => position,
+            _ => {
+                if segment.new_line {
+                    let line_height = self.text_style.line_height.to_absolute(self.line_height);
+                    self.line_position.y += (line_height * scale).saturating_cast();
+                }
+
+                self.line_height = 0;
+                self.previous_is_empty = true;
+
+                let offset = self.line_width(&segment).saturating_sub(1);
+                let offset = match self.alignment {
+                    Alignment::Left => 0,
+                    Alignment::Center => offset / 2,
+                    Alignment::Right => offset,
+                };
+
+                self.line_position - Point::new((offset * scale).saturating_cast(), 0)
+            }
+        };
+
+        if !segment.text.is_empty() {
+            if !self.previous_is_empty {
+                position.x += (self.text_style.character_spacing * scale).saturating_cast();
+            }
+            self.previous_is_empty = false;
+        }
+
+        self.line_height = self.line_height.max(segment.character_style.line_height());
+
+        let text = Text::with_text_style(
+            segment.text,
+            position,
+            segment.character_style.clone(),
+            self.text_style,
+        );
+
+        position.x += (self.width(&segment) * scale).saturating_cast();
+        self.position = Some(position);
+
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        mono_font::{
+            ascii::{FONT_6X13, FONT_6X9},
+            MonoTextStyle,
+        },
+        pixelcolor::BinaryColor,
+        text::{Baseline, DecorationColor},
+    };
+
+    const ON: MonoTextStyle<'static, BinaryColor> = MonoTextStyle {
+        text_color: Some(BinaryColor::On),
+        background_color: None,
+        underline_color: DecorationColor::None,
+        strikethrough_color: DecorationColor::None,
+        font: &FONT_6X9,
+    };
+
+    const OFF: MonoTextStyle<'static, BinaryColor> = MonoTextStyle {
+        text_color: Some(BinaryColor::Off),
+        ..ON
+    };
+
+    const TOP: TextStyle = TextStyleBuilder::new().baseline(Baseline::Top).build();
+
+    fn draw_texts(texts: &[Text<MonoTextStyle<BinaryColor>>]) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        for text in texts {
+            text.draw(&mut display).unwrap();
+        }
+
+        display
+    }
+
+    #[test]
+    fn single_line() {
+        let expected = draw_texts(&[
+            Text::with_text_style("ab", Point::new(1, 2), ON, TOP),
+            Text::with_text_style("c", Point::new(13, 2), OFF, TOP),
+        ]);
+
+        let mut display = MockDisplay::new();
+        let next = TextSpans::with_text_style(&[("ab", ON), ("c", OFF)], Point::new(1, 2), TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, Point::new(19, 2));
+    }
+
+    #[test]
+    fn multiple_lines() {
+        let expected = draw_texts(&[
+            Text::with_text_style("ab", Point::zero(), ON, TOP),
+            Text::with_text_style("c", Point::new(12, 0), OFF, TOP),
+            Text::with_text_style("d", Point::new(0, 9), OFF, TOP),
+            Text::with_text_style("e", Point::new(6, 9), ON, TOP),
+        ]);
+
+        let mut display = MockDisplay::new();
+        TextSpans::with_text_style(&[("ab", ON), ("c\nd", OFF), ("e", ON)], Point::zero(), TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn line_height_of_tallest_style() {
+        let large = MonoTextStyle::new(&FONT_6X13, BinaryColor::On);
+
+        let expected = draw_texts(&[
+            Text::with_text_style("a", Point::zero(), ON, TOP),
+            Text::with_text_style("b", Point::new(6, 0), large, TOP),
+            Text::with_text_style("c", Point::new(0, 13), ON, TOP),
+        ]);
+
+        let mut display = MockDisplay::new();
+        TextSpans::with_text_style(&[("a", ON), ("b\n", large), ("c", ON)], Point::zero(), TOP)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn right_aligned() {
+        let text_style = TextStyleBuilder::from(&TOP)
+            .alignment(Alignment::Right)
+            .build();
+
+        let expected = draw_texts(&[
+            Text::with_text_style("ab", Point::new(12, 0), ON, TOP),
+            Text::with_text_style("c", Point::new(24, 0), OFF, TOP),
+            Text::with_text_style("d", Point::new(24, 9), OFF, TOP),
+        ]);
+
+        let mut display = MockDisplay::new();
+        TextSpans::with_text_style(&[("ab", ON), ("c\nd", OFF)], Point::new(29, 0), text_style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn character_spacing() {
+        let text_style = TextStyleBuilder::from(&TOP).character_spacing(2).build();
+
+        let expected = draw_texts(&[
+            Text::with_text_style("ab", Point::zero(), ON, text_style),
+            Text::with_text_style("c", Point::new(16, 0), OFF, text_style),
+        ]);
+
+        let mut display = MockDisplay::new();
+        TextSpans::with_text_style(
+            &[("ab", ON), ("", ON), ("c", OFF)],
+            Point::zero(),
+            text_style,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn scaled() {
+        let text_style = TextStyleBuilder::from(&TOP).scale(2).build();
+
+        let expected = draw_texts(&[
+            Text::with_text_style("a", Point::zero(), ON, text_style),
+            Text::with_text_style("b", Point::new(12, 0), OFF, text_style),
+            Text::with_text_style("c", Point::new(0, 18), ON, text_style),
+        ]);
+
+        let mut display = MockDisplay::new();
+        TextSpans::with_text_style(
+            &[("a", ON), ("b\n", OFF), ("c", ON)],
+            Point::zero(),
+            text_style,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let spans = [("ab", ON), ("c\nd", OFF)];
+
+        assert_eq!(
+            TextSpans::with_text_style(&spans, Point::new(1, 2), TOP).bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::new(6 * 3, 9 * 2))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let spans: [(&str, MonoTextStyle<BinaryColor>); 0] = [];
+
+        let mut display = MockDisplay::new();
+        let next = TextSpans::new(&spans, Point::new(1, 2))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[]);
+        assert_eq!(next, Point::new(1, 2));
+        assert_eq!(
+            TextSpans::new(&spans, Point::new(1, 2)).bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero())
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use crate::text::{Alignment, Baseline, LineHeight};
+use crate::{
+    draw_target::BlendMode,
+    geometry::Rotation,
+    text::{Alignment, Baseline, LineHeight},
+};
 
 /// Text style.
 ///
@@ -21,6 +25,40 @@ pub struct TextStyle {
 
     /// Line height.
     pub line_height: LineHeight,
+
+    /// Character spacing.
+    ///
+    /// Additional horizontal spacing in pixels, which is added between adjacent characters. The
+    /// spacing is added to the spacing that is defined by the character style.
+    pub character_spacing: u32,
+
+    /// Scale.
+    ///
+    /// Each pixel drawn by the character style is scaled to a block of `scale` by `scale` pixels,
+    /// which makes it possible to use a small font for headings and body text. The text is scaled
+    /// around the text position. A scale of `0` doesn't draw any pixels.
+    pub scale: u32,
+
+    /// Rotation.
+    ///
+    /// The text is rotated around the text position.
+    pub rotation: Rotation,
+
+    /// Opacity.
+    ///
+    /// The opacity ranges from `0` for fully transparent to `255` for fully opaque text.
+    ///
+    /// The opacity and the blend mode are only applied if the text is drawn using
+    /// [`Text::draw_blended`], which requires a draw target that can read back its pixels.
+    /// Drawing the text using [`Drawable::draw`] ignores both properties and draws opaque
+    /// pixels.
+    ///
+    /// [`Text::draw_blended`]: struct.Text.html#method.draw_blended
+    /// [`Drawable::draw`]: ../trait.Drawable.html#tymethod.draw
+    pub opacity: u8,
+
+    /// Blend mode.
+    pub blend_mode: BlendMode,
 }
 
 impl TextStyle {
@@ -55,11 +93,22 @@ impl TextStyleBuilder {
                 alignment: Alignment::Left,
                 baseline: Baseline::Alphabetic,
                 line_height: LineHeight::Percent(100),
+                character_spacing: 0,
+                scale: 1,
+                rotation: Rotation::Deg0,
+                opacity: 255,
+                blend_mode: BlendMode::SourceOver,
             },
         }
     }
 }
 
+impl Default for TextStyleBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TextStyleBuilder {
     /// Sets the horizontal text alignment.
     pub const fn alignment(mut self, alignment: Alignment) -> Self {
@@ -82,6 +131,41 @@ impl TextStyleBuilder {
         self
     }
 
+    /// Sets the additional character spacing.
+    pub const fn character_spacing(mut self, character_spacing: u32) -> Self {
+        self.style.character_spacing = character_spacing;
+
+        self
+    }
+
+    /// Sets the scale.
+    pub const fn scale(mut self, scale: u32) -> Self {
+        self.style.scale = scale;
+
+        self
+    }
+
+    /// Sets the rotation.
+    pub const fn rotation(mut self, rotation: Rotation) -> Self {
+        self.style.rotation = rotation;
+
+        self
+    }
+
+    /// Sets the opacity.
+    pub const fn opacity(mut self, opacity: u8) -> Self {
+        self.style.opacity = opacity;
+
+        self
+    }
+
+    /// Sets the blend mode.
+    pub const fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.style.blend_mode = blend_mode;
+
+        self
+    }
+
     /// Builds the text style.
     pub const fn build(self) -> TextStyle {
         self.style
@@ -104,11 +188,21 @@ mod tests {
             .alignment(Alignment::Right)
             .baseline(Baseline::Top)
             .line_height(LineHeight::Pixels(123))
+            .character_spacing(2)
+            .scale(3)
+            .rotation(Rotation::Deg270)
+            .opacity(50)
+            .blend_mode(BlendMode::Xor)
             .build();
 
         assert_eq!(text_style.alignment, Alignment::Right);
         assert_eq!(text_style.baseline, Baseline::Top);
         assert_eq!(text_style.line_height, LineHeight::Pixels(123));
+        assert_eq!(text_style.character_spacing, 2);
+        assert_eq!(text_style.scale, 3);
+        assert_eq!(text_style.rotation, Rotation::Deg270);
+        assert_eq!(text_style.opacity, 50);
+        assert_eq!(text_style.blend_mode, BlendMode::Xor);
     }
 
     #[test]
@@ -118,5 +212,10 @@ mod tests {
         assert_eq!(text_style.alignment, Alignment::Left);
         assert_eq!(text_style.baseline, Baseline::Alphabetic);
         assert_eq!(text_style.line_height, LineHeight::Percent(100));
+        assert_eq!(text_style.character_spacing, 0);
+        assert_eq!(text_style.scale, 1);
+        assert_eq!(text_style.rotation, Rotation::Deg0);
+        assert_eq!(text_style.opacity, 255);
+        assert_eq!(text_style.blend_mode, BlendMode::SourceOver);
     }
 }
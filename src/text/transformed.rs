@@ -0,0 +1,82 @@
+use crate::{
+    geometry::{Point, Rotation, Size},
+    primitives::Rectangle,
+    SaturatingCast,
+};
+
+/// Scaling and rotation around an origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Transformation {
+    pub origin: Point,
+    pub scale: u32,
+    pub rotation: Rotation,
+}
+
+impl Transformation {
+    /// Returns `true` if the transformation doesn't change the position of any point.
+    pub fn is_identity(&self) -> bool {
+        self.scale == 1 && self.rotation == Rotation::Deg0
+    }
+
+    /// Transforms a point.
+    pub fn point(&self, point: Point) -> Point {
+        self.origin
+            + self
+                .rotation
+                .rotate((point - self.origin) * self.scale.saturating_cast())
+    }
+
+    /// Transforms the area that is covered by a rectangle.
+    pub fn rectangle(&self, rectangle: &Rectangle) -> Rectangle {
+        let scaled = Rectangle::new(
+            self.origin + (rectangle.top_left - self.origin) * self.scale.saturating_cast(),
+            rectangle.size * self.scale,
+        );
+
+        match scaled.bottom_right() {
+            Some(bottom_right) => Rectangle::with_corners(
+                self.origin + self.rotation.rotate(scaled.top_left - self.origin),
+                self.origin + self.rotation.rotate(bottom_right - self.origin),
+            ),
+            None => Rectangle::new(self.point(rectangle.top_left), Size::zero()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangle() {
+        let origin = Point::new(10, 20);
+        let rectangle = Rectangle::new(Point::new(11, 20), Size::new(3, 2));
+
+        let transform = |scale, rotation| {
+            Transformation {
+                origin,
+                scale,
+                rotation,
+            }
+            .rectangle(&rectangle)
+        };
+
+        assert_eq!(transform(1, Rotation::Deg0), rectangle);
+        assert_eq!(
+            transform(2, Rotation::Deg0),
+            Rectangle::new(Point::new(12, 20), Size::new(6, 4))
+        );
+        assert_eq!(
+            transform(1, Rotation::Deg90),
+            Rectangle::new(Point::new(9, 21), Size::new(2, 3))
+        );
+        assert_eq!(
+            transform(1, Rotation::Deg180),
+            Rectangle::new(Point::new(7, 19), Size::new(3, 2))
+        );
+        assert_eq!(
+            transform(1, Rotation::Deg270),
+            Rectangle::new(Point::new(10, 17), Size::new(2, 3))
+        );
+    }
+}
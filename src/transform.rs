@@ -1,6 +1,6 @@
 //! Transformations for graphics objects
 
-use crate::geometry::Point;
+use crate::geometry::{div_round, Point};
 
 /// Transform operations
 pub trait Transform {
@@ -11,3 +11,365 @@ pub trait Transform {
     /// in place
     fn translate_mut(&mut self, by: Point) -> &mut Self;
 }
+
+/// Rotation in steps of 90°.
+///
+/// All rotations are clockwise and use the center of the pixel at `center` as the center of
+/// rotation. Because rotations by multiples of 90° map pixels onto pixels, the rotated object
+/// covers exactly the rotated pixels of the original object.
+///
+/// Primitives which reference their vertices by a slice, like [`Polyline`] or [`Polygon`], can't
+/// be rotated without allocating a new vertex array and don't implement this trait. Images and
+/// text can be rotated by drawing them to a [`transformed`] draw target.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// let line = Line::new(Point::new(10, 10), Point::new(20, 10));
+///
+/// assert_eq!(
+///     line.rotate_90(Point::new(10, 10)),
+///     Line::new(Point::new(10, 10), Point::new(10, 20))
+/// );
+/// ```
+///
+/// [`Polyline`]: ../primitives/polyline/struct.Polyline.html
+/// [`Polygon`]: ../primitives/polygon/struct.Polygon.html
+/// [`transformed`]: ../draw_target/trait.DrawTargetExt.html#tymethod.transformed
+pub trait Rotate: Sized {
+    /// Rotates the object by 90° clockwise around `center`.
+    fn rotate_90(&self, center: Point) -> Self;
+
+    /// Rotates the object by 180° around `center`.
+    fn rotate_180(&self, center: Point) -> Self {
+        self.rotate_90(center).rotate_90(center)
+    }
+
+    /// Rotates the object by 270° clockwise around `center`.
+    fn rotate_270(&self, center: Point) -> Self {
+        self.rotate_180(center).rotate_90(center)
+    }
+}
+
+/// Mirroring at horizontal or vertical axes.
+///
+/// The mirror axes pass through the center of a pixel row or column.
+///
+/// # Examples
+///
+/// Flip a layout horizontally for a display that is mounted upside down:
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let display_size = Size::new(64, 32);
+/// let rectangle = Rectangle::new(Point::new(2, 3), Size::new(10, 5));
+///
+/// let flipped = rectangle
+///     .mirror_x((display_size.width as i32 - 1) / 2)
+///     .mirror_y((display_size.height as i32 - 1) / 2)
+///     .translate(Point::new(1, 1));
+///
+/// assert_eq!(flipped, Rectangle::new(Point::new(52, 24), Size::new(10, 5)));
+/// ```
+///
+/// The same restrictions as for [`Rotate`] apply to the types which implement this trait.
+///
+/// [`Rotate`]: trait.Rotate.html
+pub trait Mirror {
+    /// Mirrors the X coordinates of the object at the vertical line through `x = axis`.
+    fn mirror_x(&self, axis: i32) -> Self;
+
+    /// Mirrors the Y coordinates of the object at the horizontal line through `y = axis`.
+    fn mirror_y(&self, axis: i32) -> Self;
+}
+
+/// Scaling by rational factors.
+///
+/// The scaling factor is given as a fraction `num / denom` to make it possible to zoom in and out
+/// without floating point calculations. All coordinates and lengths are rounded to the nearest
+/// integer after scaling. Styles, like the stroke width of a styled primitive, aren't scaled.
+///
+/// The same restrictions as for [`Rotate`] apply to the types which implement this trait.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Circle};
+///
+/// let circle = Circle::new(Point::new(10, 10), 11);
+///
+/// // Zoom in by a factor of 2.
+/// assert_eq!(circle.scale(2), Circle::new(Point::new(20, 20), 22));
+///
+/// // Zoom out to 2/3 of the original size, keeping the point at (10, 10) at the same position.
+/// assert_eq!(
+///     circle.scale_around(Point::new(10, 10), 2, 3),
+///     Circle::new(Point::new(10, 10), 7)
+/// );
+/// ```
+///
+/// [`Rotate`]: trait.Rotate.html
+pub trait Scale: Sized {
+    /// Scales the object by `num / denom` relative to `center`.
+    ///
+    /// Scaled coordinates are rounded to the nearest integer, with halfway values rounded away
+    /// from `center`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero.
+    fn scale_around(&self, center: Point, num: u32, denom: u32) -> Self;
+
+    /// Scales the object by an integer factor relative to the origin.
+    fn scale(&self, factor: u32) -> Self {
+        self.scale_around(Point::zero(), factor, 1)
+    }
+}
+
+/// Rotates a point by 90° clockwise around `center`.
+pub(crate) fn rotate_point_90(point: Point, center: Point) -> Point {
+    Point::new(center.x + center.y - point.y, center.y - center.x + point.x)
+}
+
+/// Mirrors a point at the vertical line through `x = axis`.
+pub(crate) fn mirror_point_x(point: Point, axis: i32) -> Point {
+    Point::new(2 * axis - point.x, point.y)
+}
+
+/// Mirrors a point at the horizontal line through `y = axis`.
+pub(crate) fn mirror_point_y(point: Point, axis: i32) -> Point {
+    Point::new(point.x, 2 * axis - point.y)
+}
+
+/// Scales a point by `num / denom` relative to `center`.
+pub(crate) fn scale_point(point: Point, center: Point, num: u32, denom: u32) -> Point {
+    let scale = |value: i32, center: i32| {
+        let delta = i64::from(value - center) * i64::from(num);
+
+        center + div_round(delta, i64::from(denom)) as i32
+    };
+
+    Point::new(scale(point.x, center.x), scale(point.y, center.y))
+}
+
+/// Scales a length by `num / denom`.
+pub(crate) fn scale_length(length: u32, num: u32, denom: u32) -> u32 {
+    let length = u64::from(length) * u64::from(num);
+    let denom = u64::from(denom);
+
+    ((length + denom / 2) / denom) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTarget,
+        geometry::{AngleUnit, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            Arc, Circle, CornerRadiiBuilder, Ellipse, Line, PointsIter, Rectangle,
+            RoundedRectangle, Sector, Triangle,
+        },
+        Pixel,
+    };
+
+    const CENTER: Point = Point::new(30, 30);
+
+    /// Draws the points of a primitive after applying `f` to each point.
+    fn draw_mapped<T, F>(primitive: &T, f: F) -> MockDisplay<BinaryColor>
+    where
+        T: PointsIter,
+        F: Fn(Point) -> Point,
+    {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        display
+            .draw_iter(
+                primitive
+                    .points()
+                    .map(|point| Pixel(f(point), BinaryColor::On)),
+            )
+            .unwrap();
+
+        display
+    }
+
+    /// Checks that a transformed primitive covers the same points as the transformed points of
+    /// the original primitive.
+    fn check_mapped<T, F>(primitive: &T, transformed: &T, f: F, name: &str)
+    where
+        T: PointsIter + core::fmt::Debug,
+        F: Fn(Point) -> Point,
+    {
+        let expected = draw_mapped(primitive, f);
+        let display = draw_mapped(transformed, |point| point);
+
+        display.assert_eq_with_message(&expected, |f| write!(f, "{} of {:?}", name, primitive));
+    }
+
+    /// Checks that rotating and mirroring a primitive matches transforming its points.
+    fn check<T>(primitive: T)
+    where
+        T: Rotate + Mirror + PointsIter + core::fmt::Debug,
+    {
+        let rotate_90 = |point| rotate_point_90(point, CENTER);
+
+        check_mapped(&primitive, &primitive.rotate_90(CENTER), rotate_90, "90°");
+        check_mapped(
+            &primitive,
+            &primitive.rotate_180(CENTER),
+            |point| rotate_90(rotate_90(point)),
+            "180°",
+        );
+        check_mapped(
+            &primitive,
+            &primitive.rotate_270(CENTER),
+            |point| rotate_90(rotate_90(rotate_90(point))),
+            "270°",
+        );
+        check_mapped(
+            &primitive,
+            &primitive.mirror_x(CENTER.x),
+            |point| mirror_point_x(point, CENTER.x),
+            "mirror X",
+        );
+        check_mapped(
+            &primitive,
+            &primitive.mirror_y(CENTER.y),
+            |point| mirror_point_y(point, CENTER.y),
+            "mirror Y",
+        );
+    }
+
+    #[test]
+    fn rotate_point() {
+        let center = Point::new(2, 3);
+
+        assert_eq!(rotate_point_90(Point::new(5, 3), center), Point::new(2, 6));
+        assert_eq!(rotate_point_90(Point::new(2, 6), center), Point::new(-1, 3));
+        assert_eq!(rotate_point_90(center, center), center);
+    }
+
+    #[test]
+    fn mirror_point() {
+        assert_eq!(mirror_point_x(Point::new(5, 3), 2), Point::new(-1, 3));
+        assert_eq!(mirror_point_y(Point::new(5, 3), 2), Point::new(5, 1));
+    }
+
+    #[test]
+    fn rectangles() {
+        check(Rectangle::new(Point::new(25, 22), Size::new(10, 4)));
+        check(Rectangle::new(Point::new(31, 32), Size::new(3, 7)));
+        check(RoundedRectangle::new(
+            Rectangle::new(Point::new(22, 24), Size::new(20, 11)),
+            CornerRadiiBuilder::new()
+                .top_left(Size::new(6, 4))
+                .bottom_right(Size::new(3, 5))
+                .build(),
+        ));
+    }
+
+    #[test]
+    fn lines_and_triangles() {
+        check(Line::new(Point::new(20, 25), Point::new(33, 29)));
+        check(Triangle::new(
+            Point::new(21, 22),
+            Point::new(35, 26),
+            Point::new(27, 37),
+        ));
+    }
+
+    #[test]
+    fn circles_and_ellipses() {
+        check(Circle::new(Point::new(24, 20), 9));
+        check(Circle::new(Point::new(24, 20), 10));
+        check(Ellipse::new(Point::new(20, 26), Size::new(15, 8)));
+    }
+
+    #[test]
+    fn arcs_and_sectors() {
+        check(Arc::new(Point::new(22, 22), 15, 30.0.deg(), 120.0.deg()));
+        check(Arc::new(Point::new(22, 22), 14, 0.0.deg(), -90.0.deg()));
+        check(Sector::new(Point::new(22, 22), 15, 45.0.deg(), 90.0.deg()));
+    }
+
+    #[test]
+    fn scale_point_rounding() {
+        let center = Point::new(10, 10);
+
+        assert_eq!(
+            scale_point(Point::new(13, 7), center, 2, 1),
+            Point::new(16, 4)
+        );
+        assert_eq!(
+            scale_point(Point::new(13, 7), center, 1, 2),
+            Point::new(12, 8)
+        );
+        assert_eq!(
+            scale_point(Point::new(11, 9), center, 1, 2),
+            Point::new(11, 9)
+        );
+        assert_eq!(scale_point(center, center, 7, 3), center);
+    }
+
+    #[test]
+    fn scale_length_rounding() {
+        assert_eq!(scale_length(10, 3, 2), 15);
+        assert_eq!(scale_length(5, 1, 2), 3);
+        assert_eq!(scale_length(4, 1, 3), 1);
+        assert_eq!(scale_length(u32::max_value(), 1, 1), u32::max_value());
+    }
+
+    #[test]
+    fn scale_adjacent_rectangles() {
+        let left = Rectangle::new(Point::new(0, 0), Size::new(3, 3));
+        let right = Rectangle::new(Point::new(3, 0), Size::new(4, 3));
+
+        for &(num, denom) in &[(1, 2), (2, 3), (5, 4), (3, 1)] {
+            let left = left.scale_around(Point::new(1, 1), num, denom);
+            let right = right.scale_around(Point::new(1, 1), num, denom);
+
+            assert_eq!(
+                left.top_left.x + left.size.width as i32,
+                right.top_left.x,
+                "{}/{}",
+                num,
+                denom
+            );
+        }
+    }
+
+    #[test]
+    fn scale_primitives() {
+        assert_eq!(
+            Line::new(Point::new(1, 2), Point::new(3, -4)).scale(3),
+            Line::new(Point::new(3, 6), Point::new(9, -12))
+        );
+        assert_eq!(
+            Ellipse::new(Point::new(4, 4), Size::new(10, 5)).scale_around(Point::new(4, 4), 1, 2),
+            Ellipse::new(Point::new(4, 4), Size::new(5, 3))
+        );
+        assert_eq!(
+            Arc::new(Point::new(2, 2), 10, 30.0.deg(), 90.0.deg()).scale(2),
+            Arc::new(Point::new(4, 4), 20, 30.0.deg(), 90.0.deg())
+        );
+
+        let rounded_rectangle = RoundedRectangle::new(
+            Rectangle::new(Point::new(2, 2), Size::new(20, 10)),
+            CornerRadiiBuilder::new().top_left(Size::new(6, 4)).build(),
+        );
+        assert_eq!(
+            rounded_rectangle.scale(2),
+            RoundedRectangle::new(
+                Rectangle::new(Point::new(4, 4), Size::new(40, 20)),
+                CornerRadiiBuilder::new().top_left(Size::new(12, 8)).build(),
+            )
+        );
+    }
+}
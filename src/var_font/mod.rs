@@ -0,0 +1,262 @@
+//! Proportional bitmap fonts.
+//!
+//! In contrast to [monospaced fonts], the glyphs of a proportional font can have different widths.
+//! Narrow glyphs, like `i` or `l`, take up less space than wide glyphs, like `m` or `w`, which
+//! makes text more compact and easier to read.
+//!
+//! A [`VarFont`] uses the same image layout as a [`MonoFont`]: all glyphs are stored in a grid of
+//! equally sized cells. The [`GlyphMetrics`] of each glyph define which columns of the cell are
+//! used by the glyph. Text drawn with a [`VarTextStyle`] advances by the width of each glyph,
+//! instead of the width of the cell.
+//!
+//! # Examples
+//!
+//! This example defines a font with two glyphs, a narrow `i` and a wide `m`, and uses it to draw
+//! a text.
+//!
+//! ```
+//! use embedded_graphics::{
+//!     geometry::Size,
+//!     image::ImageRaw,
+//!     mono_font::{mapping::StrGlyphMapping, DecorationDimensions},
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//!     var_font::{GlyphMetrics, VarFont, VarTextStyle},
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::new();
+//!
+//! const FONT: VarFont = VarFont {
+//!     image: ImageRaw::new_binary(&[0b010_111_00, 0b010_111_00, 0b010_101_00], 6),
+//!     glyph_mapping: &StrGlyphMapping::new("im", 0),
+//!     glyph_metrics: &[GlyphMetrics::new(1, 1), GlyphMetrics::new(0, 3)],
+//!     character_size: Size::new(3, 3),
+//!     character_spacing: 1,
+//!     baseline: 2,
+//!     strikethrough: DecorationDimensions::default_strikethrough(3),
+//!     underline: DecorationDimensions::default_underline(3),
+//! };
+//!
+//! let style = VarTextStyle::new(&FONT, BinaryColor::On);
+//!
+//! let next = Text::with_baseline("imi", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+//! assert_eq!(next, Point::new(7, 0));
+//! #
+//! # display.assert_pattern(&[
+//! #     "# ### #",
+//! #     "# ### #",
+//! #     "# # # #",
+//! # ]);
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
+//! [monospaced fonts]: ../mono_font/index.html
+//! [`MonoFont`]: ../mono_font/struct.MonoFont.html
+//! [`VarFont`]: struct.VarFont.html
+//! [`GlyphMetrics`]: struct.GlyphMetrics.html
+//! [`VarTextStyle`]: struct.VarTextStyle.html
+
+mod var_text_style;
+
+use core::fmt;
+
+pub use var_text_style::VarTextStyle;
+
+use crate::{
+    geometry::{OriginDimensions, Point, Size},
+    image::{ImageRaw, SubImage},
+    mono_font::{mapping::GlyphMapping, DecorationDimensions},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+};
+
+/// Proportional bitmap font.
+///
+/// See the [module documentation] for more information about proportional fonts.
+///
+/// [module documentation]: index.html
+#[derive(Clone, Copy)]
+pub struct VarFont<'a> {
+    /// Raw image data containing the font.
+    pub image: ImageRaw<'a, BinaryColor>,
+
+    /// Size of a glyph cell in the image in pixel.
+    ///
+    /// The height of the cell is also used as the height of all glyphs.
+    pub character_size: Size,
+
+    /// Spacing between characters.
+    ///
+    /// The spacing defines how many empty pixels are added horizontally between adjacent characters
+    /// on a single line of text.
+    pub character_spacing: u32,
+
+    /// The baseline.
+    ///
+    /// Offset from the top of the glyph bounding box to the baseline.
+    pub baseline: u32,
+
+    /// Strikethrough decoration dimensions.
+    pub strikethrough: DecorationDimensions,
+
+    /// Underline decoration dimensions.
+    pub underline: DecorationDimensions,
+
+    /// Glyph mapping.
+    pub glyph_mapping: &'a dyn GlyphMapping,
+
+    /// Glyph metrics.
+    ///
+    /// The metrics are indexed by the glyph index returned by the glyph mapping. Glyphs without an
+    /// entry in this slice use the full width of the glyph cell.
+    pub glyph_metrics: &'a [GlyphMetrics],
+}
+
+impl VarFont<'_> {
+    /// Returns the metrics for a glyph.
+    fn metrics(&self, glyph_index: usize) -> GlyphMetrics {
+        self.glyph_metrics
+            .get(glyph_index)
+            .copied()
+            .unwrap_or_else(|| GlyphMetrics::new(0, self.character_size.width))
+    }
+
+    /// Returns the width of a glyph.
+    pub(crate) fn glyph_width(&self, c: char) -> u32 {
+        self.metrics(self.glyph_mapping.index(c)).width
+    }
+
+    /// Returns a subimage for a glyph.
+    pub(crate) fn glyph(&self, c: char) -> SubImage<'_, ImageRaw<'_, BinaryColor>> {
+        let glyphs_per_row = self.image.size().width / self.character_size.width;
+
+        let glyph_index = self.glyph_mapping.index(c);
+        let metrics = self.metrics(glyph_index);
+
+        let glyph_index = glyph_index as u32;
+        let row = glyph_index / glyphs_per_row;
+
+        // Top left corner of the glyph, in pixels
+        let char_x = (glyph_index - (row * glyphs_per_row)) * self.character_size.width;
+        let char_y = row * self.character_size.height;
+
+        SubImage::new_unchecked(
+            &self.image,
+            Rectangle::new(
+                Point::new((char_x + metrics.offset) as i32, char_y as i32),
+                Size::new(metrics.width, self.character_size.height),
+            ),
+        )
+    }
+}
+
+impl PartialEq for VarFont<'_> {
+    #[allow(trivial_casts)]
+    fn eq(&self, other: &Self) -> bool {
+        self.image == other.image
+            && self.character_size == other.character_size
+            && self.character_spacing == other.character_spacing
+            && self.baseline == other.baseline
+            && self.strikethrough == other.strikethrough
+            && self.underline == other.underline
+            && self.glyph_metrics == other.glyph_metrics
+            && core::ptr::eq(
+                self.glyph_mapping as *const dyn GlyphMapping as *const u8,
+                other.glyph_mapping as *const dyn GlyphMapping as *const u8,
+            )
+    }
+}
+
+impl fmt::Debug for VarFont<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VarFont")
+            .field("image", &self.image)
+            .field("character_size", &self.character_size)
+            .field("character_spacing", &self.character_spacing)
+            .field("baseline", &self.baseline)
+            .field("strikethrough", &self.strikethrough)
+            .field("underline", &self.underline)
+            .field("glyph_mapping", &"?")
+            .field("glyph_metrics", &self.glyph_metrics)
+            // MSRV 1.53.0: use `finish_non_exhaustive`
+            .finish()
+    }
+}
+
+/// Glyph metrics.
+///
+/// The metrics define which columns of a glyph cell in a [`VarFont`] image are used by a glyph.
+///
+/// [`VarFont`]: struct.VarFont.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct GlyphMetrics {
+    /// Offset from the left edge of the glyph cell to the first column of the glyph.
+    pub offset: u32,
+
+    /// Width of the glyph.
+    ///
+    /// The width is used to advance the text position after the glyph is drawn.
+    pub width: u32,
+}
+
+impl GlyphMetrics {
+    /// Creates new glyph metrics.
+    pub const fn new(offset: u32, width: u32) -> Self {
+        Self { offset, width }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        image::Image, mock_display::MockDisplay, mono_font::mapping::StrGlyphMapping, Drawable,
+    };
+
+    /// Font with a narrow `i`, a wide `m` and a `?` replacement glyph without metrics.
+    #[allow(clippy::unusual_byte_groupings)]
+    pub const TEST_FONT: VarFont = VarFont {
+        image: ImageRaw::new_binary(
+            &[
+                0b010_111_11,
+                0b1000_0000, //
+                0b010_111_00,
+                0b1000_0000, //
+                0b010_101_01,
+                0b0000_0000, //
+            ],
+            9,
+        ),
+        glyph_mapping: &StrGlyphMapping::new("im?", 2),
+        glyph_metrics: &[GlyphMetrics::new(1, 1), GlyphMetrics::new(0, 3)],
+        character_size: Size::new(3, 3),
+        character_spacing: 1,
+        baseline: 2,
+        strikethrough: DecorationDimensions::new(1, 1),
+        underline: DecorationDimensions::new(4, 1),
+    };
+
+    #[test]
+    fn glyph_width() {
+        assert_eq!(TEST_FONT.glyph_width('i'), 1);
+        assert_eq!(TEST_FONT.glyph_width('m'), 3);
+        assert_eq!(TEST_FONT.glyph_width('?'), 3);
+        assert_eq!(TEST_FONT.glyph_width('x'), 3);
+    }
+
+    #[test]
+    fn glyph() {
+        let draw = |c| {
+            let mut display = MockDisplay::new();
+            Image::new(&TEST_FONT.glyph(c), Point::zero())
+                .draw(&mut display)
+                .unwrap();
+            display
+        };
+
+        draw('i').assert_pattern(&["#", "#", "#"]);
+        draw('m').assert_pattern(&["###", "###", "#.#"]);
+        draw('x').assert_pattern(&["###", "..#", ".#."]);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::Image,
+    mono_font::draw_target::{Background, Both, Foreground, MonoFontDrawTarget},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+    var_font::VarFont,
+    Drawable, SaturatingCast,
+};
+
+/// Style properties for text using a proportional font.
+///
+/// A `VarTextStyle` can be applied to a [`Text`] object to define how the text is drawn.
+///
+/// Because `VarTextStyle` has the [`non_exhaustive`] attribute, it cannot be created using a
+/// struct literal. To create a `VarTextStyle` with a given text color and transparent
+/// background, use the [`new`] method. The other properties can be changed by using the
+/// [`CharacterStyle`] trait.
+///
+/// [`Text`]: ../text/struct.Text.html
+/// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
+/// [`new`]: #method.new
+/// [`CharacterStyle`]: ../text/renderer/trait.CharacterStyle.html
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct VarTextStyle<'a, C> {
+    /// Text color.
+    pub text_color: Option<C>,
+
+    /// Background color.
+    pub background_color: Option<C>,
+
+    /// Underline color.
+    pub underline_color: DecorationColor<C>,
+
+    /// Strikethrough color.
+    pub strikethrough_color: DecorationColor<C>,
+
+    /// Font.
+    pub font: &'a VarFont<'a>,
+}
+
+impl<'a, C: PixelColor> VarTextStyle<'a, C> {
+    /// Creates a text style with transparent background.
+    pub fn new(font: &'a VarFont<'a>, text_color: C) -> Self {
+        Self {
+            text_color: Some(text_color),
+            background_color: None,
+            underline_color: DecorationColor::None,
+            strikethrough_color: DecorationColor::None,
+            font,
+        }
+    }
+
+    /// Returns `true` if the style is transparent.
+    ///
+    /// Drawing a `Text` with a transparent `VarTextStyle` will not draw any pixels.
+    ///
+    /// [`Text`]: ../text/struct.Text.html
+    pub fn is_transparent(&self) -> bool {
+        self.text_color.is_none()
+            && self.background_color.is_none()
+            && self.underline_color.is_none()
+            && self.strikethrough_color.is_none()
+    }
+
+    /// Returns the width of a string, excluding the spacing after the last character.
+    fn string_width(&self, text: &str) -> u32 {
+        let (width, count) = text.chars().fold((0, 0), |(width, count), c| {
+            (width + self.font.glyph_width(c), count + 1)
+        });
+
+        (width + count * self.font.character_spacing).saturating_sub(self.font.character_spacing)
+    }
+
+    fn draw_decorations<D>(
+        &self,
+        width: u32,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(color) = self.strikethrough_color.to_color(self.text_color) {
+            let rect = self.font.strikethrough.to_rectangle(position, width);
+            target.fill_solid(&rect, color)?;
+        }
+
+        if let Some(color) = self.underline_color.to_color(self.text_color) {
+            let rect = self.font.underline.to_rectangle(position, width);
+            target.fill_solid(&rect, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw_string_binary<D>(
+        &self,
+        text: &str,
+        mut position: Point,
+        mut target: D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor>,
+    {
+        let spacing = self.font.character_spacing;
+
+        for (index, c) in text.chars().enumerate() {
+            if index > 0 && spacing > 0 {
+                // Fill space between characters if background color is set.
+                if self.background_color.is_some() {
+                    target.fill_solid(
+                        &Rectangle::new(
+                            position,
+                            Size::new(spacing, self.font.character_size.height),
+                        ),
+                        BinaryColor::Off,
+                    )?;
+                }
+
+                position.x += spacing.saturating_cast();
+            }
+
+            let glyph = self.font.glyph(c);
+            Image::new(&glyph, position).draw(&mut target)?;
+
+            position.x += self.font.glyph_width(c).saturating_cast();
+        }
+
+        Ok(position)
+    }
+
+    /// Returns the vertical offset between the line position and the top edge of the bounding box.
+    fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        match baseline {
+            Baseline::Top => 0,
+            Baseline::Bottom => self
+                .font
+                .character_size
+                .height
+                .saturating_sub(1)
+                .saturating_cast(),
+            Baseline::Middle => {
+                (self.font.character_size.height.saturating_sub(1) / 2).saturating_cast()
+            }
+            Baseline::Alphabetic => self.font.baseline.saturating_cast(),
+        }
+    }
+}
+
+impl<C: PixelColor> TextRenderer for VarTextStyle<'_, C> {
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+
+        let next = match (self.text_color, self.background_color) {
+            (Some(text_color), Some(background_color)) => self.draw_string_binary(
+                text,
+                position,
+                MonoFontDrawTarget::new(target, Both(text_color, background_color)),
+            )?,
+            (Some(text_color), None) => self.draw_string_binary(
+                text,
+                position,
+                MonoFontDrawTarget::new(target, Foreground(text_color)),
+            )?,
+            (None, Some(background_color)) => self.draw_string_binary(
+                text,
+                position,
+                MonoFontDrawTarget::new(target, Background(background_color)),
+            )?,
+            (None, None) => position + Size::new(self.string_width(text), 0),
+        };
+
+        if next.x > position.x {
+            let width = (next.x - position.x) as u32;
+            self.draw_decorations(width, position, target)?;
+        }
+
+        Ok(next + Point::new(0, self.baseline_offset(baseline)))
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+
+        if width != 0 {
+            if let Some(background_color) = self.background_color {
+                target.fill_solid(
+                    &Rectangle::new(position, Size::new(width, self.font.character_size.height)),
+                    background_color,
+                )?;
+            }
+
+            self.draw_decorations(width, position, target)?;
+        }
+
+        Ok(position + Point::new(width.saturating_cast(), self.baseline_offset(baseline)))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let bb_position = position - Point::new(0, self.baseline_offset(baseline));
+
+        let bb_width = self.string_width(text);
+
+        let bb_height = if self.underline_color != DecorationColor::None {
+            self.font.underline.height + self.font.underline.offset
+        } else {
+            self.font.character_size.height
+        };
+
+        let bb_size = Size::new(bb_width, bb_height);
+
+        TextMetrics {
+            bounding_box: Rectangle::new(bb_position, bb_size),
+            next_position: position + bb_size.x_axis(),
+        }
+    }
+
+    fn line_height(&self) -> u32 {
+        self.font.character_size.height
+    }
+}
+
+impl<C: PixelColor> CharacterStyle for VarTextStyle<'_, C> {
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Dimensions,
+        mock_display::MockDisplay,
+        text::{Alignment, Text},
+        var_font::tests::TEST_FONT,
+    };
+
+    fn draw(text: &str, style: VarTextStyle<BinaryColor>) -> (MockDisplay<BinaryColor>, Point) {
+        let mut display = MockDisplay::new();
+        let next = Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        (display, next)
+    }
+
+    #[test]
+    fn advance_by_glyph_width() {
+        let (display, next) = draw("imi?", VarTextStyle::new(&TEST_FONT, BinaryColor::On));
+
+        display.assert_pattern(&[
+            "# ### # ###", //
+            "# ### #   #", //
+            "# # # #  # ", //
+        ]);
+        assert_eq!(next, Point::new(11, 0));
+    }
+
+    #[test]
+    fn background() {
+        let mut style = VarTextStyle::new(&TEST_FONT, BinaryColor::On);
+        style.set_background_color(Some(BinaryColor::Off));
+
+        let (display, next) = draw("mi", style);
+
+        display.assert_pattern(&[
+            "###.#", //
+            "###.#", //
+            "#.#.#", //
+        ]);
+        assert_eq!(next, Point::new(5, 0));
+    }
+
+    #[test]
+    fn decorations() {
+        let mut style = VarTextStyle::new(&TEST_FONT, BinaryColor::On);
+        style.set_background_color(Some(BinaryColor::Off));
+        style.set_strikethrough_color(DecorationColor::TextColor);
+        style.set_underline_color(DecorationColor::TextColor);
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Text::with_baseline("ii", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#.#", //
+            "###", //
+            "#.#", //
+            "   ", //
+            "###", //
+        ]);
+    }
+
+    #[test]
+    fn measure_string() {
+        let style = VarTextStyle::new(&TEST_FONT, BinaryColor::On);
+
+        let metrics = style.measure_string("imi", Point::new(1, 2), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(1, 2), Size::new(7, 3))
+        );
+        assert_eq!(metrics.next_position, Point::new(8, 2));
+
+        let metrics = style.measure_string("", Point::new(1, 2), Baseline::Top);
+        assert_eq!(metrics.bounding_box.size, Size::new(0, 3));
+    }
+
+    #[test]
+    fn transparent_style_advances_by_glyph_width() {
+        let mut style = VarTextStyle::new(&TEST_FONT, BinaryColor::On);
+        style.set_text_color(None);
+        assert!(style.is_transparent());
+
+        let (display, next) = draw("imi", style);
+
+        display.assert_pattern(&[]);
+        assert_eq!(next, Point::new(7, 0));
+    }
+
+    #[test]
+    fn right_aligned() {
+        let style = VarTextStyle::new(&TEST_FONT, BinaryColor::On);
+        let text = Text::with_alignment("mi", Point::new(10, 0), style, Alignment::Right);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(6, -2), Size::new(5, 3))
+        );
+    }
+}